@@ -196,6 +196,16 @@ pub mod math_utils {
 	}
 	#[inline(always)]
 	#[must_use]
+	pub fn ln<T: RealField>(value: &T) -> T {
+		T::ln_impl((value).by_ref())
+	}
+	#[inline(always)]
+	#[must_use]
+	pub fn exp<T: RealField>(value: &T) -> T {
+		T::exp_impl((value).by_ref())
+	}
+	#[inline(always)]
+	#[must_use]
 	pub fn recip<T: ComplexField>(value: &T) -> T {
 		T::recip_impl((value).by_ref())
 	}
@@ -368,6 +378,89 @@ fn sqrt_impl<T: RealField>(re: T, im: T) -> (T, T) {
 	(out_re, out_im)
 }
 
+// default implementation of `RealField::ln_impl`, using only arithmetic. `x` is split as
+// $y 2^k$ with $y \in [1/\sqrt 2, \sqrt 2)$, and $\ln y = 2 \operatorname{atanh}((y - 1) / (y + 1))$
+#[faer_macros::math]
+fn ln_default<T: RealField>(x: T) -> T {
+	if is_nan(x) || x < zero() {
+		return nan();
+	}
+	if x == zero() {
+		return -infinity::<T>();
+	}
+	if !is_finite(x) {
+		return x;
+	}
+
+	let two = from_f64::<T>(2.0);
+	let sqrt2 = sqrt(two);
+	let mut y = x;
+	let mut k = 0i64;
+	while y >= sqrt2 {
+		y = y / two;
+		k += 1;
+	}
+	while y * sqrt2 < one() {
+		y = y * two;
+		k -= 1;
+	}
+
+	let atanh = |z: T| {
+		let z2 = z * z;
+		let mut sum = copy(z);
+		let mut pow = z;
+		let mut n = 1.0;
+		loop {
+			pow = pow * z2;
+			n += 2.0;
+			let term = pow / from_f64::<T>(n);
+			if abs(term) <= eps::<T>() * abs(sum) {
+				break;
+			}
+			sum = sum + term;
+		}
+		sum * two
+	};
+	let ln2 = atanh(recip(from_f64::<T>(3.0)));
+	atanh((y - one()) / (y + one())) + from_f64::<T>(k as f64) * ln2
+}
+
+// default implementation of `RealField::exp_impl`, using only arithmetic. computes
+// $\exp(x 2^{-s})$ with a taylor series, where $|x 2^{-s}| \le 1/2$, then squares it $s$ times
+#[faer_macros::math]
+fn exp_default<T: RealField>(x: T) -> T {
+	if is_nan(x) {
+		return x;
+	}
+	if !is_finite(x) {
+		return if x > zero() { x } else { zero() };
+	}
+
+	let half = from_f64::<T>(0.5);
+	let mut r = x;
+	let mut s = 0usize;
+	while abs(r) > half {
+		r = r * half;
+		s += 1;
+	}
+
+	let mut sum = one::<T>();
+	let mut term = one::<T>();
+	let mut n = 1.0;
+	loop {
+		term = term * r / from_f64::<T>(n);
+		if abs(term) <= eps::<T>() * abs(sum) {
+			break;
+		}
+		sum = sum + term;
+		n += 1.0;
+	}
+	for _ in 0..s {
+		sum = sum * sum;
+	}
+	sum
+}
+
 pub trait ByRef<T> {
 	fn by_ref(&self) -> &T;
 }
@@ -1152,6 +1245,62 @@ pub trait RealField:
 	fn max_positive_impl() -> Self;
 	fn sqrt_min_positive_impl() -> Self;
 	fn sqrt_max_positive_impl() -> Self;
+
+	/// natural logarithm. the default implementation only uses arithmetic, and should be
+	/// overridden with a native routine when one is available
+	fn ln_impl(value: &Self) -> Self {
+		ln_default(value.clone())
+	}
+	/// exponential. the default implementation only uses arithmetic, and should be overridden
+	/// with a native routine when one is available
+	fn exp_impl(value: &Self) -> Self {
+		exp_default(value.clone())
+	}
+}
+
+/// scalar type that can represent the values of the narrower type `T`, used as the working
+/// precision of mixed precision computations
+pub trait WidenFrom<T: ComplexField>: ComplexField {
+	/// converts `value` to the wider type
+	fn widen(value: &T) -> Self;
+	/// rounds `self` to the narrower type
+	fn narrow(&self) -> T;
+}
+
+impl<T: ComplexField> WidenFrom<T> for T {
+	#[inline]
+	fn widen(value: &T) -> Self {
+		value.clone()
+	}
+
+	#[inline]
+	fn narrow(&self) -> T {
+		self.clone()
+	}
+}
+
+impl WidenFrom<f32> for f64 {
+	#[inline]
+	fn widen(value: &f32) -> Self {
+		*value as f64
+	}
+
+	#[inline]
+	fn narrow(&self) -> f32 {
+		*self as f32
+	}
+}
+
+impl WidenFrom<Complex<f32>> for Complex<f64> {
+	#[inline]
+	fn widen(value: &Complex<f32>) -> Self {
+		Complex::new(value.re as f64, value.im as f64)
+	}
+
+	#[inline]
+	fn narrow(&self) -> Complex<f32> {
+		Complex::new(self.re as f32, self.im as f32)
+	}
 }
 
 impl ComplexField for f32 {
@@ -1490,6 +1639,16 @@ impl RealField for f32 {
 	fn nbits_impl() -> usize {
 		Self::MANTISSA_DIGITS as usize
 	}
+
+	#[inline(always)]
+	fn ln_impl(value: &Self) -> Self {
+		libm::logf(*value)
+	}
+
+	#[inline(always)]
+	fn exp_impl(value: &Self) -> Self {
+		libm::expf(*value)
+	}
 }
 
 impl ComplexField for f64 {
@@ -1828,6 +1987,16 @@ impl RealField for f64 {
 	fn nbits_impl() -> usize {
 		Self::MANTISSA_DIGITS as usize
 	}
+
+	#[inline(always)]
+	fn ln_impl(value: &Self) -> Self {
+		libm::log(*value)
+	}
+
+	#[inline(always)]
+	fn exp_impl(value: &Self) -> Self {
+		libm::exp(*value)
+	}
 }
 
 impl<T: RealField> ComplexField for Complex<T> {
@@ -3332,6 +3501,14 @@ impl RealField for Symbolic {
 	fn sqrt_max_positive_impl() -> Self {
 		Self
 	}
+
+	fn ln_impl(_: &Self) -> Self {
+		Self
+	}
+
+	fn exp_impl(_: &Self) -> Self {
+		Self
+	}
 }
 
 impl ComplexField for Symbolic {
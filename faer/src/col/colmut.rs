@@ -71,6 +71,18 @@ impl<'a, T> ColMut<'a, T> {
 	}
 }
 
+impl<T, RStride: Stride> ColMut<'_, T, usize, RStride> {
+	#[inline]
+	#[track_caller]
+	/// see [`ColRef::cross3`]
+	pub fn cross3(&self, other: ColRef<'_, T, usize, impl Stride>) -> Col<T>
+	where
+		T: ComplexField,
+	{
+		self.rb().cross3(other)
+	}
+}
+
 impl<'a, T, Rows: Shape, RStride: Stride> ColMut<'a, T, Rows, RStride> {
 	/// creates a `ColMut` from pointers to the column vector data, number of rows, and row stride
 	///
@@ -145,6 +157,13 @@ impl<'a, T, Rows: Shape, RStride: Stride> ColMut<'a, T, Rows, RStride> {
 		self.into_const().split_at_row(row)
 	}
 
+	#[inline]
+	#[track_caller]
+	/// see [`ColRef::split_at`]
+	pub fn split_at(self, row: IdxInc<Rows>) -> (ColRef<'a, T, usize, RStride>, ColRef<'a, T, usize, RStride>) {
+		self.into_const().split_at(row)
+	}
+
 	#[inline(always)]
 	/// see [`ColRef::transpose`]
 	pub fn transpose(self) -> RowRef<'a, T, Rows, RStride> {
@@ -360,6 +379,15 @@ impl<'a, T, Rows: Shape, RStride: Stride> ColMut<'a, T, Rows, RStride> {
 		self.rb().as_mat().sum()
 	}
 
+	#[inline]
+	/// see [`ColRef::dot`]
+	pub fn dot(&self, other: ColRef<'_, T, Rows, impl Stride>) -> T
+	where
+		T: ComplexField,
+	{
+		self.rb().dot(other)
+	}
+
 	/// see [`ColRef::cloned`]
 	#[inline]
 	pub fn cloned(&self) -> Col<T, Rows>
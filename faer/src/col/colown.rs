@@ -112,6 +112,44 @@ impl<T, Rows: Shape> Col<T, Rows> {
 	}
 }
 
+impl<T> Col<T> {
+	/// returns a new column holding the cloned elements of `slice`
+	#[inline]
+	pub fn from_slice(slice: &[T]) -> Self
+	where
+		T: Clone,
+	{
+		ColRef::from_slice(slice).cloned()
+	}
+
+	#[inline]
+	#[track_caller]
+	/// see [`ColRef::cross3`]
+	pub fn cross3(&self, other: ColRef<'_, T, usize, impl Stride>) -> Col<T>
+	where
+		T: ComplexField,
+	{
+		self.as_ref().cross3(other)
+	}
+
+	/// returns a new column obtained by stacking the given columns vertically
+	pub fn concat(cols: &[ColRef<'_, T>]) -> Self
+	where
+		T: Clone,
+	{
+		let nrows = cols.iter().map(|col| col.nrows()).sum();
+		let mut iter = cols.iter().flat_map(|col| col.iter());
+		Self::from_fn(nrows, |_| iter.next().unwrap().clone())
+	}
+}
+
+impl<T> FromIterator<T> for Col<T> {
+	fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+		let mut iter = iter.into_iter().collect::<alloc::vec::Vec<_>>().into_iter();
+		Self::from_fn(iter.len(), |_| iter.next().unwrap())
+	}
+}
+
 impl<T, Rows: Shape> Col<T, Rows> {
 	/// returns the number of rows of the column
 	#[inline]
@@ -182,6 +220,15 @@ impl<T, Rows: Shape> Col<T, Rows> {
 		self.rb().as_mat().sum()
 	}
 
+	#[inline]
+	/// see [`ColRef::dot`]
+	pub fn dot(&self, other: ColRef<'_, T, Rows, impl Stride>) -> T
+	where
+		T: ComplexField,
+	{
+		self.rb().dot(other)
+	}
+
 	/// see [`ColRef::cloned`]
 	#[inline]
 	pub fn cloned(&self) -> Col<T, Rows>
@@ -264,6 +311,13 @@ impl<T, Rows: Shape> Col<T, Rows> {
 		self.as_ref().split_at_row(row)
 	}
 
+	#[inline]
+	#[track_caller]
+	/// see [`ColRef::split_at`]
+	pub fn split_at(&self, row: IdxInc<Rows>) -> (ColRef<'_, T, usize>, ColRef<'_, T, usize>) {
+		self.as_ref().split_at(row)
+	}
+
 	#[inline(always)]
 	/// see [`ColRef::transpose`]
 	pub fn transpose(&self) -> RowRef<'_, T, Rows> {
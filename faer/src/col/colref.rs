@@ -165,6 +165,19 @@ impl<'a, T, Rows: Shape, RStride: Stride> ColRef<'a, T, Rows, RStride> {
 		}
 	}
 
+	/// splits the column at the given row, returning the top and bottom parts, in that order
+	///
+	/// this is equivalent to [`ColRef::split_at_row`]
+	///
+	/// # panics
+	/// the function panics if the following condition is violated:
+	/// * `row <= self.nrows()`
+	#[inline]
+	#[track_caller]
+	pub fn split_at(self, row: IdxInc<Rows>) -> (ColRef<'a, T, usize, RStride>, ColRef<'a, T, usize, RStride>) {
+		self.split_at_row(row)
+	}
+
 	/// returns a view over the transpose of `self`
 	#[inline(always)]
 	pub fn transpose(self) -> RowRef<'a, T, Rows, RStride> {
@@ -467,6 +480,37 @@ impl<'a, T, Rows: Shape, RStride: Stride> ColRef<'a, T, Rows, RStride> {
 	{
 		self.rb().as_mat().sum()
 	}
+
+	/// returns the inner product $\sum_i \bar a_i b_i$ of `self` and `other`
+	#[inline]
+	pub fn dot(&self, other: ColRef<'_, T, Rows, impl Stride>) -> T
+	where
+		T: ComplexField,
+	{
+		crate::linalg::matmul::dot::inner_prod(self.rb().transpose().as_dyn_stride(), Conj::Yes, other.as_dyn_stride(), Conj::No)
+	}
+}
+
+impl<T, RStride: Stride> ColRef<'_, T, usize, RStride> {
+	/// returns the cross product $a \times b$ of `self` and `other`
+	///
+	/// # panics
+	/// panics if `self` or `other` doesn't have exactly three elements
+	#[track_caller]
+	#[math]
+	pub fn cross3(&self, other: ColRef<'_, T, usize, impl Stride>) -> Col<T>
+	where
+		T: ComplexField,
+	{
+		assert!(all(self.nrows() == 3, other.nrows() == 3));
+
+		let a = self.rb();
+		let b = other;
+		Col::from_fn(3, |i| {
+			let (j, k) = ((i + 1) % 3, (i + 2) % 3);
+			a[j] * b[k] - a[k] * b[j]
+		})
+	}
 }
 
 impl<'a, T, Rows: Shape> ColRef<'a, T, Rows, ContiguousFwd> {
@@ -122,3 +122,53 @@ impl<T, Rows: Shape> AsMat<T> for Col<T, Rows> {
 		Col::zeros(rows)
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::assert;
+
+	#[test]
+	fn test_concat_split() {
+		let a = col![1.0, 2.0, 3.0];
+		let b = col![4.0];
+		let c = Col::<f64>::zeros(0);
+		let d = col![5.0, 6.0];
+
+		let x = Col::concat(&[a.as_ref(), b.as_ref(), c.as_ref(), d.as_ref()]);
+		assert!(x == col![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+
+		let (top, bot) = x.split_at(3);
+		assert!(top == a);
+		let (mid, bot) = bot.split_at(1);
+		assert!(mid == b);
+		assert!(bot == d);
+
+		let y: Col<f64> = (1..=6).map(|i| i as f64).collect();
+		assert!(y == x);
+		assert!(Col::<f64>::from_iter(core::iter::empty()).nrows() == 0);
+	}
+
+	#[test]
+	fn test_dot_cross3() {
+		use crate::{Row, c64};
+
+		let a = Col::from_slice(&[1.0, 2.0, 3.0]);
+		let b = col![4.0, -5.0, 6.0];
+		assert!(a.dot(b.as_ref()) == 12.0);
+		assert!(a.transpose().dot(b.transpose()) == 12.0);
+		assert!(Row::from_slice(&[1.0, 2.0, 3.0]) == a.transpose());
+
+		let c = a.cross3(b.as_ref());
+		assert!(c == col![27.0, 6.0, -13.0]);
+		assert!(all(c.dot(a.as_ref()) == 0.0, c.dot(b.as_ref()) == 0.0));
+
+		let z = col![c64::new(1.0, 2.0), c64::new(0.0, -1.0)];
+		let w = col![c64::new(3.0, 0.0), c64::new(1.0, 1.0)];
+		// conj(1 + 2i) * 3 + conj(-i) * (1 + i)
+		assert!(z.dot(w.as_ref()) == c64::new(2.0, -5.0));
+		assert!(z.dot(z.as_ref()) == c64::new(z.squared_norm_l2(), 0.0));
+
+		assert!(a.as_mat_ref().as_dyn_cols().col(0) == a.as_ref());
+	}
+}
@@ -161,6 +161,71 @@ impl Debug for C64Conj {
 	}
 }
 
+/// formats `x` with `digits` significant digits, in scientific notation if its magnitude is
+/// outside of $[10^{-3}, 10^4)$
+fn fmt_significant(x: f64, digits: usize) -> alloc::string::String {
+	let a = x.abs();
+	if !a.is_finite() {
+		return alloc::format!("{x:?}");
+	}
+	if a != 0.0 && !(1e-3..1e4).contains(&a) {
+		return alloc::format!("{:.*e}", digits - 1, x);
+	}
+
+	// number of digits before the decimal point, or minus the number of zeros after it
+	let mut decimals = digits as isize - 1;
+	if a != 0.0 {
+		let mut p = 1.0;
+		while a >= 10.0 * p {
+			p *= 10.0;
+			decimals -= 1;
+		}
+		while a < p {
+			p /= 10.0;
+			decimals += 1;
+		}
+	}
+	alloc::format!("{:.*}", Ord::max(decimals, 0) as usize, x)
+}
+
+/// formats a floating point scalar, or a complex number of them, with `digits` significant digits
+/// per component. returns `None` for other types
+pub fn debug_significant<T>(x: &T, digits: usize) -> Option<alloc::string::String> {
+	let id = nonstatic_typeid::<T>();
+	let fmt = |x: f64| fmt_significant(x, digits);
+	let cplx = |re: f64, im: f64| {
+		let im_sign = if im.is_sign_negative() { '-' } else { '+' };
+		alloc::format!("{} {im_sign} {}i", fmt(re), fmt(im.abs()))
+	};
+	let cplx_conj = |re: f64, neg_im: f64| {
+		let re_sign = if re.is_sign_negative() { '-' } else { '+' };
+		let im_sign = if neg_im.is_sign_negative() { '+' } else { '-' };
+		alloc::format!("{re_sign}{} {im_sign} {}i", fmt(re.abs()), fmt(neg_im.abs()))
+	};
+
+	unsafe {
+		if id == nonstatic_typeid::<f32>() {
+			Some(fmt(*(x as *const T as *const f32) as f64))
+		} else if id == nonstatic_typeid::<f64>() {
+			Some(fmt(*(x as *const T as *const f64)))
+		} else if id == nonstatic_typeid::<Complex<f32>>() {
+			let x = &*(x as *const T as *const C32);
+			Some(cplx(x.re as f64, x.im as f64))
+		} else if id == nonstatic_typeid::<Complex<f64>>() {
+			let x = &*(x as *const T as *const C64);
+			Some(cplx(x.re, x.im))
+		} else if id == nonstatic_typeid::<ComplexConj<f32>>() {
+			let x = &*(x as *const T as *const C32Conj);
+			Some(cplx_conj(x.re as f64, x.neg_im as f64))
+		} else if id == nonstatic_typeid::<ComplexConj<f64>>() {
+			let x = &*(x as *const T as *const C64Conj);
+			Some(cplx_conj(x.re, x.neg_im))
+		} else {
+			None
+		}
+	}
+}
+
 pub fn hijack_debug<T: Debug>(x: &T) -> &dyn Debug {
 	if nonstatic_typeid::<T>() == nonstatic_typeid::<Complex<f32>>() {
 		unsafe { &*(x as *const T as *const C32) }
@@ -153,7 +153,13 @@
 //!   as cpu feature detection at runtime
 //! - `rayon`: enabled by default. enables the `rayon` parallel backend and enables global
 //!   parallelism by default
-//! - `serde`: Enables serialization and deserialization of [`Mat`]
+//! - `serde`: enables serialization and deserialization of [`Mat`], [`perm::Perm`], [`diag::Diag`] and
+//!   the dense decompositions [`linalg::solvers::Llt`], [`linalg::solvers::Ldlt`],
+//!   [`linalg::solvers::Lblt`], [`linalg::solvers::PartialPivLu`], [`linalg::solvers::FullPivLu`],
+//!   [`linalg::solvers::Qr`], [`linalg::solvers::ColPivQr`], [`linalg::solvers::CompOrtho`],
+//!   [`linalg::solvers::Svd`], [`linalg::solvers::SelfAdjointEigen`] and [`linalg::solvers::Eigen`].
+//!   matrices are serialized as `{nrows, ncols, data}` with `data` in row-major order, which is the
+//!   layout used by earlier versions, so that previously serialized matrices can still be loaded
 //! - `npy`: enables conversions to/from numpy's matrix file format
 //! - `perf-warn`: produces performance warnings when matrix operations are called with suboptimal
 //! data layout
@@ -185,6 +191,20 @@ use core::sync::atomic::AtomicUsize;
 use equator::{assert, debug_assert};
 use faer_traits::*;
 
+/// asserts that a dimension is equal to its expected value, otherwise panics with a message of the
+/// form `expected rhs.nrows() == 5 but got 7`
+macro_rules! assert_dim {
+	($got: expr, $expected: expr $(,)?) => {
+		match (&$got, &$expected) {
+			(got, expected) => {
+				if *got != *expected {
+					$crate::utils::panic_dim_mismatch(::core::stringify!($got), *expected, *got)
+				}
+			},
+		}
+	};
+}
+
 macro_rules! auto {
 	($ty: ty) => {
 		$crate::Auto::<$ty>::auto()
@@ -863,6 +883,24 @@ pub enum Side {
 	Upper,
 }
 
+/// axis of a matrix along which an operation is applied
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Axis {
+	/// the operation is applied to each column
+	Col,
+	/// the operation is applied to each row
+	Row,
+}
+
+/// storage order of the elements of a matrix in a flat buffer
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Order {
+	/// columns are stored one after the other, so that `A[(i, j)]` is at index `i + j * nrows`
+	ColMajor,
+	/// rows are stored one after the other, so that `A[(i, j)]` is at index `i * ncols + j`
+	RowMajor,
+}
+
 impl Conj {
 	/// returns `self == Conj::Yes`
 	#[inline]
@@ -941,6 +979,8 @@ pub type c32 = num_complex::Complex32;
 /// `Complex<f64>`
 pub type c64 = num_complex::Complex64;
 
+pub use faer_traits::WidenFrom;
+
 pub use col::{Col, ColMut, ColRef};
 pub use mat::{Mat, MatMut, MatRef};
 pub use row::{Row, RowMut, RowRef};
@@ -1003,7 +1043,7 @@ mod internal_prelude {
 
 	pub use faer_macros::math;
 	pub use faer_traits::math_utils::*;
-	pub use faer_traits::{ComplexField, Conjugate, Index, Real, RealField, SignedIndex, SimdArch};
+	pub use faer_traits::{ComplexField, Conjugate, Index, Real, RealField, SignedIndex, SimdArch, WidenFrom};
 
 	#[inline]
 	pub fn simd_align(i: usize) -> usize {
@@ -0,0 +1,696 @@
+use crate::assert;
+use crate::internal_prelude::*;
+use alloc::vec::Vec;
+use linalg::cholesky::llt::factor::LltError;
+use linalg::solvers::{ShapeCore, SolveCore};
+
+/// band matrix with `kl` sub-diagonals and `ku` super-diagonals, stored in the same layout as
+/// lapack's banded routines
+///
+/// the element at position $(i, j)$ of the matrix, with $j - k_u \le i \le j + k_l$, is stored at
+/// position $(k_u + i - j, j)$ of a dense matrix with $k_l + k_u + 1$ rows, so that the storage
+/// is $O(n (k_l + k_u))$
+#[derive(Clone, Debug)]
+pub struct BandMat<T> {
+	data: Mat<T>,
+	nrows: usize,
+	kl: usize,
+	ku: usize,
+}
+
+impl<T: ComplexField> BandMat<T> {
+	/// returns a zero band matrix with the given dimensions and bandwidths
+	pub fn new(nrows: usize, ncols: usize, kl: usize, ku: usize) -> Self {
+		Self {
+			data: Mat::zeros(kl + ku + 1, ncols),
+			nrows,
+			kl,
+			ku,
+		}
+	}
+
+	/// returns the band matrix with `kl` sub-diagonals and `ku` super-diagonals that is equal to
+	/// $A$
+	///
+	/// # panics
+	/// panics if $A$ has a nonzero element outside of the band
+	#[track_caller]
+	#[math]
+	pub fn from_dense(A: MatRef<'_, T>, kl: usize, ku: usize) -> Self {
+		let mut this = Self::new(A.nrows(), A.ncols(), kl, ku);
+		for j in 0..A.ncols() {
+			for i in 0..A.nrows() {
+				if this.in_band(i, j) {
+					this.data[(ku + i - j, j)] = copy(A[(i, j)]);
+				} else {
+					assert!(A[(i, j)] == zero::<T>());
+				}
+			}
+		}
+		this
+	}
+
+	/// returns the dense matrix equal to `self`
+	#[math]
+	pub fn to_dense(&self) -> Mat<T> {
+		Mat::from_fn(self.nrows(), self.ncols(), |i, j| {
+			if self.in_band(i, j) {
+				copy(self.data[(self.ku + i - j, j)])
+			} else {
+				zero()
+			}
+		})
+	}
+
+	/// returns the number of rows of the matrix
+	#[inline]
+	pub fn nrows(&self) -> usize {
+		self.nrows
+	}
+
+	/// returns the number of columns of the matrix
+	#[inline]
+	pub fn ncols(&self) -> usize {
+		self.data.ncols()
+	}
+
+	/// returns the number of sub-diagonals
+	#[inline]
+	pub fn kl(&self) -> usize {
+		self.kl
+	}
+
+	/// returns the number of super-diagonals
+	#[inline]
+	pub fn ku(&self) -> usize {
+		self.ku
+	}
+
+	/// returns a view over the band storage of the matrix
+	///
+	/// the elements of the storage that don't correspond to a position in the matrix are zero
+	#[inline]
+	pub fn data(&self) -> MatRef<'_, T> {
+		self.data.as_ref()
+	}
+
+	/// returns a mutable view over the band storage of the matrix
+	///
+	/// the elements of the storage that don't correspond to a position in the matrix must be zero
+	/// when the matrix is factored
+	#[inline]
+	pub fn data_mut(&mut self) -> MatMut<'_, T> {
+		self.data.as_mut()
+	}
+
+	#[inline]
+	fn in_band(&self, i: usize, j: usize) -> bool {
+		i + self.ku >= j && i <= j + self.kl
+	}
+}
+
+/// $LU$ decomposition of a band matrix with partial pivoting, computed with the unblocked
+/// algorithm of lapack's `?gbtf2`
+///
+/// row interchanges make $U$ have $k_l + k_u$ super-diagonals, so the factors are stored in a band
+/// matrix with $2 k_l + k_u + 1$ rows. the factorization and the solves take $O(n k_l (k_l +
+/// k_u))$ operations
+#[derive(Clone, Debug)]
+pub struct BandLu<T> {
+	LU: Mat<T>,
+	row_swaps: Vec<usize>,
+	kl: usize,
+	ku: usize,
+}
+
+impl<T: ComplexField> BandLu<T> {
+	/// returns the $LU$ decomposition of the square band matrix $A$
+	///
+	/// # panics
+	/// panics if $A$ is not square
+	#[track_caller]
+	#[math]
+	pub fn new(A: &BandMat<T>) -> Self {
+		assert!(A.nrows() == A.ncols());
+
+		let n = A.ncols();
+		let kl = A.kl();
+		let ku = A.ku();
+		let kv = kl + ku;
+
+		// element (i, j) is stored at (kv + i - j, j), the extra kl rows on top hold the fill-in
+		let mut LU = Mat::<T>::zeros(2 * kl + ku + 1, n);
+		LU.as_mut().subrows_mut(kl, kv + 1).copy_from(A.data());
+
+		let mut row_swaps = alloc::vec![0usize; n];
+
+		let mut ju = 0usize;
+		for j in 0..n {
+			let km = Ord::min(kl, n - 1 - j);
+
+			let mut jp = 0usize;
+			let mut max = zero::<T::Real>();
+			for i in 0..km + 1 {
+				let v = abs1(LU[(kv + i, j)]);
+				if v > max {
+					max = v;
+					jp = i;
+				}
+			}
+			row_swaps[j] = j + jp;
+
+			if max == zero::<T::Real>() {
+				continue;
+			}
+
+			ju = Ord::max(ju, Ord::min(j + ku + jp, n - 1));
+			if jp != 0 {
+				for c in j..ju + 1 {
+					let tmp = copy(LU[(kv + j - c, c)]);
+					LU[(kv + j - c, c)] = copy(LU[(kv + j + jp - c, c)]);
+					LU[(kv + j + jp - c, c)] = tmp;
+				}
+			}
+
+			let s = recip(LU[(kv, j)]);
+			for i in 1..km + 1 {
+				LU[(kv + i, j)] = LU[(kv + i, j)] * s;
+			}
+			for c in j + 1..ju + 1 {
+				let u = copy(LU[(kv + j - c, c)]);
+				for i in 1..km + 1 {
+					LU[(kv + j + i - c, c)] = LU[(kv + j + i - c, c)] - LU[(kv + i, j)] * u;
+				}
+			}
+		}
+
+		Self { LU, row_swaps, kl, ku }
+	}
+
+	/// returns the band storage of the factors, see [`BandLu`]
+	///
+	/// the element $(i, j)$ of $U$ is stored at position $(k_l + k_u + i - j, j)$, and the
+	/// multipliers of the $j$-th elimination step are stored below the diagonal of column $j$
+	pub fn LU(&self) -> MatRef<'_, T> {
+		self.LU.as_ref()
+	}
+
+	/// returns the row swapped with the $j$-th row at the $j$-th elimination step
+	pub fn row_swaps(&self) -> &[usize] {
+		&self.row_swaps
+	}
+}
+
+impl<T: ComplexField> ShapeCore for BandLu<T> {
+	fn nrows(&self) -> usize {
+		self.LU.ncols()
+	}
+
+	fn ncols(&self) -> usize {
+		self.LU.ncols()
+	}
+}
+
+impl<T: ComplexField> SolveCore<T> for BandLu<T> {
+	#[track_caller]
+	#[math]
+	fn solve_in_place_with_conj(&self, conj: Conj, rhs: MatMut<'_, T>) {
+		assert!(rhs.nrows() == self.nrows());
+
+		let n = self.nrows();
+		let kl = self.kl;
+		let kv = self.kl + self.ku;
+		let LU = &self.LU;
+		let a = |i: usize, j: usize| conj.apply_rt(&LU[(i, j)]);
+
+		let mut rhs = rhs;
+		for mut x in rhs.rb_mut().col_iter_mut() {
+			// L x = P b, with the row swaps interleaved with the elimination steps
+			for j in 0..n {
+				let p = self.row_swaps[j];
+				if p != j {
+					let tmp = copy(x[j]);
+					x[j] = copy(x[p]);
+					x[p] = tmp;
+				}
+				let xj = copy(x[j]);
+				for i in 1..Ord::min(kl, n - 1 - j) + 1 {
+					x[j + i] = x[j + i] - a(kv + i, j) * xj;
+				}
+			}
+
+			// U x = y
+			for j in (0..n).rev() {
+				x[j] = x[j] * recip(a(kv, j));
+				let xj = copy(x[j]);
+				for i in j.saturating_sub(kv)..j {
+					x[i] = x[i] - a(kv + i - j, j) * xj;
+				}
+			}
+		}
+	}
+
+	#[track_caller]
+	#[math]
+	fn solve_transpose_in_place_with_conj(&self, conj: Conj, rhs: MatMut<'_, T>) {
+		assert!(rhs.nrows() == self.ncols());
+
+		let n = self.nrows();
+		let kl = self.kl;
+		let kv = self.kl + self.ku;
+		let LU = &self.LU;
+		let a = |i: usize, j: usize| conj.apply_rt(&LU[(i, j)]);
+
+		let mut rhs = rhs;
+		for mut x in rhs.rb_mut().col_iter_mut() {
+			// U^T y = b
+			for j in 0..n {
+				let mut acc = copy(x[j]);
+				for i in j.saturating_sub(kv)..j {
+					acc = acc - a(kv + i - j, j) * x[i];
+				}
+				x[j] = acc * recip(a(kv, j));
+			}
+
+			// (P^T L)^T x = y, undoing the elimination steps in reverse order
+			for j in (0..n).rev() {
+				let mut acc = copy(x[j]);
+				for i in 1..Ord::min(kl, n - 1 - j) + 1 {
+					acc = acc - a(kv + i, j) * x[j + i];
+				}
+				x[j] = acc;
+
+				let p = self.row_swaps[j];
+				if p != j {
+					let tmp = copy(x[j]);
+					x[j] = copy(x[p]);
+					x[p] = tmp;
+				}
+			}
+		}
+	}
+}
+
+/// tridiagonal matrix, stored as its sub-diagonal, diagonal and super-diagonal
+#[derive(Clone, Debug)]
+pub struct Tridiagonal<T> {
+	lower: Vec<T>,
+	diag: Vec<T>,
+	upper: Vec<T>,
+}
+
+impl<T: ComplexField> Tridiagonal<T> {
+	/// returns the tridiagonal matrix with the given sub-diagonal, diagonal and super-diagonal
+	///
+	/// `lower[i]` is the element at position $(i + 1, i)$ and `upper[i]` is the element at
+	/// position $(i, i + 1)$
+	///
+	/// # panics
+	/// panics if `lower` or `upper` doesn't have `diag.len() - 1` elements, or `diag` is empty
+	/// and they are not
+	#[track_caller]
+	pub fn new(lower: &[T], diag: &[T], upper: &[T]) -> Self {
+		let n = diag.len();
+		assert!(all(lower.len() == n.saturating_sub(1), upper.len() == n.saturating_sub(1)));
+		Self {
+			lower: lower.to_vec(),
+			diag: diag.to_vec(),
+			upper: upper.to_vec(),
+		}
+	}
+
+	/// returns the dense matrix equal to `self`
+	#[math]
+	pub fn to_dense(&self) -> Mat<T> {
+		let n = self.dim();
+		Mat::from_fn(n, n, |i, j| {
+			if i == j {
+				copy(self.diag[i])
+			} else if i == j + 1 {
+				copy(self.lower[j])
+			} else if j == i + 1 {
+				copy(self.upper[i])
+			} else {
+				zero()
+			}
+		})
+	}
+
+	/// returns the dimension of the matrix
+	#[inline]
+	pub fn dim(&self) -> usize {
+		self.diag.len()
+	}
+
+	/// returns the sub-diagonal of the matrix
+	#[inline]
+	pub fn lower(&self) -> &[T] {
+		&self.lower
+	}
+
+	/// returns the diagonal of the matrix
+	#[inline]
+	pub fn diag(&self) -> &[T] {
+		&self.diag
+	}
+
+	/// returns the super-diagonal of the matrix
+	#[inline]
+	pub fn upper(&self) -> &[T] {
+		&self.upper
+	}
+}
+
+/// $LU$ decomposition of a tridiagonal matrix without pivoting, computed with the thomas
+/// algorithm in $O(n)$ operations
+///
+/// $L$ is unit lower bidiagonal and $U$ is upper bidiagonal with the same super-diagonal as the
+/// original matrix. since no pivoting is performed, the decomposition is only stable for matrices
+/// such as diagonally dominant or positive definite ones, and the solutions contain non-finite
+/// values if a zero pivot is encountered. [`BandLu`] can be used for general tridiagonal matrices
+#[derive(Clone, Debug)]
+pub struct TridiagonalLu<T> {
+	l: Vec<T>,
+	d: Vec<T>,
+	u: Vec<T>,
+}
+
+impl<T: ComplexField> TridiagonalLu<T> {
+	/// returns the $LU$ decomposition of $A$
+	#[math]
+	pub fn new(A: &Tridiagonal<T>) -> Self {
+		let n = A.dim();
+		let mut l = Vec::with_capacity(n.saturating_sub(1));
+		let mut d = Vec::with_capacity(n);
+
+		for i in 0..n {
+			if i == 0 {
+				d.push(copy(A.diag[0]));
+			} else {
+				let li = A.lower[i - 1] * recip(d[i - 1]);
+				d.push(A.diag[i] - li * A.upper[i - 1]);
+				l.push(li);
+			}
+		}
+
+		Self { l, d, u: A.upper.clone() }
+	}
+
+	/// returns the sub-diagonal of $L$
+	pub fn L_lower(&self) -> &[T] {
+		&self.l
+	}
+
+	/// returns the diagonal of $U$
+	pub fn U_diag(&self) -> &[T] {
+		&self.d
+	}
+
+	/// returns the super-diagonal of $U$
+	pub fn U_upper(&self) -> &[T] {
+		&self.u
+	}
+}
+
+impl<T: ComplexField> ShapeCore for TridiagonalLu<T> {
+	fn nrows(&self) -> usize {
+		self.d.len()
+	}
+
+	fn ncols(&self) -> usize {
+		self.d.len()
+	}
+}
+
+impl<T: ComplexField> SolveCore<T> for TridiagonalLu<T> {
+	#[track_caller]
+	#[math]
+	fn solve_in_place_with_conj(&self, conj: Conj, rhs: MatMut<'_, T>) {
+		assert!(rhs.nrows() == self.nrows());
+
+		let n = self.nrows();
+		let c = |x: &T| conj.apply_rt(x);
+
+		let mut rhs = rhs;
+		for mut x in rhs.rb_mut().col_iter_mut() {
+			// L y = b
+			for i in 1..n {
+				x[i] = x[i] - c(&self.l[i - 1]) * x[i - 1];
+			}
+			// U x = y
+			for i in (0..n).rev() {
+				if i + 1 < n {
+					x[i] = x[i] - c(&self.u[i]) * x[i + 1];
+				}
+				x[i] = x[i] * recip(c(&self.d[i]));
+			}
+		}
+	}
+
+	#[track_caller]
+	#[math]
+	fn solve_transpose_in_place_with_conj(&self, conj: Conj, rhs: MatMut<'_, T>) {
+		assert!(rhs.nrows() == self.ncols());
+
+		let n = self.nrows();
+		let c = |x: &T| conj.apply_rt(x);
+
+		let mut rhs = rhs;
+		for mut x in rhs.rb_mut().col_iter_mut() {
+			// U^T y = b
+			for i in 0..n {
+				if i > 0 {
+					x[i] = x[i] - c(&self.u[i - 1]) * x[i - 1];
+				}
+				x[i] = x[i] * recip(c(&self.d[i]));
+			}
+			// L^T x = y
+			for i in (0..n.saturating_sub(1)).rev() {
+				x[i] = x[i] - c(&self.l[i]) * x[i + 1];
+			}
+		}
+	}
+}
+
+/// $L L^H$ decomposition of a self-adjoint positive definite tridiagonal matrix, computed in
+/// $O(n)$ operations
+///
+/// $L$ is lower bidiagonal with a real positive diagonal
+#[derive(Clone, Debug)]
+pub struct TridiagonalLlt<T: ComplexField> {
+	d: Vec<T::Real>,
+	l: Vec<T>,
+}
+
+impl<T: ComplexField> TridiagonalLlt<T> {
+	/// returns the $L L^H$ decomposition of $A$, assuming it is self-adjoint
+	///
+	/// only the diagonal and the sub-diagonal of $A$ are accessed, and the imaginary parts of the
+	/// diagonal are ignored
+	#[math]
+	pub fn new(A: &Tridiagonal<T>) -> Result<Self, LltError> {
+		let n = A.dim();
+		let mut d = Vec::<T::Real>::with_capacity(n);
+		let mut l = Vec::<T>::with_capacity(n.saturating_sub(1));
+
+		for i in 0..n {
+			let mut pivot = real(A.diag[i]);
+			if i > 0 {
+				let li = A.lower[i - 1] * from_real(recip(d[i - 1]));
+				pivot = pivot - abs2(li);
+				l.push(li);
+			}
+			if pivot <= zero::<T::Real>() || is_nan(pivot) {
+				return Err(LltError::NonPositivePivot { index: i });
+			}
+			d.push(sqrt(pivot));
+		}
+
+		Ok(Self { d, l })
+	}
+
+	/// returns the diagonal of $L$
+	pub fn L_diag(&self) -> &[T::Real] {
+		&self.d
+	}
+
+	/// returns the sub-diagonal of $L$
+	pub fn L_lower(&self) -> &[T] {
+		&self.l
+	}
+}
+
+impl<T: ComplexField> ShapeCore for TridiagonalLlt<T> {
+	fn nrows(&self) -> usize {
+		self.d.len()
+	}
+
+	fn ncols(&self) -> usize {
+		self.d.len()
+	}
+}
+
+impl<T: ComplexField> SolveCore<T> for TridiagonalLlt<T> {
+	#[track_caller]
+	#[math]
+	fn solve_in_place_with_conj(&self, conj: Conj, rhs: MatMut<'_, T>) {
+		assert!(rhs.nrows() == self.nrows());
+
+		let n = self.nrows();
+		let c = |x: &T| conj.apply_rt(x);
+		let c_adj = |x: &T| conj.compose(Conj::Yes).apply_rt(x);
+
+		let mut rhs = rhs;
+		for mut x in rhs.rb_mut().col_iter_mut() {
+			// L y = b
+			for i in 0..n {
+				if i > 0 {
+					x[i] = x[i] - c(&self.l[i - 1]) * x[i - 1];
+				}
+				x[i] = mul_real(x[i], recip(self.d[i]));
+			}
+			// L^H x = y
+			for i in (0..n).rev() {
+				if i + 1 < n {
+					x[i] = x[i] - c_adj(&self.l[i]) * x[i + 1];
+				}
+				x[i] = mul_real(x[i], recip(self.d[i]));
+			}
+		}
+	}
+
+	#[track_caller]
+	fn solve_transpose_in_place_with_conj(&self, conj: Conj, rhs: MatMut<'_, T>) {
+		// A^T = conj(A)
+		self.solve_in_place_with_conj(conj.compose(Conj::Yes), rhs)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::assert;
+	use crate::stats::prelude::*;
+	use crate::utils::approx::*;
+	use linalg::solvers::Solve;
+
+	#[test]
+	fn test_band_lu() {
+		let rng = &mut StdRng::seed_from_u64(0);
+		let n = 40;
+
+		for (kl, ku) in [(0, 0), (1, 1), (2, 1), (1, 3), (4, 0)] {
+			let X = CwiseMatDistribution {
+				nrows: n,
+				ncols: n,
+				dist: ComplexDistribution::new(StandardNormal, StandardNormal),
+			}
+			.rand::<Mat<c64>>(rng);
+			let A = Mat::from_fn(n, n, |i, j| if i + ku >= j && i <= j + kl { X[(i, j)] } else { c64::ZERO });
+
+			let band = BandMat::from_dense(A.as_ref(), kl, ku);
+			assert!(band.data().shape() == (kl + ku + 1, n));
+			assert!(band.to_dense() == A);
+
+			let B = CwiseMatDistribution {
+				nrows: n,
+				ncols: 3,
+				dist: ComplexDistribution::new(StandardNormal, StandardNormal),
+			}
+			.rand::<Mat<c64>>(rng);
+
+			let approx_eq = CwiseMat(ApproxEq::eps() * 1e4);
+			let A_dec = BandLu::new(&band);
+			let A_lu = A.partial_piv_lu();
+
+			assert!(A_dec.solve(&B) ~ A_lu.solve(&B));
+			assert!(A_dec.solve_conjugate(&B) ~ A_lu.solve_conjugate(&B));
+			assert!(A_dec.solve_transpose(&B) ~ A_lu.solve_transpose(&B));
+			assert!(A_dec.solve_adjoint(&B) ~ A_lu.solve_adjoint(&B));
+
+			let residual = |X: Mat<c64>| (&A * X - &B).norm_l2();
+			assert!(residual(A_dec.solve(&B)) <= 1e2 * residual(A_lu.solve(&B)) + 1e-12);
+		}
+	}
+
+	#[test]
+	#[should_panic]
+	fn test_band_outside_bandwidth() {
+		let A = Mat::<f64>::from_fn(5, 5, |i, j| if i.abs_diff(j) <= 2 { 1.0 + (i + 2 * j) as f64 } else { 0.0 });
+		BandMat::from_dense(A.as_ref(), 1, 2);
+	}
+
+	#[test]
+	fn test_tridiagonal() {
+		let rng = &mut StdRng::seed_from_u64(0);
+		let n = 50;
+
+		let mut rand = |len: usize| {
+			CwiseColDistribution {
+				nrows: len,
+				dist: ComplexDistribution::new(StandardNormal, StandardNormal),
+			}
+			.rand::<Col<c64>>(rng)
+			.iter()
+			.copied()
+			.collect::<Vec<_>>()
+		};
+		let lower = rand(n - 1);
+		let upper = rand(n - 1);
+		// diagonally dominant, so that no pivoting is needed
+		let diag = rand(n).into_iter().map(|x| x + c64::new(5.0, 0.0)).collect::<Vec<_>>();
+
+		let tri = Tridiagonal::new(&lower, &diag, &upper);
+		let A = tri.to_dense();
+		assert!(A == BandMat::from_dense(A.as_ref(), 1, 1).to_dense());
+
+		let B = CwiseMatDistribution {
+			nrows: n,
+			ncols: 3,
+			dist: ComplexDistribution::new(StandardNormal, StandardNormal),
+		}
+		.rand::<Mat<c64>>(rng);
+
+		let approx_eq = CwiseMat(ApproxEq::eps() * 1e3);
+		let A_dec = TridiagonalLu::new(&tri);
+		let A_lu = A.partial_piv_lu();
+		assert!(A_dec.solve(&B) ~ A_lu.solve(&B));
+		assert!(A_dec.solve_conjugate(&B) ~ A_lu.solve_conjugate(&B));
+		assert!(A_dec.solve_transpose(&B) ~ A_lu.solve_transpose(&B));
+		assert!(A_dec.solve_adjoint(&B) ~ A_lu.solve_adjoint(&B));
+
+		// self-adjoint positive definite
+		let upper = lower.iter().map(|x| x.conj()).collect::<Vec<_>>();
+		let tri = Tridiagonal::new(&lower, &diag.iter().map(|x| c64::new(x.re.abs() + 5.0, 0.0)).collect::<Vec<_>>(), &upper);
+		let A = tri.to_dense();
+		let A_dec = TridiagonalLlt::new(&tri).unwrap();
+		let A_lu = A.partial_piv_lu();
+		assert!(A_dec.solve(&B) ~ A_lu.solve(&B));
+		assert!(A_dec.solve_conjugate(&B) ~ A_lu.solve_conjugate(&B));
+		assert!(A_dec.solve_transpose(&B) ~ A_lu.solve_transpose(&B));
+		assert!(A_dec.solve_adjoint(&B) ~ A_lu.solve_adjoint(&B));
+
+		let tri = Tridiagonal::new(&[1.0], &[1.0, 0.5], &[1.0]);
+		assert!(matches!(TridiagonalLlt::new(&tri), Err(LltError::NonPositivePivot { index: 1 })));
+	}
+
+	#[test]
+	fn test_tridiagonal_poisson() {
+		// -u'' = 2 on (0, 1) with u(0) = u(1) = 0 has the solution u(x) = x (1 - x), which the
+		// second order finite difference scheme reproduces exactly
+		let n = 99;
+		let h = 1.0 / (n + 1) as f64;
+
+		let tri = Tridiagonal::new(&vec![-1.0; n - 1], &vec![2.0; n], &vec![-1.0; n - 1]);
+		let rhs = Col::<f64>::full(n, 2.0 * h * h);
+		let exact = Col::<f64>::from_fn(n, |i| {
+			let x = (i + 1) as f64 * h;
+			x * (1.0 - x)
+		});
+
+		let x = TridiagonalLu::new(&tri).solve(&rhs);
+		assert!((&x - &exact).norm_max() <= 1e-12);
+		let x = TridiagonalLlt::new(&tri).unwrap().solve(&rhs);
+		assert!((&x - &exact).norm_max() <= 1e-12);
+	}
+}
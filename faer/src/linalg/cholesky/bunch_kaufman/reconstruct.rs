@@ -1,4 +1,3 @@
-use crate::assert;
 use crate::internal_prelude::*;
 use linalg::matmul::triangular::BlockStructure;
 
@@ -19,15 +18,13 @@ pub fn reconstruct<I: Index, T: ComplexField>(
 	stack: &mut MemStack,
 ) {
 	let n = L.nrows();
-	assert!(all(
-		out.nrows() == n,
-		out.ncols() == n,
-		L.nrows() == n,
-		L.ncols() == n,
-		diagonal.dim() == n,
-		subdiagonal.dim() == n,
-		perm.len() == n,
-	));
+	assert_dim!(out.nrows(), n);
+	assert_dim!(out.ncols(), n);
+	assert_dim!(L.nrows(), n);
+	assert_dim!(L.ncols(), n);
+	assert_dim!(diagonal.dim(), n);
+	assert_dim!(subdiagonal.dim(), n);
+	assert_dim!(perm.len(), n);
 
 	let (mut tmp, _) = unsafe { temp_mat_uninit::<T, _, _>(n, n, stack) };
 	let mut tmp = tmp.as_mat_mut();
@@ -1,4 +1,3 @@
-use crate::assert;
 use crate::internal_prelude::*;
 use linalg::matmul::triangular::BlockStructure;
 
@@ -14,7 +13,11 @@ pub fn reconstruct<T: ComplexField>(out: MatMut<'_, T>, L: MatRef<'_, T>, D: Dia
 	_ = stack;
 
 	let n = out.nrows();
-	assert!(all(out.nrows() == n, out.ncols() == n, L.nrows() == n, L.ncols() == n, D.dim() == n,));
+	assert_dim!(out.nrows(), n);
+	assert_dim!(out.ncols(), n);
+	assert_dim!(L.nrows(), n);
+	assert_dim!(L.ncols(), n);
+	assert_dim!(D.dim(), n);
 
 	let (mut LxD, _) = unsafe { temp_mat_uninit::<T, _, _>(n, n, stack) };
 	let mut LxD = LxD.as_mat_mut();
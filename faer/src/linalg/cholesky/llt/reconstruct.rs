@@ -1,4 +1,3 @@
-use crate::assert;
 use crate::internal_prelude::*;
 use linalg::matmul::triangular::BlockStructure;
 
@@ -13,7 +12,10 @@ pub fn reconstruct<T: ComplexField>(out: MatMut<'_, T>, L: MatRef<'_, T>, par: P
 	_ = stack;
 
 	let n = out.nrows();
-	assert!(all(out.nrows() == n, out.ncols() == n, L.nrows() == n, L.ncols() == n,));
+	assert_dim!(out.nrows(), n);
+	assert_dim!(out.ncols(), n);
+	assert_dim!(L.nrows(), n);
+	assert_dim!(L.ncols(), n);
 
 	linalg::matmul::triangular::matmul(
 		out.rb_mut(),
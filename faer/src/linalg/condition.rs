@@ -0,0 +1,248 @@
+use crate::assert;
+use crate::internal_prelude::*;
+use linalg::evd::{ComputeEigenvectors, EvdError};
+use linalg::solvers::{PartialPivLu, SelfAdjointEigen, SolveCore};
+
+/// computes the $1$-norm condition number $\kappa_1(A) = \|A\|_1 \|A^{-1}\|_1$ of $A$, given its
+/// explicit inverse
+///
+/// this is exact (up to rounding), but it is only worth using if the inverse is already
+/// available, since computing it costs more than the factorization itself
+///
+/// # panics
+///
+/// panics if `A` and `A_inv` are not square matrices of the same dimension
+#[track_caller]
+#[math]
+pub fn condition_number_1<T: ComplexField>(A: MatRef<'_, T>, A_inv: MatRef<'_, T>) -> T::Real {
+	assert!(all(A.nrows() == A.ncols(), A_inv.nrows() == A_inv.ncols(), A.nrows() == A_inv.nrows()));
+
+	A.operator_norm_l1() * A_inv.operator_norm_l1()
+}
+
+/// estimates the $1$-norm of $A^{-1}$ from a solver for $A$, using hager's method with higham's
+/// refinements
+///
+/// the estimate is a lower bound that is usually within a factor of $3$ of the exact value
+pub(crate) fn estimate_inverse_norm_1<T: ComplexField>(solver: &impl SolveCore<T>, n: usize) -> T::Real {
+	estimate_norm_1(
+		n,
+		|x| solver.solve_in_place_with_conj(Conj::No, x),
+		|x| solver.solve_transpose_in_place_with_conj(Conj::Yes, x),
+	)
+}
+
+/// estimates the $1$-norm of an $n \times n$ operator $B$, given functions that overwrite their
+/// argument $x$ with $B x$ and $B^H x$ respectively, using hager's method with higham's refinements
+///
+/// the estimate is a lower bound that is usually within a factor of $3$ of the exact value
+#[math]
+pub(crate) fn estimate_norm_1<T: ComplexField>(
+	n: usize,
+	mut apply: impl FnMut(MatMut<'_, T>),
+	mut apply_adjoint: impl FnMut(MatMut<'_, T>),
+) -> T::Real {
+	if n == 0 {
+		return zero();
+	}
+
+	let sign = |x: &T| -> T {
+		let abs = abs(*x);
+		if abs == zero::<T::Real>() { one() } else { mul_real(*x, recip(abs)) }
+	};
+
+	let mut x = Col::<T>::full(n, from_real(recip(from_f64::<T::Real>(n as f64))));
+	let mut est = zero::<T::Real>();
+	let mut prev_j = usize::MAX;
+
+	for _ in 0..5 {
+		// y = B × x
+		let x_prev = x.clone();
+		apply(x.as_mat_mut());
+		let new_est = x.as_mat().operator_norm_l1();
+		if prev_j != usize::MAX && new_est <= est {
+			break;
+		}
+		est = new_est;
+
+		// z = B^H × sign(y)
+		z!(x.as_mut()).for_each(|uz!(x)| *x = sign(x));
+		apply_adjoint(x.as_mat_mut());
+
+		let mut j = 0;
+		let mut z_max = zero::<T::Real>();
+		for i in 0..n {
+			let zi = abs(x[i]);
+			if zi > z_max {
+				z_max = zi;
+				j = i;
+			}
+		}
+
+		let dot = real(x_prev.adjoint() * x.as_ref());
+		if j == prev_j || z_max <= dot {
+			break;
+		}
+		prev_j = j;
+
+		x.as_mut().fill(zero());
+		x[j] = one();
+	}
+
+	// alternative estimate that guards against the cases where the iteration above is misled
+	let mut x = Col::<T>::from_fn(n, |i| {
+		let v = from_f64::<T>(1.0 + (i as f64) / (Ord::max(n, 2) - 1) as f64);
+		if i % 2 == 0 { v } else { -v }
+	});
+	apply(x.as_mat_mut());
+	let alt = x.as_mat().operator_norm_l1() * from_f64::<T::Real>(2.0 / (3.0 * n as f64));
+
+	max(&est, &alt)
+}
+
+/// estimates the $1$-norm condition number $\kappa_1(A) = \|A\|_1 \|A^{-1}\|_1$ of $A$
+///
+/// this factorizes $A$ with partial pivoting, then estimates the reciprocal condition number from
+/// a few solves with the factors, which is much cheaper than computing the inverse. the estimate
+/// never exceeds the exact value (up to rounding), and is usually within a factor of $10$ of it
+///
+/// if $A$ is exactly singular, the result is infinite or `NaN`
+///
+/// # panics
+///
+/// panics if `A` is not square
+#[track_caller]
+#[math]
+pub fn condition_estimate<T: ComplexField>(A: MatRef<'_, T>) -> T::Real {
+	assert!(A.nrows() == A.ncols());
+	let n = A.nrows();
+
+	let norm = A.operator_norm_l1();
+	let lu = PartialPivLu::new(A);
+	let inv_norm = estimate_inverse_norm_1(&lu, n);
+
+	norm * inv_norm
+}
+
+/// computes the $2$-norm condition number $\kappa_2(A) = \max_i |\lambda_i| / \min_i |\lambda_i|$ of
+/// $A$, assuming it is self-adjoint. only the lower (resp. upper) triangular half of $A$ is accessed
+/// if `side` is [`Side::Lower`] (resp. [`Side::Upper`])
+///
+/// only the eigenvalues are computed, which is cheaper than computing the singular values of $A$.
+/// see [`SelfAdjointEigen::condition_number`]
+///
+/// # panics
+///
+/// panics if `A` is not square
+#[track_caller]
+pub fn symmetric_condition_number<T: ComplexField>(A: MatRef<'_, T>, side: Side) -> Result<T::Real, EvdError> {
+	assert!(A.nrows() == A.ncols());
+	Ok(SelfAdjointEigen::builder()
+		.vectors(ComputeEigenvectors::No)
+		.build(A, side)?
+		.condition_number())
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::assert;
+	use crate::linalg::solvers::{DenseSolveCore, PartialPivLu};
+	use crate::stats::prelude::*;
+
+	#[test]
+	fn test_condition_number_1() {
+		let rng = &mut StdRng::seed_from_u64(0);
+
+		let D = Mat::from_fn(4, 4, |i, j| if i == j { [1.0, -8.0, 0.5, 2.0][i] } else { 0.0f64 });
+		let D_inv = D.partial_piv_lu().inverse();
+		assert!((condition_number_1(D.as_ref(), D_inv.as_ref()) - 16.0).abs() < 1e-12);
+
+		let A = mat![[1.0, 2.0], [3.0, 4.0f64]];
+		let A_inv = mat![[-2.0, 1.0], [1.5, -0.5f64]];
+		assert!(condition_number_1(A.as_ref(), A_inv.as_ref()) == 21.0);
+
+		for n in [1, 10, 50] {
+			let A = CwiseMatDistribution {
+				nrows: n,
+				ncols: n,
+				dist: ComplexDistribution::new(StandardNormal, StandardNormal),
+			}
+			.rand::<Mat<c64>>(rng);
+			let A_inv = PartialPivLu::new(A.as_ref()).inverse();
+
+			let cond = condition_number_1(A.as_ref(), A_inv.as_ref());
+			assert!(cond >= 1.0 - 1e-10);
+
+			// the reciprocal of the estimated rcond is a lower bound, usually within a factor of 10
+			let rcond = 1.0 / condition_estimate(A.as_ref());
+			assert!(all(1.0 / rcond <= cond * (1.0 + 1e-6), 1.0 / rcond >= cond / 10.0));
+
+			// the condition number is invariant under scaling
+			let B = Scale(c64::new(0.0, 3.0)) * &A;
+			let B_inv = PartialPivLu::new(B.as_ref()).inverse();
+			let cond_B = condition_number_1(B.as_ref(), B_inv.as_ref());
+			assert!((cond - cond_B).abs() < 1e-10 * cond);
+		}
+	}
+
+	#[test]
+	fn test_condition_estimate() {
+		let rng = &mut StdRng::seed_from_u64(0);
+
+		let D = Mat::from_fn(4, 4, |i, j| if i == j { [1.0, -8.0, 0.5, 2.0][i] } else { 0.0f64 });
+		assert!((condition_estimate(D.as_ref()) - 16.0).abs() < 1e-12);
+
+		for n in [1, 2, 10, 50] {
+			for log_cond in [0.0, 4.0, 8.0] {
+				// graded singular values
+				let U = randn_c64(n, n, rng).qr().compute_Q();
+				let V = randn_c64(n, n, rng).qr().compute_Q();
+				let S = Col::from_fn(n, |i| c64::new(10.0f64.powf(-log_cond * i as f64 / Ord::max(n - 1, 1) as f64), 0.0));
+				let A = &U * S.as_diagonal() * V.adjoint();
+
+				let est = condition_estimate(A.as_ref());
+
+				let exact_1 = condition_number_1(A.as_ref(), PartialPivLu::new(A.as_ref()).inverse().as_ref());
+				assert!(all(est <= exact_1 * (1.0 + 1e-6), est >= exact_1 / 10.0));
+
+				// the 1-norm and 2-norm condition numbers agree up to a factor of n
+				let svd = A.svd().unwrap();
+				let S = svd.S().column_vector();
+				let exact_2 = S[0].re / S[n - 1].re;
+				assert!(all(est <= exact_2 * n as f64 * (1.0 + 1e-6), est >= exact_2 / (10.0 * n as f64)));
+			}
+		}
+	}
+
+	#[test]
+	fn test_symmetric_condition_number() {
+		let rng = &mut StdRng::seed_from_u64(0);
+
+		let D = Mat::from_fn(4, 4, |i, j| if i == j { [1.0, -8.0, 0.5, 2.0][i] } else { 0.0f64 });
+		assert!((symmetric_condition_number(D.as_ref(), Side::Lower).unwrap() - 16.0).abs() < 1e-12);
+		assert!(symmetric_condition_number(Mat::<f64>::identity(3, 3).as_ref(), Side::Upper).unwrap() == 1.0);
+		assert!(symmetric_condition_number(Mat::<f64>::zeros(3, 3).as_ref(), Side::Lower).unwrap() == f64::INFINITY);
+
+		for n in [1, 2, 10, 50] {
+			let A = CwiseMatDistribution {
+				nrows: n,
+				ncols: n,
+				dist: ComplexDistribution::new(StandardNormal, StandardNormal),
+			}
+			.rand::<Mat<c64>>(rng);
+			let H = &A * A.adjoint() + Mat::<c64>::identity(n, n);
+
+			let svd = H.svd().unwrap();
+			let S = svd.S().column_vector();
+			let expected = S[0].re / S[n - 1].re;
+
+			for side in [Side::Lower, Side::Upper] {
+				let cond = symmetric_condition_number(H.as_ref(), side).unwrap();
+				assert!((cond - expected).abs() < 1e-10 * expected);
+			}
+			let evd = H.self_adjoint_eigen(Side::Lower).unwrap();
+			assert!((evd.condition_number() - expected).abs() < 1e-10 * expected);
+		}
+	}
+}
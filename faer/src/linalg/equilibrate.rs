@@ -0,0 +1,209 @@
+use crate::assert;
+use crate::internal_prelude::*;
+use linalg::solvers::{ShapeCore, SolveCore};
+
+/// computes row and column scaling factors $R$ and $C$ such that the largest element in
+/// magnitude of every row and column of $R A C$ is $1$, similarly to lapack's `?geequ`
+///
+/// the row factors are the inverses of the row maxima of $A$, and the column factors are the
+/// inverses of the column maxima of $R A$. rows and columns that are entirely zero get a factor of
+/// $1$
+///
+/// factoring $R A C$ instead of a badly scaled $A$ usually improves the accuracy of the solution,
+/// see [`Equilibrated`]
+#[math]
+pub fn equilibrate<T: ComplexField>(A: MatRef<'_, T>) -> (Diag<T::Real>, Diag<T::Real>) {
+	let (m, n) = A.shape();
+
+	let inv = |x: T::Real| if x == zero::<T::Real>() { one() } else { recip(x) };
+
+	let mut R = Col::<T::Real>::zeros(m);
+	for j in 0..n {
+		zip!(R.as_mut(), A.col(j)).for_each(|unzip!(r, a)| *r = max(*r, abs(*a)));
+	}
+	for r in R.iter_mut() {
+		*r = inv(copy(*r));
+	}
+
+	let mut C = Col::<T::Real>::zeros(n);
+	for j in 0..n {
+		let mut c = zero::<T::Real>();
+		zip!(R.as_ref(), A.col(j)).for_each(|unzip!(r, a)| c = max(c, *r * abs(*a)));
+		C[j] = inv(c);
+	}
+
+	(R.into_diagonal(), C.into_diagonal())
+}
+
+/// replaces $A$ with $R A C$
+///
+/// # panics
+/// panics if `R` doesn't have `A.nrows()` elements or `C` doesn't have `A.ncols()` elements
+#[track_caller]
+#[math]
+pub fn apply_equilibration<T: ComplexField>(A: MatMut<'_, T>, R: DiagRef<'_, T::Real>, C: DiagRef<'_, T::Real>) {
+	let R = R.column_vector();
+	let C = C.column_vector();
+	assert!(all(R.nrows() == A.nrows(), C.nrows() == A.ncols()));
+
+	let mut A = A;
+	for j in 0..A.ncols() {
+		let c = copy(C[j]);
+		zip!(A.rb_mut().col_mut(j), R).for_each(|unzip!(a, r)| *a = mul_real(*a, *r * c));
+	}
+}
+
+/// solver for $A x = b$ that works on the equilibrated matrix $R A C$, where $R$ and $C$ are
+/// computed by [`equilibrate`]
+///
+/// the right-hand side is scaled by $R$ before solving with the inner solver, and the solution is
+/// scaled by $C$ afterwards
+#[derive(Clone, Debug)]
+pub struct Equilibrated<T: ComplexField, S> {
+	R: Diag<T::Real>,
+	C: Diag<T::Real>,
+	inner: S,
+}
+
+impl<T: ComplexField, S> Equilibrated<T, S> {
+	/// equilibrates $A$, then factors $R A C$ with `factor`
+	///
+	/// # example
+	/// ```
+	/// use faer::linalg::equilibrate::Equilibrated;
+	/// use faer::linalg::solvers::Solve;
+	/// use faer::mat;
+	///
+	/// let A = mat![[1e10, 2e10], [3e-5, -1e-5]];
+	/// let A_dec = Equilibrated::new(A.as_ref(), |A| A.partial_piv_lu());
+	/// let x = A_dec.solve(mat![[5e10], [1e-5]]);
+	/// ```
+	pub fn new(A: MatRef<'_, T>, factor: impl FnOnce(MatRef<'_, T>) -> S) -> Self {
+		let (R, C) = equilibrate(A);
+		let mut A = A.to_owned();
+		apply_equilibration(A.as_mut(), R.as_ref(), C.as_ref());
+		let inner = factor(A.as_ref());
+		Self { R, C, inner }
+	}
+
+	/// returns the row scaling factors $R$
+	pub fn R(&self) -> DiagRef<'_, T::Real> {
+		self.R.as_ref()
+	}
+
+	/// returns the column scaling factors $C$
+	pub fn C(&self) -> DiagRef<'_, T::Real> {
+		self.C.as_ref()
+	}
+
+	/// returns the solver for the equilibrated matrix $R A C$
+	pub fn inner(&self) -> &S {
+		&self.inner
+	}
+}
+
+#[math]
+fn scale_rows<T: ComplexField>(rhs: MatMut<'_, T>, s: DiagRef<'_, T::Real>) {
+	let s = s.column_vector();
+	let mut rhs = rhs;
+	for j in 0..rhs.ncols() {
+		zip!(rhs.rb_mut().col_mut(j), s).for_each(|unzip!(x, s)| *x = mul_real(*x, *s));
+	}
+}
+
+impl<T: ComplexField, S: ShapeCore> ShapeCore for Equilibrated<T, S> {
+	fn nrows(&self) -> usize {
+		self.inner.nrows()
+	}
+
+	fn ncols(&self) -> usize {
+		self.inner.ncols()
+	}
+}
+
+impl<T: ComplexField, S: SolveCore<T>> SolveCore<T> for Equilibrated<T, S> {
+	#[track_caller]
+	fn solve_in_place_with_conj(&self, conj: Conj, rhs: MatMut<'_, T>) {
+		assert!(rhs.nrows() == self.nrows());
+
+		// A x = b  <=>  (R A C) (C^-1 x) = R b
+		let mut rhs = rhs;
+		scale_rows(rhs.rb_mut(), self.R());
+		self.inner.solve_in_place_with_conj(conj, rhs.rb_mut());
+		scale_rows(rhs, self.C());
+	}
+
+	#[track_caller]
+	fn solve_transpose_in_place_with_conj(&self, conj: Conj, rhs: MatMut<'_, T>) {
+		assert!(rhs.nrows() == self.ncols());
+
+		// A^T x = b  <=>  (R A C)^T (R^-1 x) = C b
+		let mut rhs = rhs;
+		scale_rows(rhs.rb_mut(), self.C());
+		self.inner.solve_transpose_in_place_with_conj(conj, rhs.rb_mut());
+		scale_rows(rhs, self.R());
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::assert;
+	use crate::stats::prelude::*;
+	use crate::utils::approx::*;
+	use linalg::solvers::Solve;
+
+	#[test]
+	fn test_equilibrate() {
+		let rng = &mut StdRng::seed_from_u64(0);
+		let n = 20;
+
+		let A = CwiseMatDistribution {
+			nrows: n,
+			ncols: n,
+			dist: ComplexDistribution::new(StandardNormal, StandardNormal),
+		}
+		.rand::<Mat<c64>>(rng);
+
+		// scale the rows and columns over many orders of magnitude
+		let A = Mat::from_fn(n, n, |i, j| {
+			A[(i, j)] * 10.0f64.powi(i as i32 - 10) * 10.0f64.powi(6 - (j as i32 % 7) * 2)
+		});
+
+		let (R, C) = equilibrate(A.as_ref());
+		let mut B = A.clone();
+		apply_equilibration(B.as_mut(), R.as_ref(), C.as_ref());
+
+		for i in 0..n {
+			assert!(B.row(i).norm_max() <= 1.0 + 1e-12);
+		}
+		for j in 0..n {
+			assert!(B.col(j).norm_max() <= 1.0 + 1e-12);
+		}
+
+		let cond = |A: &Mat<c64>| A.svd().unwrap().condition_number();
+		assert!(cond(&B) * 1e6 < cond(&A));
+
+		let y = CwiseMatDistribution {
+			nrows: n,
+			ncols: 2,
+			dist: ComplexDistribution::new(StandardNormal, StandardNormal),
+		}
+		.rand::<Mat<c64>>(rng);
+
+		let approx_eq = CwiseMat(ApproxEq::eps() * 1e4);
+		let A_dec = Equilibrated::new(A.as_ref(), |A| A.partial_piv_lu());
+
+		// solutions whose components are scaled like the columns (resp. rows) of A are determined
+		// to full relative accuracy
+		let x = Mat::from_fn(n, 2, |i, j| y[(i, j)] * C[i]);
+		assert!(A_dec.solve(&A * &x) ~ x);
+		let x = Mat::from_fn(n, 2, |i, j| y[(i, j)] * R[i]);
+		assert!(A_dec.solve_transpose(A.transpose() * &x) ~ x);
+		assert!(A_dec.solve_adjoint(A.adjoint() * &x) ~ x);
+
+		let Z = Mat::<f64>::zeros(2, 3);
+		let (R, C) = equilibrate(Z.as_ref());
+		assert!(all(R.column_vector() == Col::<f64>::ones(2), C.column_vector() == Col::<f64>::ones(3)));
+	}
+}
@@ -822,65 +822,75 @@ pub fn evd_scratch<T: ComplexField>(
 	}
 
 	let compute_eigen = eigen_left == ComputeEigenvectors::Yes || eigen_right == ComputeEigenvectors::Yes;
-	let bs = linalg::qr::no_pivoting::factor::recommended_blocksize::<T>(n - 1, n - 1);
 
 	let H = temp_mat_scratch::<T>(n, n);
 	let X = H;
 	let Z = temp_mat_scratch::<T>(n, if compute_eigen { n } else { 0 });
-	let householder = temp_mat_scratch::<T>(bs, n);
-	let apply = linalg::householder::apply_block_householder_sequence_on_the_right_in_place_scratch::<T>(n - 1, bs, n - 1);
 
 	StackReq::all_of(&[
 		H,
 		Z,
-		StackReq::any_of(&[
-			householder.and(hessenberg::hessenberg_in_place_scratch::<T>(n, bs, par, params.hessenberg.into()).or(apply)),
-			schur::multishift_qr_scratch::<T>(n, n, compute_eigen, compute_eigen, par, params.schur),
-			X,
-		]),
+		StackReq::any_of(&[schur_in_place_scratch::<T>(n, compute_eigen, par, params.config), X]),
+	])
+}
+
+/// computes the size and alignment of the workspace required by [`schur_in_place`]
+pub(crate) fn schur_in_place_scratch<T: ComplexField>(dim: usize, compute_z: bool, par: Par, params: EvdParams) -> StackReq {
+	let n = dim;
+
+	if n == 0 {
+		return StackReq::EMPTY;
+	}
+
+	let bs = linalg::qr::no_pivoting::factor::recommended_blocksize::<T>(n - 1, n - 1);
+	let householder = temp_mat_scratch::<T>(bs, n - 1);
+	let apply = linalg::householder::apply_block_householder_sequence_on_the_right_in_place_scratch::<T>(n - 1, bs, n - 1);
+
+	StackReq::any_of(&[
+		householder.and(hessenberg::hessenberg_in_place_scratch::<T>(n, bs, par, params.hessenberg.into()).or(apply)),
+		schur::multishift_qr_scratch::<T>(n, n, compute_z, compute_z, par, params.schur),
 	])
 }
 
+/// overwrites $H$ with its schur form $T$, such that $H = Z T Z^H$, by reducing it to hessenberg
+/// form and running the multishift $QR$ algorithm. this is shared by the eigendecomposition and
+/// the schur decompositions
+///
+/// $T$ is upper triangular in the complex case, and quasi-upper triangular in the real case, in
+/// which case `s_im` must be provided. the eigenvalues are stored in `s` (and `s_im`), and $Z$ is
+/// only accumulated if it is provided
+///
+/// returns the status of the $QR$ algorithm, which is nonzero if it did not converge
 #[math]
-fn evd_imp<T: ComplexField>(
-	A: MatRef<'_, T>,
+pub(crate) fn schur_in_place<T: ComplexField>(
+	H: MatMut<'_, T>,
+	Z: Option<MatMut<'_, T>>,
 	s: ColMut<'_, T>,
 	s_im: Option<ColMut<'_, T>>,
-	u_left: Option<MatMut<'_, T>>,
-	u_right: Option<MatMut<'_, T>>,
 	par: Par,
 	stack: &mut MemStack,
 	params: EvdParams,
-) -> Result<(), EvdError> {
-	let n = A.nrows();
+) -> Result<isize, EvdError> {
+	let n = H.nrows();
 
 	if n == 0 {
-		return Ok(());
+		return Ok(0);
 	}
 
+	let mut H = H;
+	let mut Z = Z;
+	let mut s = s;
+	let mut s_im = s_im;
+
 	for j in 0..n {
 		for i in 0..n {
-			if !is_finite(A[(i, j)]) {
+			if !is_finite(H[(i, j)]) {
 				return Err(EvdError::NoConvergence);
 			}
 		}
 	}
 
 	let bs = linalg::qr::no_pivoting::factor::recommended_blocksize::<T>(n - 1, n - 1);
-	let mut s = s;
-	let mut s_im = s_im;
-
-	let (mut H, stack) = unsafe { temp_mat_uninit::<T, _, _>(n, n, stack) };
-	let (mut Z, stack) = unsafe { temp_mat_uninit::<T, _, _>(n, if u_left.is_some() || u_right.is_some() { n } else { 0 }, stack) };
-
-	let mut H = H.as_mat_mut();
-	let mut Z = if u_left.is_some() || u_right.is_some() {
-		Some(Z.as_mat_mut())
-	} else {
-		None
-	};
-
-	H.copy_from(A);
 
 	{
 		let (mut householder, stack) = unsafe { temp_mat_uninit::<T, _, _>(bs, n - 1, stack) };
@@ -909,7 +919,7 @@ fn evd_imp<T: ComplexField>(
 		}
 	}
 
-	if try_const! { T::IS_REAL } {
+	let (info, _, _) = if try_const! { T::IS_REAL } {
 		schur::real_schur::multishift_qr::<T::Real>(
 			unsafe { core::mem::transmute(Z.is_some()) },
 			unsafe { core::mem::transmute(H.rb_mut()) },
@@ -921,11 +931,52 @@ fn evd_imp<T: ComplexField>(
 			par,
 			stack,
 			params.schur,
-		);
+		)
 	} else {
-		schur::complex_schur::multishift_qr::<T>(Z.is_some(), H.rb_mut(), Z.rb_mut(), s.rb_mut(), 0, n, par, stack, params.schur);
+		schur::complex_schur::multishift_qr::<T>(Z.is_some(), H.rb_mut(), Z.rb_mut(), s.rb_mut(), 0, n, par, stack, params.schur)
+	};
+
+	// the multishift qr uses the lower left part of `H` as workspace
+	let offset = if try_const! { T::IS_REAL } { 2 } else { 1 };
+	for j in 0..n {
+		for i in j + offset..n {
+			H[(i, j)] = zero();
+		}
 	}
 
+	Ok(info)
+}
+
+#[math]
+fn evd_imp<T: ComplexField>(
+	A: MatRef<'_, T>,
+	s: ColMut<'_, T>,
+	s_im: Option<ColMut<'_, T>>,
+	u_left: Option<MatMut<'_, T>>,
+	u_right: Option<MatMut<'_, T>>,
+	par: Par,
+	stack: &mut MemStack,
+	params: EvdParams,
+) -> Result<(), EvdError> {
+	let n = A.nrows();
+
+	if n == 0 {
+		return Ok(());
+	}
+
+	let (mut H, stack) = unsafe { temp_mat_uninit::<T, _, _>(n, n, stack) };
+	let (mut Z, stack) = unsafe { temp_mat_uninit::<T, _, _>(n, if u_left.is_some() || u_right.is_some() { n } else { 0 }, stack) };
+
+	let mut H = H.as_mat_mut();
+	let mut Z = if u_left.is_some() || u_right.is_some() {
+		Some(Z.as_mat_mut())
+	} else {
+		None
+	};
+
+	H.copy_from(A);
+	schur_in_place(H.rb_mut(), Z.rb_mut(), s, s_im, par, stack, params)?;
+
 	let H = H.rb();
 
 	if let (Some(mut u), Some(Z)) = (u_right, Z.rb()) {
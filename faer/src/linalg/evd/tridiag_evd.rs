@@ -813,6 +813,137 @@ pub(crate) fn divide_and_conquer_scratch<T: ComplexField>(n: usize, par: Par) ->
 	])
 }
 
+/// computes the eigendecomposition of `diag(d) + rho×z×z.T`, where `d` is sorted in nondecreasing
+/// order
+///
+/// returns the eigenvalues in nondecreasing order, along with the corresponding eigenvectors
+#[math]
+pub(crate) fn rank_one_update<T: RealField>(d: ColRef<'_, T>, z: ColRef<'_, T>, rho: T) -> (Col<T>, Mat<T>) {
+	let n = d.nrows();
+
+	if rho < zero() {
+		// diag(d) + rho×z×z.T = -(diag(-d) + |rho|×z×z.T), and -d is sorted once reversed
+		let d = Col::<T>::from_fn(n, |i| -d[n - 1 - i]);
+		let z = Col::<T>::from_fn(n, |i| copy(z[n - 1 - i]));
+		let (w, u) = rank_one_update(d.as_ref(), z.as_ref(), -rho);
+
+		return (
+			Col::from_fn(n, |i| -w[n - 1 - i]),
+			Mat::from_fn(n, n, |i, j| copy(u[(n - 1 - i, n - 1 - j)])),
+		);
+	}
+
+	let mut d = d.to_owned();
+	let mut z = z.to_owned();
+
+	// diag(d) + rho×z×z.T = q × (diag(d) + rho×z'×z'.T) × q.T, where z' is the deflated z
+	let mut q = Mat::<T>::identity(n, n);
+
+	let dmax = d.norm_max();
+	let zmax = z.norm_max();
+
+	let eps = eps::<T>();
+	let tol = from_f64::<T>(8.0) * eps * max(dmax, zmax);
+
+	if rho * zmax <= tol {
+		return (d, q);
+	}
+
+	for i in 0..n {
+		let zi = copy(z[i]);
+		if abs(rho * zi) <= tol {
+			z[i] = zero();
+		}
+	}
+
+	// rotate the components of z that correspond to consecutive almost-equal eigenvalues into the
+	// last one of each run
+	for i in 1..n {
+		if d[i] - d[i - 1] <= tol {
+			(d[i] = copy(d[i - 1]));
+
+			let r = hypot(z[i - 1], z[i]);
+			if r != zero() {
+				let c = z[i] / r;
+				let s = z[i - 1] / r;
+				z[i - 1] = zero();
+				(z[i] = r);
+
+				let (x, y) = q.two_cols_mut(i - 1, i);
+				z!(x, y).for_each(|uz!(x, y)| {
+					let (a, b) = (copy(*x), copy(*y));
+					*x = c * a - s * b;
+					*y = s * a + c * b;
+				});
+			}
+		}
+	}
+
+	let non_deflated = (0..n).filter(|&i| z[i] != zero()).collect::<alloc::vec::Vec<_>>();
+	let k = non_deflated.len();
+
+	let dk = Col::<T>::from_fn(k, |p| copy(d[non_deflated[p]]));
+	let mut zk = Col::<T>::from_fn(k, |p| copy(z[non_deflated[p]]));
+	let mut mus = Col::<T>::zeros(k);
+	let mut shifts = Col::<T>::zeros(k);
+	let dk = dk.as_ref().try_as_col_major().unwrap();
+
+	compute_eigenvalues(
+		mus.as_mut().try_as_col_major_mut().unwrap(),
+		shifts.as_mut().try_as_col_major_mut().unwrap(),
+		dk,
+		zk.as_ref().try_as_col_major().unwrap(),
+		copy(rho),
+		k,
+	);
+
+	// perturb z so that the computed eigenvectors are numerically orthogonal
+	// new_zi^2 = prod(wk - di) / prod_{k != i} (dk - di)
+	for i in 0..k {
+		let di = copy(dk[i]);
+
+		// NOTE: order of operations is crucial here
+		let mut prod = mus[i] + (shifts[i] - di);
+		(0..i).chain(i + 1..k).for_each(|j| {
+			let numerator = mus[j] + (shifts[j] - di);
+			let denominator = dk[j] - di;
+			prod = prod * (numerator / denominator);
+		});
+
+		let prod = sqrt(abs(prod));
+		let zi = zk.as_mut().at_mut(i);
+		let new_zi = if *zi < zero() { -prod } else { prod };
+		*zi = new_zi;
+	}
+
+	// eigenvalues and eigenvectors of diag(d) + rho×z'×z'.T
+	let mut w = Col::<T>::zeros(n);
+	let mut v = Mat::<T>::zeros(n, n);
+	let mut col = 0;
+	for i in 0..n {
+		if z[i] == zero() {
+			(w[col] = copy(d[i]));
+			v[(i, col)] = one();
+			col += 1;
+		}
+	}
+	for j in 0..k {
+		(w[col] = mus[j] + shifts[j]);
+		for (p, &i) in non_deflated.iter().enumerate() {
+			(v[(i, col)] = zk[p] / ((dk[p] - shifts[j]) - mus[j]));
+		}
+		let inv_norm = recip(v.col(col).norm_l2());
+		z!(v.col_mut(col)).for_each(|uz!(x)| *x = *x * inv_norm);
+		col += 1;
+	}
+
+	let mut perm = (0..n).collect::<alloc::vec::Vec<_>>();
+	perm.sort_unstable_by(|&i, &j| PartialOrd::partial_cmp(&w[i], &w[j]).unwrap_or(core::cmp::Ordering::Equal));
+
+	let u = q.as_ref() * v.as_ref();
+	(Col::from_fn(n, |i| copy(w[perm[i]])), Mat::from_fn(n, n, |i, j| copy(u[(i, perm[j])])))
+}
+
 #[cfg(test)]
 mod evd_qr_tests {
 	use dyn_stack::MemBuffer;
@@ -0,0 +1,111 @@
+use crate::assert;
+use crate::internal_prelude::*;
+use alloc::vec::Vec;
+use linalg::solvers::{EvdError, SelfAdjointEigen};
+
+/// samples the boundary of the field of values $W(A) = \{x^H A x : \|x\| = 1\}$ of $A$
+///
+/// for each of the `num_angles` angles $\theta_k = 2 \pi k / \text{num\_angles}$, this computes
+/// the eigenvector $x$ of the hermitian part of $e^{i\theta_k} A$ associated with its largest
+/// eigenvalue, and returns $x^H A x$, which is the point of the boundary of $W(A)$ that is the
+/// furthest along the direction $e^{-i\theta_k}$
+///
+/// the angles are computed in double precision
+///
+/// # panics
+///
+/// panics if `A` is not square
+#[track_caller]
+#[math]
+pub fn field_of_values_boundary<T: ComplexField>(A: MatRef<'_, T>, num_angles: usize) -> Result<Vec<Complex<T::Real>>, EvdError> {
+	assert!(A.nrows() == A.ncols());
+	let n = A.nrows();
+
+	let A = Mat::<Complex<T::Real>>::from_fn(n, n, |i, j| Complex::new(real(A[(i, j)]), imag(A[(i, j)])));
+	let half = from_f64::<T::Real>(0.5);
+
+	let mut boundary = Vec::with_capacity(num_angles);
+	if n == 0 {
+		return Ok(boundary);
+	}
+
+	let mut H = Mat::<Complex<T::Real>>::zeros(n, n);
+	for k in 0..num_angles {
+		let theta = 2.0 * core::f64::consts::PI * (k as f64) / (num_angles as f64);
+		let rot = Complex::new(from_f64::<T::Real>(libm::cos(theta)), from_f64::<T::Real>(libm::sin(theta)));
+
+		for j in 0..n {
+			for i in 0..n {
+				H[(i, j)] = mul_real(rot * A[(i, j)] + conj(rot * A[(j, i)]), half);
+			}
+		}
+
+		// the eigenvalues are sorted in nondecreasing order
+		let evd = SelfAdjointEigen::new(H.as_ref(), Side::Lower)?;
+		let x = evd.U().col(n - 1);
+		boundary.push(x.adjoint() * (A.as_ref() * x));
+	}
+
+	Ok(boundary)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::assert;
+	use crate::stats::prelude::*;
+
+	#[test]
+	fn test_field_of_values_normal() {
+		let rng = &mut StdRng::seed_from_u64(0);
+
+		// the field of values of a normal matrix is the convex hull of its eigenvalues
+		let eigenvalues = [
+			c64::new(1.0, 0.0),
+			c64::new(-1.0, 2.0),
+			c64::new(-2.0, -1.0),
+			c64::new(0.5, 3.0),
+			c64::new(-0.5, 0.5),
+		];
+		let n = eigenvalues.len();
+
+		let Q: Mat<c64> = UnitaryMat {
+			dim: n,
+			standard_normal: ComplexDistribution::new(StandardNormal, StandardNormal),
+		}
+		.rand(rng);
+		let D = Col::from_fn(n, |i| eigenvalues[i]);
+		let A = &Q * D.as_diagonal() * Q.adjoint();
+
+		let num_angles = 64;
+		let boundary = field_of_values_boundary(A.as_ref(), num_angles).unwrap();
+		assert!(boundary.len() == num_angles);
+
+		// support function of the convex hull along the direction e^{-i theta}
+		let support = |theta: f64| {
+			let rot = c64::new(theta.cos(), theta.sin());
+			eigenvalues.iter().map(|z| (rot * z).re).fold(f64::NEG_INFINITY, f64::max)
+		};
+
+		for (k, z) in boundary.iter().enumerate() {
+			let theta = 2.0 * core::f64::consts::PI * (k as f64) / (num_angles as f64);
+			let rot = c64::new(theta.cos(), theta.sin());
+
+			// each sample is on the boundary of the hull, in the direction of its angle
+			assert!(((rot * z).re - support(theta)).abs() < 1e-10);
+
+			// and inside the hull for every other direction
+			for l in 0..num_angles {
+				let theta = 2.0 * core::f64::consts::PI * (l as f64) / (num_angles as f64);
+				let rot = c64::new(theta.cos(), theta.sin());
+				assert!((rot * z).re <= support(theta) + 1e-10);
+			}
+		}
+
+		// real input
+		let R = mat![[1.0, 0.0], [0.0, -1.0f64]];
+		let boundary = field_of_values_boundary(R.as_ref(), 4).unwrap();
+		assert!((boundary[0] - c64::new(1.0, 0.0)).l1_norm() < 1e-12);
+		assert!((boundary[2] - c64::new(-1.0, 0.0)).l1_norm() < 1e-12);
+	}
+}
@@ -0,0 +1,89 @@
+use crate::internal_prelude::*;
+use crate::{assert, get_global_parallelism};
+use alloc::vec::Vec;
+use linalg::solvers::ColPivQr;
+
+/// computes a rank-$k$ column interpolative decomposition of $A$
+///
+/// returns the indices of the $k$ selected columns, and the $k \times n$ interpolation matrix $X$
+/// such that $A \approx A_{:, \text{cols}} X$
+///
+/// the columns are selected with a column pivoted $QR$ decomposition $A P = Q R$. if $R_{11}$
+/// denotes the leading $k \times k$ block of $R$ and $R_{12}$ the block to its right, then
+/// $X = [I | R_{11}^{-1} R_{12}] P^{-1}$
+///
+/// # panics
+///
+/// panics if `k > min(A.nrows(), A.ncols())`
+#[track_caller]
+pub fn interpolative_decomposition<T: ComplexField>(A: MatRef<'_, T>, k: usize) -> (Vec<usize>, Mat<T>) {
+	let par = get_global_parallelism();
+
+	let (m, n) = A.shape();
+	assert!(k <= Ord::min(m, n));
+
+	let qr = ColPivQr::new(A);
+	let R = qr.thin_R();
+	let (fwd, _) = qr.P().arrays();
+
+	let mut coeffs = R.get(..k, k..).to_owned();
+	linalg::triangular_solve::solve_upper_triangular_in_place(R.get(..k, ..k), coeffs.as_mut(), par);
+
+	let mut X = Mat::zeros(k, n);
+	for (j, &p) in fwd[..k].iter().enumerate() {
+		X[(j, p)] = one();
+	}
+	for (j, &p) in fwd[k..].iter().enumerate() {
+		X.col_mut(p).copy_from(coeffs.col(j));
+	}
+
+	(fwd[..k].to_vec(), X)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::assert;
+	use crate::stats::prelude::*;
+
+	#[test]
+	fn test_interpolative() {
+		let rng = &mut StdRng::seed_from_u64(0);
+		let (m, n, r) = (60, 40, 6);
+
+		let B = CwiseMatDistribution {
+			nrows: m,
+			ncols: r,
+			dist: StandardNormal,
+		}
+		.rand::<Mat<f64>>(rng);
+		let C = CwiseMatDistribution {
+			nrows: r,
+			ncols: n,
+			dist: StandardNormal,
+		}
+		.rand::<Mat<f64>>(rng);
+		let E = CwiseMatDistribution {
+			nrows: m,
+			ncols: n,
+			dist: StandardNormal,
+		}
+		.rand::<Mat<f64>>(rng);
+
+		let A = &B * &C + 1e-6 * &E;
+
+		for k in [r - 2, r, r + 2] {
+			let (cols, X) = interpolative_decomposition(A.as_ref(), k);
+			assert!(cols.len() == k);
+			assert!(all(X.nrows() == k, X.ncols() == n));
+
+			let A_cols = Mat::from_fn(m, k, |i, j| A[(i, cols[j])]);
+			let err = (&A - &A_cols * &X).norm_l2();
+
+			let s = A.singular_values().unwrap();
+			let err_svd = s[k..].iter().map(|s| s * s).sum::<f64>().sqrt();
+
+			assert!(err <= 10.0 * f64::sqrt((1 + k * (n - k)) as f64) * err_svd);
+		}
+	}
+}
@@ -0,0 +1,8 @@
+//! the closure-based solvers are implemented next to the [`LinOp`](crate::matrix_free::LinOp)
+//! based ones in [`matrix_free`](crate::matrix_free), with which they share their parameters,
+//! statistics and errors. they are re-exported here so that they can be found along with the
+//! dense solvers
+
+pub use crate::matrix_free::conjugate_gradient::{CgError, CgInfo, cg_solve};
+pub use crate::matrix_free::gmres::{GmresError, GmresInfo, gmres_solve};
+pub use crate::matrix_free::minres::{MinresError, MinresInfo, minres_solve};
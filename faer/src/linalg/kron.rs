@@ -73,9 +73,89 @@ pub fn kron<T: ComplexField>(dst: MatMut<'_, T>, lhs: MatRef<'_, T>, rhs: MatRef
 	// the other lever
 }
 
+/// kronecker sum of two square matrices
+///
+/// the kronecker sum of $A$ ($m \times m$) and $B$ ($n \times n$) is the $mn \times mn$ matrix
+/// $A \oplus B = A \otimes I_n + I_m \otimes B$. it is built directly, without forming either
+/// kronecker product
+///
+/// the eigenvalues of $A \oplus B$ are the sums $\lambda_i + \mu_j$ of the eigenvalues $\lambda_i$ of
+/// $A$ and $\mu_j$ of $B$
+///
+/// # panics
+///
+/// panics if either `A` or `B` is not square
+#[track_caller]
+#[math]
+pub fn kron_sum<T: ComplexField>(A: MatRef<'_, T>, B: MatRef<'_, T>) -> Mat<T> {
+	Assert!(all(A.nrows() == A.ncols(), B.nrows() == B.ncols()));
+	let m = A.nrows();
+	let n = B.nrows();
+
+	let mut dst = Mat::<T>::zeros(m * n, m * n);
+	for j in 0..m {
+		for i in 0..m {
+			let mut block = dst.get_mut(i * n..(i + 1) * n, j * n..(j + 1) * n);
+			let a = copy(A[(i, j)]);
+			for k in 0..n {
+				block[(k, k)] = copy(a);
+			}
+			if i == j {
+				z!(block, B).for_each(|uz!(dst, b)| *dst = *dst + *b);
+			}
+		}
+	}
+	dst
+}
+
+/// computes $B X A^\top$, which is the matrix form of applying the kronecker product $A \otimes B$
+/// to $X$, without forming the kronecker product
+///
+/// $\operatorname{vec}(X)$ stacks the columns of $X$ into a single column, and
+/// $\operatorname{unvec}$ is its inverse. with these, $(A \otimes B) \operatorname{vec}(X) =
+/// \operatorname{vec}(B X A^\top)$, so the result of this function is
+/// $\operatorname{unvec}((A \otimes B) \operatorname{vec}(X))$, with `B.nrows()` rows and
+/// `A.nrows()` columns. this takes two matrix products instead of the $O(m_A n_A m_B n_B)$ storage of
+/// the kronecker product
+///
+/// # panics
+///
+/// panics if `X` is not a `B.ncols() × A.ncols()` matrix
+#[track_caller]
+pub fn kron_apply<T: ComplexField>(A: MatRef<'_, T>, B: MatRef<'_, T>, X: MatRef<'_, T>) -> Mat<T> {
+	Assert!(all(X.nrows() == B.ncols(), X.ncols() == A.ncols()));
+	let par = crate::get_global_parallelism();
+
+	let mut BX = Mat::<T>::zeros(B.nrows(), A.ncols());
+	linalg::matmul::matmul(BX.as_mut(), Accum::Replace, B, X, one::<T>(), par);
+	let mut Y = Mat::<T>::zeros(B.nrows(), A.nrows());
+	linalg::matmul::matmul(Y.as_mut(), Accum::Replace, BX.as_ref(), A.transpose(), one::<T>(), par);
+	Y
+}
+
+/// computes $(A \otimes B) v$ without forming the kronecker product
+///
+/// with $v = \operatorname{vec}(X)$, where $X$ is the `B.ncols() × A.ncols()` matrix whose columns
+/// are consecutive chunks of $v$, the result is $\operatorname{vec}(B X A^\top)$. see
+/// [`kron_apply`]
+///
+/// # panics
+///
+/// panics if `v` doesn't have `A.ncols() * B.ncols()` rows
+#[track_caller]
+pub fn kron_mv<T: ComplexField>(A: MatRef<'_, T>, B: MatRef<'_, T>, v: ColRef<'_, T>) -> Col<T> {
+	Assert!(Some(v.nrows()) == A.ncols().checked_mul(B.ncols()));
+
+	let X = Mat::from_fn(B.ncols(), A.ncols(), |i, j| v[j * B.ncols() + i].clone());
+	let Y = kron_apply(A, B, X.as_ref());
+
+	let m = B.nrows();
+	Col::from_fn(m * A.nrows(), |k| Y[(k % m, k / m)].clone())
+}
+
 #[cfg(test)]
 mod tests {
-	use super::kron;
+	use super::*;
 	use crate::{Col, Mat, Row, assert};
 
 	#[test]
@@ -135,4 +215,72 @@ mod tests {
 			assert!(out == expected);
 		}
 	}
+
+	#[test]
+	fn test_kron_sum() {
+		use crate::stats::prelude::*;
+
+		let rng = &mut StdRng::seed_from_u64(0);
+		for (m, n) in [(3, 4), (1, 5), (4, 1), (0, 3)] {
+			let A: Mat<f64> = sample_mat(StandardNormal, m, m, rng);
+			let B: Mat<f64> = sample_mat(StandardNormal, n, n, rng);
+
+			let mut expected = Mat::zeros(m * n, m * n);
+			let mut tmp = Mat::zeros(m * n, m * n);
+			kron(expected.as_mut(), A.as_ref(), Mat::identity(n, n).as_ref());
+			kron(tmp.as_mut(), Mat::identity(m, m).as_ref(), B.as_ref());
+			expected += &tmp;
+
+			assert!(kron_sum(A.as_ref(), B.as_ref()) == expected);
+		}
+
+		// the eigenvalues of the kronecker sum are the pairwise sums of the eigenvalues
+		let A = crate::mat![[2.0, 1.0], [1.0, 2.0f64]];
+		let B = crate::mat![[1.0, 0.0, 0.0], [0.0, -1.0, 0.0], [0.0, 0.0, 5.0f64]];
+		let mut expected = alloc::vec::Vec::new();
+		for a in [1.0, 3.0] {
+			for b in [-1.0, 1.0, 5.0] {
+				expected.push(a + b);
+			}
+		}
+		expected.sort_by(f64::total_cmp);
+
+		let S = kron_sum(A.as_ref(), B.as_ref()).self_adjoint_eigenvalues(crate::Side::Lower).unwrap();
+		for (s, e) in S.iter().zip(&expected) {
+			assert!((s - e).abs() < 1e-12);
+		}
+	}
+
+	#[test]
+	fn test_kron_mv() {
+		let A = Mat::from_fn(3, 2, |i, j| (i + 3 * j) as f64 - 2.0);
+		let B = Mat::from_fn(2, 4, |i, j| (2 * i + j * j) as f64 + 0.5);
+		let v = Col::from_fn(8, |i| i as f64 * 0.25 - 1.0);
+
+		let mut K = Mat::zeros(6, 8);
+		kron(K.as_mut(), A.as_ref(), B.as_ref());
+		assert!(kron_mv(A.as_ref(), B.as_ref(), v.as_ref()) == &K * &v);
+
+		let I = Mat::<f64>::identity(2, 2);
+		assert!(kron_mv(I.as_ref(), I.as_ref(), v.get(..4)) == v.get(..4));
+		assert!(kron_mv(I.as_ref(), B.as_ref(), v.as_ref()) == Col::from_fn(4, |i| (&B * v.get(i / 2 * 4..i / 2 * 4 + 4))[i % 2]));
+	}
+
+	#[test]
+	fn test_kron_apply() {
+		let A = Mat::from_fn(3, 2, |i, j| (i + 3 * j) as f64 - 2.0);
+		let B = Mat::from_fn(2, 4, |i, j| (2 * i + j * j) as f64 + 0.5);
+		let X = Mat::from_fn(4, 2, |i, j| (i * j) as f64 - 0.75 * i as f64);
+
+		let mut K = Mat::zeros(6, 8);
+		kron(K.as_mut(), A.as_ref(), B.as_ref());
+
+		let vec = |X: &Mat<f64>| Col::from_fn(X.nrows() * X.ncols(), |k| X[(k % X.nrows(), k / X.nrows())]);
+		let unvec = |v: &Col<f64>, m: usize| Mat::from_fn(m, v.nrows() / m, |i, j| v[j * m + i]);
+
+		let Y = kron_apply(A.as_ref(), B.as_ref(), X.as_ref());
+		assert!(Y.shape() == (2, 3));
+		assert!(Y == unvec(&(&K * vec(&X)), 2));
+		assert!(Y == &B * &X * A.transpose());
+	}
 }
@@ -0,0 +1,157 @@
+use crate::internal_prelude::*;
+use crate::{assert, get_global_parallelism};
+use dyn_stack::MemBuffer;
+use linalg::svd::{ComputeSvdVectors, SvdError};
+
+/// total least squares error, returned by [`solve_total_lstsq`]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum TotalLstsqError {
+	/// the svd of the augmented matrix $[A | b]$ failed
+	Svd(SvdError),
+	/// the right singular vectors associated with the smallest singular value of $[A | b]$ all
+	/// have a zero last component, so the problem has no solution
+	NoSolution,
+}
+
+impl core::fmt::Display for TotalLstsqError {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		core::fmt::Debug::fmt(self, f)
+	}
+}
+
+impl core::error::Error for TotalLstsqError {}
+
+impl From<SvdError> for TotalLstsqError {
+	#[inline]
+	fn from(value: SvdError) -> Self {
+		Self::Svd(value)
+	}
+}
+
+/// computes the total least squares solution $x$ of $A x \approx b$
+///
+/// unlike ordinary least squares, which only accounts for errors in $b$, this finds the smallest
+/// perturbation $[E | r]$ (in the frobenius norm) such that $(A + E) x = b + r$ has a solution,
+/// which is the right model when both $A$ and $b$ are noisy
+///
+/// the solution is obtained from the right singular vectors $V_2$ of the augmented matrix
+/// $[A | b]$ that are associated with its smallest singular value, as $x = -V_{2, 1} v^H /
+/// \|v\|^2$, where $v$ is the last row of $V_2$ and $V_{2, 1}$ holds the other rows. if the
+/// smallest singular value is not unique, $V_2$ spans all the associated singular vectors, which
+/// yields the minimum norm solution
+///
+/// only the singular values and right singular vectors of $[A | b]$ are computed. if $v$ is zero,
+/// the problem has no total least squares solution, and [`TotalLstsqError::NoSolution`] is
+/// returned
+///
+/// # panics
+///
+/// panics if `b.nrows() != A.nrows()`
+#[track_caller]
+#[math]
+pub fn solve_total_lstsq<T: ComplexField>(A: MatRef<'_, T>, b: ColRef<'_, T>) -> Result<Col<T>, TotalLstsqError> {
+	let (m, n) = A.shape();
+	assert!(b.nrows() == m);
+
+	let mut C = Mat::<T>::zeros(m, n + 1);
+	C.get_mut(.., ..n).copy_from(A);
+	C.col_mut(n).copy_from(b);
+
+	let par = get_global_parallelism();
+	let mut S = Diag::<T>::zeros(Ord::min(m, n + 1));
+	let mut V = Mat::<T>::zeros(n + 1, n + 1);
+	linalg::svd::svd(
+		C.as_ref(),
+		S.as_mut(),
+		None,
+		Some(V.as_mut()),
+		par,
+		MemStack::new(&mut MemBuffer::new(linalg::svd::svd_scratch::<T>(
+			m,
+			n + 1,
+			ComputeSvdVectors::No,
+			ComputeSvdVectors::Full,
+			par,
+			default(),
+		))),
+		default(),
+	)?;
+	let S = S.column_vector();
+
+	// singular values past the first `min(m, n + 1)` are zero
+	let sigma = |k: usize| -> T::Real { if k < S.nrows() { real(S[k]) } else { zero() } };
+	let sigma_min = sigma(n);
+	let tol = eps::<T::Real>() * from_f64::<T::Real>(Ord::max(m, n + 1) as f64) * sigma(0);
+
+	let mut p = n;
+	while p > 0 && sigma(p - 1) <= sigma_min + tol {
+		p -= 1;
+	}
+
+	let V2 = V.get(.., p..);
+	let v = V2.row(n);
+	let v_norm2 = v.squared_norm_l2();
+	if v_norm2 == zero::<T::Real>() {
+		return Err(TotalLstsqError::NoSolution);
+	}
+
+	let mut x = V2.get(..n, ..) * v.adjoint();
+	let scale = -recip(v_norm2);
+	z!(x.as_mut()).for_each(|uz!(x)| *x = mul_real(*x, scale));
+	Ok(x)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::assert;
+	use crate::stats::prelude::*;
+	use linalg::solvers::SolveLstsqCore;
+
+	#[test]
+	fn test_total_lstsq() {
+		let rng = &mut StdRng::seed_from_u64(0);
+		let (m, n) = (2000, 3);
+		let noise = 0.5;
+
+		let x_true = Col::from_fn(n, |i| (i + 1) as f64);
+		let A_true: Mat<f64> = sample_mat(StandardNormal, m, n, rng);
+		let b_true = &A_true * &x_true;
+
+		// the same amount of noise on both sides
+		let A_noise: Mat<f64> = sample_mat(StandardNormal, m, n, rng);
+		let b_noise: Mat<f64> = sample_mat(StandardNormal, m, 1, rng);
+		let A = &A_true + noise * A_noise;
+		let b = &b_true + noise * b_noise.col(0);
+
+		let x_tls = solve_total_lstsq(A.as_ref(), b.as_ref()).unwrap();
+		let mut x_ols = b.clone();
+		A.qr().solve_lstsq_in_place_with_conj(Conj::No, x_ols.as_mat_mut());
+		let x_ols = x_ols.get(..n);
+
+		let err_tls = (&x_tls - &x_true).norm_l2();
+		let err_ols = (x_ols - &x_true).norm_l2();
+		assert!(err_tls < 0.1);
+		assert!(err_tls < 0.25 * err_ols);
+
+		// without noise, both agree with the exact solution
+		let x = solve_total_lstsq(A_true.as_ref(), b_true.as_ref()).unwrap();
+		assert!((&x - &x_true).norm_l2() < 1e-10);
+	}
+
+	#[test]
+	fn test_total_lstsq_degenerate() {
+		// all the singular values of [A | b] are equal to one, so the smallest one is not unique and
+		// the minimum norm solution is zero
+		let A = mat![[1.0, 0.0], [0.0, 1.0], [0.0, 0.0f64]];
+		let b = col![0.0, 0.0, 1.0f64];
+		let x = solve_total_lstsq(A.as_ref(), b.as_ref()).unwrap();
+		assert!(x.norm_l2() < 1e-12);
+
+		// b is orthogonal to the range of A, and the smallest singular value belongs to the
+		// columns of A, so there is no solution
+		let A = mat![[1e-3, 0.0], [0.0, 1.0], [0.0, 0.0f64]];
+		let b = col![0.0, 0.0, 1.0f64];
+		assert!(solve_total_lstsq(A.as_ref(), b.as_ref()) == Err(TotalLstsqError::NoSolution));
+	}
+}
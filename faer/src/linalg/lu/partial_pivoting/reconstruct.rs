@@ -1,4 +1,3 @@
-use crate::assert;
 use crate::internal_prelude::*;
 use linalg::matmul::triangular::BlockStructure;
 
@@ -19,7 +18,9 @@ pub fn reconstruct<I: Index, T: ComplexField>(
 	let m = L.nrows();
 	let n = U.ncols();
 	let size = Ord::min(m, n);
-	assert!(all(out.nrows() == m, out.ncols() == n, row_perm.len() == m));
+	assert_dim!(out.nrows(), m);
+	assert_dim!(out.ncols(), n);
+	assert_dim!(row_perm.len(), m);
 
 	let (mut tmp, _) = unsafe { temp_mat_uninit::<T, _, _>(m, n, stack) };
 	let mut tmp = tmp.as_mat_mut();
@@ -547,6 +547,8 @@ impl_partial_eq!(Mat< LhsT, Rows, Cols,>, MatRef<'_,  RhsT, Rows, Cols>);
 impl_partial_eq!(Mat< LhsT, Rows, Cols,>, MatMut<'_,  RhsT, Rows, Cols>);
 impl_partial_eq!(Mat< LhsT, Rows, Cols,>, Mat< RhsT, Rows, Cols>);
 
+impl<T: ComplexField + Eq, Rows: Shape, Cols: Shape> Eq for Mat<T, Rows, Cols> {}
+
 impl<T: ComplexField, LhsT: Conjugate<Canonical = T>, RhsT: Conjugate<Canonical = T>, Len: Shape> PartialEq<ColRef<'_, RhsT, Len>>
 	for ColRef<'_, LhsT, Len>
 {
@@ -3966,6 +3968,30 @@ mod test {
 		assert!(&B * &A == (B.as_mat() * A.as_mat())[(0, 0)],);
 	}
 
+	#[test]
+	fn test_partial_eq() {
+		let A = Mat::from_fn(3, 4, |i, j| (i + 10 * j) as f64);
+		let mut B = mat![[0.0, 10.0, 20.0, 30.0], [1.0, 11.0, 21.0, 31.0], [2.0, 12.0, 22.0, 32.0]];
+
+		assert!(A == B);
+		assert!(A.as_ref() == B.as_mut());
+		assert_eq!(A, B);
+
+		// same elements, different shape
+		assert!(A.get(.., ..3) != B);
+		assert!(Mat::<f64>::zeros(2, 3) != Mat::<f64>::zeros(3, 2));
+		assert!(Mat::<f64>::zeros(0, 3) != Mat::<f64>::zeros(3, 0));
+
+		let mut C = A.clone();
+		C[(1, 2)] = f64::NAN;
+		assert!(C != C);
+
+		let D = Col::from_fn(3, |i| (11 * i) as f64).into_diagonal();
+		assert!(D == A.get(.., ..3).diagonal());
+		assert!(D != Diag::<f64>::zeros(3));
+		assert!(D != Col::from_fn(4, |i| (11 * i) as f64).into_diagonal());
+	}
+
 	fn assert_matrix_approx_eq(given: Mat<f64>, expected: &Mat<f64>) {
 		assert_eq!(given.nrows(), expected.nrows());
 		assert_eq!(given.ncols(), expected.ncols());
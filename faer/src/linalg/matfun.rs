@@ -0,0 +1,707 @@
+use crate::internal_prelude::*;
+use crate::{assert, get_global_parallelism};
+use linalg::evd::EvdError;
+use linalg::solvers::{DenseSolveCore, PartialPivLu, Solve, Svd};
+use linalg::svd::SvdError;
+
+const SIGN_MAX_ITER: usize = 100;
+
+/// eigenvalues closer than this are put in the same block of the parlett recurrence
+const PARLETT_CLUSTER_DELTA: f64 = 0.1;
+/// initial and maximal number of quadrature nodes used to evaluate the function on a block of
+/// clustered eigenvalues
+const PARLETT_QUADRATURE_MIN_NODES: usize = 16;
+const PARLETT_QUADRATURE_MAX_NODES: usize = 4096;
+
+/// computes the matrix sign function $\text{sign}(A) = A (A^2)^{-1/2}$
+///
+/// the result is computed with the newton iteration $X_{k+1} = (X_k + X_k^{-1}) / 2$, $X_0 = A$,
+/// which converges quadratically if $A$ has no eigenvalues on the imaginary axis. the iteration
+/// stops once $\|X_k^2 - I\|_F$ is small enough relative to $\|X_k\|_F^2$
+///
+/// # errors
+///
+/// returns [`EvdError::NoConvergence`] if the iteration doesn't converge, which happens when $A$
+/// has eigenvalues on (or numerically close to) the imaginary axis
+///
+/// # panics
+///
+/// panics if $A$ is not a square matrix
+#[track_caller]
+#[math]
+pub fn sign<T: ComplexField>(A: MatRef<'_, T>) -> Result<Mat<T>, EvdError> {
+	assert!(A.nrows() == A.ncols());
+
+	let par = get_global_parallelism();
+	let n = A.nrows();
+
+	let tol = from_f64::<T::Real>((8 * Ord::max(n, 1)) as f64) * eps::<T::Real>();
+	let half = from_f64::<T::Real>(0.5);
+
+	let mut X = A.to_owned();
+	let mut residual = Mat::<T>::zeros(n, n);
+
+	for _ in 0..SIGN_MAX_ITER {
+		let X_inv = PartialPivLu::new(X.as_ref()).inverse();
+		z!(X.as_mut(), X_inv.as_ref()).for_each(|uz!(x, x_inv)| *x = mul_real(*x + *x_inv, half));
+
+		linalg::matmul::matmul(residual.as_mut(), Accum::Replace, X.as_ref(), X.as_ref(), one::<T>(), par);
+		for i in 0..n {
+			residual[(i, i)] = residual[(i, i)] - one::<T>();
+		}
+
+		let residual_norm = residual.norm_l2();
+		let X_norm = X.norm_l2();
+		if is_nan(residual_norm) || is_nan(X_norm) {
+			return Err(EvdError::NoConvergence);
+		}
+		if residual_norm <= tol * X_norm * X_norm {
+			return Ok(X);
+		}
+	}
+
+	Err(EvdError::NoConvergence)
+}
+
+/// computes the singular value soft-thresholding $D_\tau(A) = U (S - \tau)_+ V^H$, where
+/// $A = U S V^H$ is the thin singular value decomposition of $A$
+///
+/// this is the proximal operator of the nuclear norm, which is the inner step of singular value
+/// thresholding algorithms for matrix completion. see [`Svd::soft_threshold`] to reuse an existing
+/// decomposition
+///
+/// # errors
+///
+/// returns an error if the singular value decomposition fails to converge
+#[track_caller]
+pub fn svt<T: ComplexField>(A: MatRef<'_, T>, tau: T::Real) -> Result<Mat<T>, SvdError> {
+	Ok(Svd::new_thin(A)?.soft_threshold(tau))
+}
+
+/// computes the self-adjoint positive semi-definite factor $P = (A^H A)^{1/2}$ of the polar
+/// decomposition $A = U P$
+///
+/// with the thin singular value decomposition $A = W S V^H$, $P = V S V^H$. see
+/// [`PolarDecomp`](linalg::solvers::PolarDecomp) to also compute $U$
+///
+/// # errors
+///
+/// returns an error if the singular value decomposition fails to converge
+#[track_caller]
+#[math]
+pub fn symmetric_polar_factor<T: ComplexField>(A: MatRef<'_, T>) -> Result<Mat<T>, SvdError> {
+	let par = get_global_parallelism();
+
+	let svd = Svd::new_thin(A)?;
+	let (V, S) = (svd.V(), svd.S());
+	let n = V.nrows();
+
+	let VxS = Mat::from_fn(n, V.ncols(), |i, j| mul_real(V[(i, j)], real(S[j])));
+	let mut P = Mat::<T>::zeros(n, n);
+	linalg::matmul::matmul(P.as_mut(), Accum::Replace, VxS.as_ref(), V.adjoint(), one::<T>(), par);
+	Ok(P)
+}
+
+/// maximum $\|A\|_1$ for which the $[m/m]$ padé approximant of $\exp(A)$ has a backward error
+/// below the unit roundoff in double precision, for $m = 3, 5, 7, 9, 13$
+const EXPM_THETA: [f64; 5] = [
+	1.495585217958292e-2,
+	2.53939833006323e-1,
+	9.504178996162932e-1,
+	2.097847961257068e0,
+	5.371920351148152e0,
+];
+
+const EXPM_PADE_3: &[f64] = &[120.0, 60.0, 12.0, 1.0];
+const EXPM_PADE_5: &[f64] = &[30240.0, 15120.0, 3360.0, 420.0, 30.0, 1.0];
+const EXPM_PADE_7: &[f64] = &[17297280.0, 8648640.0, 1995840.0, 277200.0, 25200.0, 1512.0, 56.0, 1.0];
+const EXPM_PADE_9: &[f64] = &[
+	17643225600.0,
+	8821612800.0,
+	2075673600.0,
+	302702400.0,
+	30270240.0,
+	2162160.0,
+	110880.0,
+	3960.0,
+	90.0,
+	1.0,
+];
+const EXPM_PADE_13: &[f64] = &[
+	64764752532480000.0,
+	32382376266240000.0,
+	7771770303897600.0,
+	1187353796428800.0,
+	129060195264000.0,
+	10559470521600.0,
+	670442572800.0,
+	33522128640.0,
+	1323241920.0,
+	40840800.0,
+	960960.0,
+	16380.0,
+	182.0,
+	1.0,
+];
+
+/// pairs $(m, \theta_m)$, where $\theta_m$ is the maximum $\|A\|_1$ for which the degree $m$
+/// truncated taylor series of $\exp(A)$ has a backward error below the unit roundoff in double
+/// precision, from al-mohy and higham (2011)
+const EXPMV_THETA: [(usize, f64); 11] = [
+	(5, 2.4e-3),
+	(10, 1.4e-1),
+	(15, 6.4e-1),
+	(20, 1.4e0),
+	(25, 2.4e0),
+	(30, 3.5e0),
+	(35, 4.7e0),
+	(40, 6.0e0),
+	(45, 7.2e0),
+	(50, 8.5e0),
+	(55, 9.9e0),
+];
+
+/// returns the smallest integer $k \ge 0$ such that $k \ge x$, saturating at `usize::MAX`
+///
+/// an upper bound is found by doubling, then refined by bisection, so that only $O(\log k)$
+/// comparisons are needed
+#[math]
+fn ceil_to_usize<T: RealField>(x: T) -> usize {
+	if x <= zero() {
+		return 0;
+	}
+
+	// invariant: lo < x <= hi
+	let mut hi = 1usize;
+	while from_f64::<T>(hi as f64) < x {
+		hi = match hi.checked_mul(2) {
+			Some(hi) => hi,
+			None => return usize::MAX,
+		};
+	}
+	let mut lo = hi / 2;
+	while hi - lo > 1 {
+		let mid = lo + (hi - lo) / 2;
+		if from_f64::<T>(mid as f64) < x {
+			lo = mid;
+		} else {
+			hi = mid;
+		}
+	}
+	hi
+}
+
+/// returns `true` if all the entries of $A$ are finite
+#[math]
+fn is_all_finite<T: ComplexField>(A: MatRef<'_, T>) -> bool {
+	let mut finite = true;
+	z!(A).for_each(|uz!(a)| finite = finite && is_finite(*a));
+	finite
+}
+
+/// computes the matrix exponential $\exp(A)$
+///
+/// the result is computed with the scaling and squaring algorithm of higham (2005): the smallest
+/// padé degree $m \in \{3, 5, 7, 9, 13\}$ that is accurate enough for $\|A\|_1$ is selected, and if
+/// none is, $A$ is scaled by $2^{-s}$ so that the degree $13$ approximant can be used. the
+/// approximant $r_m(A) = q_m(A)^{-1} p_m(A)$ is evaluated with an $LU$ solve, then squared $s$
+/// times
+///
+/// # panics
+///
+/// panics if $A$ is not a square matrix, or if it has non-finite entries
+#[track_caller]
+#[math]
+pub fn expm<T: ComplexField>(A: MatRef<'_, T>) -> Mat<T> {
+	assert!(all(A.nrows() == A.ncols(), is_all_finite(A)));
+
+	let par = get_global_parallelism();
+	let n = A.nrows();
+	let A_norm = A.operator_norm_l1();
+	let theta = |i: usize| from_f64::<T::Real>(EXPM_THETA[i]);
+
+	let mut A = A.to_owned();
+	let mut s = 0usize;
+
+	let degree: usize = if A_norm <= theta(0) {
+		3
+	} else if A_norm <= theta(1) {
+		5
+	} else if A_norm <= theta(2) {
+		7
+	} else if A_norm <= theta(3) {
+		9
+	} else {
+		// scale so that |A / 2^s|_1 <= theta_13, i.e., s = ceil(log2(|A|_1 / theta_13))
+		let ratio = A_norm / theta(4);
+		let two = from_f64::<T::Real>(2.0);
+		s = ceil_to_usize(ln(&ratio) / ln(&two));
+		let half = from_f64::<T::Real>(0.5);
+		let mut scale = one::<T::Real>();
+		for _ in 0..s {
+			scale = scale * half;
+		}
+		z!(A.as_mut()).for_each(|uz!(a)| *a = mul_real(*a, scale));
+		13
+	};
+
+	let b = |k: usize| {
+		from_f64::<T>(match degree {
+			3 => EXPM_PADE_3[k],
+			5 => EXPM_PADE_5[k],
+			7 => EXPM_PADE_7[k],
+			9 => EXPM_PADE_9[k],
+			_ => EXPM_PADE_13[k],
+		})
+	};
+	let I = Mat::<T>::identity(n, n);
+	let prod = |lhs: &Mat<T>, rhs: &Mat<T>| {
+		let mut out = Mat::<T>::zeros(n, n);
+		linalg::matmul::matmul(out.as_mut(), Accum::Replace, lhs.as_ref(), rhs.as_ref(), one::<T>(), par);
+		out
+	};
+
+	// p_m(A) = V + U and q_m(A) = V - U, where U holds the odd powers of A and V the even ones
+	let mut U_inner;
+	let mut V;
+	if degree < 13 {
+		let A2 = prod(&A, &A);
+		let mut power = I.clone();
+		U_inner = Mat::<T>::zeros(n, n);
+		V = Mat::<T>::zeros(n, n);
+		for k in 0..(degree + 1) / 2 {
+			if k > 0 {
+				power = prod(&power, &A2);
+			}
+			z!(U_inner.as_mut(), power.as_ref()).for_each(|uz!(u, p)| *u = *u + b(2usize * k + 1usize) * *p);
+			z!(V.as_mut(), power.as_ref()).for_each(|uz!(v, p)| *v = *v + b(2usize * k) * *p);
+		}
+	} else {
+		let A2 = prod(&A, &A);
+		let A4 = prod(&A2, &A2);
+		let A6 = prod(&A2, &A4);
+
+		let mut W = Mat::<T>::zeros(n, n);
+		z!(W.as_mut(), A2.as_ref(), A4.as_ref(), A6.as_ref()).for_each(|uz!(w, a2, a4, a6)| *w = b(13) * *a6 + b(11) * *a4 + b(9) * *a2);
+		U_inner = prod(&A6, &W);
+		z!(U_inner.as_mut(), I.as_ref(), A2.as_ref(), A4.as_ref(), A6.as_ref())
+			.for_each(|uz!(u, i, a2, a4, a6)| *u = *u + b(7) * *a6 + b(5) * *a4 + b(3) * *a2 + b(1) * *i);
+
+		z!(W.as_mut(), A2.as_ref(), A4.as_ref(), A6.as_ref()).for_each(|uz!(w, a2, a4, a6)| *w = b(12) * *a6 + b(10) * *a4 + b(8) * *a2);
+		V = prod(&A6, &W);
+		z!(V.as_mut(), I.as_ref(), A2.as_ref(), A4.as_ref(), A6.as_ref())
+			.for_each(|uz!(v, i, a2, a4, a6)| *v = *v + b(6) * *a6 + b(4) * *a4 + b(2) * *a2 + b(0) * *i);
+	}
+	let U = prod(&A, &U_inner);
+
+	// r_m(A) = (V - U)^{-1} (V + U)
+	let mut P = V.clone();
+	z!(P.as_mut(), U.as_ref()).for_each(|uz!(p, u)| *p = *p + *u);
+	z!(V.as_mut(), U.as_ref()).for_each(|uz!(q, u)| *q = *q - *u);
+	let mut R = PartialPivLu::new(V.as_ref()).solve(P);
+
+	let mut tmp = Mat::<T>::zeros(n, n);
+	for _ in 0..s {
+		linalg::matmul::matmul(tmp.as_mut(), Accum::Replace, R.as_ref(), R.as_ref(), one::<T>(), par);
+		core::mem::swap(&mut R, &mut tmp);
+	}
+	R
+}
+
+/// computes $\exp(A) v$ without forming $\exp(A)$
+///
+/// $\exp(A) v = (\exp(A / s))^s v$ is computed by applying the degree $m$ truncated taylor series
+/// of $\exp(A / s)$ to the vector $s$ times, following al-mohy and higham (2011). $m$ and
+/// $s = \lceil \|A\|_1 / \theta_m \rceil$ are chosen to minimize the number $m s$ of
+/// matrix-vector products, where $\theta_m$ bounds $\|A / s\|_1$ so that the truncation error is
+/// below the unit roundoff. each series is also stopped early once a term becomes negligible
+/// compared to the partial sum
+///
+/// # panics
+///
+/// panics if $A$ is not a square matrix, if it has non-finite entries, or if `v` doesn't have
+/// `A.nrows()` rows
+#[track_caller]
+#[math]
+pub fn expm_times_vec<T: ComplexField>(A: MatRef<'_, T>, v: ColRef<'_, T>) -> Col<T> {
+	assert!(all(A.nrows() == A.ncols(), v.nrows() == A.nrows(), is_all_finite(A)));
+
+	let A_norm = A.operator_norm_l1();
+	let (m, s) = EXPMV_THETA
+		.iter()
+		.map(|&(m, theta)| (m, Ord::max(1, ceil_to_usize(A_norm / from_f64::<T::Real>(theta)))))
+		.min_by_key(|&(m, s)| m.saturating_mul(s))
+		.unwrap();
+	let inv_s = recip(from_f64::<T::Real>(s as f64));
+	let tol = eps::<T::Real>();
+
+	let par = get_global_parallelism();
+	let mut x = v.to_owned();
+	let mut next = Col::<T>::zeros(v.nrows());
+	for _ in 0..s {
+		let mut term = x.clone();
+		for k in 1..m + 1 {
+			let scale = inv_s * from_f64::<T::Real>(1.0 / k as f64);
+			linalg::matmul::matmul(next.as_mut().as_mat_mut(), Accum::Replace, A, term.as_mat(), one::<T>(), par);
+			core::mem::swap(&mut term, &mut next);
+			z!(term.as_mut()).for_each(|uz!(t)| *t = mul_real(*t, scale));
+			z!(x.as_mut(), term.as_ref()).for_each(|uz!(x, t)| *x = *x + *t);
+
+			if term.norm_l1() <= tol * x.norm_l1() {
+				break;
+			}
+		}
+	}
+	x
+}
+
+/// computes $f(A) = Q f(T) Q^H$ from the complex schur form $A = Q T Q^H$ with the block parlett
+/// recurrence
+///
+/// the eigenvalues of $T$ are grouped into clusters that are at least
+/// [`PARLETT_CLUSTER_DELTA`] apart, and $T$ is reordered so that each cluster forms a contiguous
+/// diagonal block. the function is evaluated on each diagonal block with the trapezoidal rule
+/// applied to the cauchy integral over a circle enclosing the cluster, and the off-diagonal blocks
+/// are obtained by solving the sylvester equations of the parlett recurrence
+///
+/// on a circle of radius $r$ centered at $\sigma$, the $N$ point trapezoidal rule is exactly the
+/// taylor series of $f$ at $\sigma$ evaluated at the block, with each coefficient $a_m$ replaced by
+/// $\sum_{j \geq 0} a_{m + jN} r^{jN}$. if $f$ is analytic on a disk of radius $R > r$ and the
+/// eigenvalues of the block lie within $\rho < r$ of $\sigma$, the error therefore decays like
+/// $(\rho / r)^N + (r / R)^N$. the number of nodes is doubled, reusing the previous ones, until two
+/// successive approximations agree to $\sqrt{\varepsilon}$ relative to the result, which by the
+/// geometric convergence leaves an error of order $\varepsilon$ in the finer one
+///
+/// returns an error if the schur form can't be reordered, or if the quadrature doesn't converge
+/// within [`PARLETT_QUADRATURE_MAX_NODES`] nodes
+#[math]
+pub(crate) fn schur_parlett<T: RealField>(
+	Q: MatRef<'_, Complex<T>>,
+	S: MatRef<'_, Complex<T>>,
+	f: impl Fn(Complex<T>) -> Complex<T>,
+) -> Result<Mat<Complex<T>>, EvdError> {
+	let par = get_global_parallelism();
+	let n = S.nrows();
+
+	let mut S = S.to_owned();
+	let mut Q = Q.to_owned();
+	let delta = from_f64::<T>(PARLETT_CLUSTER_DELTA);
+
+	// merge eigenvalues that are within `delta` of each other into clusters
+	let mut cluster = (0..n).collect::<alloc::vec::Vec<_>>();
+	for i in 0..n {
+		for j in i + 1..n {
+			if cluster[i] != cluster[j] && abs(S[(i, i)] - S[(j, j)]) <= delta {
+				let (keep, discard) = (cluster[i], cluster[j]);
+				for c in cluster.iter_mut() {
+					if *c == discard {
+						*c = keep;
+					}
+				}
+			}
+		}
+	}
+
+	// reorder the schur form so that each cluster is contiguous
+	let mut blocks = alloc::vec::Vec::new();
+	let mut start = 0;
+	while start < n {
+		let mut end = start + 1;
+		for k in start + 1..n {
+			if cluster[k] == cluster[start] {
+				let mut dst = end;
+				if linalg::evd::schur::complex_schur::schur_move(S.as_mut(), Some(Q.as_mut()), k, &mut dst) != 0 {
+					return Err(EvdError::NoConvergence);
+				}
+				cluster[end..k + 1].rotate_right(1);
+				end += 1;
+			}
+		}
+		blocks.push((start, end - start));
+		start = end;
+	}
+
+	let mut F = Mat::<Complex<T>>::zeros(n, n);
+
+	// diagonal blocks
+	for &(start, size) in &blocks {
+		let S = S.as_ref().submatrix(start, start, size, size);
+		let mut F = F.as_mut().submatrix_mut(start, start, size, size);
+
+		if size == 1 {
+			F[(0, 0)] = f(copy(S[(0, 0)]));
+			continue;
+		}
+
+		let mut center = zero::<Complex<T>>();
+		for i in 0..size {
+			center = center + S[(i, i)];
+		}
+		center = mul_real(center, recip(from_f64::<T>(size as f64)));
+
+		let mut radius = zero::<T>();
+		for i in 0..size {
+			radius = max(radius, abs(S[(i, i)] - center));
+		}
+		radius = radius + delta;
+
+		// sum of f(z) (z - center) (z I - S)^{-1} over the nodes z = center + radius e^{i theta}
+		let mut sum = Mat::<Complex<T>>::zeros(size, size);
+		let mut resolvent = Mat::<Complex<T>>::zeros(size, size);
+		let mut add_nodes = |sum: &mut Mat<Complex<T>>, nodes: usize, step: usize| {
+			for k in (step / 2..nodes).step_by(step) {
+				let theta = 2.0 * core::f64::consts::PI * (k as f64) / (nodes as f64);
+				let offset = mul_real(Complex::new(from_f64::<T>(libm::cos(theta)), from_f64::<T>(libm::sin(theta))), radius);
+				let z = center + offset;
+
+				// (z I - S)^{-1}
+				let shifted = Mat::<Complex<T>>::from_fn(size, size, |i, j| if i == j { z - S[(i, j)] } else { -S[(i, j)] });
+				resolvent.as_mut().fill(zero());
+				resolvent.as_mut().diagonal_mut().fill(one());
+				linalg::triangular_solve::solve_upper_triangular_in_place(shifted.as_ref(), resolvent.as_mut(), par);
+
+				let weight = f(z) * offset;
+				for j in 0..size {
+					for i in 0..j + 1 {
+						sum[(i, j)] = sum[(i, j)] + weight * resolvent[(i, j)];
+					}
+				}
+			}
+		};
+
+		let tol = sqrt(eps::<T>());
+		let mut nodes = PARLETT_QUADRATURE_MIN_NODES;
+		add_nodes(&mut sum, nodes, 1);
+		let mut prev = Mat::<Complex<T>>::zeros(size, size);
+		loop {
+			// the nodes of the previous rule are the even nodes of the new one
+			prev.copy_from(&sum);
+			z!(prev.as_mut()).for_each(|uz!(x)| *x = mul_real(*x, recip(from_f64::<T>(nodes as f64))));
+			nodes *= 2;
+			add_nodes(&mut sum, nodes, 2);
+			F.copy_from(&sum);
+			z!(F.rb_mut()).for_each(|uz!(x)| *x = mul_real(*x, recip(from_f64::<T>(nodes as f64))));
+
+			z!(prev.as_mut(), F.rb()).for_each(|uz!(p, f)| *p = *p - *f);
+			let diff = prev.norm_l2();
+			if diff <= tol * F.norm_l2() {
+				break;
+			}
+			if nodes >= PARLETT_QUADRATURE_MAX_NODES || !is_finite(diff) {
+				return Err(EvdError::NoConvergence);
+			}
+		}
+	}
+
+	// off-diagonal blocks, one block column at a time from the diagonal upwards
+	for jb in 0..blocks.len() {
+		let (j0, nj) = blocks[jb];
+		for ib in (0..jb).rev() {
+			let (i0, ni) = blocks[ib];
+
+			let S_ij = S.as_ref().submatrix(i0, j0, ni, nj);
+			let mut C = F.as_ref().submatrix(i0, i0, ni, ni) * S_ij - S_ij * F.as_ref().submatrix(j0, j0, nj, nj);
+			for &(k0, nk) in &blocks[ib + 1..jb] {
+				C += F.as_ref().submatrix(i0, k0, ni, nk) * S.as_ref().submatrix(k0, j0, nk, nj)
+					- S.as_ref().submatrix(i0, k0, ni, nk) * F.as_ref().submatrix(k0, j0, nk, nj);
+			}
+
+			// solve S_ii X - X S_jj = C by substitution, since both blocks are upper triangular
+			let S_ii = S.as_ref().submatrix(i0, i0, ni, ni);
+			let S_jj = S.as_ref().submatrix(j0, j0, nj, nj);
+			for c in 0..nj {
+				for l in 0..c {
+					for i in 0..ni {
+						C[(i, c)] = C[(i, c)] + C[(i, l)] * S_jj[(l, c)];
+					}
+				}
+				for i in (0..ni).rev() {
+					let mut x = copy(C[(i, c)]);
+					for k in i + 1..ni {
+						x = x - S_ii[(i, k)] * C[(k, c)];
+					}
+					C[(i, c)] = x * recip(S_ii[(i, i)] - S_jj[(c, c)]);
+				}
+			}
+
+			F.as_mut().submatrix_mut(i0, j0, ni, nj).copy_from(&C);
+		}
+	}
+
+	let mut QF = Mat::<Complex<T>>::zeros(n, n);
+	linalg::matmul::matmul(QF.as_mut(), Accum::Replace, Q.as_ref(), F.as_ref(), one(), par);
+	linalg::matmul::matmul(F.as_mut(), Accum::Replace, QF.as_ref(), Q.adjoint(), one(), par);
+	Ok(F)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::assert;
+	use crate::stats::prelude::*;
+	use crate::utils::approx::*;
+
+	#[test]
+	fn test_svt() {
+		let rng = &mut StdRng::seed_from_u64(0);
+		let (m, n) = (20, 12);
+		let approx_eq = CwiseMat(ApproxEq::eps() * 128.0 * (m as f64));
+
+		let unitary = |dim: usize, rng: &mut StdRng| -> Mat<c64> {
+			UnitaryMat {
+				dim,
+				standard_normal: ComplexDistribution::new(StandardNormal, StandardNormal),
+			}
+			.rand(rng)
+		};
+		let U = unitary(m, rng);
+		let V = unitary(n, rng);
+		let sigma = Col::<f64>::from_fn(n, |i| (n - i) as f64);
+		let A = U.get(.., ..n) * Col::<c64>::from_fn(n, |i| c64::from(sigma[i])).as_diagonal() * V.adjoint();
+
+		// singular values below tau are removed, the others are shrunk by tau
+		let tau = 4.5;
+		let D = svt(A.as_ref(), tau).unwrap();
+		let shrunk = Col::<c64>::from_fn(n, |i| c64::from(f64::max(sigma[i] - tau, 0.0)));
+		assert!(D ~ U.get(.., ..n) * shrunk.as_diagonal() * V.adjoint());
+		assert!(D.thin_svd().unwrap().numerical_rank(1e-10) == 8);
+
+		assert!(svt(A.as_ref(), 0.0).unwrap() ~ A);
+		assert!(svt(A.as_ref(), 100.0).unwrap() == Mat::<c64>::zeros(m, n));
+		assert!(A.svd().unwrap().soft_threshold(tau) ~ D);
+	}
+
+	#[test]
+	fn test_sign() {
+		let rng = &mut StdRng::seed_from_u64(0);
+		let n = 6;
+		let approx_eq = CwiseMat(ApproxEq::eps() * 1e4);
+
+		let V = CwiseMatDistribution {
+			nrows: n,
+			ncols: n,
+			dist: StandardNormal,
+		}
+		.rand::<Mat<f64>>(rng);
+		let V = &V + Scale(4.0) * Mat::<f64>::identity(n, n);
+		let V_inv = V.partial_piv_lu().inverse();
+
+		let eigenvalues = [3.0, -1.0, 2.0, -0.5, 4.0, -7.0];
+		let signs = eigenvalues.map(|e: f64| e.signum());
+
+		let A = &V * Col::from_fn(n, |i| eigenvalues[i]).as_diagonal() * &V_inv;
+		let S = sign(A.as_ref()).unwrap();
+
+		let expected = &V * Col::from_fn(n, |i| signs[i]).as_diagonal() * &V_inv;
+		assert!(S ~ expected);
+		assert!(&S * &S ~ Mat::<f64>::identity(n, n));
+
+		let mut computed = S.eigenvalues_from_real().unwrap();
+		computed.sort_by(|a, b| a.re.total_cmp(&b.re));
+		let mut signs = signs;
+		signs.sort_by(f64::total_cmp);
+		for (e, s) in computed.iter().zip(signs) {
+			assert!(all((e.re - s).abs() < 1e-8, e.im.abs() < 1e-8));
+		}
+
+		// eigenvalues on the imaginary axis
+		let A = mat![[0.0, 1.0], [-1.0, 0.0f64]];
+		assert!(sign(A.as_ref()) == Err(EvdError::NoConvergence));
+	}
+
+	#[test]
+	fn test_ceil_to_usize() {
+		for (x, expected) in [
+			(-3.5, 0),
+			(0.0, 0),
+			(1e-300, 1),
+			(1.0, 1),
+			(1.5, 2),
+			(2.0, 2),
+			(1000.0, 1000),
+			(1000.5, 1001),
+			(1e15, 1_000_000_000_000_000),
+			(1e30, usize::MAX),
+			(f64::INFINITY, usize::MAX),
+		] {
+			assert!(ceil_to_usize(x) == expected);
+		}
+	}
+
+	#[test]
+	#[should_panic]
+	fn test_expm_infinite() {
+		expm(mat![[1.0, f64::INFINITY], [0.0, 1.0]].as_ref());
+	}
+
+	#[test]
+	#[should_panic]
+	fn test_expm_times_vec_nan() {
+		expm_times_vec(mat![[1.0, f64::NAN], [0.0, 1.0]].as_ref(), col![1.0, 1.0].as_ref());
+	}
+
+	#[test]
+	fn test_expm() {
+		let approx_eq = CwiseMat(ApproxEq::eps() * 64.0);
+
+		// scalar exponentials, covering every padé degree and the scaling and squaring path
+		for x in [0.0, 1e-3, 0.1, 0.5, 1.5, 4.0, -10.0, 30.0, 700.0] {
+			let E = expm(mat![[x]].as_ref());
+			// the condition number of the scalar exponential is |x|
+			assert!((E[(0, 0)] - f64::exp(x)).abs() <= 16.0 * f64::EPSILON * f64::max(1.0, x.abs()) * f64::exp(x));
+		}
+		let z = c64::new(2.5, -7.0);
+		let exp_z = c64::new(z.im.cos(), z.im.sin()) * z.re.exp();
+		assert!((expm(mat![[z]].as_ref())[(0, 0)] - exp_z).l1_norm() <= 1e-13 * exp_z.l1_norm());
+
+		// rotations
+		for t in [0.1, 1.0, 3.0, 20.0] {
+			let A = mat![[0.0, -t], [t, 0.0f64]];
+			let expected = mat![[t.cos(), -t.sin()], [t.sin(), t.cos()]];
+			assert!(expm(A.as_ref()) ~ expected);
+			let v = col![1.0, 2.0f64];
+			assert!((expm_times_vec(A.as_ref(), v.as_ref()) - &expected * &v).norm_max() <= 1e-13);
+		}
+
+		// nilpotent matrices, for which the taylor series is finite
+		let n = 5;
+		let N = Mat::<f64>::from_fn(n, n, |i, j| if j > i { (i + 2 * j) as f64 } else { 0.0 });
+		let mut expected = Mat::<f64>::identity(n, n);
+		let mut power = Mat::<f64>::identity(n, n);
+		let mut factorial = 1.0;
+		for k in 1..n {
+			power = &power * &N;
+			factorial *= k as f64;
+			expected += &power * Scale(1.0 / factorial);
+		}
+		let approx_eq = CwiseMat(ApproxEq::eps() * 1e4);
+		assert!(expm(N.as_ref()) ~ expected);
+		let v = Col::<f64>::from_fn(n, |i| i as f64 - 2.0);
+		assert!(expm_times_vec(N.as_ref(), v.as_ref()).as_mat() ~ (&expected * &v).as_mat());
+
+		// exp(A) exp(-A) = I
+		let rng = &mut StdRng::seed_from_u64(0);
+		let A = CwiseMatDistribution {
+			nrows: 8,
+			ncols: 8,
+			dist: ComplexDistribution::new(StandardNormal, StandardNormal),
+		}
+		.rand::<Mat<c64>>(rng);
+		let A_neg = -&A;
+		assert!(expm(A.as_ref()) * expm(A_neg.as_ref()) ~ Mat::<c64>::identity(8, 8));
+		let v = Col::<c64>::from_fn(8, |i| c64::new(i as f64, 1.0));
+		assert!(expm_times_vec(A.as_ref(), v.as_ref()).as_mat() ~ (expm(A.as_ref()) * &v).as_mat());
+
+		// skew-hermitian matrix with a large norm, whose exponential is unitary
+		let X = CwiseMatDistribution {
+			nrows: 8,
+			ncols: 8,
+			dist: ComplexDistribution::new(StandardNormal, StandardNormal),
+		}
+		.rand::<Mat<c64>>(rng);
+		let A = (&X - X.adjoint()) * Scale(c64::new(20.0, 0.0));
+		let x = expm_times_vec(A.as_ref(), v.as_ref());
+		assert!((x.norm_l2() - v.norm_l2()).abs() <= 1e-10 * v.norm_l2());
+		assert!((&x - expm(A.as_ref()) * &v).norm_l2() <= 1e-10 * v.norm_l2());
+	}
+}
@@ -865,6 +865,122 @@ pub fn matmul_with_conj<T: ComplexField, M: Shape, N: Shape, K: Shape>(
 	);
 }
 
+/// computes the matrix product `[beta * acc] + alpha * lhs * rhs` (implicitly conjugating the
+/// operands if needed) and stores the result in `acc`, like [`matmul`], but first checks the
+/// operands for structure that admits a cheaper kernel
+///
+/// - if `rhs` is the adjoint of `lhs` (i.e., it views the same memory with swapped strides) and
+/// `beta` is [`Accum::Replace`], the product is self-adjoint, so only its lower triangular half is
+/// computed in `dst`, and the upper half is filled in by symmetry. this saves about half of the
+/// flops
+/// - otherwise, the operands are passed to [`matmul`] along with their conjugation flags.
+/// matrix-vector products are handled there by the matrix-vector kernel
+///
+/// # panics
+///
+/// panics under the same conditions as [`matmul`]
+#[track_caller]
+#[math]
+pub fn smart_matmul<T: ComplexField, LhsT: Conjugate<Canonical = T>, RhsT: Conjugate<Canonical = T>, M: Shape, N: Shape, K: Shape>(
+	dst: impl AsMatMut<T = T, Rows = M, Cols = N>,
+	beta: Accum,
+	lhs: impl AsMatRef<T = LhsT, Rows = M, Cols = K>,
+	rhs: impl AsMatRef<T = RhsT, Rows = K, Cols = N>,
+	alpha: T,
+	par: Par,
+) {
+	let mut dst = dst;
+	let dst = dst.as_mat_mut();
+	let lhs = lhs.as_mat_ref();
+	let rhs = rhs.as_mat_ref();
+
+	precondition(dst.nrows(), dst.ncols(), lhs.nrows(), lhs.ncols(), rhs.nrows(), rhs.ncols());
+
+	let mut dst = dst.as_dyn_stride_mut().as_dyn_mut();
+	let lhs = lhs.as_dyn_stride().canonical().as_dyn();
+	let rhs = rhs.as_dyn_stride().canonical().as_dyn();
+	let conj_lhs = Conj::get::<LhsT>();
+	let conj_rhs = Conj::get::<RhsT>();
+
+	let n = dst.nrows();
+	let is_adjoint = n > 1
+		&& (try_const! { T::IS_REAL } || conj_lhs != conj_rhs)
+		&& lhs.as_ptr() == rhs.as_ptr()
+		&& lhs.nrows() == rhs.ncols()
+		&& lhs.row_stride() == rhs.col_stride()
+		&& lhs.col_stride() == rhs.row_stride();
+
+	if is_adjoint && beta == Accum::Replace {
+		triangular::matmul_with_conj(
+			dst.rb_mut(),
+			triangular::BlockStructure::TriangularLower,
+			Accum::Replace,
+			lhs,
+			triangular::BlockStructure::Rectangular,
+			conj_lhs,
+			rhs,
+			triangular::BlockStructure::Rectangular,
+			conj_rhs,
+			one(),
+			par,
+		);
+		for j in 0..n {
+			for i in 0..j {
+				dst[(i, j)] = conj(dst[(j, i)]);
+			}
+		}
+		// `alpha` is applied last, since `alpha * lhs * rhs` is not self-adjoint for a non-real `alpha`
+		z!(dst.rb_mut()).for_each(|uz!(dst)| *dst = alpha * *dst);
+		return;
+	}
+
+	matmul_with_conj(dst, beta, lhs, conj_lhs, rhs, conj_rhs, alpha, par);
+}
+
+/// measures the time taken by [`matmul`] to compute the product of two square random `f64`
+/// matrices of each of the given sizes, using the parallelism strategy `par`, and returns the
+/// sizes along with the timings
+///
+/// the matrices are generated from a fixed seed, so that repeated calls multiply the same
+/// matrices. each product is computed once to warm up the caches and the thread pool, then the
+/// fastest of three runs is reported
+///
+/// this can be used to decide whether enabling parallelism is worth it for the matrix sizes of a
+/// given workload, by comparing the timings for [`Par::Seq`] and [`Par::rayon`]
+#[cfg(all(feature = "std", feature = "rand"))]
+pub fn benchmark_matmul(sizes: &[usize], par: Par) -> alloc::vec::Vec<(usize, std::time::Duration)> {
+	use crate::stats::prelude::*;
+	use std::time::{Duration, Instant};
+
+	let rng = &mut StdRng::seed_from_u64(0);
+	sizes
+		.iter()
+		.map(|&n| {
+			let mut rand = || {
+				CwiseMatDistribution {
+					nrows: n,
+					ncols: n,
+					dist: StandardNormal,
+				}
+				.rand::<crate::Mat<f64>>(rng)
+			};
+			let lhs = rand();
+			let rhs = rand();
+			let mut dst = crate::Mat::<f64>::zeros(n, n);
+
+			matmul(dst.as_mut(), Accum::Replace, lhs.as_ref(), rhs.as_ref(), 1.0, par);
+
+			let mut best = Duration::MAX;
+			for _ in 0..3 {
+				let start = Instant::now();
+				matmul(dst.as_mut(), Accum::Replace, lhs.as_ref(), rhs.as_ref(), 1.0, par);
+				best = Ord::min(best, start.elapsed());
+			}
+			(n, best)
+		})
+		.collect()
+}
+
 #[cfg(test)]
 mod tests {
 	use crate::c32;
@@ -1277,4 +1393,108 @@ mod tests {
 			}
 		}
 	}
+
+	#[test]
+	fn test_smart_matmul() {
+		use crate::utils::approx::*;
+
+		let rng = &mut StdRng::seed_from_u64(0);
+		let (m, k) = (37, 20);
+		let approx_eq = CwiseMat(ApproxEq::eps() * 16.0 * (k as f64));
+
+		let A = CwiseMatDistribution {
+			nrows: m,
+			ncols: k,
+			dist: ComplexDistribution::new(StandardNormal, StandardNormal),
+		}
+		.rand::<Mat<c64>>(rng);
+		let B = CwiseMatDistribution {
+			nrows: k,
+			ncols: m,
+			dist: ComplexDistribution::new(StandardNormal, StandardNormal),
+		}
+		.rand::<Mat<c64>>(rng);
+		let x = CwiseMatDistribution {
+			nrows: k,
+			ncols: 1,
+			dist: ComplexDistribution::new(StandardNormal, StandardNormal),
+		}
+		.rand::<Mat<c64>>(rng);
+		let alpha = c64::new(0.5, -2.0);
+
+		for beta in [Accum::Replace, Accum::Add] {
+			let init = CwiseMatDistribution {
+				nrows: m,
+				ncols: m,
+				dist: ComplexDistribution::new(StandardNormal, StandardNormal),
+			}
+			.rand::<Mat<c64>>(rng);
+
+			// A A^H, detected as self-adjoint
+			let mut expected = init.clone();
+			let mut actual = init.clone();
+			matmul(&mut expected, beta, &A, A.adjoint(), alpha, Par::Seq);
+			smart_matmul(&mut actual, beta, &A, A.adjoint(), alpha, Par::Seq);
+			assert!(actual ~ expected);
+
+			// conj(A) A^T, detected as self-adjoint
+			let mut expected = init.clone();
+			let mut actual = init.clone();
+			matmul(&mut expected, beta, A.conjugate(), A.transpose(), alpha, Par::Seq);
+			smart_matmul(&mut actual, beta, A.conjugate(), A.transpose(), alpha, Par::Seq);
+			assert!(actual ~ expected);
+
+			// A A^T is not self-adjoint
+			let mut expected = init.clone();
+			let mut actual = init.clone();
+			matmul(&mut expected, beta, &A, A.transpose(), alpha, Par::Seq);
+			smart_matmul(&mut actual, beta, &A, A.transpose(), alpha, Par::Seq);
+			assert!(actual ~ expected);
+
+			// general product
+			let mut expected = init.clone();
+			let mut actual = init.clone();
+			matmul(&mut expected, beta, &A, &B, alpha, Par::Seq);
+			smart_matmul(&mut actual, beta, &A, &B, alpha, Par::Seq);
+			assert!(actual ~ expected);
+
+			// matrix-vector product
+			let mut expected = init.get(.., ..1).to_owned();
+			let mut actual = expected.clone();
+			matmul(&mut expected, beta, &A, &x, alpha, Par::Seq);
+			smart_matmul(&mut actual, beta, &A, &x, alpha, Par::Seq);
+			assert!(actual ~ expected);
+		}
+
+		// real A^T A
+		let A = CwiseMatDistribution {
+			nrows: k,
+			ncols: m,
+			dist: StandardNormal,
+		}
+		.rand::<Mat<f64>>(rng);
+		let mut expected = Mat::<f64>::zeros(m, m);
+		let mut actual = Mat::<f64>::zeros(m, m);
+		matmul(&mut expected, Accum::Replace, A.transpose(), &A, 1.0, Par::Seq);
+		smart_matmul(&mut actual, Accum::Replace, A.transpose(), &A, 1.0, Par::Seq);
+		assert!(actual ~ expected);
+	}
+
+	#[test]
+	#[cfg(all(feature = "std", feature = "rand"))]
+	fn test_benchmark_matmul() {
+		// large enough that each product takes longer than the resolution of a coarse clock
+		let sizes = [256, 512];
+		let mut pars = alloc::vec![Par::Seq];
+		#[cfg(feature = "rayon")]
+		pars.push(Par::rayon(2));
+
+		for par in pars {
+			let timings = benchmark_matmul(&sizes, par);
+			assert!(timings.len() == sizes.len());
+			for (&n, &(size, time)) in core::iter::zip(&sizes, &timings) {
+				assert!(all(size == n, time > std::time::Duration::ZERO));
+			}
+		}
+	}
 }
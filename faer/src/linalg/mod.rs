@@ -181,6 +181,7 @@ pub mod triangular_inverse;
 pub mod triangular_solve;
 
 pub(crate) mod reductions;
+pub use reductions::determinant::det_small;
 /// matrix zipping implementation
 pub mod zip;
 
@@ -195,6 +196,31 @@ pub mod cholesky;
 pub mod lu;
 pub mod qr;
 
+/// interpolative decomposition
+pub mod interpolative;
+
+/// condition number computation
+pub mod condition;
+
+/// row and column equilibration
+pub mod equilibrate;
+
+/// band and tridiagonal matrix storage and factorization
+pub mod band;
+
+/// least squares solvers
+pub mod lstsq;
+
+/// iterative solvers for linear systems given by a matrix-vector product
+#[cfg(feature = "unstable")]
+pub mod iterative;
+
+/// field of values computation
+pub mod field_of_values;
+
+/// matrix functions
+pub mod matfun;
+
 pub mod evd;
 pub mod svd;
 
@@ -1,4 +1,3 @@
-use crate::assert;
 use crate::internal_prelude::*;
 
 pub fn reconstruct_scratch<I: Index, T: ComplexField>(nrows: usize, ncols: usize, blocksize: usize, par: Par) -> StackReq {
@@ -22,16 +21,14 @@ pub fn reconstruct<I: Index, T: ComplexField>(
 	let m = Q_basis.nrows();
 	let n = R.ncols();
 	let size = Ord::min(m, n);
-	assert!(all(
-		out.nrows() == m,
-		out.ncols() == n,
-		Q_basis.nrows() == m,
-		Q_basis.ncols() == size,
-		Q_coeff.ncols() == size,
-		R.nrows() == size,
-		R.ncols() == n,
-		col_perm.len() == n,
-	));
+	assert_dim!(out.nrows(), m);
+	assert_dim!(out.ncols(), n);
+	assert_dim!(Q_basis.nrows(), m);
+	assert_dim!(Q_basis.ncols(), size);
+	assert_dim!(Q_coeff.ncols(), size);
+	assert_dim!(R.nrows(), size);
+	assert_dim!(R.ncols(), n);
+	assert_dim!(col_perm.len(), n);
 
 	let mut out = out;
 	out.fill(zero());
@@ -33,4 +33,66 @@ pub fn determinant<T: ComplexField>(mat: MatRef<'_, T>) -> T::Canonical {
 	} else {
 		neg(det)
 	}
-}
\ No newline at end of file
+}
+/// returns the determinant of $A$, using explicit cofactor formulas for matrices of dimension at
+/// most $4$, and an $LU$ decomposition with partial pivoting otherwise
+///
+/// the explicit formulas are faster than the factorization for tiny matrices, such as the
+/// $3 \times 3$ matrices that are common in geometry code
+///
+/// # panics
+///
+/// panics if $A$ is not a square matrix
+#[track_caller]
+#[math]
+pub fn det_small<T: ComplexField>(A: MatRef<'_, T>) -> T {
+	assert!(A.nrows() == A.ncols());
+
+	let a = |i: usize, j: usize| copy(A[(i, j)]);
+	let det2 = |i0: usize, i1: usize, j0: usize, j1: usize| a(i0, j0) * a(i1, j1) - a(i1, j0) * a(i0, j1);
+
+	match A.nrows() {
+		0 => one(),
+		1 => a(0, 0),
+		2 => det2(0, 1, 0, 1),
+		3 => a(0, 0) * det2(1, 2, 1, 2) - a(0, 1) * det2(1, 2, 0, 2) + a(0, 2) * det2(1, 2, 0, 1),
+		4 => {
+			// laplace expansion along the first two rows
+			det2(0, 1, 0, 1) * det2(2, 3, 2, 3) - det2(0, 1, 0, 2) * det2(2, 3, 1, 3)
+				+ det2(0, 1, 0, 3) * det2(2, 3, 1, 2)
+				+ det2(0, 1, 1, 2) * det2(2, 3, 0, 3)
+				- det2(0, 1, 1, 3) * det2(2, 3, 0, 2)
+				+ det2(0, 1, 2, 3) * det2(2, 3, 0, 1)
+		},
+		_ => determinant(A),
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::assert;
+	use crate::stats::prelude::*;
+
+	#[test]
+	fn test_det_small() {
+		let rng = &mut StdRng::seed_from_u64(0);
+
+		for n in 0..8 {
+			for _ in 0..10 {
+				let A = CwiseMatDistribution {
+					nrows: n,
+					ncols: n,
+					dist: ComplexDistribution::new(StandardNormal, StandardNormal),
+				}
+				.rand::<Mat<c64>>(rng);
+
+				let expected = determinant(A.as_ref());
+				let actual = det_small(A.as_ref());
+				assert!((actual - expected).l1_norm() <= 1e-12 * (1.0 + expected.l1_norm()));
+			}
+		}
+
+		assert!(det_small(mat![[2.0, 1.0, 0.0], [1.0, 3.0, 1.0], [0.0, 1.0, 4.0f64]].as_ref()) == 18.0);
+	}
+}
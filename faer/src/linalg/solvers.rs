@@ -4,12 +4,11 @@ use alloc::vec;
 use alloc::vec::Vec;
 use dyn_stack::MemBuffer;
 use faer_traits::math_utils;
-use linalg::svd::ComputeSvdVectors;
 
 pub use linalg::cholesky::ldlt::factor::LdltError;
 pub use linalg::cholesky::llt::factor::LltError;
-pub use linalg::evd::EvdError;
-pub use linalg::svd::SvdError;
+pub use linalg::evd::{ComputeEigenvectors, EvdError};
+pub use linalg::svd::{ComputeSvdVectors, SvdError};
 
 /// shape info of a linear system solver
 pub trait ShapeCore {
@@ -26,6 +25,10 @@ pub trait SolveCore<T: ComplexField>: ShapeCore {
 	fn solve_in_place_with_conj(&self, conj: Conj, rhs: MatMut<'_, T>);
 	/// solves the equation `self.transpose() × x = rhs`, implicitly conjugating `self` if needed,
 	/// and stores the result in `rhs`
+	///
+	/// `rhs` must have `self.ncols()` rows. since it is overwritten by the solution, which has
+	/// `self.nrows()` rows, the matrix must be square. see [`Qr::solve_transpose_into`] for tall
+	/// matrices and [`Svd::solve_transpose_into`] for wide ones
 	fn solve_transpose_in_place_with_conj(&self, conj: Conj, rhs: MatMut<'_, T>);
 }
 /// least squares linear system solver implementation
@@ -114,6 +117,56 @@ pub trait Solve<T: ComplexField>: SolveCore<T> {
 		self.solve_transpose_in_place_with_conj(Conj::Yes, { rhs }.as_mat_mut().as_dyn_cols_mut());
 	}
 
+	#[track_caller]
+	/// solves $A x = b$, processing `block` columns of $b$ at a time
+	///
+	/// when $b$ has many columns, this keeps the working set small enough to stay in cache
+	///
+	/// # panics
+	/// panics if `block == 0`
+	fn solve_blocked_in_place(&self, rhs: impl AsMatMut<T = T, Rows = usize>, block: usize) {
+		assert!(block > 0);
+
+		let mut rhs = rhs;
+		let mut rhs = rhs.as_mat_mut().as_dyn_cols_mut();
+		let n = rhs.ncols();
+
+		let mut j = 0;
+		while j < n {
+			let bs = Ord::min(block, n - j);
+			self.solve_in_place_with_conj(Conj::No, rhs.rb_mut().subcols_mut(j, bs));
+			j += bs;
+		}
+	}
+
+	#[track_caller]
+	/// solves $(D A D) x = b$, where $A$ is the matrix that was used to create the decomposition
+	/// and $D$ is a real diagonal matrix with nonzero diagonal elements
+	///
+	/// since $(D A D) x = b$ is equivalent to $A (D x) = D^{-1} b$, the solution is
+	/// $x = D^{-1} A^{-1} D^{-1} b$. the right-hand side is scaled by $D^{-1}$, solved for with the
+	/// existing decomposition, and the result is scaled by $D^{-1}$ again, so that the scaling can
+	/// change without refactoring $A$
+	///
+	/// # panics
+	/// panics if `d` doesn't have `rhs.nrows()` elements
+	fn solve_scaled_in_place(&self, d: DiagRef<'_, T::Real>, rhs: impl AsMatMut<T = T, Rows = usize>) {
+		let mut rhs = rhs;
+		let mut rhs = rhs.as_mat_mut().as_dyn_cols_mut();
+		let d = d.column_vector();
+		assert_dim!(rhs.nrows(), d.nrows());
+
+		let scale = |mut rhs: MatMut<'_, T>| {
+			for j in 0..rhs.ncols() {
+				zip!(rhs.rb_mut().col_mut(j), d).for_each(|unzip!(x, d)| *x = math_utils::mul_real(x, &math_utils::recip(d)));
+			}
+		};
+
+		scale(rhs.rb_mut());
+		self.solve_in_place_with_conj(Conj::No, rhs.rb_mut());
+		scale(rhs);
+	}
+
 	#[track_caller]
 	#[inline]
 	/// solves $x A = b$
@@ -250,6 +303,17 @@ impl<C: Conjugate> MatRef<'_, C> {
 		ColPivQr::new(self.as_mat_ref())
 	}
 
+	#[track_caller]
+	/// returns the indices of the columns of `self`, ordered from most to least significant by
+	/// the column pivoting of its $QR$ decomposition
+	///
+	/// this is the forward array of the permutation $P$ of [`ColPivQr`], where the $j$-th column
+	/// of $A P$ is the column of $A$ that has the largest norm once the components along the
+	/// previously selected columns are removed
+	pub fn column_importance_order(&self) -> Vec<usize> {
+		ColPivQr::new(self.as_mat_ref()).P().arrays().0.to_vec()
+	}
+
 	#[track_caller]
 	/// returns the svd of `self`
 	///
@@ -299,7 +363,7 @@ impl<C: Conjugate> MatRef<'_, C> {
 	pub fn self_adjoint_eigenvalues(&self, side: Side) -> Result<Vec<Real<C>>, EvdError> {
 		#[track_caller]
 		pub fn imp<T: ComplexField>(mut A: MatRef<'_, T>, side: Side) -> Result<Vec<T::Real>, EvdError> {
-			assert!(A.nrows() == A.ncols());
+			assert_dim!(A.ncols(), A.nrows());
 			if side == Side::Upper {
 				A = A.transpose();
 			}
@@ -362,6 +426,16 @@ impl<C: Conjugate> MatRef<'_, C> {
 
 		imp(self.as_mat_ref().canonical())
 	}
+
+	#[track_caller]
+	/// returns the spectral norm of `self`, which is its largest singular value, or zero if `self`
+	/// is empty
+	///
+	/// this is the operator norm induced by the vector l2 norm, and differs from
+	/// [`MatRef::norm_l2`], which returns the frobenius norm
+	pub fn operator_norm_l2(&self) -> Result<Real<C>, SvdError> {
+		Ok(self.singular_values()?.into_iter().next().unwrap_or_else(zero))
+	}
 }
 
 impl<T: RealField> MatRef<'_, T> {
@@ -377,7 +451,7 @@ impl<T: RealField> MatRef<'_, T> {
 		let par = get_global_parallelism();
 
 		let A = self.as_mat_ref();
-		assert!(A.nrows() == A.ncols());
+		assert_dim!(A.ncols(), A.nrows());
 		let n = A.nrows();
 
 		let mut s_re = Diag::<T>::zeros(n);
@@ -422,7 +496,7 @@ impl<T: RealField> MatRef<'_, Complex<T>> {
 		let par = get_global_parallelism();
 
 		let A = self.as_mat_ref();
-		assert!(A.nrows() == A.ncols());
+		assert_dim!(A.ncols(), A.nrows());
 		let n = A.nrows();
 
 		let mut s = Diag::<Complex<T>>::zeros(n);
@@ -472,6 +546,12 @@ impl<C: Conjugate> MatMut<'_, C> {
 		self.rb().col_piv_qr()
 	}
 
+	#[track_caller]
+	/// see [`MatRef::column_importance_order`]
+	pub fn column_importance_order(&self) -> Vec<usize> {
+		self.rb().column_importance_order()
+	}
+
 	#[track_caller]
 	/// returns the svd of `self`
 	///
@@ -529,6 +609,12 @@ impl<C: Conjugate> MatMut<'_, C> {
 	pub fn singular_values(&self) -> Result<Vec<Real<C>>, SvdError> {
 		self.rb().singular_values()
 	}
+
+	#[track_caller]
+	/// see [`MatRef::operator_norm_l2`]
+	pub fn operator_norm_l2(&self) -> Result<Real<C>, SvdError> {
+		self.rb().operator_norm_l2()
+	}
 }
 
 impl<T: RealField> MatMut<'_, T> {
@@ -584,6 +670,12 @@ impl<C: Conjugate> Mat<C> {
 		self.rb().col_piv_qr()
 	}
 
+	#[track_caller]
+	/// see [`MatRef::column_importance_order`]
+	pub fn column_importance_order(&self) -> Vec<usize> {
+		self.rb().column_importance_order()
+	}
+
 	#[track_caller]
 	/// returns the svd of `self`
 	///
@@ -641,6 +733,12 @@ impl<C: Conjugate> Mat<C> {
 	pub fn singular_values(&self) -> Result<Vec<Real<C>>, SvdError> {
 		self.rb().singular_values()
 	}
+
+	#[track_caller]
+	/// see [`MatRef::operator_norm_l2`]
+	pub fn operator_norm_l2(&self) -> Result<Real<C>, SvdError> {
+		self.rb().operator_norm_l2()
+	}
 }
 
 impl<T: RealField> Mat<T> {
@@ -682,12 +780,21 @@ impl<T: ComplexField, S: ?Sized + DenseSolveCore<T>> DenseSolve<T> for S {}
 
 /// $L L^\top$ decomposition
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(try_from = "serde_impl::Llt<T>"))]
 pub struct Llt<T> {
 	L: Mat<T>,
 }
 
+/// $L L^\top$ decomposition stored in a borrowed matrix, returned by [`Llt::factor_in_place`]
+#[derive(Copy, Clone, Debug)]
+pub struct LltRef<'a, T> {
+	L: MatRef<'a, T>,
+	conj: Conj,
+}
+
 /// $L D L^\top$ decomposition
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(try_from = "serde_impl::Ldlt<T>"))]
 pub struct Ldlt<T> {
 	L: Mat<T>,
 	D: Diag<T>,
@@ -695,6 +802,7 @@ pub struct Ldlt<T> {
 
 /// bunch-kaufman decomposition
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(try_from = "serde_impl::Lblt<T>"))]
 pub struct Lblt<T> {
 	L: Mat<T>,
 	B_diag: Diag<T>,
@@ -704,6 +812,11 @@ pub struct Lblt<T> {
 
 /// $LU$ decomposition with partial (row) pivoting
 #[derive(Clone, Debug)]
+#[cfg_attr(
+	feature = "serde",
+	derive(serde::Serialize, serde::Deserialize),
+	serde(try_from = "serde_impl::PartialPivLu<T>")
+)]
 pub struct PartialPivLu<T> {
 	L: Mat<T>,
 	U: Mat<T>,
@@ -712,6 +825,11 @@ pub struct PartialPivLu<T> {
 
 /// $LU$ decomposition with full pivoting
 #[derive(Clone, Debug)]
+#[cfg_attr(
+	feature = "serde",
+	derive(serde::Serialize, serde::Deserialize),
+	serde(try_from = "serde_impl::FullPivLu<T>")
+)]
 pub struct FullPivLu<T> {
 	L: Mat<T>,
 	U: Mat<T>,
@@ -721,6 +839,7 @@ pub struct FullPivLu<T> {
 
 /// $QR$ decomposition
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(try_from = "serde_impl::Qr<T>"))]
 pub struct Qr<T> {
 	Q_basis: Mat<T>,
 	Q_coeff: Mat<T>,
@@ -729,6 +848,11 @@ pub struct Qr<T> {
 
 /// $QR$ decomposition with column pivoting
 #[derive(Clone, Debug)]
+#[cfg_attr(
+	feature = "serde",
+	derive(serde::Serialize, serde::Deserialize),
+	serde(try_from = "serde_impl::ColPivQr<T>")
+)]
 pub struct ColPivQr<T> {
 	Q_basis: Mat<T>,
 	Q_coeff: Mat<T>,
@@ -736,8 +860,28 @@ pub struct ColPivQr<T> {
 	P: Perm<usize>,
 }
 
+/// complete orthogonal decomposition $A P^{-1} = Q \begin{bmatrix} R & 0 \\ 0 & 0 \end{bmatrix} Z^H$,
+/// where $Q$ and $Z$ are unitary, and $R$ is an $r \times r$ lower triangular matrix, with $r$ the
+/// numerical rank of $A$
+#[derive(Clone, Debug)]
+#[cfg_attr(
+	feature = "serde",
+	derive(serde::Serialize, serde::Deserialize),
+	serde(try_from = "serde_impl::CompOrtho<T>")
+)]
+pub struct CompOrtho<T> {
+	Q_basis: Mat<T>,
+	Q_coeff: Mat<T>,
+	R: Mat<T>,
+	Z_basis: Mat<T>,
+	Z_coeff: Mat<T>,
+	P: Perm<usize>,
+	rank: usize,
+}
+
 /// svd decomposition (either full or thin)
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(try_from = "serde_impl::Svd<T>"))]
 pub struct Svd<T> {
 	U: Mat<T>,
 	V: Mat<T>,
@@ -746,23 +890,207 @@ pub struct Svd<T> {
 
 /// self-adjoint eigendecomposition
 #[derive(Clone, Debug)]
+#[cfg_attr(
+	feature = "serde",
+	derive(serde::Serialize, serde::Deserialize),
+	serde(try_from = "serde_impl::SelfAdjointEigen<T>")
+)]
 pub struct SelfAdjointEigen<T> {
 	U: Mat<T>,
 	S: Diag<T>,
 }
 
+/// builder for [`Svd`], returned by [`Svd::builder`]
+#[derive(Copy, Clone, Debug)]
+pub struct SvdBuilder<T> {
+	vectors: ComputeSvdVectors,
+	__marker: core::marker::PhantomData<fn() -> T>,
+}
+
+/// builder for [`SelfAdjointEigen`], returned by [`SelfAdjointEigen::builder`]
+#[derive(Copy, Clone, Debug)]
+pub struct SelfAdjointEigenBuilder<T> {
+	vectors: ComputeEigenvectors,
+	__marker: core::marker::PhantomData<fn() -> T>,
+}
+
 /// eigendecomposition
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(try_from = "serde_impl::Eigen<T>"))]
 pub struct Eigen<T> {
 	U: Mat<Complex<T>>,
 	S: Diag<Complex<T>>,
 }
 
+/// complex schur decomposition
+#[derive(Clone, Debug)]
+pub struct Schur<T> {
+	Q: Mat<Complex<T>>,
+	T: Mat<Complex<T>>,
+}
+
+/// real schur decomposition
+#[derive(Clone, Debug)]
+pub struct RealSchur<T> {
+	Q: Mat<T>,
+	T: Mat<T>,
+}
+
+/// generalized self-adjoint eigendecomposition of the pencil $(A, B)$, where $B$ is positive
+/// definite
+#[derive(Clone, Debug)]
+pub struct GEigen<T: ComplexField> {
+	U: Mat<T>,
+	S: Diag<T::Real>,
+}
+
+/// generalized self-adjoint eigendecomposition error, returned by [`GEigen::new`]
+#[derive(Copy, Clone, Debug)]
+pub enum GEigenError {
+	/// the cholesky factorization of $B$ failed, so $B$ is not positive definite
+	Llt(LltError),
+	/// the eigendecomposition of the reduced problem failed
+	Evd(EvdError),
+}
+
+impl core::fmt::Display for GEigenError {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		core::fmt::Debug::fmt(self, f)
+	}
+}
+
+impl core::error::Error for GEigenError {}
+
+impl From<LltError> for GEigenError {
+	#[inline]
+	fn from(value: LltError) -> Self {
+		Self::Llt(value)
+	}
+}
+
+impl From<EvdError> for GEigenError {
+	#[inline]
+	fn from(value: EvdError) -> Self {
+		Self::Evd(value)
+	}
+}
+
+/// error returned by [`SelfAdjointEigen::trace_log`]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum TraceLogError {
+	/// the matrix is not positive definite
+	NonPositiveEigenvalue {
+		/// index of the first eigenvalue that is not positive
+		index: usize,
+	},
+}
+
+impl core::fmt::Display for TraceLogError {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		core::fmt::Debug::fmt(self, f)
+	}
+}
+
+impl core::error::Error for TraceLogError {}
+
+/// polar decomposition $A = U P$, where $U$ has orthonormal columns and $P$ is self-adjoint
+/// positive semi-definite
+#[derive(Clone, Debug)]
+pub struct PolarDecomp<T> {
+	U: Mat<T>,
+	P: Mat<T>,
+	svd: Svd<T>,
+}
+
+#[cfg(feature = "rand")]
+const TRUNCATED_SVD_OVERSAMPLING: usize = 10;
+#[cfg(feature = "rand")]
+const TRUNCATED_SVD_POWER_ITERATIONS: usize = 2;
+
+/// seed of the test matrix used by [`Svd::new_truncated`]
+#[cfg(feature = "rand")]
+const TRUNCATED_SVD_SEED: u64 = 0;
+
+/// returns a matrix filled with samples of the standard normal distribution drawn from `rng`
+///
+/// when `T` is complex, the real and imaginary parts are independent with variance $1/2$, so that
+/// each entry is a standard complex gaussian
+#[cfg(feature = "rand")]
+fn gaussian_test_matrix<T: ComplexField>(nrows: usize, ncols: usize, rng: &mut (impl rand::Rng + ?Sized)) -> Mat<T> {
+	use rand_distr::StandardNormal;
+
+	if try_const! { T::IS_REAL } {
+		Mat::from_fn(nrows, ncols, |_, _| from_f64::<T>(rng.sample(StandardNormal)))
+	} else {
+		let i = sqrt(&from_f64::<T>(-1.0));
+		let scale = core::f64::consts::FRAC_1_SQRT_2;
+		Mat::from_fn(nrows, ncols, |_, _| {
+			let re = from_f64::<T>(scale * rng.sample::<f64, _>(StandardNormal));
+			let im = from_f64::<T>(scale * rng.sample::<f64, _>(StandardNormal));
+			add(&re, &mul(&i, &im))
+		})
+	}
+}
+
+/// iterative refinement statistics, returned by [`PartialPivLu::solve_with_stats`]
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct SolveStats<R> {
+	/// number of refinement steps that were applied to the initial solution
+	pub iterations: usize,
+	/// frobenius norm of the residual $B - A X$ of the initial solution
+	pub initial_residual: R,
+	/// frobenius norm of the residual $B - A X$ of the returned solution
+	pub final_residual: R,
+	/// whether the normwise backward error of the returned solution is within $n \varepsilon$
+	pub converged: bool,
+}
+
+/// solves $A X = B$ with `solver`, a decomposition of $A$ computed in the precision of `T`, then
+/// applies `iters` steps of iterative refinement where the residuals are computed in the
+/// precision of `W`
+#[track_caller]
+#[math]
+fn solve_refined_imp<T: ComplexField, W: WidenFrom<T>>(solver: &impl SolveCore<T>, A: MatRef<'_, W>, rhs: MatRef<'_, W>, iters: usize) -> Mat<W> {
+	let n = solver.nrows();
+	assert_dim!(solver.ncols(), n);
+	assert_dim!(A.nrows(), n);
+	assert_dim!(A.ncols(), n);
+	assert_dim!(rhs.nrows(), n);
+
+	let par = get_global_parallelism();
+	let k = rhs.ncols();
+
+	let mut R = Mat::<T>::from_fn(n, k, |i, j| rhs[(i, j)].narrow());
+	solver.solve_in_place_with_conj(Conj::No, R.as_mut());
+	let mut X = Mat::<W>::from_fn(n, k, |i, j| W::widen(&R[(i, j)]));
+
+	let mut residual = Mat::<W>::zeros(n, k);
+	for _ in 0..iters {
+		residual.copy_from(rhs);
+		linalg::matmul::matmul(residual.as_mut(), Accum::Add, A, X.as_ref(), -one::<W>(), par);
+
+		z!(R.as_mut(), residual.as_ref()).for_each(|uz!(r, w)| *r = w.narrow());
+		solver.solve_in_place_with_conj(Conj::No, R.as_mut());
+		z!(X.as_mut(), R.as_ref()).for_each(|uz!(x, dx)| *x = *x + W::widen(dx));
+	}
+
+	X
+}
+
+/// block $LU$ decomposition of a block tridiagonal matrix
+#[derive(Clone, Debug)]
+pub struct BlockTridiagonal<T> {
+	S: Vec<PartialPivLu<T>>,
+	subdiag: Vec<Mat<T>>,
+	superdiag: Vec<Mat<T>>,
+	offsets: Vec<usize>,
+}
+
 impl<T: ComplexField> Llt<T> {
 	/// returns the $L L^\top$ decomposition of $A$
 	#[track_caller]
 	pub fn new<C: Conjugate<Canonical = T>>(A: MatRef<'_, C>, side: Side) -> Result<Self, LltError> {
-		assert!(all(A.nrows() == A.ncols()));
+		assert_dim!(A.ncols(), A.nrows());
 		let n = A.nrows();
 
 		let mut L = Mat::zeros(n, n);
@@ -774,6 +1102,105 @@ impl<T: ComplexField> Llt<T> {
 		Self::new_imp(L)
 	}
 
+	/// computes the $L L^\top$ decomposition of $A$ in place, overwriting the triangle of `A`
+	/// selected by `side` with the $L$ factor, and returns a solver borrowing it
+	///
+	/// unlike [`Llt::new`], this doesn't allocate a copy of $A$. the other triangle of `A` is used
+	/// as workspace, and its contents are unspecified afterwards
+	#[track_caller]
+	pub fn factor_in_place(A: MatMut<'_, T>, side: Side) -> Result<LltRef<'_, T>, LltError> {
+		assert_dim!(A.ncols(), A.nrows());
+		let par = get_global_parallelism();
+
+		let n = A.nrows();
+
+		// the lower triangle of the transpose is the conjugate of the lower triangle of the adjoint,
+		// so factoring it yields the conjugate of $L$
+		let (mut L, conj) = match side {
+			Side::Lower => (A, Conj::No),
+			Side::Upper => (A.transpose_mut(), Conj::Yes),
+		};
+
+		let mut mem = MemBuffer::new(linalg::cholesky::llt::factor::cholesky_in_place_scratch::<T>(n, par, default()));
+		let stack = MemStack::new(&mut mem);
+
+		linalg::cholesky::llt::factor::cholesky_in_place(L.rb_mut(), Default::default(), par, stack, default())?;
+
+		Ok(LltRef { L: L.into_const(), conj })
+	}
+
+	/// returns the $L L^\top$ decomposition of $A + E$, along with $E$, where $E$ is a nonnegative
+	/// diagonal modification making $A + E$ sufficiently positive definite, computed with the
+	/// gill-murray-wright modified cholesky algorithm
+	///
+	/// $E$ is zero if $A$ is positive definite and not too badly conditioned. otherwise, it is kept
+	/// small while bounding the growth of the entries of $L$, which makes this suitable for
+	/// regularizing hessians in optimization algorithms
+	#[track_caller]
+	#[math]
+	pub fn new_modified<C: Conjugate<Canonical = T>>(A: MatRef<'_, C>, side: Side) -> (Self, Diag<T::Real>) {
+		assert_dim!(A.ncols(), A.nrows());
+		let n = A.nrows();
+
+		let mut L = Mat::zeros(n, n);
+		match side {
+			Side::Lower => L.copy_from_triangular_lower(A),
+			Side::Upper => L.copy_from_triangular_lower(A.adjoint()),
+		}
+
+		let mut gamma = zero::<T::Real>();
+		let mut xi = zero::<T::Real>();
+		for j in 0..n {
+			gamma = max(gamma, abs(real(L[(j, j)])));
+			for i in j + 1..n {
+				xi = max(xi, abs(L[(i, j)]));
+			}
+		}
+
+		// bound on the off-diagonal entries of the unit lower triangular factor, chosen to minimize
+		// the a priori bound on the norm of E
+		let eps = eps::<T::Real>();
+		let nu = if n > 1 { sqrt(from_f64::<T::Real>((n * n - 1) as f64)) } else { one() };
+		let beta2 = max(max(gamma, xi / nu), eps);
+		let delta = eps * max(gamma + xi, one());
+
+		// L D L^H with a unit lower triangular L, computed column by column
+		let mut D = Col::<T::Real>::zeros(n);
+		let mut E = Col::<T::Real>::zeros(n);
+		for j in 0..n {
+			for k in 0..j {
+				let f = mul_real(conj(L[(j, k)]), D[k]);
+				for i in j..n {
+					L[(i, j)] = L[(i, j)] - L[(i, k)] * f;
+				}
+			}
+
+			let c = real(L[(j, j)]);
+			let mut theta = zero::<T::Real>();
+			for i in j + 1..n {
+				theta = max(theta, abs(L[(i, j)]));
+			}
+
+			let d = max(max(abs(c), theta * theta / beta2), delta);
+			E[j] = d - c;
+			for i in j + 1..n {
+				L[(i, j)] = mul_real(L[(i, j)], recip(d));
+			}
+			L[(j, j)] = one();
+			D[j] = d;
+		}
+
+		for j in 0..n {
+			let d = sqrt(D[j]);
+			for i in j..n {
+				L[(i, j)] = mul_real(L[(i, j)], d);
+			}
+		}
+		z!(&mut L).for_each_triangular_upper(linalg::zip::Diag::Skip, |uz!(x)| *x = zero());
+
+		(Self { L }, E.into_diagonal())
+	}
+
 	#[track_caller]
 	fn new_imp(mut L: Mat<T>) -> Result<Self, LltError> {
 		let par = get_global_parallelism();
@@ -793,13 +1220,180 @@ impl<T: ComplexField> Llt<T> {
 	pub fn L(&self) -> MatRef<'_, T> {
 		self.L.as_ref()
 	}
+
+	/// returns $\operatorname{tr}(A^{-1} B)$, where $A$ is the matrix that was used to create the
+	/// decomposition, without forming $A^{-1}$ or $A^{-1} B$
+	///
+	/// # panics
+	/// panics if $B$ is not square, or if its dimension doesn't match that of $A$
+	#[track_caller]
+	pub fn trace_solve(&self, B: MatRef<'_, T>) -> T {
+		trace_solve_imp(self, B)
+	}
+
+	/// solves the equation $A X = B$, then applies `iters` steps of iterative refinement, computing
+	/// the residuals in the wider precision of `W`
+	///
+	/// `A` must be the full self-adjoint matrix in the wider precision. when the decomposition is
+	/// computed in a lower precision (e.g., `f32` with `W = f64`), each step reduces the error
+	/// by a factor of about $\kappa(A) \varepsilon_T$, so a few steps recover the accuracy of `W`,
+	/// as long as $A$ is not too badly conditioned for `T`
+	///
+	/// # panics
+	/// panics if the dimensions of `A` and `rhs` don't match those of the decomposition
+	#[track_caller]
+	pub fn solve_refined<W: WidenFrom<T>>(&self, A: MatRef<'_, W>, rhs: MatRef<'_, W>, iters: usize) -> Mat<W> {
+		solve_refined_imp(self, A, rhs, iters)
+	}
+
+	/// solves the equation $A X = B$ and stores the result in `rhs`, then returns
+	/// $\log \det A = 2 \sum_i \log L_{ii}$, where $A$ is the matrix that was used to create the
+	/// decomposition
+	///
+	/// this is the computation needed by the log marginal likelihood of a gaussian process
+	#[track_caller]
+	pub fn solve_and_logdet_in_place(&self, rhs: MatMut<'_, T>) -> T::Real {
+		self.solve_in_place_with_conj(Conj::No, rhs);
+		self.log_abs_det().0
+	}
+
+	/// returns $\det A = \prod_i L_{ii}^2$, where $A$ is the matrix that was used to create the
+	/// decomposition
+	#[math]
+	pub fn det(&self) -> T {
+		let mut det = one::<T::Real>();
+		for i in 0..self.L.nrows() {
+			det = det * real(self.L[(i, i)]);
+		}
+		from_real(det * det)
+	}
+
+	/// returns $(\log |\det A|, \det A / |\det A|)$, where $A$ is the matrix that was used to create
+	/// the decomposition
+	///
+	/// the logarithm is computed as $2 \sum_i \log L_{ii}$, so it stays finite when $\det A$ would
+	/// overflow or underflow. the phase is always $1$ since $A$ is positive definite
+	#[math]
+	pub fn log_abs_det(&self) -> (T::Real, T) {
+		let mut log_abs_det = zero::<T::Real>();
+		for i in 0..self.L.nrows() {
+			log_abs_det = log_abs_det + ln(&real(self.L[(i, i)]));
+		}
+		(from_f64::<T::Real>(2.0) * log_abs_det, one())
+	}
+
+	/// estimates the $1$-norm condition number $\kappa_1(A) = \|A\|_1 \|A^{-1}\|_1$, where $A$ is
+	/// the matrix that was used to create the decomposition
+	///
+	/// both norms are estimated with hager's method from a few products with $L$ and $L^H$ and a few
+	/// solves, which takes $O(n^2)$ operations instead of the $O(n^3)$ needed to form $A^{-1}$.
+	/// returns zero if $A$ is empty
+	pub fn condition_number_estimate(&self) -> T::Real {
+		let n = self.L.nrows();
+		let L = self.L.as_ref();
+
+		// A = L L^H is self-adjoint
+		let apply = |mut x: MatMut<'_, T>| {
+			let y = L * (L.adjoint() * x.rb());
+			x.copy_from(&y);
+		};
+		let norm = linalg::condition::estimate_norm_1(n, apply, apply);
+		mul(&norm, &linalg::condition::estimate_inverse_norm_1(self, n))
+	}
+
+	/// returns the columns of $A^{-1}$ with the given indices, where $A$ is the matrix that was used
+	/// to create the decomposition
+	///
+	/// the solution $L^{-1} e_j$ vanishes above row $j$, so only the trailing block of $L$ is used
+	/// for each column, which is cheaper than computing the full inverse when few columns are
+	/// requested
+	///
+	/// # panics
+	/// panics if any of the indices is out of bounds
+	#[track_caller]
+	#[math]
+	pub fn inverse_columns(&self, cols: &[usize]) -> Mat<T> {
+		let par = get_global_parallelism();
+		let n = self.L.nrows();
+
+		let mut X = Mat::<T>::zeros(n, cols.len());
+		for (k, &j) in cols.iter().enumerate() {
+			assert!(j < n);
+			let mut x = X.col_mut(k).get_mut(j..);
+			x[0] = one();
+			linalg::triangular_solve::solve_lower_triangular_in_place(self.L.get(j.., j..), x.as_mat_mut(), par);
+		}
+		linalg::triangular_solve::solve_upper_triangular_in_place(self.L.adjoint(), X.as_mut(), par);
+		X
+	}
+
+	/// updates the decomposition to that of $A + U U^H$, where $A$ is the matrix that was used to
+	/// create the decomposition
+	///
+	/// the columns of $U$ are applied in blocks, which is faster than performing one rank one update
+	/// per column. returns an error if the updated matrix is not numerically positive definite, in
+	/// which case `self` is left unchanged
+	#[track_caller]
+	pub fn rank_k_update(&mut self, U: MatRef<'_, T>) -> Result<(), LltError> {
+		self.rank_k_update_imp(U, one())
+	}
+
+	/// updates the decomposition to that of $A - U U^H$, where $A$ is the matrix that was used to
+	/// create the decomposition
+	///
+	/// the columns of $U$ are applied in blocks, which is faster than performing one rank one
+	/// downdate per column. returns an error if the downdated matrix is not positive definite, in
+	/// which case `self` is left unchanged
+	#[track_caller]
+	#[math]
+	pub fn rank_k_downdate(&mut self, U: MatRef<'_, T>) -> Result<(), LltError> {
+		self.rank_k_update_imp(U, -one::<T>())
+	}
+
+	/// updates the decomposition to that of $A + v v^H$, where $A$ is the matrix that was used to
+	/// create the decomposition, in $O(n^2)$ operations
+	///
+	/// # panics
+	/// panics if the dimension of $v$ doesn't match that of $A$, or if the updated matrix is not
+	/// numerically positive definite, which can only happen if $v$ contains non-finite values
+	#[track_caller]
+	pub fn rank1_update(&mut self, v: ColRef<'_, T>) {
+		self.rank_k_update(v.as_mat()).expect("the updated matrix should be positive definite");
+	}
+
+	/// updates the decomposition to that of $A - v v^H$, where $A$ is the matrix that was used to
+	/// create the decomposition, in $O(n^2)$ operations
+	///
+	/// returns an error if the downdated matrix is not positive definite, in which case `self` is
+	/// left unchanged
+	///
+	/// # panics
+	/// panics if the dimension of $v$ doesn't match that of $A$
+	#[track_caller]
+	pub fn rank1_downdate(&mut self, v: ColRef<'_, T>) -> Result<(), LltError> {
+		self.rank_k_downdate(v.as_mat())
+	}
+
+	#[track_caller]
+	fn rank_k_update_imp(&mut self, U: MatRef<'_, T>, alpha: T) -> Result<(), LltError> {
+		assert_dim!(U.nrows(), self.L.nrows());
+
+		let mut L = self.L.clone();
+		let mut W = U.to_owned();
+		let mut alpha = Col::full(U.ncols(), alpha).into_diagonal();
+
+		linalg::cholesky::llt::update::rank_r_update_clobber(L.as_mut(), W.as_mut(), alpha.as_mut())?;
+
+		self.L = L;
+		Ok(())
+	}
 }
 
 impl<T: ComplexField> Ldlt<T> {
 	/// returns the $L D L^\top$ decomposition of $A$
 	#[track_caller]
 	pub fn new<C: Conjugate<Canonical = T>>(A: MatRef<'_, C>, side: Side) -> Result<Self, LdltError> {
-		assert!(all(A.nrows() == A.ncols()));
+		assert_dim!(A.ncols(), A.nrows());
 		let n = A.nrows();
 
 		let mut L = Mat::zeros(n, n);
@@ -845,7 +1439,7 @@ impl<T: ComplexField> Lblt<T> {
 	/// returns the bunch-kaufman decomposition of $A$
 	#[track_caller]
 	pub fn new<C: Conjugate<Canonical = T>>(A: MatRef<'_, C>, side: Side) -> Self {
-		assert!(all(A.nrows() == A.ncols()));
+		assert_dim!(A.ncols(), A.nrows());
 		let n = A.nrows();
 
 		let mut L = Mat::zeros(n, n);
@@ -996,43 +1590,241 @@ impl<T: ComplexField> PartialPivLu<T> {
 	pub fn P(&self) -> PermRef<'_, usize> {
 		self.P.as_ref()
 	}
-}
 
-impl<T: ComplexField> FullPivLu<T> {
-	/// returns the $LU$ decomposition of $A$ with full pivoting
+	/// returns $\operatorname{tr}(A^{-1} B)$, where $A$ is the matrix that was used to create the
+	/// decomposition, without forming $A^{-1}$ or $A^{-1} B$
+	///
+	/// # panics
+	/// panics if $B$ is not square, or if its dimension doesn't match that of $A$
 	#[track_caller]
-	pub fn new<C: Conjugate<Canonical = T>>(A: MatRef<'_, C>) -> Self {
-		let LU = A.to_owned();
-		Self::new_imp(LU)
+	pub fn trace_solve(&self, B: MatRef<'_, T>) -> T {
+		trace_solve_imp(self, B)
 	}
 
+	/// returns $\det A = \det P^\top \prod_i U_{ii}$, where $A$ is the matrix that was used to
+	/// create the decomposition
+	///
+	/// # panics
+	/// panics if $A$ is not square
 	#[track_caller]
-	fn new_imp(mut LU: Mat<T>) -> Self {
-		let par = get_global_parallelism();
+	pub fn det(&self) -> T {
+		lu_det_imp(self.U.as_ref(), perm_is_odd(self.P.as_ref()))
+	}
 
-		let (m, n) = LU.shape();
-		let mut row_perm_fwd = vec![0usize; m];
-		let mut row_perm_bwd = vec![0usize; m];
-		let mut col_perm_fwd = vec![0usize; n];
-		let mut col_perm_bwd = vec![0usize; n];
+	/// returns $(\log |\det A|, \det A / |\det A|)$, where $A$ is the matrix that was used to create
+	/// the decomposition
+	///
+	/// the logarithm is accumulated as $\sum_i \log |U_{ii}|$, so it stays finite when $\det A$
+	/// would overflow or underflow. if $A$ is singular, returns $(-\infty, 0)$
+	///
+	/// # panics
+	/// panics if $A$ is not square
+	#[track_caller]
+	pub fn log_abs_det(&self) -> (T::Real, T) {
+		lu_log_abs_det_imp(self.U.as_ref(), perm_is_odd(self.P.as_ref()))
+	}
 
-		linalg::lu::full_pivoting::factor::lu_in_place(
-			LU.as_mut(),
-			&mut row_perm_fwd,
-			&mut row_perm_bwd,
-			&mut col_perm_fwd,
-			&mut col_perm_bwd,
-			par,
-			MemStack::new(&mut MemBuffer::new(linalg::lu::full_pivoting::factor::lu_in_place_scratch::<usize, T>(
-				m,
-				n,
-				par,
-				default(),
-			))),
-			default(),
+	/// estimates the $1$-norm condition number $\kappa_1(A) = \|A\|_1 \|A^{-1}\|_1$, where $A$ is
+	/// the matrix that was used to create the decomposition
+	///
+	/// both norms are estimated with hager's method from a few products with the factors and a few
+	/// solves, which takes $O(n^2)$ operations instead of the $O(n^3)$ needed to form $A^{-1}$.
+	/// returns zero if $A$ is empty
+	///
+	/// # panics
+	/// panics if $A$ is not square
+	#[track_caller]
+	pub fn condition_number_estimate(&self) -> T::Real {
+		let n = self.L.nrows();
+		assert_dim!(self.U.ncols(), n);
+		let (L, U, P) = (self.L(), self.U(), self.P());
+
+		// A = P^-1 L U
+		let norm = linalg::condition::estimate_norm_1(
+			n,
+			|mut x: MatMut<'_, T>| {
+				let y = P.inverse() * (L * (U * x.rb()));
+				x.copy_from(&y);
+			},
+			|mut x: MatMut<'_, T>| {
+				let y = U.adjoint() * (L.adjoint() * (P * x.rb()));
+				x.copy_from(&y);
+			},
 		);
+		mul(&norm, &linalg::condition::estimate_inverse_norm_1(self, n))
+	}
 
-		let (L, U) = split_LU(LU);
+	/// returns the columns of $A^{-1}$ with the given indices, where $A$ is the matrix that was used
+	/// to create the decomposition
+	///
+	/// the permuted unit vector $P e_j$ has a single nonzero entry in row $i$, so the solution
+	/// $L^{-1} P e_j$ vanishes above row $i$, and only the trailing block of $L$ is used for each
+	/// column. this is cheaper than computing the full inverse when few columns are requested
+	///
+	/// # panics
+	/// panics if $A$ is not square, or if any of the indices is out of bounds
+	#[track_caller]
+	#[math]
+	pub fn inverse_columns(&self, cols: &[usize]) -> Mat<T> {
+		let par = get_global_parallelism();
+		let n = self.L.nrows();
+		assert_dim!(self.U.ncols(), n);
+
+		let (_, perm_inv) = self.P().arrays();
+
+		let mut X = Mat::<T>::zeros(n, cols.len());
+		for (k, &j) in cols.iter().enumerate() {
+			assert!(j < n);
+			let i = perm_inv[j];
+			let mut x = X.col_mut(k).get_mut(i..);
+			x[0] = one();
+			linalg::triangular_solve::solve_unit_lower_triangular_in_place(self.L.get(i.., i..), x.as_mat_mut(), par);
+		}
+		linalg::triangular_solve::solve_upper_triangular_in_place(self.U.as_ref(), X.as_mut(), par);
+		X
+	}
+
+	/// solves the equation $A X = B$ and stores the result in `rhs`, then applies up to
+	/// `max_refine` steps of iterative refinement and reports their progress
+	///
+	/// each step solves for a correction from the residual $B - A X$, and is only kept if it
+	/// reduces the norm of the residual. the refinement stops early once the normwise backward
+	/// error $\|B - A X\| / (\|A\| \|X\| + \|B\|)$ is within $n \varepsilon$, or when a step fails to
+	/// reduce the residual
+	///
+	/// # panics
+	/// panics if `A` is not square, or if the dimensions of `A` and `rhs` don't match those of the
+	/// decomposition
+	#[track_caller]
+	#[math]
+	pub fn solve_with_stats(&self, A: MatRef<'_, T>, rhs: MatMut<'_, T>, max_refine: usize) -> SolveStats<T::Real> {
+		let n = self.L.nrows();
+		assert_dim!(self.U.ncols(), n);
+		assert_dim!(A.nrows(), n);
+		assert_dim!(A.ncols(), n);
+		assert_dim!(rhs.nrows(), n);
+
+		let par = get_global_parallelism();
+
+		let mut X = rhs;
+		let B = X.to_owned();
+		self.solve_in_place_with_conj(Conj::No, X.rb_mut());
+
+		let tol = from_f64::<T::Real>(Ord::max(n, 1) as f64) * eps::<T::Real>();
+		let A_norm = A.norm_l2();
+		let B_norm = B.norm_l2();
+		let converged = |residual: &T::Real, X: MatRef<'_, T>| *residual <= tol * (A_norm * X.norm_l2() + B_norm);
+
+		let residual_of = |X: MatRef<'_, T>, R: MatMut<'_, T>| {
+			let mut R = R;
+			R.copy_from(&B);
+			linalg::matmul::matmul(R.rb_mut(), Accum::Add, A, X, -one::<T>(), par);
+			R.norm_l2()
+		};
+
+		let mut R = Mat::<T>::zeros(n, X.ncols());
+		let initial_residual = residual_of(X.rb(), R.as_mut());
+		let mut residual = copy(initial_residual);
+
+		let mut X_new = Mat::<T>::zeros(n, X.ncols());
+		let mut iterations = 0;
+		while iterations < max_refine && !converged(&residual, X.rb()) {
+			self.solve_in_place_with_conj(Conj::No, R.as_mut());
+			z!(X_new.as_mut(), X.rb(), R.as_ref()).for_each(|uz!(x_new, x, dx)| *x_new = *x + *dx);
+
+			let new_residual = residual_of(X_new.as_ref(), R.as_mut());
+			if is_nan(new_residual) || new_residual >= residual {
+				break;
+			}
+
+			X.copy_from(&X_new);
+			residual = new_residual;
+			iterations += 1;
+		}
+
+		SolveStats {
+			iterations,
+			converged: converged(&residual, X.rb()),
+			initial_residual,
+			final_residual: residual,
+		}
+	}
+
+	/// solves the equation $A X = B$, then applies `iters` steps of iterative refinement, computing
+	/// the residuals in the wider precision of `W`
+	///
+	/// when the decomposition is computed in a lower precision (e.g., `f32` with `W = f64`), each
+	/// step reduces the error by a factor of about $\kappa(A) \varepsilon_T$, so a few steps recover
+	/// the accuracy of `W`, as long as $A$ is not too badly conditioned for `T`
+	///
+	/// # panics
+	/// panics if the dimensions of `A` and `rhs` don't match those of the decomposition
+	#[track_caller]
+	pub fn solve_refined<W: WidenFrom<T>>(&self, A: MatRef<'_, W>, rhs: MatRef<'_, W>, iters: usize) -> Mat<W> {
+		solve_refined_imp(self, A, rhs, iters)
+	}
+
+	/// solves $A^\top x = b$, where $A$ is the matrix that was used to create the decomposition,
+	/// and stores $x$ in `out` without modifying `rhs`
+	///
+	/// only square matrices are supported. see [`Qr::solve_transpose_into`] for tall matrices and
+	/// [`Svd::solve_transpose_into`] for matrices of any shape
+	///
+	/// # panics
+	/// - panics if $A$ is not square
+	/// - panics if `rhs` does not have `self.ncols()` rows
+	/// - panics if `out` does not have `self.nrows()` rows and as many columns as `rhs`
+	#[track_caller]
+	pub fn solve_transpose_into(&self, rhs: MatRef<'_, T>, out: MatMut<'_, T>) {
+		assert!(
+			self.nrows() == self.ncols(),
+			"the transpose solve of an LU decomposition requires a square matrix, use `Svd::solve_transpose_into` for rectangular matrices",
+		);
+		assert_dim!(rhs.nrows(), self.ncols());
+		assert_dim!(out.nrows(), self.nrows());
+		assert_dim!(out.ncols(), rhs.ncols());
+
+		let mut out = out;
+		out.copy_from(rhs);
+		self.solve_transpose_in_place_with_conj(Conj::No, out);
+	}
+}
+
+impl<T: ComplexField> FullPivLu<T> {
+	/// returns the $LU$ decomposition of $A$ with full pivoting
+	#[track_caller]
+	pub fn new<C: Conjugate<Canonical = T>>(A: MatRef<'_, C>) -> Self {
+		let LU = A.to_owned();
+		Self::new_imp(LU)
+	}
+
+	#[track_caller]
+	fn new_imp(mut LU: Mat<T>) -> Self {
+		let par = get_global_parallelism();
+
+		let (m, n) = LU.shape();
+		let mut row_perm_fwd = vec![0usize; m];
+		let mut row_perm_bwd = vec![0usize; m];
+		let mut col_perm_fwd = vec![0usize; n];
+		let mut col_perm_bwd = vec![0usize; n];
+
+		linalg::lu::full_pivoting::factor::lu_in_place(
+			LU.as_mut(),
+			&mut row_perm_fwd,
+			&mut row_perm_bwd,
+			&mut col_perm_fwd,
+			&mut col_perm_bwd,
+			par,
+			MemStack::new(&mut MemBuffer::new(linalg::lu::full_pivoting::factor::lu_in_place_scratch::<usize, T>(
+				m,
+				n,
+				par,
+				default(),
+			))),
+			default(),
+		);
+
+		let (L, U) = split_LU(LU);
 
 		Self {
 			L,
@@ -1061,6 +1853,76 @@ impl<T: ComplexField> FullPivLu<T> {
 	pub fn Q(&self) -> PermRef<'_, usize> {
 		self.Q.as_ref()
 	}
+
+	/// returns the number of leading diagonal entries of $U$ whose magnitude is greater than `tol`
+	///
+	/// full pivoting moves the largest remaining element to the diagonal at each step, so the
+	/// count stops at the first entry that isn't greater than `tol`. if `tol` is zero, the
+	/// threshold $|U_{00}| \varepsilon \max(m, n)$ is used instead
+	#[math]
+	pub fn numerical_rank(&self, tol: T::Real) -> usize {
+		let U = self.U();
+		let size = Ord::min(self.L.nrows(), U.ncols());
+		if size == 0 {
+			return 0;
+		}
+
+		let tol = if tol == zero() {
+			abs(U[(0, 0)]) * eps::<T::Real>() * from_f64::<T::Real>(Ord::max(self.L.nrows(), U.ncols()) as f64)
+		} else {
+			tol
+		};
+
+		(0..size).take_while(|&i| abs(U[(i, i)]) > tol).count()
+	}
+
+	/// returns $\det A = \det P^\top \det Q \prod_i U_{ii}$, where $A$ is the matrix that was
+	/// used to create the decomposition
+	///
+	/// # panics
+	/// panics if $A$ is not square
+	#[track_caller]
+	pub fn det(&self) -> T {
+		lu_det_imp(self.U.as_ref(), perm_is_odd(self.P.as_ref()) != perm_is_odd(self.Q.as_ref()))
+	}
+
+	/// returns $(\log |\det A|, \det A / |\det A|)$, where $A$ is the matrix that was used to create
+	/// the decomposition
+	///
+	/// the logarithm is accumulated as $\sum_i \log |U_{ii}|$, so it stays finite when $\det A$
+	/// would overflow or underflow. if $A$ is singular, returns $(-\infty, 0)$
+	///
+	/// # panics
+	/// panics if $A$ is not square
+	#[track_caller]
+	pub fn log_abs_det(&self) -> (T::Real, T) {
+		lu_log_abs_det_imp(self.U.as_ref(), perm_is_odd(self.P.as_ref()) != perm_is_odd(self.Q.as_ref()))
+	}
+
+	/// solves $A^\top x = b$, where $A$ is the matrix that was used to create the decomposition,
+	/// and stores $x$ in `out` without modifying `rhs`
+	///
+	/// only square matrices are supported. see [`Qr::solve_transpose_into`] for tall matrices and
+	/// [`Svd::solve_transpose_into`] for matrices of any shape
+	///
+	/// # panics
+	/// - panics if $A$ is not square
+	/// - panics if `rhs` does not have `self.ncols()` rows
+	/// - panics if `out` does not have `self.nrows()` rows and as many columns as `rhs`
+	#[track_caller]
+	pub fn solve_transpose_into(&self, rhs: MatRef<'_, T>, out: MatMut<'_, T>) {
+		assert!(
+			self.nrows() == self.ncols(),
+			"the transpose solve of an LU decomposition requires a square matrix, use `Svd::solve_transpose_into` for rectangular matrices",
+		);
+		assert_dim!(rhs.nrows(), self.ncols());
+		assert_dim!(out.nrows(), self.nrows());
+		assert_dim!(out.ncols(), rhs.ncols());
+
+		let mut out = out;
+		out.copy_from(rhs);
+		self.solve_transpose_in_place_with_conj(Conj::No, out);
+	}
 }
 
 impl<T: ComplexField> Qr<T> {
@@ -1159,6 +2021,158 @@ impl<T: ComplexField> Qr<T> {
 		);
 		Q
 	}
+
+	/// returns the householder reflectors of $Q$ as a list of pairs $(v_i, \tau_i)$, such that
+	/// $Q = H_0 H_1 \dots H_{k - 1}$ with $H_i = I - \tau_i v_i v_i^H$
+	///
+	/// the first $i$ components of $v_i$ are zero and the component at index $i$ is one.
+	/// $\tau_i$ follows the lapack convention, and is zero when $H_i$ is the identity
+	///
+	/// # note
+	/// this differs from the convention used internally, where the diagonal of the block
+	/// householder factors stored in [`Self::Q_coeff`] holds $1 / \tau_i$, which is infinite when
+	/// $H_i$ is the identity
+	///
+	/// # example
+	/// ```
+	/// use faer::prelude::*;
+	///
+	/// let A = mat![[3.0, 1.0], [4.0, 2.0f64]];
+	/// let qr = A.qr();
+	///
+	/// // Q = H_0 H_1
+	/// let mut Q = Mat::<f64>::identity(2, 2);
+	/// for (v, tau) in qr.householder_vectors().iter().rev() {
+	/// 	Q -= v * (v.transpose() * &Q) * Scale(*tau);
+	/// }
+	/// assert!((&Q - qr.compute_Q()).norm_max() < 1e-14);
+	///
+	/// // the last reflector of a square matrix is the identity
+	/// assert!(qr.householder_vectors()[1].1 == 0.0);
+	/// ```
+	pub fn householder_vectors(&self) -> Vec<(Col<T>, T)> {
+		let m = self.nrows();
+		let size = Ord::min(self.nrows(), self.ncols());
+		let blocksize = self.Q_coeff.nrows();
+
+		(0..size)
+			.map(|i| {
+				let v = Col::from_fn(m, |k| {
+					if k < i {
+						zero()
+					} else if k == i {
+						one()
+					} else {
+						self.Q_basis[(k, i)].clone()
+					}
+				});
+				let tau_inv = &self.Q_coeff[(i % blocksize, i)];
+				let tau = if math_utils::is_finite(tau_inv) {
+					math_utils::recip(tau_inv)
+				} else {
+					zero()
+				};
+				(v, tau)
+			})
+			.collect()
+	}
+
+	/// returns the diagonal of the hat matrix $H = A (A^H A)^{-1} A^H$, i.e., the leverage scores
+	/// of the rows of $A$, where $A$ is the matrix that was used to create the decomposition
+	///
+	/// the $i$-th element is computed as the squared norm of the $i$-th row of the thin $Q$
+	/// factor, without forming $H$. this assumes that $A$ has full column rank
+	pub fn hat_diagonal(&self) -> Col<T::Real> {
+		let Q = self.compute_thin_Q();
+		Col::from_fn(Q.nrows(), |i| Q.row(i).squared_norm_l2())
+	}
+
+	/// estimates the $1$-norm condition number $\kappa_1(A) = \|A\|_1 \|A^{-1}\|_1$, where $A$ is
+	/// the matrix that was used to create the decomposition
+	///
+	/// both norms are estimated with hager's method from a few products with the factors and a few
+	/// solves, which takes $O(n^2)$ operations instead of the $O(n^3)$ needed to form $A^{-1}$.
+	/// returns zero if $A$ is empty
+	///
+	/// # panics
+	/// panics if $A$ is not square
+	#[track_caller]
+	pub fn condition_number_estimate(&self) -> T::Real {
+		let n = self.nrows();
+		assert_dim!(self.ncols(), n);
+
+		let par = get_global_parallelism();
+		let blocksize = self.Q_coeff().nrows();
+		let R = self.R();
+
+		// A = Q R
+		let norm = linalg::condition::estimate_norm_1(
+			n,
+			|mut x: MatMut<'_, T>| {
+				let y = R * x.rb();
+				x.copy_from(&y);
+				let k = x.ncols();
+				linalg::householder::apply_block_householder_sequence_on_the_left_in_place_with_conj(
+					self.Q_basis(),
+					self.Q_coeff(),
+					Conj::No,
+					x,
+					par,
+					MemStack::new(&mut MemBuffer::new(
+						linalg::householder::apply_block_householder_sequence_on_the_left_in_place_scratch::<T>(n, blocksize, k),
+					)),
+				);
+			},
+			|mut x: MatMut<'_, T>| {
+				let k = x.ncols();
+				linalg::householder::apply_block_householder_sequence_transpose_on_the_left_in_place_with_conj(
+					self.Q_basis(),
+					self.Q_coeff(),
+					Conj::Yes,
+					x.rb_mut(),
+					par,
+					MemStack::new(&mut MemBuffer::new(
+						linalg::householder::apply_block_householder_sequence_transpose_on_the_left_in_place_scratch::<T>(n, blocksize, k),
+					)),
+				);
+				let y = R.adjoint() * x.rb();
+				x.copy_from(&y);
+			},
+		);
+		mul(&norm, &linalg::condition::estimate_inverse_norm_1(self, n))
+	}
+
+	/// solves $A^\top x = b$, where $A$ is the matrix that was used to create the decomposition,
+	/// and stores $x$ in `out` without modifying `rhs`
+	///
+	/// unlike [`Solve::solve_transpose_in_place`], which overwrites a right-hand side of
+	/// `self.ncols()` rows with a solution of `self.nrows()` rows and therefore requires a square
+	/// matrix, this also accepts a tall $A$. in that case the system is underdetermined and the
+	/// minimum norm solution is returned
+	///
+	/// wide matrices are not supported, since the system is then overdetermined and its least
+	/// squares solution can't be obtained from the factors of $A$. use
+	/// [`Svd::solve_transpose_into`] for them
+	///
+	/// # panics
+	/// - panics if $A$ has fewer rows than columns
+	/// - panics if `rhs` does not have `self.ncols()` rows
+	/// - panics if `out` does not have `self.nrows()` rows and as many columns as `rhs`
+	#[track_caller]
+	pub fn solve_transpose_into(&self, rhs: MatRef<'_, T>, out: MatMut<'_, T>) {
+		let (m, n) = (self.nrows(), self.ncols());
+		assert!(
+			m >= n,
+			"the transpose solve of a QR decomposition requires at least as many rows as columns, use `Svd::solve_transpose_into` for wide matrices",
+		);
+		assert_dim!(rhs.nrows(), n);
+		assert_dim!(out.nrows(), m);
+		assert_dim!(out.ncols(), rhs.ncols());
+
+		let mut out = out;
+		out.rb_mut().get_mut(..n, ..).copy_from(rhs);
+		qr_solve_transpose_min_norm_imp(self.Q_basis(), self.Q_coeff(), self.thin_R(), out);
+	}
 }
 
 impl<T: ComplexField> ColPivQr<T> {
@@ -1272,218 +2286,566 @@ impl<T: ComplexField> ColPivQr<T> {
 	pub fn P(&self) -> PermRef<'_, usize> {
 		self.P.as_ref()
 	}
-}
 
-impl<T: ComplexField> Svd<T> {
-	/// returns the svd of $A$
-	#[track_caller]
-	pub fn new<C: Conjugate<Canonical = T>>(A: MatRef<'_, C>) -> Result<Self, SvdError> {
-		Self::new_imp(A.canonical(), Conj::get::<C>(), false)
+	/// returns the number of diagonal entries of $R$ whose magnitude is greater than `tol`
+	///
+	/// if `tol` is zero, the threshold $|R_{00}| \varepsilon \max(m, n)$ is used instead
+	#[math]
+	pub fn numerical_rank(&self, tol: T::Real) -> usize {
+		let R = self.R();
+		let size = Ord::min(R.nrows(), R.ncols());
+		if size == 0 {
+			return 0;
+		}
+
+		let tol = if tol == zero() {
+			abs(R[(0, 0)]) * eps::<T::Real>() * from_f64::<T::Real>(Ord::max(R.nrows(), R.ncols()) as f64)
+		} else {
+			tol
+		};
+
+		// the magnitudes of the diagonal entries are non-increasing, so the rank is found by
+		// bisection
+		let (mut lo, mut hi) = (0, size);
+		while lo < hi {
+			let mid = lo + (hi - lo) / 2;
+			if abs(R[(mid, mid)]) > tol {
+				lo = mid + 1;
+			} else {
+				hi = mid;
+			}
+		}
+		lo
 	}
 
-	/// returns the thin svd of $A$
+	/// returns an orthonormal basis of the right null space of $A$, where $A$ is the matrix that
+	/// was used to create the decomposition, and its rank $r$ is given by
+	/// [`ColPivQr::numerical_rank`] with the same `tol`
+	///
+	/// with $A P^{-1} = Q R$ and $R = \begin{bmatrix} R_{11} & R_{12} \\ 0 & R_{22} \end{bmatrix}$,
+	/// where $R_{11}$ is $r \times r$ and $R_{22}$ is neglected, the null space is spanned by the
+	/// columns of $P^{-1} \begin{bmatrix} -R_{11}^{-1} R_{12} \\ I \end{bmatrix}$, which are
+	/// then orthonormalized
 	#[track_caller]
-	pub fn new_thin<C: Conjugate<Canonical = T>>(A: MatRef<'_, C>) -> Result<Self, SvdError> {
-		Self::new_imp(A.canonical(), Conj::get::<C>(), true)
+	#[math]
+	pub fn null_space(&self, tol: T::Real) -> Mat<T> {
+		let par = get_global_parallelism();
+		let n = self.ncols();
+		let r = self.numerical_rank(tol);
+
+		let R = self.R();
+		let mut Y = Mat::<T>::zeros(n, n - r);
+		Y.as_mut().get_mut(..r, ..).copy_from(R.get(..r, r..n));
+		linalg::triangular_solve::solve_upper_triangular_in_place(R.get(..r, ..r), Y.as_mut().get_mut(..r, ..), par);
+		z!(Y.as_mut().get_mut(..r, ..)).for_each(|uz!(y)| *y = -*y);
+		for j in 0..n - r {
+			Y[(r + j, j)] = one();
+		}
+
+		let mut X = Mat::<T>::zeros(n, n - r);
+		crate::perm::permute_rows(X.as_mut(), Y.as_ref(), self.P().inverse());
+		Qr::new(X.as_ref()).compute_thin_Q()
 	}
 
+	/// solves the least squares problem $\min \|A x - B\|$ for a possibly rank-deficient $A$,
+	/// and returns the basic solution
+	///
+	/// the numerical rank $r$ is given by [`ColPivQr::numerical_rank`] with the same `tol`. the
+	/// first $r$ pivoted variables are obtained from the leading $r \times r$ block of $R$, and the
+	/// remaining ones are set to zero
+	///
+	/// # panics
+	/// panics if `rhs` does not have `self.nrows()` rows
 	#[track_caller]
-	fn new_imp(A: MatRef<'_, T>, conj: Conj, thin: bool) -> Result<Self, SvdError> {
+	#[math]
+	pub fn solve_lstsq_rank_deficient(&self, rhs: impl AsMatRef<T = T, Rows = usize>, tol: T::Real) -> Mat<T> {
 		let par = get_global_parallelism();
 
-		let (m, n) = A.shape();
-		let size = Ord::min(m, n);
+		let rhs = rhs.as_mat_ref().as_dyn_cols();
+		assert_dim!(rhs.nrows(), self.nrows());
 
-		let mut U = Mat::zeros(m, if thin { size } else { m });
-		let mut V = Mat::zeros(n, if thin { size } else { n });
-		let mut S = Diag::zeros(size);
+		let m = self.nrows();
+		let n = self.ncols();
+		let k = rhs.ncols();
 
-		let compute = if thin { ComputeSvdVectors::Thin } else { ComputeSvdVectors::Full };
+		let R = self.R();
+		let rank = self.numerical_rank(tol);
 
-		linalg::svd::svd(
-			A,
-			S.as_mut(),
-			Some(U.as_mut()),
-			Some(V.as_mut()),
+		let mut Y = rhs.to_owned();
+		linalg::householder::apply_block_householder_sequence_transpose_on_the_left_in_place_with_conj(
+			self.Q_basis(),
+			self.Q_coeff(),
+			Conj::Yes,
+			Y.as_mut(),
 			par,
-			MemStack::new(&mut MemBuffer::new(linalg::svd::svd_scratch::<T>(m, n, compute, compute, par, default()))),
-			default(),
-		)?;
+			MemStack::new(&mut MemBuffer::new(
+				linalg::householder::apply_block_householder_sequence_transpose_on_the_left_in_place_scratch::<T>(m, self.Q_coeff().nrows(), k),
+			)),
+		);
+		linalg::triangular_solve::solve_upper_triangular_in_place(R.get(..rank, ..rank), Y.get_mut(..rank, ..), par);
 
-		if conj == Conj::Yes {
-			for c in U.col_iter_mut() {
-				for x in c.iter_mut() {
-					*x = math_utils::conj(x);
-				}
-			}
-			for c in V.col_iter_mut() {
-				for x in c.iter_mut() {
-					*x = math_utils::conj(x);
-				}
-			}
+		let mut X = Mat::zeros(n, k);
+		let (fwd, _) = self.P().arrays();
+		for (i, &p) in fwd[..rank].iter().enumerate() {
+			X.row_mut(p).copy_from(Y.row(i));
 		}
-
-		Ok(Self { U, V, S })
-	}
-
-	/// returns the factor $U$
-	pub fn U(&self) -> MatRef<'_, T> {
-		self.U.as_ref()
+		X
 	}
 
-	/// returns the factor $V$
-	pub fn V(&self) -> MatRef<'_, T> {
-		self.V.as_ref()
-	}
+	/// solves $A^\top x = b$, where $A$ is the matrix that was used to create the decomposition,
+	/// and stores $x$ in `out` without modifying `rhs`
+	///
+	/// see [`Qr::solve_transpose_into`]. a tall $A$ yields the minimum norm solution, and wide
+	/// matrices are not supported
+	///
+	/// # panics
+	/// - panics if $A$ has fewer rows than columns
+	/// - panics if `rhs` does not have `self.ncols()` rows
+	/// - panics if `out` does not have `self.nrows()` rows and as many columns as `rhs`
+	#[track_caller]
+	pub fn solve_transpose_into(&self, rhs: MatRef<'_, T>, out: MatMut<'_, T>) {
+		let (m, n) = (self.nrows(), self.ncols());
+		assert!(
+			m >= n,
+			"the transpose solve of a QR decomposition requires at least as many rows as columns, use `Svd::solve_transpose_into` for wide matrices",
+		);
+		assert_dim!(rhs.nrows(), n);
+		assert_dim!(out.nrows(), m);
+		assert_dim!(out.ncols(), rhs.ncols());
 
-	/// returns the factor $S$
-	pub fn S(&self) -> DiagRef<'_, T> {
-		self.S.as_ref()
+		// A = Q R P, so R^T Q^T x = P b
+		let mut out = out;
+		crate::perm::permute_rows(out.rb_mut().get_mut(..n, ..), rhs, self.P());
+		qr_solve_transpose_min_norm_imp(self.Q_basis(), self.Q_coeff(), self.thin_R(), out);
 	}
 }
 
-impl<T: ComplexField> SelfAdjointEigen<T> {
-	/// returns the eigendecomposition of $A$, assuming it is self-adjoint
+impl<T: ComplexField> CompOrtho<T> {
+	/// returns the complete orthogonal decomposition of $A$, where its numerical rank $r$ is given
+	/// by [`ColPivQr::numerical_rank`] with the same `tol`
+	///
+	/// starting from the $QR$ decomposition with column pivoting $A P^{-1} = Q R_0$, the leading
+	/// $r$ rows of $R_0$ are reduced to $\begin{bmatrix} R & 0 \end{bmatrix} Z^H$ by a second
+	/// sequence of householder reflectors, obtained from the $QR$ decomposition of their adjoint.
+	/// the trailing rows of $R_0$ are neglected
 	#[track_caller]
-	pub fn new<C: Conjugate<Canonical = T>>(A: MatRef<'_, C>, side: Side) -> Result<Self, EvdError> {
-		assert!(A.nrows() == A.ncols());
-
-		match side {
-			Side::Lower => Self::new_imp(A.canonical(), Conj::get::<C>()),
-			Side::Upper => Self::new_imp(A.adjoint().canonical(), Conj::get::<C::Conj>()),
+	pub fn new<C: Conjugate<Canonical = T>>(A: MatRef<'_, C>, tol: T::Real) -> Self {
+		let qr = ColPivQr::new(A);
+		let rank = qr.numerical_rank(tol);
+		let n = qr.ncols();
+
+		let (Z_basis, Z_coeff, R) = if rank == 0 {
+			(Mat::zeros(n, 0), Mat::zeros(1, 0), Mat::zeros(0, 0))
+		} else {
+			// [R_11 R_12]^H = Z [R^H; 0]
+			let rz = Qr::new(qr.thin_R().get(..rank, ..).adjoint());
+			let R = rz.thin_R().adjoint().to_owned();
+			let Qr { Q_basis, Q_coeff, .. } = rz;
+			(Q_basis, Q_coeff, R)
+		};
+
+		let ColPivQr { Q_basis, Q_coeff, P, .. } = qr;
+		Self {
+			Q_basis,
+			Q_coeff,
+			R,
+			Z_basis,
+			Z_coeff,
+			P,
+			rank,
 		}
 	}
 
-	#[track_caller]
-	fn new_imp(A: MatRef<'_, T>, conj: Conj) -> Result<Self, EvdError> {
-		let par = get_global_parallelism();
-
-		let n = A.nrows();
+	/// returns the householder basis of $Q$
+	pub fn Q_basis(&self) -> MatRef<'_, T> {
+		self.Q_basis.as_ref()
+	}
 
-		let mut U = Mat::zeros(n, n);
-		let mut S = Diag::zeros(n);
+	/// returns the householder coefficients of $Q$
+	pub fn Q_coeff(&self) -> MatRef<'_, T> {
+		self.Q_coeff.as_ref()
+	}
 
-		linalg::evd::self_adjoint_evd(
-			A,
-			S.as_mut(),
-			Some(U.as_mut()),
-			par,
-			MemStack::new(&mut MemBuffer::new(linalg::evd::self_adjoint_evd_scratch::<T>(
-				n,
-				linalg::evd::ComputeEigenvectors::Yes,
-				par,
-				default(),
-			))),
-			default(),
-		)?;
+	/// returns the lower triangular factor $R$
+	pub fn R(&self) -> MatRef<'_, T> {
+		self.R.as_ref()
+	}
 
-		if conj == Conj::Yes {
-			for c in U.col_iter_mut() {
-				for x in c.iter_mut() {
-					*x = math_utils::conj(x);
-				}
-			}
-		}
+	/// returns the householder basis of $Z$
+	pub fn Z_basis(&self) -> MatRef<'_, T> {
+		self.Z_basis.as_ref()
+	}
 
-		Ok(Self { U, S })
+	/// returns the householder coefficients of $Z$
+	pub fn Z_coeff(&self) -> MatRef<'_, T> {
+		self.Z_coeff.as_ref()
 	}
 
-	/// returns the factor $U$
-	pub fn U(&self) -> MatRef<'_, T> {
-		self.U.as_ref()
+	/// returns the column pivoting permutation $P$
+	pub fn P(&self) -> PermRef<'_, usize> {
+		self.P.as_ref()
 	}
 
-	/// returns the factor $S$
-	pub fn S(&self) -> DiagRef<'_, T> {
-		self.S.as_ref()
+	/// returns the numerical rank $r$
+	pub fn rank(&self) -> usize {
+		self.rank
 	}
-}
 
-impl<T: RealField> Eigen<T> {
-	/// returns the eigendecomposition of $A$
+	/// returns the minimum norm solution of the least squares problem $\min \|A x - B\|$, which
+	/// is also defined for underdetermined and rank-deficient systems
+	///
+	/// unlike [`SolveLstsqCore::solve_lstsq_in_place_with_conj`], which stores the solution in the
+	/// right-hand side, this accepts matrices with fewer rows than columns
+	///
+	/// # panics
+	/// panics if `rhs` does not have `self.nrows()` rows
 	#[track_caller]
-	pub fn new<C: Conjugate<Canonical = Complex<T>>>(A: MatRef<'_, C>) -> Result<Self, EvdError> {
-		assert!(A.nrows() == A.ncols());
-		Self::new_imp(A.canonical(), Conj::get::<C>())
+	pub fn solve_min_norm(&self, rhs: impl AsMatRef<T = T, Rows = usize>) -> Mat<T> {
+		let rhs = rhs.as_mat_ref().as_dyn_cols();
+		assert_dim!(rhs.nrows(), self.nrows());
+
+		let (m, n) = (self.nrows(), self.ncols());
+		let k = rhs.ncols();
+
+		let mut X = Mat::<T>::zeros(Ord::max(m, n), k);
+		X.get_mut(..m, ..).copy_from(rhs);
+		self.solve_min_norm_in_place_imp(Conj::No, X.as_mut());
+		X.truncate(n, k);
+		X
 	}
 
-	/// returns the eigendecomposition of $A$
+	// the top m rows of `rhs` hold the right-hand side, and the solution is stored in its top n rows
 	#[track_caller]
-	pub fn new_from_real(A: MatRef<'_, T>) -> Result<Self, EvdError> {
-		assert!(A.nrows() == A.ncols());
+	fn solve_min_norm_in_place_imp(&self, conj: Conj, rhs: MatMut<'_, T>) {
+		let par = get_global_parallelism();
+
+		let m = self.nrows();
+		let n = self.ncols();
+		let r = self.rank;
+		let k = rhs.ncols();
+
+		let mut rhs = rhs;
+		let mut mem = MemBuffer::new(StackReq::any_of(&[
+			linalg::householder::apply_block_householder_sequence_transpose_on_the_left_in_place_scratch::<T>(m, self.Q_coeff.nrows(), k),
+			linalg::householder::apply_block_householder_sequence_on_the_left_in_place_scratch::<T>(n, self.Z_coeff.nrows(), k),
+		]));
+		let stack = MemStack::new(&mut mem);
+
+		// P x = Z [R^-1 (Q^H b)_1; 0]
+		linalg::householder::apply_block_householder_sequence_transpose_on_the_left_in_place_with_conj(
+			self.Q_basis(),
+			self.Q_coeff(),
+			conj.compose(Conj::Yes),
+			rhs.rb_mut().get_mut(..m, ..),
+			par,
+			stack,
+		);
+		linalg::triangular_solve::solve_lower_triangular_in_place_with_conj(self.R(), conj, rhs.rb_mut().get_mut(..r, ..), par);
+		rhs.rb_mut().get_mut(r..n, ..).fill(zero());
+		linalg::householder::apply_block_householder_sequence_on_the_left_in_place_with_conj(
+			self.Z_basis(),
+			self.Z_coeff(),
+			conj,
+			rhs.rb_mut().get_mut(..n, ..),
+			par,
+			stack,
+		);
+
+		let mut x = Mat::<T>::zeros(n, k);
+		crate::perm::permute_rows(x.as_mut(), rhs.rb().get(..n, ..), self.P().inverse());
+		rhs.get_mut(..n, ..).copy_from(x);
+	}
 
+	/// returns an orthonormal basis of the right null space of $A$, where $A$ is the matrix that
+	/// was used to create the decomposition
+	///
+	/// the basis is given by the last $n - r$ columns of $P^{-1} Z$
+	pub fn null_space(&self) -> Mat<T> {
 		let par = get_global_parallelism();
+		let n = self.ncols();
+		let r = self.rank;
 
-		let n = A.nrows();
+		let mut Y = Mat::<T>::zeros(n, n - r);
+		for j in 0..n - r {
+			Y[(r + j, j)] = one();
+		}
+		linalg::householder::apply_block_householder_sequence_on_the_left_in_place_with_conj(
+			self.Z_basis(),
+			self.Z_coeff(),
+			Conj::No,
+			Y.as_mut(),
+			par,
+			MemStack::new(&mut MemBuffer::new(
+				linalg::householder::apply_block_householder_sequence_on_the_left_in_place_scratch::<T>(n, self.Z_coeff.nrows(), n - r),
+			)),
+		);
 
-		let mut U_real = Mat::zeros(n, n);
-		let mut S_re = Diag::zeros(n);
-		let mut S_im = Diag::zeros(n);
+		let mut X = Mat::<T>::zeros(n, n - r);
+		crate::perm::permute_rows(X.as_mut(), Y.as_ref(), self.P().inverse());
+		X
+	}
 
-		linalg::evd::evd_real(
-			A,
-			S_re.as_mut(),
-			S_im.as_mut(),
-			None,
-			Some(U_real.as_mut()),
+	/// returns an orthonormal basis of the column space of $A$, where $A$ is the matrix that was
+	/// used to create the decomposition
+	///
+	/// the basis is given by the first $r$ columns of $Q$
+	pub fn column_space(&self) -> Mat<T> {
+		let par = get_global_parallelism();
+		let m = self.nrows();
+		let r = self.rank;
+
+		let mut Q = Mat::<T>::identity(m, r);
+		linalg::householder::apply_block_householder_sequence_on_the_left_in_place_with_conj(
+			self.Q_basis(),
+			self.Q_coeff(),
+			Conj::No,
+			Q.as_mut(),
 			par,
-			MemStack::new(&mut MemBuffer::new(linalg::evd::evd_scratch::<T>(
-				n,
-				linalg::evd::ComputeEigenvectors::No,
-				linalg::evd::ComputeEigenvectors::Yes,
+			MemStack::new(&mut MemBuffer::new(
+				linalg::householder::apply_block_householder_sequence_on_the_left_in_place_scratch::<T>(m, self.Q_coeff.nrows(), r),
+			)),
+		);
+		Q
+	}
+}
+
+impl<T: ComplexField> Svd<T> {
+	/// returns the svd of $A$
+	#[track_caller]
+	pub fn new<C: Conjugate<Canonical = T>>(A: MatRef<'_, C>) -> Result<Self, SvdError> {
+		Self::new_imp(A.canonical(), Conj::get::<C>(), false)
+	}
+
+	/// returns the thin svd of $A$
+	#[track_caller]
+	pub fn new_thin<C: Conjugate<Canonical = T>>(A: MatRef<'_, C>) -> Result<Self, SvdError> {
+		Self::new_imp(A.canonical(), Conj::get::<C>(), true)
+	}
+
+	/// returns an approximation of the `k` largest singular values of $A$ and their singular
+	/// vectors, computed with a randomized range finder
+	///
+	/// $A$ is sketched with a gaussian test matrix of $k + p$ columns, where $p$ is a small
+	/// oversampling amount, and a few power iterations sharpen the sketch when the singular values
+	/// decay slowly. the small matrix $Q^H A$, where $Q$ is an orthonormal basis of the sketch, is
+	/// then decomposed with a dense svd (halko, martinsson and tropp). the test matrix is drawn
+	/// from a generator with a fixed seed, so the result is deterministic. use
+	/// [`Svd::new_randomized`] to supply the generator
+	///
+	/// $U$ has shape $(m, k)$, $V$ has shape $(n, k)$ and $S$ has $k$ entries. if $k = \min(m, n)$,
+	/// this is the same as [`Svd::new_thin`]
+	///
+	/// # panics
+	/// panics if $k > \min(m, n)$
+	#[track_caller]
+	#[cfg(feature = "rand")]
+	pub fn new_truncated<C: Conjugate<Canonical = T>>(A: MatRef<'_, C>, k: usize, par: Par) -> Result<Self, SvdError> {
+		let (m, n) = A.shape();
+		let size = Ord::min(m, n);
+		assert!(k <= size);
+
+		let conj = Conj::get::<C>();
+		let A = A.canonical();
+
+		if k == size {
+			return Self::new_imp(A, conj, true);
+		}
+		let rng = &mut <rand::rngs::StdRng as rand::SeedableRng>::seed_from_u64(TRUNCATED_SVD_SEED);
+		Self::new_randomized_imp(A, conj, k, TRUNCATED_SVD_OVERSAMPLING, TRUNCATED_SVD_POWER_ITERATIONS, rng, par)
+	}
+
+	/// returns an approximation of the `rank` largest singular values of $A$ and their singular
+	/// vectors, computed with the randomized svd of halko, martinsson and tropp
+	///
+	/// $A$ is sketched with a gaussian test matrix $\Omega$, drawn from `rng`, of
+	/// $\text{rank} + \text{oversampling}$ columns (capped at $\min(m, n)$), and the sketch
+	/// $Y = (A A^H)^q A \Omega$, where $q$ is `power_iters`, is orthogonalized with a $QR$
+	/// decomposition. the small matrix $Q^H A$ is then decomposed with a dense svd. $Y$ is
+	/// reorthogonalized after every power iteration
+	///
+	/// unlike [`Svd::new_truncated`], which picks the oversampling and the number of power
+	/// iterations itself, both are chosen by the caller. when `rank == min(m, n)`, the range of $A$
+	/// is captured entirely and the result is exact up to rounding, even without oversampling
+	///
+	/// $U$ has shape $(m, \text{rank})$, $V$ has shape $(n, \text{rank})$ and $S$ has `rank` entries
+	///
+	/// # panics
+	/// panics if $\text{rank} > \min(m, n)$
+	#[track_caller]
+	#[cfg(feature = "rand")]
+	pub fn new_randomized<C: Conjugate<Canonical = T>>(
+		A: MatRef<'_, C>,
+		rank: usize,
+		oversampling: usize,
+		power_iters: usize,
+		rng: &mut (impl rand::Rng + ?Sized),
+	) -> Result<Self, SvdError> {
+		assert!(rank <= Ord::min(A.nrows(), A.ncols()));
+		Self::new_randomized_imp(
+			A.canonical(),
+			Conj::get::<C>(),
+			rank,
+			oversampling,
+			power_iters,
+			rng,
+			get_global_parallelism(),
+		)
+	}
+
+	#[track_caller]
+	#[cfg(feature = "rand")]
+	fn new_randomized_imp(
+		A: MatRef<'_, T>,
+		conj: Conj,
+		k: usize,
+		oversampling: usize,
+		power_iters: usize,
+		rng: &mut (impl rand::Rng + ?Sized),
+		par: Par,
+	) -> Result<Self, SvdError> {
+		let (m, n) = A.shape();
+		let size = Ord::min(m, n);
+
+		if k == 0 {
+			return Ok(Self {
+				U: Mat::zeros(m, 0),
+				V: Mat::zeros(n, 0),
+				S: Diag::zeros(0),
+			});
+		}
+
+		let l = Ord::min(k + oversampling, size);
+
+		let omega = gaussian_test_matrix::<T>(n, l, rng);
+		let mut Y = Mat::<T>::zeros(m, l);
+		let mut Z = Mat::<T>::zeros(n, l);
+
+		linalg::matmul::matmul_with_conj(Y.as_mut(), Accum::Replace, A, conj, omega.as_ref(), Conj::No, one(), par);
+		let mut Q = Qr::new(Y.as_ref()).compute_thin_Q();
+
+		for _ in 0..power_iters {
+			linalg::matmul::matmul_with_conj(
+				Z.as_mut(),
+				Accum::Replace,
+				A.transpose(),
+				conj.compose(Conj::Yes),
+				Q.as_ref(),
+				Conj::No,
+				one(),
 				par,
-				default(),
-			))),
-			default(),
-		)?;
+			);
+			let W = Qr::new(Z.as_ref()).compute_thin_Q();
+			linalg::matmul::matmul_with_conj(Y.as_mut(), Accum::Replace, A, conj, W.as_ref(), Conj::No, one(), par);
+			Q = Qr::new(Y.as_ref()).compute_thin_Q();
+		}
 
-		let mut U = Mat::zeros(n, n);
-		let mut S = Diag::zeros(n);
+		let mut B = Mat::<T>::zeros(l, n);
+		linalg::matmul::matmul_with_conj(B.as_mut(), Accum::Replace, Q.transpose(), Conj::Yes, A, conj, one(), par);
+		let svd = Self::new_imp(B.as_ref(), Conj::No, true)?;
 
-		let mut j = 0;
-		while j < n {
-			if S_im[j] == zero() {
-				S[j] = Complex::new(S_re[j].clone(), zero());
+		let mut U = Mat::<T>::zeros(m, k);
+		linalg::matmul::matmul(U.as_mut(), Accum::Replace, Q.as_ref(), svd.U().get(.., ..k), one(), par);
 
-				for i in 0..n {
-					U[(i, j)] = Complex::new(U_real[(i, j)].clone(), zero());
-				}
+		Ok(Self {
+			U,
+			V: svd.V().get(.., ..k).to_owned(),
+			S: svd.S().column_vector().get(..k).to_owned().into_diagonal(),
+		})
+	}
 
-				j += 1;
-			} else {
-				S[j] = Complex::new(S_re[j].clone(), S_im[j].clone());
-				S[j + 1] = Complex::new(S_re[j].clone(), neg(&S_im[j]));
+	/// computes the svd of $A$, writing the singular values to `S` and the singular vectors to `U`
+	/// and `V` instead of allocating storage for them
+	///
+	/// `U` must have either `A.nrows()` columns for the full factor, or
+	/// $\min(\text{nrows}, \text{ncols})$ columns for the thin factor, and similarly for `V` with
+	/// `A.ncols()`. a factor whose output is `None` is not computed
+	///
+	/// `S` must have dimension $\min(\text{nrows}, \text{ncols})$
+	#[track_caller]
+	pub fn new_into<C: Conjugate<Canonical = T>>(
+		A: MatRef<'_, C>,
+		U: Option<MatMut<'_, T>>,
+		V: Option<MatMut<'_, T>>,
+		S: DiagMut<'_, T::Real>,
+	) -> Result<(), SvdError> {
+		let mut S = S;
+		assert!(S.dim() == Ord::min(A.nrows(), A.ncols()));
+
+		// the svd kernel writes the singular values as elements of `T`
+		let mut S_T = Diag::<T>::zeros(S.dim());
+		Self::new_into_imp(A.canonical(), Conj::get::<C>(), U, V, S_T.as_mut())?;
+		for i in 0..S.dim() {
+			S[i] = math_utils::real(&S_T[i]);
+		}
+		Ok(())
+	}
 
-				for i in 0..n {
-					U[(i, j)] = Complex::new(U_real[(i, j)].clone(), U_real[(i, j + 1)].clone());
-					U[(i, j + 1)] = Complex::new(U_real[(i, j)].clone(), neg(&U_real[(i, j + 1)]));
-				}
+	/// returns a builder that allows choosing which singular vectors are computed
+	///
+	/// by default, the full singular vectors are computed
+	pub fn builder() -> SvdBuilder<T> {
+		SvdBuilder {
+			vectors: ComputeSvdVectors::Full,
+			__marker: core::marker::PhantomData,
+		}
+	}
 
-				j += 2;
-			}
+	#[track_caller]
+	fn new_imp(A: MatRef<'_, T>, conj: Conj, thin: bool) -> Result<Self, SvdError> {
+		Self::new_with_vectors_imp(A, conj, if thin { ComputeSvdVectors::Thin } else { ComputeSvdVectors::Full })
+	}
+
+	#[track_caller]
+	fn new_with_vectors_imp(A: MatRef<'_, T>, conj: Conj, vectors: ComputeSvdVectors) -> Result<Self, SvdError> {
+		let (m, n) = A.shape();
+		let size = Ord::min(m, n);
+
+		let ncols = |dim: usize| match vectors {
+			ComputeSvdVectors::No => 0,
+			ComputeSvdVectors::Thin => size,
+			ComputeSvdVectors::Full => dim,
+		};
+		let mut U = Mat::zeros(m, ncols(m));
+		let mut V = Mat::zeros(n, ncols(n));
+		let mut S = Diag::zeros(size);
+
+		if vectors == ComputeSvdVectors::No {
+			Self::new_into_imp(A, conj, None, None, S.as_mut())?;
+		} else {
+			Self::new_into_imp(A, conj, Some(U.as_mut()), Some(V.as_mut()), S.as_mut())?;
 		}
 
-		Ok(Self { U, S })
+		Ok(Self { U, V, S })
 	}
 
-	fn new_imp(A: MatRef<'_, Complex<T>>, conj: Conj) -> Result<Self, EvdError> {
+	#[track_caller]
+	fn new_into_imp(A: MatRef<'_, T>, conj: Conj, U: Option<MatMut<'_, T>>, V: Option<MatMut<'_, T>>, S: DiagMut<'_, T>) -> Result<(), SvdError> {
 		let par = get_global_parallelism();
 
-		let n = A.nrows();
+		let (m, n) = A.shape();
 
-		let mut U = Mat::zeros(n, n);
-		let mut S = Diag::zeros(n);
+		let compute = |factor: Option<&MatMut<'_, T>>, dim: usize| match factor {
+			None => ComputeSvdVectors::No,
+			Some(factor) if factor.ncols() == dim => ComputeSvdVectors::Full,
+			Some(_) => ComputeSvdVectors::Thin,
+		};
+		let compute_u = compute(U.as_ref(), m);
+		let compute_v = compute(V.as_ref(), n);
 
-		linalg::evd::evd_cplx(
+		let mut U = U;
+		let mut V = V;
+
+		linalg::svd::svd(
 			A,
-			S.as_mut(),
-			None,
-			Some(U.as_mut()),
+			S,
+			U.rb_mut(),
+			V.rb_mut(),
 			par,
-			MemStack::new(&mut MemBuffer::new(linalg::evd::evd_scratch::<Complex<T>>(
+			MemStack::new(&mut MemBuffer::new(linalg::svd::svd_scratch::<T>(
+				m,
 				n,
-				linalg::evd::ComputeEigenvectors::No,
-				linalg::evd::ComputeEigenvectors::Yes,
+				compute_u,
+				compute_v,
 				par,
 				default(),
 			))),
@@ -1491,1184 +2853,4249 @@ impl<T: RealField> Eigen<T> {
 		)?;
 
 		if conj == Conj::Yes {
-			for c in U.col_iter_mut() {
-				for x in c.iter_mut() {
-					*x = math_utils::conj(x);
+			for factor in [U, V].into_iter().flatten() {
+				for c in factor.col_iter_mut() {
+					for x in c.iter_mut() {
+						*x = math_utils::conj(x);
+					}
 				}
 			}
 		}
 
-		Ok(Self { U, S })
+		Ok(())
 	}
 
 	/// returns the factor $U$
-	pub fn U(&self) -> MatRef<'_, Complex<T>> {
+	pub fn U(&self) -> MatRef<'_, T> {
 		self.U.as_ref()
 	}
 
+	/// returns the factor $V$
+	pub fn V(&self) -> MatRef<'_, T> {
+		self.V.as_ref()
+	}
+
 	/// returns the factor $S$
-	pub fn S(&self) -> DiagRef<'_, Complex<T>> {
+	pub fn S(&self) -> DiagRef<'_, T> {
 		self.S.as_ref()
 	}
-}
 
-impl<T: ComplexField> ShapeCore for Llt<T> {
-	#[inline]
-	fn nrows(&self) -> usize {
-		self.L().nrows()
+	#[track_caller]
+	fn assert_has_vectors(&self) {
+		let size = self.S.dim();
+		assert!(
+			self.U.ncols() >= size && self.V.ncols() >= size,
+			"the singular vectors were not computed, build the decomposition with `ComputeSvdVectors::Thin` or `ComputeSvdVectors::Full`",
+		);
 	}
 
-	#[inline]
-	fn ncols(&self) -> usize {
-		self.L().ncols()
+	#[track_caller]
+	fn assert_not_truncated(&self) {
+		assert!(
+			self.S.dim() == Ord::min(self.nrows(), self.ncols()),
+			"the decomposition is truncated, the solve requires all the singular values",
+		);
 	}
-}
-impl<T: ComplexField> ShapeCore for Ldlt<T> {
-	#[inline]
-	fn nrows(&self) -> usize {
-		self.L().nrows()
+
+	/// returns the number of singular values that are greater than `tol`
+	///
+	/// if `tol` is zero, the threshold $\sigma_{\max} \varepsilon \max(m, n)$ is used instead
+	pub fn numerical_rank(&self, tol: T::Real) -> usize {
+		let tol = self.rank_threshold(tol);
+		self.S.column_vector().iter().take_while(|s| real(*s) > tol).count()
 	}
 
-	#[inline]
-	fn ncols(&self) -> usize {
-		self.L().ncols()
+	/// returns the moore-penrose pseudoinverse $A^+ = V S^+ U^H$ of the matrix that was used to
+	/// create the decomposition
+	///
+	/// the reciprocals of the singular values that are not greater than `tol` are replaced by zero.
+	/// if `tol` is zero, the threshold $\sigma_{\max} \varepsilon \max(m, n)$ is used instead
+	///
+	/// # panics
+	/// panics if the decomposition was computed without its singular vectors
+	#[track_caller]
+	#[math]
+	pub fn pseudo_inverse(&self, tol: T::Real) -> Mat<T> {
+		self.assert_has_vectors();
+		let rank = self.numerical_rank(tol);
+		let U = self.U.get(.., ..rank);
+		let V = self.V.get(.., ..rank);
+		let S = self.S.column_vector();
+
+		let mut VS = V.to_owned();
+		for j in 0..rank {
+			let s_inv = recip(real(S[j]));
+			z!(VS.col_mut(j)).for_each(|uz!(x)| *x = mul_real(*x, s_inv));
+		}
+
+		let mut pinv = Mat::zeros(V.nrows(), U.nrows());
+		linalg::matmul::matmul(
+			pinv.as_mut(),
+			Accum::Replace,
+			VS.as_ref(),
+			U.adjoint(),
+			one::<T>(),
+			get_global_parallelism(),
+		);
+		pinv
 	}
-}
-impl<T: ComplexField> ShapeCore for Lblt<T> {
-	#[inline]
-	fn nrows(&self) -> usize {
-		self.L().nrows()
+
+	/// solves $A^\top x = b$, where $A$ is the matrix that was used to create the decomposition,
+	/// and stores $x$ in `out` without modifying `rhs`
+	///
+	/// unlike the $LU$ and $QR$ versions, this accepts matrices of any shape. the solution
+	/// $x = \bar U S^{-1} V^\top b$ is the minimum norm solution when $A$ is tall and the least
+	/// squares solution when it is wide, assuming $A$ has full rank. a truncated decomposition only
+	/// uses its retained singular values
+	///
+	/// # panics
+	/// - panics if the decomposition was computed without its singular vectors
+	/// - panics if `rhs` does not have `self.ncols()` rows
+	/// - panics if `out` does not have `self.nrows()` rows and as many columns as `rhs`
+	#[track_caller]
+	pub fn solve_transpose_into(&self, rhs: MatRef<'_, T>, out: MatMut<'_, T>) {
+		self.assert_has_vectors();
+		assert_dim!(rhs.nrows(), self.ncols());
+		assert_dim!(out.nrows(), self.nrows());
+		assert_dim!(out.ncols(), rhs.ncols());
+
+		let par = get_global_parallelism();
+		let size = self.S().dim();
+		let U = self.U().get(.., ..size);
+		let V = self.V().get(.., ..size);
+		let k = rhs.ncols();
+
+		let mut tmp = Mat::zeros(size, k);
+		linalg::matmul::matmul(tmp.as_mut(), Accum::Replace, V.transpose(), rhs, one(), par);
+
+		for j in 0..k {
+			for i in 0..size {
+				let s = recip(&real(&self.S()[i]));
+				tmp[(i, j)] = mul_real(&tmp[(i, j)], &s);
+			}
+		}
+
+		linalg::matmul::matmul_with_conj(out, Accum::Replace, U, Conj::Yes, tmp.as_ref(), Conj::No, one(), par);
 	}
 
-	#[inline]
-	fn ncols(&self) -> usize {
-		self.L().ncols()
+	/// returns the $2$-norm condition number $\kappa_2(A) = \sigma_{\max} / \sigma_{\min}$, where $A$
+	/// is the matrix that was used to create the decomposition
+	///
+	/// returns infinity if the smallest singular value is zero, and zero if $A$ is empty. for a
+	/// decomposition created with [`Svd::new_truncated`], only the retained singular values are used
+	#[math]
+	pub fn condition_number(&self) -> T::Real {
+		let S = self.S.column_vector();
+		let size = S.nrows();
+		if size == 0 {
+			return zero();
+		}
+
+		let s_max = real(S[0]);
+		let s_min = real(S[size - 1]);
+		if s_min == zero::<T::Real>() { infinity() } else { s_max / s_min }
 	}
-}
-impl<T: ComplexField> ShapeCore for PartialPivLu<T> {
-	#[inline]
-	fn nrows(&self) -> usize {
-		self.L().nrows()
+
+	/// returns the singular value soft-thresholding $U (S - \tau)_+ V^H$, which is the proximal
+	/// operator of $\tau \|\cdot\|_*$ evaluated at the matrix that was used to create the
+	/// decomposition
+	///
+	/// each singular value is shrunk by `tau` and clamped at zero, so that the singular values that
+	/// are not greater than `tau` are removed from the result
+	///
+	/// # panics
+	/// panics if the decomposition was computed without its singular vectors
+	#[track_caller]
+	#[math]
+	pub fn soft_threshold(&self, tau: T::Real) -> Mat<T> {
+		self.assert_has_vectors();
+		let S = self.S.column_vector();
+		let rank = S.iter().take_while(|s| real(**s) > tau).count();
+		let U = self.U.get(.., ..rank);
+		let V = self.V.get(.., ..rank);
+
+		let mut US = U.to_owned();
+		for j in 0..rank {
+			let s = real(S[j]) - tau;
+			z!(US.col_mut(j)).for_each(|uz!(x)| *x = mul_real(*x, s));
+		}
+
+		let mut out = Mat::zeros(U.nrows(), V.nrows());
+		linalg::matmul::matmul(
+			out.as_mut(),
+			Accum::Replace,
+			US.as_ref(),
+			V.adjoint(),
+			one::<T>(),
+			get_global_parallelism(),
+		);
+		out
 	}
 
-	#[inline]
-	fn ncols(&self) -> usize {
-		self.U().ncols()
+	#[math]
+	fn rank_threshold(&self, tol: T::Real) -> T::Real {
+		if tol != zero() {
+			return tol;
+		}
+		let S = self.S.column_vector();
+		if S.nrows() == 0 {
+			return zero();
+		}
+		real(S[0]) * eps::<T::Real>() * from_f64::<T::Real>(Ord::max(self.U.nrows(), self.V.nrows()) as f64)
 	}
 }
-impl<T: ComplexField> ShapeCore for FullPivLu<T> {
-	#[inline]
-	fn nrows(&self) -> usize {
-		self.L().nrows()
+
+impl<T: ComplexField> SvdBuilder<T> {
+	/// sets which singular vectors should be computed. if they are not, [`Svd::U`] and [`Svd::V`]
+	/// are matrices with no columns
+	pub fn vectors(self, vectors: ComputeSvdVectors) -> Self {
+		Self { vectors, ..self }
 	}
 
-	#[inline]
-	fn ncols(&self) -> usize {
-		self.U().ncols()
+	/// returns the svd of $A$
+	#[track_caller]
+	pub fn build<C: Conjugate<Canonical = T>>(self, A: MatRef<'_, C>) -> Result<Svd<T>, SvdError> {
+		Svd::new_with_vectors_imp(A.canonical(), Conj::get::<C>(), self.vectors)
 	}
 }
-impl<T: ComplexField> ShapeCore for Qr<T> {
-	#[inline]
-	fn nrows(&self) -> usize {
-		self.Q_basis().nrows()
-	}
 
-	#[inline]
-	fn ncols(&self) -> usize {
-		self.R().ncols()
-	}
-}
-impl<T: ComplexField> ShapeCore for ColPivQr<T> {
-	#[inline]
-	fn nrows(&self) -> usize {
-		self.Q_basis().nrows()
+impl<T: ComplexField> SelfAdjointEigen<T> {
+	/// returns the eigendecomposition of $A$, assuming it is self-adjoint
+	#[track_caller]
+	pub fn new<C: Conjugate<Canonical = T>>(A: MatRef<'_, C>, side: Side) -> Result<Self, EvdError> {
+		Self::new_with_vectors(A, side, ComputeEigenvectors::Yes)
 	}
 
-	#[inline]
-	fn ncols(&self) -> usize {
-		self.R().ncols()
-	}
-}
-impl<T: ComplexField> ShapeCore for Svd<T> {
-	#[inline]
-	fn nrows(&self) -> usize {
-		self.U().nrows()
+	/// returns the eigenvalues of $A$, assuming it is self-adjoint, without computing the
+	/// eigenvectors
+	///
+	/// [`SelfAdjointEigen::try_U`] returns `None` for the resulting decomposition
+	#[track_caller]
+	pub fn new_values_only<C: Conjugate<Canonical = T>>(A: MatRef<'_, C>, side: Side) -> Result<Self, EvdError> {
+		Self::new_with_vectors(A, side, ComputeEigenvectors::No)
 	}
 
-	#[inline]
-	fn ncols(&self) -> usize {
-		self.V().nrows()
-	}
-}
-impl<T: ComplexField> ShapeCore for SelfAdjointEigen<T> {
-	#[inline]
-	fn nrows(&self) -> usize {
-		self.U().nrows()
+	/// returns a builder that allows choosing whether the eigenvectors are computed
+	///
+	/// by default, the eigenvectors are computed
+	pub fn builder() -> SelfAdjointEigenBuilder<T> {
+		SelfAdjointEigenBuilder {
+			vectors: ComputeEigenvectors::Yes,
+			__marker: core::marker::PhantomData,
+		}
 	}
 
-	#[inline]
-	fn ncols(&self) -> usize {
-		self.U().nrows()
-	}
-}
-impl<T: RealField> ShapeCore for Eigen<T> {
-	#[inline]
-	fn nrows(&self) -> usize {
-		self.U().nrows()
-	}
+	#[track_caller]
+	fn new_with_vectors<C: Conjugate<Canonical = T>>(A: MatRef<'_, C>, side: Side, vectors: ComputeEigenvectors) -> Result<Self, EvdError> {
+		assert_dim!(A.ncols(), A.nrows());
 
-	#[inline]
-	fn ncols(&self) -> usize {
-		self.U().nrows()
+		match side {
+			Side::Lower => Self::new_imp(A.canonical(), Conj::get::<C>(), vectors),
+			Side::Upper => Self::new_imp(A.adjoint().canonical(), Conj::get::<C::Conj>(), vectors),
+		}
 	}
-}
 
-impl<T: ComplexField> SolveCore<T> for Llt<T> {
 	#[track_caller]
-	fn solve_in_place_with_conj(&self, conj: Conj, rhs: MatMut<'_, T>) {
+	fn new_imp(A: MatRef<'_, T>, conj: Conj, vectors: ComputeEigenvectors) -> Result<Self, EvdError> {
 		let par = get_global_parallelism();
 
-		let mut mem = MemBuffer::new(linalg::cholesky::llt::solve::solve_in_place_scratch::<T>(
-			self.L.nrows(),
-			rhs.ncols(),
-			par,
-		));
-		let stack = MemStack::new(&mut mem);
-
-		linalg::cholesky::llt::solve::solve_in_place_with_conj(self.L.as_ref(), conj, rhs, par, stack);
-	}
+		let n = A.nrows();
 
-	#[track_caller]
-	fn solve_transpose_in_place_with_conj(&self, conj: Conj, rhs: MatMut<'_, T>) {
-		let par = get_global_parallelism();
+		let mut U = Mat::zeros(n, if vectors == ComputeEigenvectors::Yes { n } else { 0 });
+		let mut S = Diag::zeros(n);
 
-		let mut mem = MemBuffer::new(linalg::cholesky::llt::solve::solve_in_place_scratch::<T>(
-			self.L.nrows(),
-			rhs.ncols(),
+		linalg::evd::self_adjoint_evd(
+			A,
+			S.as_mut(),
+			if vectors == ComputeEigenvectors::Yes { Some(U.as_mut()) } else { None },
 			par,
-		));
-		let stack = MemStack::new(&mut mem);
+			MemStack::new(&mut MemBuffer::new(linalg::evd::self_adjoint_evd_scratch::<T>(
+				n,
+				vectors,
+				par,
+				default(),
+			))),
+			default(),
+		)?;
 
-		linalg::cholesky::llt::solve::solve_in_place_with_conj(self.L.as_ref(), conj.compose(Conj::Yes), rhs, par, stack);
+		if conj == Conj::Yes {
+			for c in U.col_iter_mut() {
+				for x in c.iter_mut() {
+					*x = math_utils::conj(x);
+				}
+			}
+		}
+
+		Ok(Self { U, S })
 	}
-}
 
-#[math]
-fn make_self_adjoint<T: ComplexField>(mut A: MatMut<'_, T>) {
-	assert!(A.nrows() == A.ncols());
-	let n = A.nrows();
-	for j in 0..n {
-		A[(j, j)] = from_real(real(A[(j, j)]));
-		for i in 0..j {
-			A[(i, j)] = conj(A[(j, i)]);
-		}
+	/// returns the factor $U$
+	///
+	/// if the eigenvectors were not computed, this is an empty matrix
+	pub fn U(&self) -> MatRef<'_, T> {
+		self.U.as_ref()
+	}
+
+	/// returns the factor $U$, or `None` if the eigenvectors were not computed
+	pub fn try_U(&self) -> Option<MatRef<'_, T>> {
+		if self.U.ncols() == self.U.nrows() { Some(self.U.as_ref()) } else { None }
 	}
-}
 
-impl<T: ComplexField> DenseSolveCore<T> for Llt<T> {
 	#[track_caller]
-	fn reconstruct(&self) -> Mat<T> {
-		let par = get_global_parallelism();
+	fn assert_has_vectors(&self) {
+		assert!(
+			self.U.ncols() == self.U.nrows(),
+			"the eigenvectors were not computed, build the decomposition with `vectors(ComputeEigenvectors::Yes)`",
+		);
+	}
 
-		let n = self.L.nrows();
-		let mut out = Mat::zeros(n, n);
+	/// returns the factor $S$
+	pub fn S(&self) -> DiagRef<'_, T> {
+		self.S.as_ref()
+	}
 
-		let mut mem = MemBuffer::new(linalg::cholesky::llt::reconstruct::reconstruct_scratch::<T>(n, par));
-		let stack = MemStack::new(&mut mem);
+	/// returns $\operatorname{tr}(\ln A) = \sum_i \ln \lambda_i$, which is equal to
+	/// $\ln \det A$, assuming $A$ is positive definite
+	///
+	/// this matches the log-determinant computed by [`Llt::solve_and_logdet_in_place`], and can be
+	/// used when the eigendecomposition is already available
+	///
+	/// returns [`TraceLogError::NonPositiveEigenvalue`] with the index of the first eigenvalue that
+	/// is not positive
+	pub fn trace_log(&self) -> Result<T::Real, TraceLogError> {
+		let mut acc = math_utils::zero::<T::Real>();
+		for i in 0..self.S.dim() {
+			let s = math_utils::real(&self.S[i]);
+			if s <= math_utils::zero() || math_utils::is_nan(&s) {
+				return Err(TraceLogError::NonPositiveEigenvalue { index: i });
+			}
+			acc = math_utils::add(&acc, &math_utils::ln(&s));
+		}
+		Ok(acc)
+	}
 
-		linalg::cholesky::llt::reconstruct::reconstruct(out.as_mut(), self.L(), par, stack);
+	/// returns the $2$-norm condition number $\kappa_2(A) = \max_i |\lambda_i| / \min_i |\lambda_i|$,
+	/// where $A$ is the self-adjoint matrix that was used to create the decomposition
+	///
+	/// returns infinity if one of the eigenvalues is zero, and zero if $A$ is empty
+	#[math]
+	pub fn condition_number(&self) -> T::Real {
+		let S = self.S.column_vector();
+		let n = S.nrows();
+		if n == 0 {
+			return zero();
+		}
 
-		make_self_adjoint(out.as_mut());
-		out
+		let mut s_max = zero::<T::Real>();
+		let mut s_min = infinity::<T::Real>();
+		for i in 0..n {
+			let s = abs(real(S[i]));
+			s_max = max(&s_max, &s);
+			s_min = min(&s_min, &s);
+		}
+
+		if s_min == zero::<T::Real>() { infinity() } else { s_max / s_min }
 	}
 
+	/// updates the decomposition in place so that it becomes the eigendecomposition of
+	/// $A + \rho v v^H$
+	///
+	/// the new eigenvalues are computed as the roots of the secular equation of the rank-one
+	/// modification, and the new eigenvectors are obtained from them without refactoring the
+	/// matrix
+	///
+	/// # panics
+	///
+	/// panics if `v.nrows()` is not equal to the dimension of the matrix, or if the eigenvectors
+	/// were not computed
 	#[track_caller]
-	fn inverse(&self) -> Mat<T> {
-		let par = get_global_parallelism();
+	pub fn rank_one_update(&mut self, v: ColRef<'_, T>, rho: T::Real) {
+		let n = self.U.nrows();
+		assert_dim!(v.nrows(), n);
+		assert_dim!(self.U.ncols(), n);
+
+		// A + rho×v×v^H = U × (S + rho×z×z^H) × U^H, where z = U^H×v
+		// the phases of z are moved into U so that the inner problem is real
+		let z = self.U.adjoint() * v;
+		let mut z_abs = Col::<T::Real>::zeros(n);
+		for i in 0..n {
+			let abs = math_utils::abs(&z[i]);
+			if abs != math_utils::zero() {
+				let phase = math_utils::mul_real(&z[i], &math_utils::recip(&abs));
+				z!(self.U.col_mut(i)).for_each(|uz!(u)| *u = math_utils::mul(u, &phase));
+			}
+			z_abs[i] = abs;
+		}
 
-		let n = self.L.nrows();
-		let mut out = Mat::zeros(n, n);
+		let d = Col::<T::Real>::from_fn(n, |i| math_utils::real(&self.S[i]));
+		let (w, W) = linalg::evd::tridiag_evd::rank_one_update(d.as_ref(), z_abs.as_ref(), rho);
 
-		let mut mem = MemBuffer::new(linalg::cholesky::llt::inverse::inverse_scratch::<T>(n, par));
-		let stack = MemStack::new(&mut mem);
+		let W = Mat::<T>::from_fn(n, n, |i, j| math_utils::from_real(&W[(i, j)]));
+		self.U = &self.U * &W;
+		for i in 0..n {
+			self.S[i] = math_utils::from_real(&w[i]);
+		}
+	}
+}
 
-		linalg::cholesky::llt::inverse::inverse(out.as_mut(), self.L(), par, stack);
+impl<T: ComplexField> SelfAdjointEigenBuilder<T> {
+	/// sets whether the eigenvectors should be computed. if they are not, [`SelfAdjointEigen::U`]
+	/// is an empty matrix
+	pub fn vectors(self, vectors: ComputeEigenvectors) -> Self {
+		Self { vectors, ..self }
+	}
 
-		make_self_adjoint(out.as_mut());
-		out
+	/// returns the eigendecomposition of $A$, assuming it is self-adjoint
+	#[track_caller]
+	pub fn build<C: Conjugate<Canonical = T>>(self, A: MatRef<'_, C>, side: Side) -> Result<SelfAdjointEigen<T>, EvdError> {
+		SelfAdjointEigen::new_with_vectors(A, side, self.vectors)
 	}
 }
 
-impl<T: ComplexField> SolveCore<T> for Ldlt<T> {
+impl<T: ComplexField> GEigen<T> {
+	/// returns the eigendecomposition of the pencil $(A, B)$, i.e., the solutions of
+	/// $A u = \lambda B u$, assuming $A$ is self-adjoint and $B$ is self-adjoint positive definite.
+	/// only the triangular halves of $A$ and $B$ selected by `side` are accessed
+	///
+	/// with $B = L L^H$, the eigenvalues are those of $C = L^{-1} A L^{-H}$, and the eigenvectors
+	/// are $U = L^{-H} V$, where $V$ holds the eigenvectors of $C$. they are normalized so that
+	/// $U^H B U = I$
+	///
+	/// returns [`GEigenError::Llt`] if the cholesky factorization of $B$ fails
 	#[track_caller]
-	fn solve_in_place_with_conj(&self, conj: Conj, rhs: MatMut<'_, T>) {
+	pub fn new<C: Conjugate<Canonical = T>>(A: MatRef<'_, C>, B: MatRef<'_, C>, side: Side) -> Result<Self, GEigenError> {
+		assert_dim!(A.ncols(), A.nrows());
+		assert_dim!(B.nrows(), A.nrows());
+		assert_dim!(B.ncols(), A.nrows());
 		let par = get_global_parallelism();
 
-		let mut mem = MemBuffer::new(linalg::cholesky::ldlt::solve::solve_in_place_scratch::<T>(
-			self.L.nrows(),
-			rhs.ncols(),
-			par,
-		));
-		let stack = MemStack::new(&mut mem);
+		let n = A.nrows();
+		let llt = Llt::new(B, side)?;
+		let L = llt.L();
 
-		linalg::cholesky::ldlt::solve::solve_in_place_with_conj(self.L.as_ref(), self.D.as_ref(), conj, rhs, par, stack);
-	}
+		let mut C = Mat::<T>::zeros(n, n);
+		match side {
+			Side::Lower => C.copy_from_triangular_lower(A),
+			Side::Upper => C.copy_from_triangular_lower(A.adjoint()),
+		}
+		for j in 0..n {
+			for i in 0..j {
+				C[(i, j)] = math_utils::conj(&C[(j, i)]);
+			}
+		}
 
-	#[track_caller]
-	fn solve_transpose_in_place_with_conj(&self, conj: Conj, rhs: MatMut<'_, T>) {
-		let par = get_global_parallelism();
+		// C = L^-1 A L^-H = L^-1 (L^-1 A)^H, since A is self-adjoint
+		linalg::triangular_solve::solve_lower_triangular_in_place(L, C.as_mut(), par);
+		let mut C = C.adjoint().to_owned();
+		linalg::triangular_solve::solve_lower_triangular_in_place(L, C.as_mut(), par);
 
-		let mut mem = MemBuffer::new(linalg::cholesky::ldlt::solve::solve_in_place_scratch::<T>(
-			self.L.nrows(),
-			rhs.ncols(),
-			par,
-		));
-		let stack = MemStack::new(&mut mem);
+		let SelfAdjointEigen { mut U, S } = SelfAdjointEigen::new(C.as_ref(), Side::Lower)?;
+		linalg::triangular_solve::solve_upper_triangular_in_place(L.adjoint(), U.as_mut(), par);
+		let S = Col::from_fn(n, |i| math_utils::real(&S[i])).into_diagonal();
 
-		linalg::cholesky::ldlt::solve::solve_in_place_with_conj(self.L(), self.D(), conj.compose(Conj::Yes), rhs, par, stack);
+		Ok(Self { U, S })
+	}
+
+	/// returns the eigenvectors $U$, normalized so that $U^H B U = I$
+	pub fn eigenvectors(&self) -> MatRef<'_, T> {
+		self.U.as_ref()
+	}
+
+	/// returns the eigenvalues, which are real, in nondecreasing order
+	pub fn eigenvalues(&self) -> DiagRef<'_, T::Real> {
+		self.S.as_ref()
 	}
 }
 
-impl<T: ComplexField> DenseSolveCore<T> for Ldlt<T> {
+impl<T: ComplexField> PolarDecomp<T> {
+	/// returns the polar decomposition of $A$, which must have at least as many rows as columns
+	///
+	/// with the thin singular value decomposition $A = W S V^H$, the factors are $U = W V^H$ and
+	/// $P = V S V^H$. $P = (A^H A)^{1/2}$ is always unique, and so is $U$ if $A$ has full column
+	/// rank
+	///
+	/// the singular value decomposition is kept for solving systems with a square $A$. if $A$ is
+	/// singular, the solutions contain non-finite values, as with [`Svd`]
+	///
+	/// # panics
+	/// panics if $A$ has fewer rows than columns
 	#[track_caller]
-	fn reconstruct(&self) -> Mat<T> {
+	pub fn new<C: Conjugate<Canonical = T>>(A: MatRef<'_, C>) -> Result<Self, SvdError> {
+		assert!(A.nrows() >= A.ncols());
 		let par = get_global_parallelism();
 
-		let n = self.L.nrows();
-		let mut out = Mat::zeros(n, n);
+		let svd = Svd::new_thin(A)?;
+		let (W, V, S) = (svd.U(), svd.V(), svd.S());
+		let n = V.nrows();
 
-		let mut mem = MemBuffer::new(linalg::cholesky::ldlt::reconstruct::reconstruct_scratch::<T>(n, par));
-		let stack = MemStack::new(&mut mem);
+		let mut U = Mat::zeros(A.nrows(), n);
+		linalg::matmul::matmul(U.as_mut(), Accum::Replace, W, V.adjoint(), one(), par);
 
-		linalg::cholesky::ldlt::reconstruct::reconstruct(out.as_mut(), self.L(), self.D(), par, stack);
+		let VxS = Mat::from_fn(n, n, |i, j| mul_real(&V[(i, j)], &real(&S[j])));
+		let mut P = Mat::zeros(n, n);
+		linalg::matmul::matmul(P.as_mut(), Accum::Replace, VxS.as_ref(), V.adjoint(), one(), par);
 
-		make_self_adjoint(out.as_mut());
-		out
+		Ok(Self { U, P, svd })
 	}
 
-	#[track_caller]
-	fn inverse(&self) -> Mat<T> {
-		let par = get_global_parallelism();
-
-		let n = self.L.nrows();
-		let mut out = Mat::zeros(n, n);
-
-		let mut mem = MemBuffer::new(linalg::cholesky::ldlt::inverse::inverse_scratch::<T>(n, par));
-		let stack = MemStack::new(&mut mem);
+	/// returns the factor $U$
+	pub fn U(&self) -> MatRef<'_, T> {
+		self.U.as_ref()
+	}
 
-		linalg::cholesky::ldlt::inverse::inverse(out.as_mut(), self.L(), self.D(), par, stack);
+	/// returns the factor $P$
+	pub fn P(&self) -> MatRef<'_, T> {
+		self.P.as_ref()
+	}
 
-		make_self_adjoint(out.as_mut());
-		out
+	#[track_caller]
+	fn square_svd(&self) -> &Svd<T> {
+		assert!(
+			self.nrows() == self.ncols(),
+			"the polar decomposition can only solve square systems, use `Svd` or `Qr` for tall matrices",
+		);
+		&self.svd
 	}
 }
 
-impl<T: ComplexField> SolveCore<T> for Lblt<T> {
+impl<T: RealField> Eigen<T> {
+	/// returns the eigendecomposition of $A$
 	#[track_caller]
-	fn solve_in_place_with_conj(&self, conj: Conj, rhs: MatMut<'_, T>) {
-		let par = get_global_parallelism();
-
-		let mut mem = MemBuffer::new(linalg::cholesky::bunch_kaufman::solve::solve_in_place_scratch::<usize, T>(
-			self.L.nrows(),
-			rhs.ncols(),
-			par,
-		));
-		let stack = MemStack::new(&mut mem);
+	pub fn new<C: Conjugate<Canonical = Complex<T>>>(A: MatRef<'_, C>) -> Result<Self, EvdError> {
+		assert_dim!(A.ncols(), A.nrows());
+		Self::new_imp(A.canonical(), Conj::get::<C>(), ComputeEigenvectors::Yes)
+	}
 
-		linalg::cholesky::bunch_kaufman::solve::solve_in_place_with_conj(
-			self.L.as_ref(),
-			self.B_diag(),
-			self.B_subdiag(),
-			conj,
-			self.P(),
-			rhs,
-			par,
-			stack,
-		);
+	/// returns the eigendecomposition of $A$
+	#[track_caller]
+	pub fn new_from_real(A: MatRef<'_, T>) -> Result<Self, EvdError> {
+		assert_dim!(A.ncols(), A.nrows());
+		Self::new_from_real_imp(A, ComputeEigenvectors::Yes)
 	}
 
+	/// returns the eigenvalues of $A$, without computing the eigenvectors
+	///
+	/// [`Eigen::try_U`] returns `None` for the resulting decomposition
 	#[track_caller]
-	fn solve_transpose_in_place_with_conj(&self, conj: Conj, rhs: MatMut<'_, T>) {
-		let par = get_global_parallelism();
+	pub fn new_values_only<C: Conjugate<Canonical = Complex<T>>>(A: MatRef<'_, C>) -> Result<Self, EvdError> {
+		assert_dim!(A.ncols(), A.nrows());
+		Self::new_imp(A.canonical(), Conj::get::<C>(), ComputeEigenvectors::No)
+	}
 
-		let mut mem = MemBuffer::new(linalg::cholesky::bunch_kaufman::solve::solve_in_place_scratch::<usize, T>(
-			self.L.nrows(),
-			rhs.ncols(),
-			par,
-		));
-		let stack = MemStack::new(&mut mem);
-
-		linalg::cholesky::bunch_kaufman::solve::solve_in_place_with_conj(
-			self.L(),
-			self.B_diag(),
-			self.B_subdiag(),
-			conj.compose(Conj::Yes),
-			self.P(),
-			rhs,
-			par,
-			stack,
-		);
+	/// returns the eigenvalues of $A$, without computing the eigenvectors
+	///
+	/// [`Eigen::try_U`] returns `None` for the resulting decomposition
+	#[track_caller]
+	pub fn new_values_only_from_real(A: MatRef<'_, T>) -> Result<Self, EvdError> {
+		assert_dim!(A.ncols(), A.nrows());
+		Self::new_from_real_imp(A, ComputeEigenvectors::No)
 	}
-}
 
-impl<T: ComplexField> DenseSolveCore<T> for Lblt<T> {
-	#[track_caller]
-	fn reconstruct(&self) -> Mat<T> {
+	fn new_from_real_imp(A: MatRef<'_, T>, vectors: ComputeEigenvectors) -> Result<Self, EvdError> {
 		let par = get_global_parallelism();
 
-		let n = self.L.nrows();
-		let mut out = Mat::zeros(n, n);
+		let n = A.nrows();
+		let ncols = if vectors == ComputeEigenvectors::Yes { n } else { 0 };
 
-		let mut mem = MemBuffer::new(linalg::cholesky::bunch_kaufman::reconstruct::reconstruct_scratch::<usize, T>(n, par));
-		let stack = MemStack::new(&mut mem);
+		let mut U_real = Mat::zeros(n, ncols);
+		let mut S_re = Diag::zeros(n);
+		let mut S_im = Diag::zeros(n);
 
-		linalg::cholesky::bunch_kaufman::reconstruct::reconstruct(out.as_mut(), self.L(), self.B_diag(), self.B_subdiag(), self.P(), par, stack);
+		linalg::evd::evd_real(
+			A,
+			S_re.as_mut(),
+			S_im.as_mut(),
+			None,
+			if vectors == ComputeEigenvectors::Yes {
+				Some(U_real.as_mut())
+			} else {
+				None
+			},
+			par,
+			MemStack::new(&mut MemBuffer::new(linalg::evd::evd_scratch::<T>(
+				n,
+				linalg::evd::ComputeEigenvectors::No,
+				vectors,
+				par,
+				default(),
+			))),
+			default(),
+		)?;
 
-		make_self_adjoint(out.as_mut());
-		out
-	}
+		let mut U = Mat::zeros(n, ncols);
+		let mut S = Diag::zeros(n);
 
-	#[track_caller]
-	fn inverse(&self) -> Mat<T> {
-		let par = get_global_parallelism();
+		let mut j = 0;
+		while j < n {
+			if S_im[j] == zero() {
+				S[j] = Complex::new(S_re[j].clone(), zero());
 
-		let n = self.L.nrows();
-		let mut out = Mat::zeros(n, n);
+				if vectors == ComputeEigenvectors::Yes {
+					for i in 0..n {
+						U[(i, j)] = Complex::new(U_real[(i, j)].clone(), zero());
+					}
+				}
 
-		let mut mem = MemBuffer::new(linalg::cholesky::bunch_kaufman::inverse::inverse_scratch::<usize, T>(n, par));
-		let stack = MemStack::new(&mut mem);
+				j += 1;
+			} else {
+				S[j] = Complex::new(S_re[j].clone(), S_im[j].clone());
+				S[j + 1] = Complex::new(S_re[j].clone(), neg(&S_im[j]));
 
-		linalg::cholesky::bunch_kaufman::inverse::inverse(out.as_mut(), self.L(), self.B_diag(), self.B_subdiag(), self.P(), par, stack);
+				if vectors == ComputeEigenvectors::Yes {
+					for i in 0..n {
+						U[(i, j)] = Complex::new(U_real[(i, j)].clone(), U_real[(i, j + 1)].clone());
+						U[(i, j + 1)] = Complex::new(U_real[(i, j)].clone(), neg(&U_real[(i, j + 1)]));
+					}
+				}
 
-		make_self_adjoint(out.as_mut());
-		out
+				j += 2;
+			}
+		}
+
+		Ok(Self { U, S })
 	}
-}
 
-impl<T: ComplexField> SolveCore<T> for PartialPivLu<T> {
-	#[track_caller]
-	fn solve_in_place_with_conj(&self, conj: Conj, rhs: MatMut<'_, T>) {
+	fn new_imp(A: MatRef<'_, Complex<T>>, conj: Conj, vectors: ComputeEigenvectors) -> Result<Self, EvdError> {
 		let par = get_global_parallelism();
 
-		assert!(all(self.nrows() == self.ncols(), self.nrows() == rhs.nrows(),));
+		let n = A.nrows();
 
-		let k = rhs.ncols();
+		let mut U = Mat::zeros(n, if vectors == ComputeEigenvectors::Yes { n } else { 0 });
+		let mut S = Diag::zeros(n);
 
-		linalg::lu::partial_pivoting::solve::solve_in_place_with_conj(
-			self.L(),
-			self.U(),
-			self.P(),
-			conj,
-			rhs,
+		linalg::evd::evd_cplx(
+			A,
+			S.as_mut(),
+			None,
+			if vectors == ComputeEigenvectors::Yes { Some(U.as_mut()) } else { None },
 			par,
-			MemStack::new(&mut MemBuffer::new(
-				linalg::lu::partial_pivoting::solve::solve_in_place_scratch::<usize, T>(self.nrows(), k, par),
-			)),
-		);
+			MemStack::new(&mut MemBuffer::new(linalg::evd::evd_scratch::<Complex<T>>(
+				n,
+				linalg::evd::ComputeEigenvectors::No,
+				vectors,
+				par,
+				default(),
+			))),
+			default(),
+		)?;
+
+		if conj == Conj::Yes {
+			for c in U.col_iter_mut() {
+				for x in c.iter_mut() {
+					*x = math_utils::conj(x);
+				}
+			}
+		}
+
+		Ok(Self { U, S })
 	}
 
-	#[track_caller]
-	fn solve_transpose_in_place_with_conj(&self, conj: Conj, rhs: MatMut<'_, T>) {
-		let par = get_global_parallelism();
+	/// returns the factor $U$
+	///
+	/// if the eigenvectors were not computed, this is an empty matrix
+	pub fn U(&self) -> MatRef<'_, Complex<T>> {
+		self.U.as_ref()
+	}
 
-		assert!(all(self.nrows() == self.ncols(), self.ncols() == rhs.nrows(),));
+	/// returns the factor $U$, or `None` if the eigenvectors were not computed
+	pub fn try_U(&self) -> Option<MatRef<'_, Complex<T>>> {
+		if self.U.ncols() == self.U.nrows() { Some(self.U.as_ref()) } else { None }
+	}
 
-		let k = rhs.ncols();
+	/// returns the factor $S$
+	pub fn S(&self) -> DiagRef<'_, Complex<T>> {
+		self.S.as_ref()
+	}
 
-		linalg::lu::partial_pivoting::solve::solve_transpose_in_place_with_conj(
-			self.L(),
-			self.U(),
-			self.P(),
-			conj,
-			rhs,
-			par,
-			MemStack::new(&mut MemBuffer::new(
-				linalg::lu::partial_pivoting::solve::solve_transpose_in_place_scratch::<usize, T>(self.nrows(), k, par),
-			)),
-		);
+	/// reconstructs the original matrix $A = U S U^{-1}$ from its eigendecomposition
+	///
+	/// returns `None` if $U$ is numerically singular, which happens when $A$ is defective, or if the
+	/// eigenvectors were not computed
+	#[math]
+	pub fn reconstruct(&self) -> Option<Mat<Complex<T>>> {
+		let U = self.try_U()?;
+		let S = self.S().column_vector();
+		let n = U.nrows();
+
+		let lu = PartialPivLu::new(U);
+
+		let mut max = zero::<T>();
+		let mut min = infinity::<T>();
+		for x in lu.U().diagonal().column_vector().iter() {
+			let x = abs(*x);
+			max = math_utils::max(&max, &x);
+			min = math_utils::min(&min, &x);
+		}
+		if is_nan(min) || min <= from_f64::<T>(n as f64) * eps::<T>() * max {
+			return None;
+		}
+
+		let US = Mat::from_fn(n, n, |i, j| U[(i, j)] * S[j]);
+		Some(lu.rsolve(US))
 	}
 }
 
-impl<T: ComplexField> DenseSolveCore<T> for PartialPivLu<T> {
-	fn reconstruct(&self) -> Mat<T> {
+impl<T: RealField> Schur<T> {
+	/// returns the complex schur decomposition $A = Q T Q^H$ of $A$, where $Q$ is unitary and $T$
+	/// is upper triangular
+	#[track_caller]
+	pub fn new<C: Conjugate<Canonical = Complex<T>>>(A: MatRef<'_, C>) -> Result<Self, EvdError> {
+		assert_dim!(A.ncols(), A.nrows());
+		Self::new_imp(A.to_owned())
+	}
+
+	/// returns the complex schur decomposition $A = Q T Q^H$ of $A$, where $Q$ is unitary and $T$
+	/// is upper triangular
+	#[track_caller]
+	pub fn new_from_real(A: MatRef<'_, T>) -> Result<Self, EvdError> {
+		assert_dim!(A.ncols(), A.nrows());
+		Self::new_imp(Mat::from_fn(A.nrows(), A.ncols(), |i, j| Complex::new(A[(i, j)].clone(), zero())))
+	}
+
+	fn new_imp(mut H: Mat<Complex<T>>) -> Result<Self, EvdError> {
 		let par = get_global_parallelism();
-		let m = self.nrows();
-		let n = self.ncols();
 
-		let mut out = Mat::zeros(m, n);
+		let n = H.nrows();
+		let mut Q = Mat::<Complex<T>>::identity(n, n);
 
-		linalg::lu::partial_pivoting::reconstruct::reconstruct(
-			out.as_mut(),
-			self.L(),
-			self.U(),
-			self.P(),
+		let mut w = Col::<Complex<T>>::zeros(n);
+		let params: linalg::evd::EvdParams = auto!(Complex<T>);
+		let info = linalg::evd::schur_in_place(
+			H.as_mut(),
+			Some(Q.as_mut()),
+			w.as_mut(),
+			None,
 			par,
-			MemStack::new(&mut MemBuffer::new(linalg::lu::partial_pivoting::reconstruct::reconstruct_scratch::<
-				usize,
-				T,
-			>(m, n, par))),
-		);
+			MemStack::new(&mut MemBuffer::new(linalg::evd::schur_in_place_scratch::<Complex<T>>(
+				n, true, par, params,
+			))),
+			params,
+		)?;
+		if info != 0 {
+			return Err(EvdError::NoConvergence);
+		}
 
-		out
+		Ok(Self { Q, T: H })
+	}
+
+	/// returns the unitary factor $Q$
+	pub fn Q(&self) -> MatRef<'_, Complex<T>> {
+		self.Q.as_ref()
+	}
+
+	/// returns the upper triangular factor $T$
+	pub fn T(&self) -> MatRef<'_, Complex<T>> {
+		self.T.as_ref()
 	}
 
+	/// computes the matrix function $f(A)$ with the schur-parlett algorithm
+	///
+	/// $f$ is evaluated on the triangular factor $T$ with the block parlett recurrence, and the
+	/// result is transformed back with $Q$. eigenvalues closer than $0.1$ are grouped in the same
+	/// block, and $f$ is evaluated on each such block through its cauchy integral over a circle
+	/// enclosing the block's eigenvalues, with the trapezoidal rule. the number of quadrature nodes
+	/// is doubled until the result is accurate to about machine precision. unlike the
+	/// eigendecomposition based approach, this works for defective matrices
+	///
+	/// $f$ must be analytic on a neighborhood of the spectrum of $A$ that contains these circles
+	///
+	/// # errors
+	/// returns an error if the clusters can't be reordered into contiguous blocks, or if the
+	/// quadrature doesn't converge, e.g., because $f$ has a singularity close to a circle
+	pub fn apply_fn(&self, f: impl Fn(Complex<T>) -> Complex<T>) -> Result<Mat<Complex<T>>, EvdError> {
+		linalg::matfun::schur_parlett(self.Q(), self.T(), f)
+	}
+
+	/// returns the eigenvalues of $A$, read from the diagonal of $T$
+	pub fn eigenvalues(&self) -> Vec<Complex<T>> {
+		self.T.diagonal().column_vector().iter().cloned().collect()
+	}
+}
+
+impl<T: RealField> RealSchur<T> {
+	/// returns the real schur decomposition $A = Q T Q^\top$ of $A$, where $Q$ is orthogonal and
+	/// $T$ is quasi-upper triangular, with $1 \times 1$ diagonal blocks for the real eigenvalues
+	/// and $2 \times 2$ diagonal blocks for the pairs of complex conjugate eigenvalues
 	#[track_caller]
-	fn inverse(&self) -> Mat<T> {
+	pub fn new(A: MatRef<'_, T>) -> Result<Self, EvdError> {
+		assert_dim!(A.ncols(), A.nrows());
+
 		let par = get_global_parallelism();
 
-		assert!(self.nrows() == self.ncols());
+		let n = A.nrows();
+		let mut H = A.to_owned();
+		let mut Q = Mat::<T>::identity(n, n);
+
+		let mut w_re = Col::<T>::zeros(n);
+		let mut w_im = Col::<T>::zeros(n);
+		let params: linalg::evd::EvdParams = auto!(T);
+		let info = linalg::evd::schur_in_place(
+			H.as_mut(),
+			Some(Q.as_mut()),
+			w_re.as_mut(),
+			Some(w_im.as_mut()),
+			par,
+			MemStack::new(&mut MemBuffer::new(linalg::evd::schur_in_place_scratch::<T>(n, true, par, params))),
+			params,
+		)?;
+		if info != 0 {
+			return Err(EvdError::NoConvergence);
+		}
 
-		let n = self.ncols();
+		Ok(Self { Q, T: H })
+	}
 
-		let mut out = Mat::zeros(n, n);
+	/// returns the orthogonal factor $Q$
+	pub fn Q(&self) -> MatRef<'_, T> {
+		self.Q.as_ref()
+	}
 
-		linalg::lu::partial_pivoting::inverse::inverse(
-			out.as_mut(),
-			self.L(),
-			self.U(),
-			self.P(),
-			par,
-			MemStack::new(&mut MemBuffer::new(linalg::lu::partial_pivoting::inverse::inverse_scratch::<usize, T>(
-				n, par,
-			))),
-		);
+	/// returns the quasi-upper triangular factor $T$
+	pub fn T(&self) -> MatRef<'_, T> {
+		self.T.as_ref()
+	}
 
+	/// returns the eigenvalues of $A$, read from the diagonal blocks of $T$
+	///
+	/// the eigenvalues of a $2 \times 2$ block are returned as a complex conjugate pair, with the
+	/// one with positive imaginary part first
+	#[math]
+	pub fn eigenvalues(&self) -> Vec<Complex<T>> {
+		let T = self.T();
+		let n = T.nrows();
+
+		let mut out = Vec::with_capacity(n);
+		let mut i = 0;
+		while i < n {
+			if i + 1 < n && T[(i + 1, i)] != zero() {
+				let (a, b, c, d) = (copy(T[(i, i)]), copy(T[(i, i + 1)]), copy(T[(i + 1, i)]), copy(T[(i + 1, i + 1)]));
+				let half = from_f64::<T>(0.5);
+				let re = half * (a + d);
+				let p = half * (a - d);
+				// the discriminant of a block that wasn't split is negative
+				let im = sqrt(abs(p * p + b * c));
+				out.push(Complex::new(copy(re), copy(im)));
+				out.push(Complex::new(re, -im));
+				i += 2;
+			} else {
+				out.push(Complex::new(copy(T[(i, i)]), zero()));
+				i += 1;
+			}
+		}
 		out
 	}
 }
 
-impl<T: ComplexField> SolveCore<T> for FullPivLu<T> {
+impl<T: ComplexField> BlockTridiagonal<T> {
+	/// returns the block $LU$ decomposition of the block tridiagonal matrix with diagonal blocks
+	/// `diag`, sub-diagonal blocks `subdiag` and super-diagonal blocks `superdiag`, computed with
+	/// the block thomas algorithm
+	///
+	/// `subdiag[i]` is the block at position $(i + 1, i)$ and `superdiag[i]` is the block at
+	/// position $(i, i + 1)$
 	#[track_caller]
-	fn solve_in_place_with_conj(&self, conj: Conj, rhs: MatMut<'_, T>) {
+	#[math]
+	pub fn new(diag: &[Mat<T>], subdiag: &[Mat<T>], superdiag: &[Mat<T>]) -> Self {
 		let par = get_global_parallelism();
 
-		assert!(all(self.nrows() == self.ncols(), self.nrows() == rhs.nrows(),));
+		let nblocks = diag.len();
+		assert_dim!(subdiag.len(), nblocks.saturating_sub(1));
+		assert_dim!(superdiag.len(), nblocks.saturating_sub(1));
+
+		let mut offsets = Vec::with_capacity(nblocks + 1);
+		offsets.push(0usize);
+		for (i, D) in diag.iter().enumerate() {
+			assert_dim!(D.ncols(), D.nrows());
+			if i > 0 {
+				let n = diag[i - 1].nrows();
+				assert_dim!(subdiag[i - 1].nrows(), D.nrows());
+				assert_dim!(subdiag[i - 1].ncols(), n);
+				assert_dim!(superdiag[i - 1].nrows(), n);
+				assert_dim!(superdiag[i - 1].ncols(), D.nrows());
+			}
+			offsets.push(offsets[i] + D.nrows());
+		}
 
-		let k = rhs.ncols();
+		let mut S = Vec::<PartialPivLu<T>>::with_capacity(nblocks);
+		for i in 0..nblocks {
+			// S_i = D_i - L_i S_{i-1}^{-1} U_{i-1}
+			let mut Si = diag[i].clone();
+			if i > 0 {
+				let X = S[i - 1].solve(&superdiag[i - 1]);
+				linalg::matmul::matmul(Si.as_mut(), Accum::Add, &subdiag[i - 1], &X, -one::<T>(), par);
+			}
+			S.push(PartialPivLu::new(Si.as_ref()));
+		}
 
-		linalg::lu::full_pivoting::solve::solve_in_place_with_conj(
-			self.L(),
-			self.U(),
-			self.P(),
-			self.Q(),
-			conj,
-			rhs,
-			par,
-			MemStack::new(&mut MemBuffer::new(linalg::lu::full_pivoting::solve::solve_in_place_scratch::<usize, T>(
-				self.nrows(),
-				k,
-				par,
-			))),
-		);
+		Self {
+			S,
+			subdiag: subdiag.to_vec(),
+			superdiag: superdiag.to_vec(),
+			offsets,
+		}
 	}
 
-	#[track_caller]
-	fn solve_transpose_in_place_with_conj(&self, conj: Conj, rhs: MatMut<'_, T>) {
-		let par = get_global_parallelism();
-
-		assert!(all(self.nrows() == self.ncols(), self.ncols() == rhs.nrows(),));
-
-		let k = rhs.ncols();
+	/// returns the number of diagonal blocks
+	pub fn nblocks(&self) -> usize {
+		self.S.len()
+	}
 
-		linalg::lu::full_pivoting::solve::solve_transpose_in_place_with_conj(
-			self.L(),
-			self.U(),
-			self.P(),
-			self.Q(),
-			conj,
-			rhs,
-			par,
-			MemStack::new(&mut MemBuffer::new(linalg::lu::full_pivoting::solve::solve_transpose_in_place_scratch::<
-				usize,
-				T,
-			>(self.nrows(), k, par))),
-		);
+	/// returns the $LU$ decomposition of the `i`-th schur complement block
+	pub fn S(&self, i: usize) -> &PartialPivLu<T> {
+		&self.S[i]
 	}
 }
 
-impl<T: ComplexField> DenseSolveCore<T> for FullPivLu<T> {
-	fn reconstruct(&self) -> Mat<T> {
+impl<T: ComplexField> ShapeCore for Llt<T> {
+	#[inline]
+	fn nrows(&self) -> usize {
+		self.L().nrows()
+	}
+
+	#[inline]
+	fn ncols(&self) -> usize {
+		self.L().ncols()
+	}
+}
+impl<T: ComplexField> ShapeCore for LltRef<'_, T> {
+	#[inline]
+	fn nrows(&self) -> usize {
+		self.L.nrows()
+	}
+
+	#[inline]
+	fn ncols(&self) -> usize {
+		self.L.ncols()
+	}
+}
+impl<T: ComplexField> ShapeCore for Ldlt<T> {
+	#[inline]
+	fn nrows(&self) -> usize {
+		self.L().nrows()
+	}
+
+	#[inline]
+	fn ncols(&self) -> usize {
+		self.L().ncols()
+	}
+}
+impl<T: ComplexField> ShapeCore for Lblt<T> {
+	#[inline]
+	fn nrows(&self) -> usize {
+		self.L().nrows()
+	}
+
+	#[inline]
+	fn ncols(&self) -> usize {
+		self.L().ncols()
+	}
+}
+impl<T: ComplexField> ShapeCore for PartialPivLu<T> {
+	#[inline]
+	fn nrows(&self) -> usize {
+		self.L().nrows()
+	}
+
+	#[inline]
+	fn ncols(&self) -> usize {
+		self.U().ncols()
+	}
+}
+impl<T: ComplexField> ShapeCore for FullPivLu<T> {
+	#[inline]
+	fn nrows(&self) -> usize {
+		self.L().nrows()
+	}
+
+	#[inline]
+	fn ncols(&self) -> usize {
+		self.U().ncols()
+	}
+}
+impl<T: ComplexField> ShapeCore for Qr<T> {
+	#[inline]
+	fn nrows(&self) -> usize {
+		self.Q_basis().nrows()
+	}
+
+	#[inline]
+	fn ncols(&self) -> usize {
+		self.R().ncols()
+	}
+}
+impl<T: ComplexField> ShapeCore for ColPivQr<T> {
+	#[inline]
+	fn nrows(&self) -> usize {
+		self.Q_basis().nrows()
+	}
+
+	#[inline]
+	fn ncols(&self) -> usize {
+		self.R().ncols()
+	}
+}
+impl<T: ComplexField> ShapeCore for CompOrtho<T> {
+	#[inline]
+	fn nrows(&self) -> usize {
+		self.Q_basis().nrows()
+	}
+
+	#[inline]
+	fn ncols(&self) -> usize {
+		self.P().len()
+	}
+}
+impl<T: ComplexField> ShapeCore for Svd<T> {
+	#[inline]
+	fn nrows(&self) -> usize {
+		self.U().nrows()
+	}
+
+	#[inline]
+	fn ncols(&self) -> usize {
+		self.V().nrows()
+	}
+}
+impl<T: ComplexField> ShapeCore for SelfAdjointEigen<T> {
+	#[inline]
+	fn nrows(&self) -> usize {
+		self.U().nrows()
+	}
+
+	#[inline]
+	fn ncols(&self) -> usize {
+		self.U().nrows()
+	}
+}
+impl<T: RealField> ShapeCore for Eigen<T> {
+	#[inline]
+	fn nrows(&self) -> usize {
+		self.U().nrows()
+	}
+
+	#[inline]
+	fn ncols(&self) -> usize {
+		self.U().nrows()
+	}
+}
+impl<T: RealField> ShapeCore for Schur<T> {
+	#[inline]
+	fn nrows(&self) -> usize {
+		self.Q().nrows()
+	}
+
+	#[inline]
+	fn ncols(&self) -> usize {
+		self.Q().nrows()
+	}
+}
+impl<T: RealField> ShapeCore for RealSchur<T> {
+	#[inline]
+	fn nrows(&self) -> usize {
+		self.Q().nrows()
+	}
+
+	#[inline]
+	fn ncols(&self) -> usize {
+		self.Q().nrows()
+	}
+}
+impl<T: ComplexField> ShapeCore for BlockTridiagonal<T> {
+	#[inline]
+	fn nrows(&self) -> usize {
+		self.offsets[self.offsets.len() - 1]
+	}
+
+	#[inline]
+	fn ncols(&self) -> usize {
+		self.nrows()
+	}
+}
+
+impl<T: ComplexField> SolveCore<T> for Llt<T> {
+	#[track_caller]
+	fn solve_in_place_with_conj(&self, conj: Conj, rhs: MatMut<'_, T>) {
 		let par = get_global_parallelism();
-		let m = self.nrows();
-		let n = self.ncols();
 
-		let mut out = Mat::zeros(m, n);
+		let mut mem = MemBuffer::new(linalg::cholesky::llt::solve::solve_in_place_scratch::<T>(
+			self.L.nrows(),
+			rhs.ncols(),
+			par,
+		));
+		let stack = MemStack::new(&mut mem);
 
-		linalg::lu::full_pivoting::reconstruct::reconstruct(
-			out.as_mut(),
-			self.L(),
-			self.U(),
-			self.P(),
-			self.Q(),
+		linalg::cholesky::llt::solve::solve_in_place_with_conj(self.L.as_ref(), conj, rhs, par, stack);
+	}
+
+	#[track_caller]
+	fn solve_transpose_in_place_with_conj(&self, conj: Conj, rhs: MatMut<'_, T>) {
+		let par = get_global_parallelism();
+
+		let mut mem = MemBuffer::new(linalg::cholesky::llt::solve::solve_in_place_scratch::<T>(
+			self.L.nrows(),
+			rhs.ncols(),
 			par,
-			MemStack::new(&mut MemBuffer::new(
-				linalg::lu::full_pivoting::reconstruct::reconstruct_scratch::<usize, T>(m, n, par),
-			)),
-		);
+		));
+		let stack = MemStack::new(&mut mem);
+
+		linalg::cholesky::llt::solve::solve_in_place_with_conj(self.L.as_ref(), conj.compose(Conj::Yes), rhs, par, stack);
+	}
+}
+
+impl<T: ComplexField> SolveCore<T> for LltRef<'_, T> {
+	#[track_caller]
+	fn solve_in_place_with_conj(&self, conj: Conj, rhs: MatMut<'_, T>) {
+		let par = get_global_parallelism();
+
+		let mut mem = MemBuffer::new(linalg::cholesky::llt::solve::solve_in_place_scratch::<T>(
+			self.L.nrows(),
+			rhs.ncols(),
+			par,
+		));
+		let stack = MemStack::new(&mut mem);
+
+		linalg::cholesky::llt::solve::solve_in_place_with_conj(self.L, conj.compose(self.conj), rhs, par, stack);
+	}
+
+	#[track_caller]
+	fn solve_transpose_in_place_with_conj(&self, conj: Conj, rhs: MatMut<'_, T>) {
+		let par = get_global_parallelism();
+
+		let mut mem = MemBuffer::new(linalg::cholesky::llt::solve::solve_in_place_scratch::<T>(
+			self.L.nrows(),
+			rhs.ncols(),
+			par,
+		));
+		let stack = MemStack::new(&mut mem);
+
+		linalg::cholesky::llt::solve::solve_in_place_with_conj(self.L, conj.compose(self.conj).compose(Conj::Yes), rhs, par, stack);
+	}
+}
+
+/// returns `true` if the permutation is a product of an odd number of transpositions
+fn perm_is_odd(P: PermRef<'_, usize>) -> bool {
+	let fwd = P.arrays().0;
+	let n = fwd.len();
+	let mut visited = vec![false; n];
+
+	let mut cycles = 0usize;
+	for i in 0..n {
+		if !visited[i] {
+			cycles += 1;
+			let mut j = i;
+			while !visited[j] {
+				visited[j] = true;
+				j = fwd[j];
+			}
+		}
+	}
+	(n - cycles) % 2 == 1
+}
+
+#[track_caller]
+#[math]
+fn lu_det_imp<T: ComplexField>(U: MatRef<'_, T>, odd: bool) -> T {
+	assert_dim!(U.ncols(), U.nrows());
+
+	let mut det = one::<T>();
+	for i in 0..U.nrows() {
+		det = det * U[(i, i)];
+	}
+	if odd { -det } else { det }
+}
+
+#[track_caller]
+#[math]
+fn lu_log_abs_det_imp<T: ComplexField>(U: MatRef<'_, T>, odd: bool) -> (T::Real, T) {
+	assert_dim!(U.ncols(), U.nrows());
+
+	let mut log_abs_det = zero::<T::Real>();
+	let mut phase = if odd { -one::<T>() } else { one::<T>() };
+	for i in 0..U.nrows() {
+		let u = copy(U[(i, i)]);
+		let abs_u = abs(u);
+		if abs_u == zero::<T::Real>() {
+			return (-infinity::<T::Real>(), zero());
+		}
+		log_abs_det = log_abs_det + ln(&abs_u);
+		phase = phase * mul_real(u, recip(abs_u));
+	}
+	// renormalize to undo the rounding drift accumulated by the product
+	let abs_phase = abs(phase);
+	(log_abs_det, mul_real(phase, recip(abs_phase)))
+}
+
+/// computes the minimum norm solution of $A^\top x = b$ from the $QR$ factors of a tall $A$, where
+/// the top rows of `out` already hold $b$
+///
+/// with $A = Q R$, a solution is $\bar Q \begin{bmatrix} z \\ 0 \end{bmatrix}$ where $R^\top z = b$
+#[track_caller]
+fn qr_solve_transpose_min_norm_imp<T: ComplexField>(Q_basis: MatRef<'_, T>, Q_coeff: MatRef<'_, T>, thin_R: MatRef<'_, T>, out: MatMut<'_, T>) {
+	let par = get_global_parallelism();
+	let m = out.nrows();
+	let n = thin_R.ncols();
+	let k = out.ncols();
+	let mut out = out;
+
+	linalg::triangular_solve::solve_lower_triangular_in_place(thin_R.transpose(), out.rb_mut().get_mut(..n, ..), par);
+	out.rb_mut().get_mut(n.., ..).fill(zero());
+
+	linalg::householder::apply_block_householder_sequence_on_the_left_in_place_with_conj(
+		Q_basis,
+		Q_coeff,
+		Conj::Yes,
+		out,
+		par,
+		MemStack::new(&mut MemBuffer::new(
+			linalg::householder::apply_block_householder_sequence_on_the_left_in_place_scratch::<T>(m, Q_coeff.nrows(), k),
+		)),
+	);
+}
+
+/// solves for one column of $B$ at a time, keeping only the diagonal element of each solution
+#[track_caller]
+#[math]
+fn trace_solve_imp<T: ComplexField>(solver: &impl SolveCore<T>, B: MatRef<'_, T>) -> T {
+	let n = solver.nrows();
+	assert_dim!(solver.ncols(), n);
+	assert_dim!(B.nrows(), n);
+	assert_dim!(B.ncols(), n);
+
+	let mut x = Col::<T>::zeros(n);
+	let mut trace = zero::<T>();
+	for j in 0..n {
+		x.copy_from(B.col(j));
+		solver.solve_in_place_with_conj(Conj::No, x.as_mat_mut());
+		trace = trace + x[j];
+	}
+	trace
+}
+
+#[math]
+fn make_self_adjoint<T: ComplexField>(mut A: MatMut<'_, T>) {
+	assert_dim!(A.ncols(), A.nrows());
+	let n = A.nrows();
+	for j in 0..n {
+		A[(j, j)] = from_real(real(A[(j, j)]));
+		for i in 0..j {
+			A[(i, j)] = conj(A[(j, i)]);
+		}
+	}
+}
+
+impl<T: ComplexField> DenseSolveCore<T> for Llt<T> {
+	#[track_caller]
+	fn reconstruct(&self) -> Mat<T> {
+		let par = get_global_parallelism();
+
+		let n = self.L.nrows();
+		let mut out = Mat::zeros(n, n);
+
+		let mut mem = MemBuffer::new(linalg::cholesky::llt::reconstruct::reconstruct_scratch::<T>(n, par));
+		let stack = MemStack::new(&mut mem);
+
+		linalg::cholesky::llt::reconstruct::reconstruct(out.as_mut(), self.L(), par, stack);
+
+		make_self_adjoint(out.as_mut());
+		out
+	}
+
+	#[track_caller]
+	fn inverse(&self) -> Mat<T> {
+		let par = get_global_parallelism();
+
+		let n = self.L.nrows();
+		let mut out = Mat::zeros(n, n);
+
+		let mut mem = MemBuffer::new(linalg::cholesky::llt::inverse::inverse_scratch::<T>(n, par));
+		let stack = MemStack::new(&mut mem);
+
+		linalg::cholesky::llt::inverse::inverse(out.as_mut(), self.L(), par, stack);
+
+		make_self_adjoint(out.as_mut());
+		out
+	}
+}
+
+impl<T: ComplexField> SolveCore<T> for Ldlt<T> {
+	#[track_caller]
+	fn solve_in_place_with_conj(&self, conj: Conj, rhs: MatMut<'_, T>) {
+		let par = get_global_parallelism();
+
+		let mut mem = MemBuffer::new(linalg::cholesky::ldlt::solve::solve_in_place_scratch::<T>(
+			self.L.nrows(),
+			rhs.ncols(),
+			par,
+		));
+		let stack = MemStack::new(&mut mem);
+
+		linalg::cholesky::ldlt::solve::solve_in_place_with_conj(self.L.as_ref(), self.D.as_ref(), conj, rhs, par, stack);
+	}
+
+	#[track_caller]
+	fn solve_transpose_in_place_with_conj(&self, conj: Conj, rhs: MatMut<'_, T>) {
+		let par = get_global_parallelism();
+
+		let mut mem = MemBuffer::new(linalg::cholesky::ldlt::solve::solve_in_place_scratch::<T>(
+			self.L.nrows(),
+			rhs.ncols(),
+			par,
+		));
+		let stack = MemStack::new(&mut mem);
+
+		linalg::cholesky::ldlt::solve::solve_in_place_with_conj(self.L(), self.D(), conj.compose(Conj::Yes), rhs, par, stack);
+	}
+}
+
+impl<T: ComplexField> DenseSolveCore<T> for Ldlt<T> {
+	#[track_caller]
+	fn reconstruct(&self) -> Mat<T> {
+		let par = get_global_parallelism();
+
+		let n = self.L.nrows();
+		let mut out = Mat::zeros(n, n);
+
+		let mut mem = MemBuffer::new(linalg::cholesky::ldlt::reconstruct::reconstruct_scratch::<T>(n, par));
+		let stack = MemStack::new(&mut mem);
+
+		linalg::cholesky::ldlt::reconstruct::reconstruct(out.as_mut(), self.L(), self.D(), par, stack);
+
+		make_self_adjoint(out.as_mut());
+		out
+	}
+
+	#[track_caller]
+	fn inverse(&self) -> Mat<T> {
+		let par = get_global_parallelism();
+
+		let n = self.L.nrows();
+		let mut out = Mat::zeros(n, n);
+
+		let mut mem = MemBuffer::new(linalg::cholesky::ldlt::inverse::inverse_scratch::<T>(n, par));
+		let stack = MemStack::new(&mut mem);
+
+		linalg::cholesky::ldlt::inverse::inverse(out.as_mut(), self.L(), self.D(), par, stack);
+
+		make_self_adjoint(out.as_mut());
+		out
+	}
+}
+
+impl<T: ComplexField> SolveCore<T> for Lblt<T> {
+	#[track_caller]
+	fn solve_in_place_with_conj(&self, conj: Conj, rhs: MatMut<'_, T>) {
+		let par = get_global_parallelism();
+
+		let mut mem = MemBuffer::new(linalg::cholesky::bunch_kaufman::solve::solve_in_place_scratch::<usize, T>(
+			self.L.nrows(),
+			rhs.ncols(),
+			par,
+		));
+		let stack = MemStack::new(&mut mem);
+
+		linalg::cholesky::bunch_kaufman::solve::solve_in_place_with_conj(
+			self.L.as_ref(),
+			self.B_diag(),
+			self.B_subdiag(),
+			conj,
+			self.P(),
+			rhs,
+			par,
+			stack,
+		);
+	}
+
+	#[track_caller]
+	fn solve_transpose_in_place_with_conj(&self, conj: Conj, rhs: MatMut<'_, T>) {
+		let par = get_global_parallelism();
+
+		let mut mem = MemBuffer::new(linalg::cholesky::bunch_kaufman::solve::solve_in_place_scratch::<usize, T>(
+			self.L.nrows(),
+			rhs.ncols(),
+			par,
+		));
+		let stack = MemStack::new(&mut mem);
+
+		linalg::cholesky::bunch_kaufman::solve::solve_in_place_with_conj(
+			self.L(),
+			self.B_diag(),
+			self.B_subdiag(),
+			conj.compose(Conj::Yes),
+			self.P(),
+			rhs,
+			par,
+			stack,
+		);
+	}
+}
+
+impl<T: ComplexField> DenseSolveCore<T> for Lblt<T> {
+	#[track_caller]
+	fn reconstruct(&self) -> Mat<T> {
+		let par = get_global_parallelism();
+
+		let n = self.L.nrows();
+		let mut out = Mat::zeros(n, n);
+
+		let mut mem = MemBuffer::new(linalg::cholesky::bunch_kaufman::reconstruct::reconstruct_scratch::<usize, T>(n, par));
+		let stack = MemStack::new(&mut mem);
+
+		linalg::cholesky::bunch_kaufman::reconstruct::reconstruct(out.as_mut(), self.L(), self.B_diag(), self.B_subdiag(), self.P(), par, stack);
+
+		make_self_adjoint(out.as_mut());
+		out
+	}
+
+	#[track_caller]
+	fn inverse(&self) -> Mat<T> {
+		let par = get_global_parallelism();
+
+		let n = self.L.nrows();
+		let mut out = Mat::zeros(n, n);
+
+		let mut mem = MemBuffer::new(linalg::cholesky::bunch_kaufman::inverse::inverse_scratch::<usize, T>(n, par));
+		let stack = MemStack::new(&mut mem);
+
+		linalg::cholesky::bunch_kaufman::inverse::inverse(out.as_mut(), self.L(), self.B_diag(), self.B_subdiag(), self.P(), par, stack);
+
+		make_self_adjoint(out.as_mut());
+		out
+	}
+}
+
+impl<T: ComplexField> SolveCore<T> for PartialPivLu<T> {
+	#[track_caller]
+	fn solve_in_place_with_conj(&self, conj: Conj, rhs: MatMut<'_, T>) {
+		let par = get_global_parallelism();
+
+		assert_dim!(self.ncols(), self.nrows());
+		assert_dim!(rhs.nrows(), self.nrows());
+
+		let k = rhs.ncols();
+
+		linalg::lu::partial_pivoting::solve::solve_in_place_with_conj(
+			self.L(),
+			self.U(),
+			self.P(),
+			conj,
+			rhs,
+			par,
+			MemStack::new(&mut MemBuffer::new(
+				linalg::lu::partial_pivoting::solve::solve_in_place_scratch::<usize, T>(self.nrows(), k, par),
+			)),
+		);
+	}
+
+	#[track_caller]
+	fn solve_transpose_in_place_with_conj(&self, conj: Conj, rhs: MatMut<'_, T>) {
+		let par = get_global_parallelism();
+
+		assert_dim!(self.ncols(), self.nrows());
+		assert_dim!(rhs.nrows(), self.ncols());
+
+		let k = rhs.ncols();
+
+		linalg::lu::partial_pivoting::solve::solve_transpose_in_place_with_conj(
+			self.L(),
+			self.U(),
+			self.P(),
+			conj,
+			rhs,
+			par,
+			MemStack::new(&mut MemBuffer::new(
+				linalg::lu::partial_pivoting::solve::solve_transpose_in_place_scratch::<usize, T>(self.nrows(), k, par),
+			)),
+		);
+	}
+}
+
+impl<T: ComplexField> DenseSolveCore<T> for PartialPivLu<T> {
+	fn reconstruct(&self) -> Mat<T> {
+		let par = get_global_parallelism();
+		let m = self.nrows();
+		let n = self.ncols();
+
+		let mut out = Mat::zeros(m, n);
+
+		linalg::lu::partial_pivoting::reconstruct::reconstruct(
+			out.as_mut(),
+			self.L(),
+			self.U(),
+			self.P(),
+			par,
+			MemStack::new(&mut MemBuffer::new(linalg::lu::partial_pivoting::reconstruct::reconstruct_scratch::<
+				usize,
+				T,
+			>(m, n, par))),
+		);
+
+		out
+	}
+
+	#[track_caller]
+	fn inverse(&self) -> Mat<T> {
+		let par = get_global_parallelism();
+
+		assert_dim!(self.ncols(), self.nrows());
+
+		let n = self.ncols();
+
+		let mut out = Mat::zeros(n, n);
+
+		linalg::lu::partial_pivoting::inverse::inverse(
+			out.as_mut(),
+			self.L(),
+			self.U(),
+			self.P(),
+			par,
+			MemStack::new(&mut MemBuffer::new(linalg::lu::partial_pivoting::inverse::inverse_scratch::<usize, T>(
+				n, par,
+			))),
+		);
+
+		out
+	}
+}
+
+impl<T: ComplexField> SolveCore<T> for FullPivLu<T> {
+	#[track_caller]
+	fn solve_in_place_with_conj(&self, conj: Conj, rhs: MatMut<'_, T>) {
+		let par = get_global_parallelism();
+
+		assert_dim!(self.ncols(), self.nrows());
+		assert_dim!(rhs.nrows(), self.nrows());
+
+		let k = rhs.ncols();
+
+		linalg::lu::full_pivoting::solve::solve_in_place_with_conj(
+			self.L(),
+			self.U(),
+			self.P(),
+			self.Q(),
+			conj,
+			rhs,
+			par,
+			MemStack::new(&mut MemBuffer::new(linalg::lu::full_pivoting::solve::solve_in_place_scratch::<usize, T>(
+				self.nrows(),
+				k,
+				par,
+			))),
+		);
+	}
+
+	#[track_caller]
+	fn solve_transpose_in_place_with_conj(&self, conj: Conj, rhs: MatMut<'_, T>) {
+		let par = get_global_parallelism();
+
+		assert_dim!(self.ncols(), self.nrows());
+		assert_dim!(rhs.nrows(), self.ncols());
+
+		let k = rhs.ncols();
+
+		linalg::lu::full_pivoting::solve::solve_transpose_in_place_with_conj(
+			self.L(),
+			self.U(),
+			self.P(),
+			self.Q(),
+			conj,
+			rhs,
+			par,
+			MemStack::new(&mut MemBuffer::new(linalg::lu::full_pivoting::solve::solve_transpose_in_place_scratch::<
+				usize,
+				T,
+			>(self.nrows(), k, par))),
+		);
+	}
+}
+
+impl<T: ComplexField> DenseSolveCore<T> for FullPivLu<T> {
+	fn reconstruct(&self) -> Mat<T> {
+		let par = get_global_parallelism();
+		let m = self.nrows();
+		let n = self.ncols();
+
+		let mut out = Mat::zeros(m, n);
+
+		linalg::lu::full_pivoting::reconstruct::reconstruct(
+			out.as_mut(),
+			self.L(),
+			self.U(),
+			self.P(),
+			self.Q(),
+			par,
+			MemStack::new(&mut MemBuffer::new(
+				linalg::lu::full_pivoting::reconstruct::reconstruct_scratch::<usize, T>(m, n, par),
+			)),
+		);
+
+		out
+	}
+
+	#[track_caller]
+	fn inverse(&self) -> Mat<T> {
+		let par = get_global_parallelism();
+
+		assert_dim!(self.ncols(), self.nrows());
+
+		let n = self.ncols();
+
+		let mut out = Mat::zeros(n, n);
+
+		linalg::lu::full_pivoting::inverse::inverse(
+			out.as_mut(),
+			self.L(),
+			self.U(),
+			self.P(),
+			self.Q(),
+			par,
+			MemStack::new(&mut MemBuffer::new(linalg::lu::full_pivoting::inverse::inverse_scratch::<usize, T>(
+				n, par,
+			))),
+		);
+
+		out
+	}
+}
+
+impl<T: ComplexField> SolveCore<T> for Qr<T> {
+	#[track_caller]
+	fn solve_in_place_with_conj(&self, conj: Conj, rhs: MatMut<'_, T>) {
+		let par = get_global_parallelism();
+
+		assert_dim!(self.ncols(), self.nrows());
+		assert_dim!(rhs.nrows(), self.nrows());
+
+		let n = self.nrows();
+		let blocksize = self.Q_coeff().nrows();
+		let k = rhs.ncols();
+
+		linalg::qr::no_pivoting::solve::solve_in_place_with_conj(
+			self.Q_basis(),
+			self.Q_coeff(),
+			self.R(),
+			conj,
+			rhs,
+			par,
+			MemStack::new(&mut MemBuffer::new(linalg::qr::no_pivoting::solve::solve_in_place_scratch::<T>(
+				n, blocksize, k, par,
+			))),
+		);
+	}
+
+	#[track_caller]
+	fn solve_transpose_in_place_with_conj(&self, conj: Conj, rhs: MatMut<'_, T>) {
+		let par = get_global_parallelism();
+
+		assert!(
+			self.nrows() == self.ncols(),
+			"the in-place transpose solve requires a square matrix, use `solve_transpose_into` for tall matrices",
+		);
+		assert_dim!(rhs.nrows(), self.ncols());
+
+		let n = self.nrows();
+		let blocksize = self.Q_coeff().nrows();
+		let k = rhs.ncols();
+
+		linalg::qr::no_pivoting::solve::solve_transpose_in_place_with_conj(
+			self.Q_basis(),
+			self.Q_coeff(),
+			self.R(),
+			conj,
+			rhs,
+			par,
+			MemStack::new(&mut MemBuffer::new(
+				linalg::qr::no_pivoting::solve::solve_transpose_in_place_scratch::<T>(n, blocksize, k, par),
+			)),
+		);
+	}
+}
+
+impl<T: ComplexField> SolveLstsqCore<T> for Qr<T> {
+	#[track_caller]
+	fn solve_lstsq_in_place_with_conj(&self, conj: Conj, rhs: MatMut<'_, T>) {
+		let par = get_global_parallelism();
+
+		assert_dim!(rhs.nrows(), self.nrows());
+		assert!(self.nrows() >= self.ncols());
+
+		let m = self.nrows();
+		let n = self.ncols();
+		let blocksize = self.Q_coeff().nrows();
+		let k = rhs.ncols();
+
+		linalg::qr::no_pivoting::solve::solve_lstsq_in_place_with_conj(
+			self.Q_basis(),
+			self.Q_coeff(),
+			self.R(),
+			conj,
+			rhs,
+			par,
+			MemStack::new(&mut MemBuffer::new(linalg::qr::no_pivoting::solve::solve_lstsq_in_place_scratch::<T>(
+				m, n, blocksize, k, par,
+			))),
+		);
+	}
+}
+
+impl<T: ComplexField> DenseSolveCore<T> for Qr<T> {
+	fn reconstruct(&self) -> Mat<T> {
+		let par = get_global_parallelism();
+		let m = self.nrows();
+		let n = self.ncols();
+		let blocksize = self.Q_coeff().nrows();
+
+		let mut out = Mat::zeros(m, n);
+
+		linalg::qr::no_pivoting::reconstruct::reconstruct(
+			out.as_mut(),
+			self.Q_basis(),
+			self.Q_coeff(),
+			self.R(),
+			par,
+			MemStack::new(&mut MemBuffer::new(linalg::qr::no_pivoting::reconstruct::reconstruct_scratch::<T>(
+				m, n, blocksize, par,
+			))),
+		);
+
+		out
+	}
+
+	fn inverse(&self) -> Mat<T> {
+		let par = get_global_parallelism();
+		assert_dim!(self.ncols(), self.nrows());
+
+		let n = self.ncols();
+		let blocksize = self.Q_coeff().nrows();
+
+		let mut out = Mat::zeros(n, n);
+
+		linalg::qr::no_pivoting::inverse::inverse(
+			out.as_mut(),
+			self.Q_basis(),
+			self.Q_coeff(),
+			self.R(),
+			par,
+			MemStack::new(&mut MemBuffer::new(linalg::qr::no_pivoting::inverse::inverse_scratch::<T>(
+				n, blocksize, par,
+			))),
+		);
+
+		out
+	}
+}
+
+impl<T: ComplexField> SolveCore<T> for ColPivQr<T> {
+	#[track_caller]
+	fn solve_in_place_with_conj(&self, conj: Conj, rhs: MatMut<'_, T>) {
+		let par = get_global_parallelism();
+
+		assert_dim!(self.ncols(), self.nrows());
+		assert_dim!(rhs.nrows(), self.nrows());
+
+		let n = self.nrows();
+		let blocksize = self.Q_coeff().nrows();
+		let k = rhs.ncols();
+
+		linalg::qr::col_pivoting::solve::solve_in_place_with_conj(
+			self.Q_basis(),
+			self.Q_coeff(),
+			self.R(),
+			self.P(),
+			conj,
+			rhs,
+			par,
+			MemStack::new(&mut MemBuffer::new(linalg::qr::col_pivoting::solve::solve_in_place_scratch::<usize, T>(
+				n, blocksize, k, par,
+			))),
+		);
+	}
+
+	#[track_caller]
+	fn solve_transpose_in_place_with_conj(&self, conj: Conj, rhs: MatMut<'_, T>) {
+		let par = get_global_parallelism();
+
+		assert!(
+			self.nrows() == self.ncols(),
+			"the in-place transpose solve requires a square matrix, use `solve_transpose_into` for tall matrices",
+		);
+		assert_dim!(rhs.nrows(), self.ncols());
+
+		let n = self.nrows();
+		let blocksize = self.Q_coeff().nrows();
+		let k = rhs.ncols();
+
+		linalg::qr::col_pivoting::solve::solve_transpose_in_place_with_conj(
+			self.Q_basis(),
+			self.Q_coeff(),
+			self.R(),
+			self.P(),
+			conj,
+			rhs,
+			par,
+			MemStack::new(&mut MemBuffer::new(linalg::qr::col_pivoting::solve::solve_transpose_in_place_scratch::<
+				usize,
+				T,
+			>(n, blocksize, k, par))),
+		);
+	}
+}
+
+impl<T: ComplexField> SolveLstsqCore<T> for ColPivQr<T> {
+	#[track_caller]
+	fn solve_lstsq_in_place_with_conj(&self, conj: Conj, rhs: MatMut<'_, T>) {
+		let par = get_global_parallelism();
+
+		assert_dim!(rhs.nrows(), self.nrows());
+		assert!(self.nrows() >= self.ncols());
+
+		let m = self.nrows();
+		let n = self.ncols();
+		let blocksize = self.Q_coeff().nrows();
+		let k = rhs.ncols();
+
+		linalg::qr::col_pivoting::solve::solve_lstsq_in_place_with_conj(
+			self.Q_basis(),
+			self.Q_coeff(),
+			self.R(),
+			self.P(),
+			conj,
+			rhs,
+			par,
+			MemStack::new(&mut MemBuffer::new(linalg::qr::col_pivoting::solve::solve_lstsq_in_place_scratch::<
+				usize,
+				T,
+			>(m, n, blocksize, k, par))),
+		);
+	}
+}
+
+impl<T: ComplexField> SolveLstsqCore<T> for CompOrtho<T> {
+	/// computes the minimum norm solution of the least squares problem, which is also defined for
+	/// rank-deficient systems
+	///
+	/// like the other implementations, this requires $m \geq n$, so that the solution fits in the
+	/// top $n$ rows of `rhs`. see [`CompOrtho::solve_min_norm`] for underdetermined systems
+	#[track_caller]
+	fn solve_lstsq_in_place_with_conj(&self, conj: Conj, rhs: MatMut<'_, T>) {
+		assert_dim!(rhs.nrows(), self.nrows());
+		assert!(self.nrows() >= self.ncols());
+
+		self.solve_min_norm_in_place_imp(conj, rhs);
+	}
+}
+
+impl<T: ComplexField> DenseSolveCore<T> for ColPivQr<T> {
+	fn reconstruct(&self) -> Mat<T> {
+		let par = get_global_parallelism();
+		let m = self.nrows();
+		let n = self.ncols();
+		let blocksize = self.Q_coeff().nrows();
+
+		let mut out = Mat::zeros(m, n);
+
+		linalg::qr::col_pivoting::reconstruct::reconstruct(
+			out.as_mut(),
+			self.Q_basis(),
+			self.Q_coeff(),
+			self.R(),
+			self.P(),
+			par,
+			MemStack::new(&mut MemBuffer::new(
+				linalg::qr::col_pivoting::reconstruct::reconstruct_scratch::<usize, T>(m, n, blocksize, par),
+			)),
+		);
+
+		out
+	}
+
+	fn inverse(&self) -> Mat<T> {
+		let par = get_global_parallelism();
+		assert_dim!(self.ncols(), self.nrows());
+
+		let n = self.ncols();
+		let blocksize = self.Q_coeff().nrows();
+
+		let mut out = Mat::zeros(n, n);
+
+		linalg::qr::col_pivoting::inverse::inverse(
+			out.as_mut(),
+			self.Q_basis(),
+			self.Q_coeff(),
+			self.R(),
+			self.P(),
+			par,
+			MemStack::new(&mut MemBuffer::new(linalg::qr::col_pivoting::inverse::inverse_scratch::<usize, T>(
+				n, blocksize, par,
+			))),
+		);
+
+		out
+	}
+}
+
+impl<T: ComplexField> SolveCore<T> for Svd<T> {
+	#[track_caller]
+	fn solve_in_place_with_conj(&self, conj: Conj, rhs: MatMut<'_, T>) {
+		let par = get_global_parallelism();
+
+		self.assert_has_vectors();
+		assert_dim!(self.ncols(), self.nrows());
+		self.assert_not_truncated();
+		assert_dim!(rhs.nrows(), self.nrows());
+
+		let mut rhs = rhs;
+		let n = self.nrows();
+		let k = rhs.ncols();
+		let mut tmp = Mat::zeros(n, k);
+
+		linalg::matmul::matmul_with_conj(
+			tmp.as_mut(),
+			Accum::Replace,
+			self.U().transpose(),
+			conj.compose(Conj::Yes),
+			rhs.as_ref(),
+			Conj::No,
+			one(),
+			par,
+		);
+
+		for j in 0..k {
+			for i in 0..n {
+				let s = recip(&real(&self.S()[i]));
+				tmp[(i, j)] = mul_real(&tmp[(i, j)], &s);
+			}
+		}
+
+		linalg::matmul::matmul_with_conj(rhs.as_mut(), Accum::Replace, self.V(), conj, tmp.as_ref(), Conj::No, one(), par);
+	}
+
+	#[track_caller]
+	fn solve_transpose_in_place_with_conj(&self, conj: Conj, rhs: MatMut<'_, T>) {
+		let par = get_global_parallelism();
+
+		self.assert_has_vectors();
+		assert_dim!(self.ncols(), self.nrows());
+		self.assert_not_truncated();
+		assert_dim!(rhs.nrows(), self.ncols());
+
+		let mut rhs = rhs;
+		let n = self.nrows();
+		let k = rhs.ncols();
+		let mut tmp = Mat::zeros(n, k);
+
+		linalg::matmul::matmul_with_conj(
+			tmp.as_mut(),
+			Accum::Replace,
+			self.V().transpose(),
+			conj,
+			rhs.as_ref(),
+			Conj::No,
+			one(),
+			par,
+		);
+
+		for j in 0..k {
+			for i in 0..n {
+				let s = recip(&real(&self.S()[i]));
+				tmp[(i, j)] = mul_real(&tmp[(i, j)], &s);
+			}
+		}
+
+		linalg::matmul::matmul_with_conj(
+			rhs.as_mut(),
+			Accum::Replace,
+			self.U(),
+			conj.compose(Conj::Yes),
+			tmp.as_ref(),
+			Conj::No,
+			one(),
+			par,
+		);
+	}
+}
+
+impl<T: ComplexField> SolveLstsqCore<T> for Svd<T> {
+	#[track_caller]
+	fn solve_lstsq_in_place_with_conj(&self, conj: Conj, rhs: MatMut<'_, T>) {
+		let par = get_global_parallelism();
+
+		self.assert_has_vectors();
+		assert_dim!(rhs.nrows(), self.nrows());
+		assert!(self.nrows() >= self.ncols());
+
+		let n = self.ncols();
+
+		// truncated decompositions have fewer than min(m, n) singular values
+		let size = self.S().dim();
+
+		let U = self.U().get(.., ..size);
+		let V = self.V().get(.., ..size);
+
+		let k = rhs.ncols();
+
+		let mut tmp = Mat::zeros(size, k);
+
+		linalg::matmul::matmul_with_conj(
+			tmp.as_mut(),
+			Accum::Replace,
+			U.transpose(),
+			conj.compose(Conj::Yes),
+			rhs.as_ref(),
+			Conj::No,
+			one(),
+			par,
+		);
+
+		for j in 0..k {
+			for i in 0..size {
+				let s = recip(&real(&self.S()[i]));
+				tmp[(i, j)] = mul_real(&tmp[(i, j)], &s);
+			}
+		}
+
+		linalg::matmul::matmul_with_conj(rhs.get_mut(..n, ..), Accum::Replace, V, conj, tmp.as_ref(), Conj::No, one(), par);
+	}
+}
+
+impl<T: ComplexField> DenseSolveCore<T> for Svd<T> {
+	#[track_caller]
+	fn reconstruct(&self) -> Mat<T> {
+		self.assert_has_vectors();
+		let par = get_global_parallelism();
+		let m = self.nrows();
+		let n = self.ncols();
+
+		// a truncated decomposition reconstructs its low-rank approximation
+		let size = self.S().dim();
+
+		let U = self.U().get(.., ..size);
+		let V = self.V().get(.., ..size);
+		let S = self.S();
+
+		let mut UxS = Mat::zeros(m, size);
+		for j in 0..size {
+			let s = real(&S[j]);
+			for i in 0..m {
+				UxS[(i, j)] = mul_real(&U[(i, j)], &s);
+			}
+		}
+
+		let mut out = Mat::zeros(m, n);
+
+		linalg::matmul::matmul(out.as_mut(), Accum::Replace, UxS.as_ref(), V.adjoint(), one(), par);
+
+		out
+	}
+
+	#[track_caller]
+	fn inverse(&self) -> Mat<T> {
+		let par = get_global_parallelism();
+
+		self.assert_has_vectors();
+		assert_dim!(self.ncols(), self.nrows());
+		self.assert_not_truncated();
+		let n = self.nrows();
+
+		let U = self.U();
+		let V = self.V();
+		let S = self.S();
+
+		let mut VxS = Mat::zeros(n, n);
+		for j in 0..n {
+			let s = recip(&real(&S[j]));
+
+			for i in 0..n {
+				VxS[(i, j)] = mul_real(&V[(i, j)], &s);
+			}
+		}
+
+		let mut out = Mat::zeros(n, n);
+
+		linalg::matmul::matmul(out.as_mut(), Accum::Replace, VxS.as_ref(), U.adjoint(), one(), par);
+
+		out
+	}
+}
+
+impl<T: ComplexField> ShapeCore for PolarDecomp<T> {
+	#[inline]
+	fn nrows(&self) -> usize {
+		self.U.nrows()
+	}
+
+	#[inline]
+	fn ncols(&self) -> usize {
+		self.P.ncols()
+	}
+}
+
+// $A^{-1} = P^{-1} U^H = V S^{-1} W^H$, which is solved with the singular value decomposition
+// computed in `new`
+impl<T: ComplexField> SolveCore<T> for PolarDecomp<T> {
+	#[track_caller]
+	fn solve_in_place_with_conj(&self, conj: Conj, rhs: MatMut<'_, T>) {
+		self.square_svd().solve_in_place_with_conj(conj, rhs);
+	}
+
+	#[track_caller]
+	fn solve_transpose_in_place_with_conj(&self, conj: Conj, rhs: MatMut<'_, T>) {
+		self.square_svd().solve_transpose_in_place_with_conj(conj, rhs);
+	}
+}
+
+impl<T: ComplexField> DenseSolveCore<T> for PolarDecomp<T> {
+	fn reconstruct(&self) -> Mat<T> {
+		let par = get_global_parallelism();
+		let mut out = Mat::zeros(self.nrows(), self.ncols());
+		linalg::matmul::matmul(out.as_mut(), Accum::Replace, self.U(), self.P(), one(), par);
+		out
+	}
+
+	#[track_caller]
+	fn inverse(&self) -> Mat<T> {
+		self.square_svd().inverse()
+	}
+}
+
+impl<T: ComplexField> SolveCore<T> for SelfAdjointEigen<T> {
+	#[track_caller]
+	fn solve_in_place_with_conj(&self, conj: Conj, rhs: MatMut<'_, T>) {
+		let par = get_global_parallelism();
+
+		self.assert_has_vectors();
+		assert_dim!(self.ncols(), self.nrows());
+		assert_dim!(rhs.nrows(), self.nrows());
+
+		let mut rhs = rhs;
+		let n = self.nrows();
+		let k = rhs.ncols();
+		let mut tmp = Mat::zeros(n, k);
+
+		linalg::matmul::matmul_with_conj(
+			tmp.as_mut(),
+			Accum::Replace,
+			self.U().transpose(),
+			conj.compose(Conj::Yes),
+			rhs.as_ref(),
+			Conj::No,
+			one(),
+			par,
+		);
+
+		for j in 0..k {
+			for i in 0..n {
+				let s = recip(&real(&self.S()[i]));
+				tmp[(i, j)] = mul_real(&tmp[(i, j)], &s);
+			}
+		}
+
+		linalg::matmul::matmul_with_conj(rhs.as_mut(), Accum::Replace, self.U(), conj, tmp.as_ref(), Conj::No, one(), par);
+	}
+
+	#[track_caller]
+	fn solve_transpose_in_place_with_conj(&self, conj: Conj, rhs: MatMut<'_, T>) {
+		let par = get_global_parallelism();
+
+		self.assert_has_vectors();
+		assert_dim!(self.ncols(), self.nrows());
+		assert_dim!(rhs.nrows(), self.ncols());
+
+		let mut rhs = rhs;
+		let n = self.nrows();
+		let k = rhs.ncols();
+		let mut tmp = Mat::zeros(n, k);
+
+		linalg::matmul::matmul_with_conj(
+			tmp.as_mut(),
+			Accum::Replace,
+			self.U().transpose(),
+			conj,
+			rhs.as_ref(),
+			Conj::No,
+			one(),
+			par,
+		);
+
+		for j in 0..k {
+			for i in 0..n {
+				let s = recip(&real(&self.S()[i]));
+				tmp[(i, j)] = mul_real(&tmp[(i, j)], &s);
+			}
+		}
+
+		linalg::matmul::matmul_with_conj(
+			rhs.as_mut(),
+			Accum::Replace,
+			self.U(),
+			conj.compose(Conj::Yes),
+			tmp.as_ref(),
+			Conj::No,
+			one(),
+			par,
+		);
+	}
+}
+
+impl<T: ComplexField> DenseSolveCore<T> for SelfAdjointEigen<T> {
+	#[track_caller]
+	fn reconstruct(&self) -> Mat<T> {
+		self.assert_has_vectors();
+		let par = get_global_parallelism();
+		let m = self.nrows();
+		let n = self.ncols();
+
+		let size = Ord::min(m, n);
+
+		let U = self.U().get(.., ..size);
+		let V = self.U().get(.., ..size);
+		let S = self.S();
+
+		let mut UxS = Mat::zeros(m, size);
+		for j in 0..size {
+			let s = real(&S[j]);
+			for i in 0..m {
+				UxS[(i, j)] = mul_real(&U[(i, j)], &s);
+			}
+		}
+
+		let mut out = Mat::zeros(m, n);
+
+		linalg::matmul::matmul(out.as_mut(), Accum::Replace, UxS.as_ref(), V.adjoint(), one(), par);
+
+		out
+	}
+
+	#[track_caller]
+	fn inverse(&self) -> Mat<T> {
+		let par = get_global_parallelism();
+
+		self.assert_has_vectors();
+		assert_dim!(self.ncols(), self.nrows());
+		let n = self.nrows();
+
+		let U = self.U();
+		let V = self.U();
+		let S = self.S();
+
+		let mut VxS = Mat::zeros(n, n);
+		for j in 0..n {
+			let s = recip(&real(&S[j]));
+
+			for i in 0..n {
+				VxS[(i, j)] = mul_real(&V[(i, j)], &s);
+			}
+		}
+
+		let mut out = Mat::zeros(n, n);
+
+		linalg::matmul::matmul(out.as_mut(), Accum::Replace, VxS.as_ref(), U.adjoint(), one(), par);
+
+		out
+	}
+}
+
+impl<T: ComplexField> SolveCore<T> for BlockTridiagonal<T> {
+	#[track_caller]
+	#[math]
+	fn solve_in_place_with_conj(&self, conj: Conj, rhs: MatMut<'_, T>) {
+		let par = get_global_parallelism();
+
+		assert_dim!(rhs.nrows(), self.nrows());
+
+		let mut rhs = rhs;
+		let nblocks = self.nblocks();
+		let off = &*self.offsets;
+
+		// forward sweep: z_i = b_i - L_i S_{i-1}^{-1} z_{i-1}
+		for i in 1..nblocks {
+			let (top, bot) = rhs.rb_mut().split_at_row_mut(off[i]);
+			let mut tmp = top.rb().subrows(off[i - 1], off[i] - off[i - 1]).to_owned();
+			self.S[i - 1].solve_in_place_with_conj(conj, tmp.as_mut());
+
+			linalg::matmul::matmul_with_conj(
+				bot.subrows_mut(0, off[i + 1] - off[i]),
+				Accum::Add,
+				&self.subdiag[i - 1],
+				conj,
+				&tmp,
+				Conj::No,
+				-one::<T>(),
+				par,
+			);
+		}
+
+		// backward sweep: x_i = S_i^{-1} (z_i - U_i x_{i+1})
+		for i in (0..nblocks).rev() {
+			let (top, bot) = rhs.rb_mut().split_at_row_mut(off[i + 1]);
+			let mut xi = top.subrows_mut(off[i], off[i + 1] - off[i]);
+			if i + 1 < nblocks {
+				linalg::matmul::matmul_with_conj(
+					xi.rb_mut(),
+					Accum::Add,
+					&self.superdiag[i],
+					conj,
+					bot.rb().subrows(0, off[i + 2] - off[i + 1]),
+					Conj::No,
+					-one::<T>(),
+					par,
+				);
+			}
+			self.S[i].solve_in_place_with_conj(conj, xi);
+		}
+	}
+
+	#[track_caller]
+	#[math]
+	fn solve_transpose_in_place_with_conj(&self, conj: Conj, rhs: MatMut<'_, T>) {
+		let par = get_global_parallelism();
+
+		assert_dim!(rhs.nrows(), self.ncols());
+
+		let mut rhs = rhs;
+		let nblocks = self.nblocks();
+		let off = &*self.offsets;
+		let k = rhs.ncols();
+
+		// forward sweep: y_i = S_i^{-T} (b_i - U_{i-1}^T y_{i-1})
+		for i in 0..nblocks {
+			let (top, bot) = rhs.rb_mut().split_at_row_mut(off[i]);
+			let mut yi = bot.subrows_mut(0, off[i + 1] - off[i]);
+			if i > 0 {
+				linalg::matmul::matmul_with_conj(
+					yi.rb_mut(),
+					Accum::Add,
+					self.superdiag[i - 1].transpose(),
+					conj,
+					top.rb().subrows(off[i - 1], off[i] - off[i - 1]),
+					Conj::No,
+					-one::<T>(),
+					par,
+				);
+			}
+			self.S[i].solve_transpose_in_place_with_conj(conj, yi);
+		}
+
+		// backward sweep: x_i = y_i - S_i^{-T} L_{i+1}^T x_{i+1}
+		for i in (0..nblocks.saturating_sub(1)).rev() {
+			let (top, bot) = rhs.rb_mut().split_at_row_mut(off[i + 1]);
+			let mut tmp = Mat::zeros(off[i + 1] - off[i], k);
+			linalg::matmul::matmul_with_conj(
+				tmp.as_mut(),
+				Accum::Replace,
+				self.subdiag[i].transpose(),
+				conj,
+				bot.rb().subrows(0, off[i + 2] - off[i + 1]),
+				Conj::No,
+				one(),
+				par,
+			);
+			self.S[i].solve_transpose_in_place_with_conj(conj, tmp.as_mut());
+
+			z!(top.subrows_mut(off[i], off[i + 1] - off[i]), &tmp).for_each(|uz!(x, t)| *x = *x - *t);
+		}
+	}
+}
+
+/// solves $T x = b$ in place, implicitly conjugating $T$ if needed, where $T$ is upper triangular
+/// if `quasi` is `false`, and quasi-upper triangular with $1 \times 1$ and $2 \times 2$ diagonal
+/// blocks otherwise
+#[math]
+fn quasi_upper_triangular_solve_in_place<T: ComplexField>(T: MatRef<'_, T>, conj_T: Conj, quasi: bool, rhs: MatMut<'_, T>) {
+	let par = get_global_parallelism();
+
+	let n = T.nrows();
+	let k = rhs.ncols();
+	let mut rhs = rhs;
+
+	let t = |i: usize, j: usize| if conj_T.is_conj() { conj(T[(i, j)]) } else { copy(T[(i, j)]) };
+
+	let mut end = n;
+	while end > 0 {
+		let start = if quasi && end > 1 && T[(end - 1, end - 2)] != zero() {
+			end - 2
+		} else {
+			end - 1
+		};
+
+		if end - start == 1 {
+			let d = recip(t(start, start));
+			for j in 0..k {
+				rhs[(start, j)] = d * rhs[(start, j)];
+			}
+		} else {
+			let (a, b, c, d) = (t(start, start), t(start, end - 1), t(end - 1, start), t(end - 1, end - 1));
+			let det_inv = recip(a * d - b * c);
+			for j in 0..k {
+				let x0 = copy(rhs[(start, j)]);
+				let x1 = copy(rhs[(end - 1, j)]);
+				rhs[(start, j)] = det_inv * (d * x0 - b * x1);
+				rhs[(end - 1, j)] = det_inv * (a * x1 - c * x0);
+			}
+		}
+
+		let (top, bot) = rhs.rb_mut().split_at_row_mut(start);
+		linalg::matmul::matmul_with_conj(
+			top,
+			Accum::Add,
+			T.get(..start, start..end),
+			conj_T,
+			bot.rb().get(..end - start, ..),
+			Conj::No,
+			-one::<T>(),
+			par,
+		);
+
+		end = start;
+	}
+}
+
+/// solves $Q T Q^H x = b$ in place, implicitly conjugating the matrix if needed
+#[track_caller]
+fn schur_solve_in_place<T: ComplexField>(Q: MatRef<'_, T>, T: MatRef<'_, T>, quasi: bool, conj: Conj, rhs: MatMut<'_, T>) {
+	let par = get_global_parallelism();
+
+	assert_dim!(rhs.nrows(), Q.nrows());
+
+	let mut rhs = rhs;
+	let mut tmp = Mat::zeros(Q.nrows(), rhs.ncols());
+
+	linalg::matmul::matmul_with_conj(
+		tmp.as_mut(),
+		Accum::Replace,
+		Q.transpose(),
+		conj.compose(Conj::Yes),
+		rhs.as_ref(),
+		Conj::No,
+		one(),
+		par,
+	);
+	quasi_upper_triangular_solve_in_place(T, conj, quasi, tmp.as_mut());
+	linalg::matmul::matmul_with_conj(rhs.as_mut(), Accum::Replace, Q, conj, tmp.as_ref(), Conj::No, one(), par);
+}
+
+/// solves $(Q T Q^H)^\top x = b$ in place, implicitly conjugating the matrix if needed
+#[track_caller]
+fn schur_solve_transpose_in_place<T: ComplexField>(Q: MatRef<'_, T>, T: MatRef<'_, T>, quasi: bool, conj: Conj, rhs: MatMut<'_, T>) {
+	let par = get_global_parallelism();
+
+	assert_dim!(rhs.nrows(), Q.nrows());
+
+	let mut rhs = rhs;
+	let mut tmp = Mat::zeros(Q.nrows(), rhs.ncols());
+
+	linalg::matmul::matmul_with_conj(tmp.as_mut(), Accum::Replace, Q.transpose(), conj, rhs.as_ref(), Conj::No, one(), par);
+	// reversing the rows and columns of the lower triangular T^T makes it upper triangular again
+	quasi_upper_triangular_solve_in_place(T.transpose().reverse_rows_and_cols(), conj, quasi, tmp.as_mut().reverse_rows_mut());
+	linalg::matmul::matmul_with_conj(
+		rhs.as_mut(),
+		Accum::Replace,
+		Q,
+		conj.compose(Conj::Yes),
+		tmp.as_ref(),
+		Conj::No,
+		one(),
+		par,
+	);
+}
+
+fn schur_reconstruct<T: ComplexField>(Q: MatRef<'_, T>, T: MatRef<'_, T>) -> Mat<T> {
+	let par = get_global_parallelism();
+	let n = Q.nrows();
+
+	let mut QT = Mat::zeros(n, n);
+	linalg::matmul::matmul(QT.as_mut(), Accum::Replace, Q, T, one(), par);
+
+	let mut out = Mat::zeros(n, n);
+	linalg::matmul::matmul(out.as_mut(), Accum::Replace, QT.as_ref(), Q.adjoint(), one(), par);
+	out
+}
+
+impl<T: RealField> SolveCore<Complex<T>> for Schur<T> {
+	#[track_caller]
+	fn solve_in_place_with_conj(&self, conj: Conj, rhs: MatMut<'_, Complex<T>>) {
+		schur_solve_in_place(self.Q(), self.T(), false, conj, rhs);
+	}
+
+	#[track_caller]
+	fn solve_transpose_in_place_with_conj(&self, conj: Conj, rhs: MatMut<'_, Complex<T>>) {
+		schur_solve_transpose_in_place(self.Q(), self.T(), false, conj, rhs);
+	}
+}
+
+impl<T: RealField> DenseSolveCore<Complex<T>> for Schur<T> {
+	fn reconstruct(&self) -> Mat<Complex<T>> {
+		schur_reconstruct(self.Q(), self.T())
+	}
+
+	fn inverse(&self) -> Mat<Complex<T>> {
+		let n = self.nrows();
+		let mut out = Mat::identity(n, n);
+		self.solve_in_place_with_conj(Conj::No, out.as_mut());
+		out
+	}
+}
+
+impl<T: RealField> SolveCore<T> for RealSchur<T> {
+	#[track_caller]
+	fn solve_in_place_with_conj(&self, conj: Conj, rhs: MatMut<'_, T>) {
+		schur_solve_in_place(self.Q(), self.T(), true, conj, rhs);
+	}
+
+	#[track_caller]
+	fn solve_transpose_in_place_with_conj(&self, conj: Conj, rhs: MatMut<'_, T>) {
+		schur_solve_transpose_in_place(self.Q(), self.T(), true, conj, rhs);
+	}
+}
+
+impl<T: RealField> DenseSolveCore<T> for RealSchur<T> {
+	fn reconstruct(&self) -> Mat<T> {
+		schur_reconstruct(self.Q(), self.T())
+	}
+
+	fn inverse(&self) -> Mat<T> {
+		let n = self.nrows();
+		let mut out = Mat::identity(n, n);
+		self.solve_in_place_with_conj(Conj::No, out.as_mut());
+		out
+	}
+}
+
+#[cfg(feature = "serde")]
+mod serde_impl {
+	//! the decompositions are deserialized through these mirrors of their fields, so that the
+	//! dimensions of the factors can be checked before the solvers use them
+
+	use super::*;
+
+	const DIM_MISMATCH: &str = "the dimensions of the factors are inconsistent";
+
+	fn check(cond: bool) -> Result<(), &'static str> {
+		if cond { Ok(()) } else { Err(DIM_MISMATCH) }
+	}
+
+	// a block householder sequence of `size` reflectors, with a nonzero block size
+	fn check_householder<T>(basis: &Mat<T>, coeff: &Mat<T>, nrows: usize, size: usize) -> Result<(), &'static str> {
+		check(basis.nrows() == nrows && basis.ncols() == size && coeff.ncols() == size && coeff.nrows() > 0)
+	}
+
+	#[derive(serde::Deserialize)]
+	pub struct Llt<T> {
+		L: Mat<T>,
+	}
+
+	impl<T> TryFrom<Llt<T>> for super::Llt<T> {
+		type Error = &'static str;
+
+		fn try_from(Llt { L }: Llt<T>) -> Result<Self, Self::Error> {
+			check(L.nrows() == L.ncols())?;
+			Ok(Self { L })
+		}
+	}
+
+	#[derive(serde::Deserialize)]
+	pub struct Ldlt<T> {
+		L: Mat<T>,
+		D: Diag<T>,
+	}
+
+	impl<T> TryFrom<Ldlt<T>> for super::Ldlt<T> {
+		type Error = &'static str;
+
+		fn try_from(Ldlt { L, D }: Ldlt<T>) -> Result<Self, Self::Error> {
+			let n = L.nrows();
+			check(L.ncols() == n && D.dim() == n)?;
+			Ok(Self { L, D })
+		}
+	}
+
+	#[derive(serde::Deserialize)]
+	pub struct Lblt<T> {
+		L: Mat<T>,
+		B_diag: Diag<T>,
+		B_subdiag: Diag<T>,
+		P: Perm<usize>,
+	}
+
+	impl<T> TryFrom<Lblt<T>> for super::Lblt<T> {
+		type Error = &'static str;
+
+		fn try_from(Lblt { L, B_diag, B_subdiag, P }: Lblt<T>) -> Result<Self, Self::Error> {
+			let n = L.nrows();
+			check(L.ncols() == n && B_diag.dim() == n && B_subdiag.dim() == n && P.len() == n)?;
+			Ok(Self { L, B_diag, B_subdiag, P })
+		}
+	}
+
+	#[derive(serde::Deserialize)]
+	pub struct PartialPivLu<T> {
+		L: Mat<T>,
+		U: Mat<T>,
+		P: Perm<usize>,
+	}
+
+	impl<T> TryFrom<PartialPivLu<T>> for super::PartialPivLu<T> {
+		type Error = &'static str;
+
+		fn try_from(PartialPivLu { L, U, P }: PartialPivLu<T>) -> Result<Self, Self::Error> {
+			let (m, n) = (L.nrows(), U.ncols());
+			let size = Ord::min(m, n);
+			check(L.ncols() == size && U.nrows() == size && P.len() == m)?;
+			Ok(Self { L, U, P })
+		}
+	}
+
+	#[derive(serde::Deserialize)]
+	pub struct FullPivLu<T> {
+		L: Mat<T>,
+		U: Mat<T>,
+		P: Perm<usize>,
+		Q: Perm<usize>,
+	}
+
+	impl<T> TryFrom<FullPivLu<T>> for super::FullPivLu<T> {
+		type Error = &'static str;
+
+		fn try_from(FullPivLu { L, U, P, Q }: FullPivLu<T>) -> Result<Self, Self::Error> {
+			let (m, n) = (L.nrows(), U.ncols());
+			let size = Ord::min(m, n);
+			check(L.ncols() == size && U.nrows() == size && P.len() == m && Q.len() == n)?;
+			Ok(Self { L, U, P, Q })
+		}
+	}
+
+	#[derive(serde::Deserialize)]
+	pub struct Qr<T> {
+		Q_basis: Mat<T>,
+		Q_coeff: Mat<T>,
+		R: Mat<T>,
+	}
+
+	impl<T> TryFrom<Qr<T>> for super::Qr<T> {
+		type Error = &'static str;
+
+		fn try_from(Qr { Q_basis, Q_coeff, R }: Qr<T>) -> Result<Self, Self::Error> {
+			let (m, n) = (Q_basis.nrows(), R.ncols());
+			let size = Ord::min(m, n);
+			check_householder(&Q_basis, &Q_coeff, m, size)?;
+			check(R.nrows() == size)?;
+			Ok(Self { Q_basis, Q_coeff, R })
+		}
+	}
+
+	#[derive(serde::Deserialize)]
+	pub struct ColPivQr<T> {
+		Q_basis: Mat<T>,
+		Q_coeff: Mat<T>,
+		R: Mat<T>,
+		P: Perm<usize>,
+	}
+
+	impl<T> TryFrom<ColPivQr<T>> for super::ColPivQr<T> {
+		type Error = &'static str;
+
+		fn try_from(ColPivQr { Q_basis, Q_coeff, R, P }: ColPivQr<T>) -> Result<Self, Self::Error> {
+			let (m, n) = (Q_basis.nrows(), R.ncols());
+			let size = Ord::min(m, n);
+			check_householder(&Q_basis, &Q_coeff, m, size)?;
+			check(R.nrows() == size && P.len() == n)?;
+			Ok(Self { Q_basis, Q_coeff, R, P })
+		}
+	}
+
+	#[derive(serde::Deserialize)]
+	pub struct CompOrtho<T> {
+		Q_basis: Mat<T>,
+		Q_coeff: Mat<T>,
+		R: Mat<T>,
+		Z_basis: Mat<T>,
+		Z_coeff: Mat<T>,
+		P: Perm<usize>,
+		rank: usize,
+	}
+
+	impl<T> TryFrom<CompOrtho<T>> for super::CompOrtho<T> {
+		type Error = &'static str;
+
+		fn try_from(
+			CompOrtho {
+				Q_basis,
+				Q_coeff,
+				R,
+				Z_basis,
+				Z_coeff,
+				P,
+				rank,
+			}: CompOrtho<T>,
+		) -> Result<Self, Self::Error> {
+			let (m, n) = (Q_basis.nrows(), P.len());
+			let size = Ord::min(m, n);
+			check(rank <= size)?;
+			check_householder(&Q_basis, &Q_coeff, m, size)?;
+			check_householder(&Z_basis, &Z_coeff, n, rank)?;
+			check(R.nrows() == rank && R.ncols() == rank)?;
+			Ok(Self {
+				Q_basis,
+				Q_coeff,
+				R,
+				Z_basis,
+				Z_coeff,
+				P,
+				rank,
+			})
+		}
+	}
+
+	#[derive(serde::Deserialize)]
+	pub struct Svd<T> {
+		U: Mat<T>,
+		V: Mat<T>,
+		S: Diag<T>,
+	}
+
+	impl<T> TryFrom<Svd<T>> for super::Svd<T> {
+		type Error = &'static str;
+
+		fn try_from(Svd { U, V, S }: Svd<T>) -> Result<Self, Self::Error> {
+			let (m, n, k) = (U.nrows(), V.nrows(), S.dim());
+			// the singular vectors are either absent, thin (or truncated), or full
+			check(k <= Ord::min(m, n) && [0, k, m].contains(&U.ncols()) && [0, k, n].contains(&V.ncols()))?;
+			Ok(Self { U, V, S })
+		}
+	}
+
+	#[derive(serde::Deserialize)]
+	pub struct SelfAdjointEigen<T> {
+		U: Mat<T>,
+		S: Diag<T>,
+	}
+
+	impl<T> TryFrom<SelfAdjointEigen<T>> for super::SelfAdjointEigen<T> {
+		type Error = &'static str;
+
+		fn try_from(SelfAdjointEigen { U, S }: SelfAdjointEigen<T>) -> Result<Self, Self::Error> {
+			let n = U.nrows();
+			check(S.dim() == n && (U.ncols() == n || U.ncols() == 0))?;
+			Ok(Self { U, S })
+		}
+	}
+
+	#[derive(serde::Deserialize)]
+	pub struct Eigen<T> {
+		U: Mat<Complex<T>>,
+		S: Diag<Complex<T>>,
+	}
+
+	impl<T> TryFrom<Eigen<T>> for super::Eigen<T> {
+		type Error = &'static str;
+
+		fn try_from(Eigen { U, S }: Eigen<T>) -> Result<Self, Self::Error> {
+			let n = U.nrows();
+			check(S.dim() == n && (U.ncols() == n || U.ncols() == 0))?;
+			Ok(Self { U, S })
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::assert;
+	use crate::stats::prelude::*;
+	use crate::utils::approx::*;
+
+	#[track_caller]
+	fn test_solver(A: MatRef<'_, c64>, A_dec: impl SolveCore<c64>) {
+		#[track_caller]
+		fn test_solver_imp(A: MatRef<'_, c64>, A_dec: &dyn SolveCore<c64>) {
+			let rng = &mut StdRng::seed_from_u64(0xC0FFEE);
+
+			let n = A.nrows();
+			let approx_eq = CwiseMat(ApproxEq::eps() * 128.0 * (n as f64));
+
+			let k = 3;
+
+			let ref R = CwiseMatDistribution {
+				nrows: n,
+				ncols: k,
+				dist: ComplexDistribution::new(StandardNormal, StandardNormal),
+			}
+			.rand::<Mat<c64>>(rng);
+
+			let ref L = CwiseMatDistribution {
+				nrows: k,
+				ncols: n,
+				dist: ComplexDistribution::new(StandardNormal, StandardNormal),
+			}
+			.rand::<Mat<c64>>(rng);
+
+			assert!(A * A_dec.solve(R) ~ R);
+			assert!(A.conjugate() * A_dec.solve_conjugate(R) ~ R);
+			assert!(A.transpose() * A_dec.solve_transpose(R) ~ R);
+			assert!(A.adjoint() * A_dec.solve_adjoint(R) ~ R);
+
+			assert!(A_dec.rsolve(L) * A ~ L);
+			assert!(A_dec.rsolve_conjugate(L) * A.conjugate() ~ L);
+			assert!(A_dec.rsolve_transpose(L) * A.transpose() ~ L);
+			assert!(A_dec.rsolve_adjoint(L) * A.adjoint() ~ L);
+		}
+
+		test_solver_imp(A, &A_dec)
+	}
+
+	#[test]
+	fn test_all_solvers() {
+		let rng = &mut StdRng::seed_from_u64(0);
+		let n = 50;
+
+		let ref A = CwiseMatDistribution {
+			nrows: n,
+			ncols: n,
+			dist: ComplexDistribution::new(StandardNormal, StandardNormal),
+		}
+		.rand::<Mat<c64>>(rng);
+		let A = A.rb();
+
+		test_solver(A, A.partial_piv_lu());
+		test_solver(A, A.full_piv_lu());
+		test_solver(A, A.qr());
+		test_solver(A, A.col_piv_qr());
+		test_solver(A, A.svd().unwrap());
+
+		{
+			let ref A = A * A.adjoint();
+			let A = A.rb();
+			test_solver(A, A.llt(Side::Lower).unwrap());
+			test_solver(A, A.ldlt(Side::Lower).unwrap());
+		}
+
+		{
+			let ref A = A + A.adjoint();
+			let A = A.rb();
+			test_solver(A, A.lblt(Side::Lower));
+			test_solver(A, A.self_adjoint_eigen(Side::Lower).unwrap());
+		}
+	}
+
+	#[test]
+	fn test_block_tridiagonal() {
+		let rng = &mut StdRng::seed_from_u64(0);
+		let sizes = [3usize, 5, 2, 4];
+		let nblocks = sizes.len();
+		let n = sizes.iter().sum::<usize>();
+
+		let mut rand = |m: usize, n: usize| {
+			CwiseMatDistribution {
+				nrows: m,
+				ncols: n,
+				dist: ComplexDistribution::new(StandardNormal, StandardNormal),
+			}
+			.rand::<Mat<c64>>(rng)
+		};
+
+		let diag = (0..nblocks).map(|i| rand(sizes[i], sizes[i])).collect::<Vec<_>>();
+		let subdiag = (1..nblocks).map(|i| rand(sizes[i], sizes[i - 1])).collect::<Vec<_>>();
+		let superdiag = (1..nblocks).map(|i| rand(sizes[i - 1], sizes[i])).collect::<Vec<_>>();
+
+		let mut A = Mat::<c64>::zeros(n, n);
+		let mut off = 0;
+		for i in 0..nblocks {
+			let ni = sizes[i];
+			A.submatrix_mut(off, off, ni, ni).copy_from(&diag[i]);
+			if i + 1 < nblocks {
+				let nj = sizes[i + 1];
+				A.submatrix_mut(off + ni, off, nj, ni).copy_from(&subdiag[i]);
+				A.submatrix_mut(off, off + ni, ni, nj).copy_from(&superdiag[i]);
+			}
+			off += ni;
+		}
+
+		let approx_eq = CwiseMat(ApproxEq::eps() * 128.0 * (n as f64));
+		let A_dec = BlockTridiagonal::new(&diag, &subdiag, &superdiag);
+
+		let B = rand(n, 2);
+		assert!(A_dec.solve(&B) ~ A.partial_piv_lu().solve(&B));
+
+		test_solver(A.as_ref(), A_dec);
+	}
+
+	#[test]
+	fn test_svd_new_into() {
+		let rng = &mut StdRng::seed_from_u64(0);
+		let (m, n) = (50, 30);
+
+		let A = CwiseMatDistribution {
+			nrows: m,
+			ncols: n,
+			dist: ComplexDistribution::new(StandardNormal, StandardNormal),
+		}
+		.rand::<Mat<c64>>(rng);
+		let A = A.as_ref();
+
+		let approx_eq = CwiseMat(ApproxEq::eps() * 128.0 * (m as f64));
+
+		let svd = Svd::new(A.conjugate()).unwrap();
+		let mut U = Mat::zeros(m, m);
+		let mut V = Mat::zeros(n, n);
+		let mut S = Diag::<f64>::zeros(n);
+		Svd::new_into(A.conjugate(), Some(U.as_mut()), Some(V.as_mut()), S.as_mut()).unwrap();
+
+		assert!(U ~ svd.U());
+		assert!(V ~ svd.V());
+		assert!(S.column_vector() ~ Col::from_fn(n, |i| svd.S()[i].re));
+
+		let svd = Svd::new_thin(A).unwrap();
+		let mut U = Mat::zeros(m, n);
+		let mut S = Diag::<f64>::zeros(n);
+		Svd::new_into(A, Some(U.as_mut()), None, S.as_mut()).unwrap();
+
+		assert!(U ~ svd.U());
+		assert!(S.column_vector() ~ Col::from_fn(n, |i| svd.S()[i].re));
+	}
+
+	#[test]
+	fn test_lstsq_rank_deficient() {
+		let rng = &mut StdRng::seed_from_u64(0);
+		let (m, n, r) = (40, 10, 6);
+
+		let A = randn_c64(m, r, rng) * randn_c64(r, n, rng);
+		let B = randn_c64(m, 2, rng);
+
+		let approx_eq = CwiseMat(ApproxEq::eps() * 128.0 * (m as f64));
+
+		let qr = A.col_piv_qr();
+		assert!(qr.numerical_rank(1e-10) == r);
+		let X = qr.solve_lstsq_rank_deficient(&B, 1e-10);
+
+		// minimum norm solution from the svd
+		let svd = A.thin_svd().unwrap();
+		let S = svd.S().column_vector();
+		let s_max = S[0].re;
+		let S_inv = Col::from_fn(n, |i| {
+			if S[i].re > 1e-10 * s_max {
+				c64::new(1.0 / S[i].re, 0.0)
+			} else {
+				c64::new(0.0, 0.0)
+			}
+		});
+		let X_svd = svd.V() * S_inv.as_diagonal() * svd.U().adjoint() * &B;
+
+		assert!(&A * &X - &B ~ &A * &X_svd - &B);
+	}
+
+	#[test]
+	#[should_panic(expected = "expected rhs.nrows() == 5 but got 7")]
+	fn test_solve_dim_mismatch() {
+		let A = Mat::<f64>::identity(5, 5);
+		let B = Mat::<f64>::zeros(7, 2);
+		A.partial_piv_lu().solve(&B);
+	}
+
+	#[test]
+	#[should_panic(expected = "expected rhs.nrows() == 5 but got 7")]
+	fn test_solve_lstsq_dim_mismatch() {
+		let A = Mat::<f64>::identity(5, 3);
+		let mut B = Mat::<f64>::zeros(7, 2);
+		A.col_piv_qr().solve_lstsq_in_place_with_conj(Conj::No, B.as_mut());
+	}
+
+	#[test]
+	fn test_builders() {
+		let rng = &mut StdRng::seed_from_u64(0);
+		let n = 20;
+
+		let A = CwiseMatDistribution {
+			nrows: n,
+			ncols: n - 5,
+			dist: ComplexDistribution::new(StandardNormal, StandardNormal),
+		}
+		.rand::<Mat<c64>>(rng);
+		let H = &A * A.adjoint();
+
+		let approx_eq = CwiseMat(ApproxEq::eps() * 128.0 * (n as f64));
+
+		let full = SelfAdjointEigen::new(H.as_ref(), Side::Lower).unwrap();
+		let values = SelfAdjointEigen::builder()
+			.vectors(ComputeEigenvectors::No)
+			.build(H.as_ref(), Side::Lower)
+			.unwrap();
+		assert!(values.U().ncols() == 0);
+		assert!(values.S().column_vector().as_mat() ~ full.S().column_vector().as_mat());
+		let vectors = SelfAdjointEigen::builder().build(H.as_ref(), Side::Lower).unwrap();
+		assert!(vectors.U() == full.U());
+
+		let full = Svd::new(A.as_ref()).unwrap();
+		let values = Svd::builder().vectors(ComputeSvdVectors::No).build(A.as_ref()).unwrap();
+		assert!(all(values.U().ncols() == 0, values.V().ncols() == 0));
+		assert!(values.S().column_vector().as_mat() ~ full.S().column_vector().as_mat());
+		let thin = Svd::builder().vectors(ComputeSvdVectors::Thin).build(A.as_ref()).unwrap();
+		assert!(all(thin.U().ncols() == n - 5, thin.V().ncols() == n - 5));
+		assert!(thin.U() * thin.S() * thin.V().adjoint() ~ A);
+	}
+
+	#[test]
+	fn test_qr_compute_Q() {
+		let rng = &mut StdRng::seed_from_u64(0);
+
+		for (m, n) in [(12, 7), (7, 12), (9, 9)] {
+			let A = CwiseMatDistribution {
+				nrows: m,
+				ncols: n,
+				dist: ComplexDistribution::new(StandardNormal, StandardNormal),
+			}
+			.rand::<Mat<c64>>(rng);
+			let k = Ord::min(m, n);
+			let approx_eq = CwiseMat(ApproxEq::eps() * 64.0 * (m as f64));
+
+			let qr = A.qr();
+			let (Q, Q_thin) = (qr.compute_Q(), qr.compute_thin_Q());
+			assert!(all(Q.shape() == (m, m), Q_thin.shape() == (m, k)));
+			assert!(Q_thin.adjoint() * &Q_thin ~ Mat::<c64>::identity(k, k));
+			assert!(&Q * Q.adjoint() ~ Mat::<c64>::identity(m, m));
+			assert!(Q.get(.., ..k) ~ Q_thin);
+			assert!(&Q_thin * qr.thin_R() ~ A);
+
+			let qr = A.col_piv_qr();
+			let (Q, Q_thin) = (qr.compute_Q(), qr.compute_thin_Q());
+			assert!(Q_thin.adjoint() * &Q_thin ~ Mat::<c64>::identity(k, k));
+			assert!(&Q * Q.adjoint() ~ Mat::<c64>::identity(m, m));
+			// A P^{-1} = Q R, where the columns of A are permuted by P
+			assert!(&Q_thin * qr.thin_R() ~ &A * qr.P().inverse());
+		}
+	}
+
+	#[test]
+	fn test_qr_householder_vectors() {
+		let rng = &mut StdRng::seed_from_u64(0);
+
+		for (m, n) in [(12, 7), (7, 12), (9, 9), (80, 70)] {
+			let A = CwiseMatDistribution {
+				nrows: m,
+				ncols: n,
+				dist: ComplexDistribution::new(StandardNormal, StandardNormal),
+			}
+			.rand::<Mat<c64>>(rng);
+			let approx_eq = CwiseMat(ApproxEq::eps() * 64.0 * (m as f64));
+
+			let qr = A.qr();
+			let reflectors = qr.householder_vectors();
+			assert!(reflectors.len() == Ord::min(m, n));
+
+			// Q = H_0 H_1 ... H_{k-1} I
+			let mut Q = Mat::<c64>::identity(m, m);
+			for (v, tau) in reflectors.iter().rev() {
+				let w = v.adjoint() * &Q;
+				Q -= v * w * Scale(*tau);
+			}
+			assert!(Q ~ qr.compute_Q());
+
+			// when m <= n, the last reflector has nothing to annihilate, so it is the identity
+			if m <= n {
+				assert!(reflectors[m - 1].1 == c64::ZERO);
+			}
+		}
+	}
+
+	#[test]
+	fn test_numerical_rank_null_space() {
+		let rng = &mut StdRng::seed_from_u64(0);
+		let (m, n, r) = (15, 10, 6);
+
+		let A = randn_c64(m, r, rng) * randn_c64(r, n, rng);
+		let approx_eq = CwiseMat(ApproxEq::eps() * 1e4);
+
+		let qr = A.col_piv_qr();
+		assert!(qr.numerical_rank(0.0) == r);
+		assert!(A.full_piv_lu().numerical_rank(0.0) == r);
+		assert!(randn_c64(n, n, rng).full_piv_lu().numerical_rank(0.0) == n);
+		assert!(Mat::<c64>::zeros(4, 3).full_piv_lu().numerical_rank(1e-10) == 0);
+
+		let N = qr.null_space(0.0);
+		assert!(N.shape() == (n, n - r));
+		assert!(N.adjoint() * &N ~ Mat::<c64>::identity(n - r, n - r));
+		assert!(&A * &N ~ Mat::<c64>::zeros(m, n - r));
+
+		// the first r columns of Q span the range of A
+		let Q = qr.compute_thin_Q();
+		let Q = Q.get(.., ..r);
+		assert!(Q * (Q.adjoint() * &A) ~ A);
+		assert!(A.svd().unwrap().numerical_rank(0.0) + N.ncols() == n);
+
+		let qr = randn_c64(m, n, rng).col_piv_qr();
+		assert!(qr.null_space(0.0).shape() == (n, 0));
+	}
+
+	#[test]
+	fn test_comp_ortho() {
+		let rng = &mut StdRng::seed_from_u64(0);
+
+		for (m, n, r) in [(15, 10, 6), (8, 14, 5), (12, 12, 12), (9, 13, 9)] {
+			let A = randn_c64(m, r, rng) * randn_c64(r, n, rng);
+			let B = randn_c64(m, 3, rng);
+			let approx_eq = CwiseMat(ApproxEq::eps() * 1e4);
+
+			let cod = CompOrtho::new(A.as_ref(), 0.0);
+			assert!(cod.rank() == r);
+			assert!(cod.R().shape() == (r, r));
+
+			let N = cod.null_space();
+			assert!(N.shape() == (n, n - r));
+			assert!(N.adjoint() * &N ~ Mat::<c64>::identity(n - r, n - r));
+			assert!(&A * &N ~ Mat::<c64>::zeros(m, n - r));
+
+			let C = cod.column_space();
+			assert!(C.shape() == (m, r));
+			assert!(C.adjoint() * &C ~ Mat::<c64>::identity(r, r));
+			assert!(&C * (C.adjoint() * &A) ~ A);
+
+			let mut solutions = vec![(Conj::No, cod.solve_min_norm(&B))];
+			if m >= n {
+				for conj in [Conj::No, Conj::Yes] {
+					let mut X = B.clone();
+					cod.solve_lstsq_in_place_with_conj(conj, X.as_mut());
+					solutions.push((conj, X.get(..n, ..).to_owned()));
+				}
+			}
+
+			for (conj, X) in &solutions {
+				let A = if *conj == Conj::Yes { A.conjugate().to_owned() } else { A.clone() };
+
+				// the residual is orthogonal to the range of A, and the solution to its null space,
+				// which characterizes the minimum norm solution
+				assert!(A.adjoint() * (&A * X - &B) ~ Mat::<c64>::zeros(n, 3));
+				assert!(A.svd().unwrap().pseudo_inverse(0.0) * &B ~ X);
+
+				// adding any null space component increases the norm
+				let N = if *conj == Conj::Yes { N.conjugate().to_owned() } else { N.clone() };
+				if n > r {
+					let Y = X + &N * randn_c64(n - r, 3, rng);
+					assert!(&A * &Y ~ &A * X);
+					assert!(Y.norm_l2() > X.norm_l2());
+				}
+			}
+		}
+
+		let cod = CompOrtho::new(Mat::<c64>::zeros(4, 3).as_ref(), 1e-10);
+		assert!(all(
+			cod.rank() == 0,
+			cod.null_space().shape() == (3, 3),
+			cod.column_space().shape() == (4, 0)
+		));
+		let mut X = Mat::<c64>::ones(4, 2);
+		cod.solve_lstsq_in_place_with_conj(Conj::No, X.as_mut());
+		assert!(X.get(..3, ..) == Mat::<c64>::zeros(3, 2));
+	}
+
+	#[test]
+	fn test_polar() {
+		let rng = &mut StdRng::seed_from_u64(0);
+
+		for (m, n) in [(10, 10), (15, 6)] {
+			let A = CwiseMatDistribution {
+				nrows: m,
+				ncols: n,
+				dist: ComplexDistribution::new(StandardNormal, StandardNormal),
+			}
+			.rand::<Mat<c64>>(rng);
+
+			let approx_eq = CwiseMat(ApproxEq::eps() * 64.0 * (m as f64));
+			let polar = PolarDecomp::new(A.as_ref()).unwrap();
+			let (U, P) = (polar.U(), polar.P());
+
+			assert!(U.adjoint() * U ~ Mat::<c64>::identity(n, n));
+			assert!(P ~ P.adjoint().to_owned());
+			assert!(P.self_adjoint_eigenvalues(Side::Lower).unwrap().iter().all(|&s| s > 0.0));
+			assert!(polar.reconstruct() ~ A);
+			assert!(U * P ~ A);
+			assert!(linalg::matfun::symmetric_polar_factor(A.as_ref()).unwrap() ~ P);
+
+			if m == n {
+				let B = CwiseMatDistribution {
+					nrows: n,
+					ncols: 3,
+					dist: ComplexDistribution::new(StandardNormal, StandardNormal),
+				}
+				.rand::<Mat<c64>>(rng);
+				let approx_eq = CwiseMat(ApproxEq::eps() * 1e5);
+				assert!(&A * polar.solve(&B) ~ B);
+				assert!(A.conjugate() * polar.solve_conjugate(&B) ~ B);
+				assert!(A.transpose() * polar.solve_transpose(&B) ~ B);
+				assert!(A.adjoint() * polar.solve_adjoint(&B) ~ B);
+				assert!(polar.inverse() * &A ~ Mat::<c64>::identity(n, n));
+			}
+		}
+	}
+
+	#[test]
+	fn test_polar_singular() {
+		let A = mat![[0.0, 2.0], [0.0, 0.0f64]];
+		let polar = PolarDecomp::new(A.as_ref()).unwrap();
+
+		let approx_eq = CwiseMat(ApproxEq::eps() * 64.0);
+		assert!(polar.reconstruct() ~ A);
+		// singular systems don't panic, and produce non-finite values
+		let X = polar.solve(Mat::<f64>::ones(2, 1));
+		assert!(X.col(0).iter().any(|x| !x.is_finite()));
+	}
+
+	#[test]
+	#[should_panic(expected = "the polar decomposition can only solve square systems")]
+	fn test_polar_tall_solve() {
+		let A = Mat::<f64>::identity(5, 3);
+		PolarDecomp::new(A.as_ref()).unwrap().solve(Mat::<f64>::ones(5, 2));
+	}
+
+	#[test]
+	fn test_geigen() {
+		let n = 10;
+		let approx_eq = CwiseMat(ApproxEq::eps() * 1e4);
+
+		// chain of unit springs with fixed ends and masses m
+		let K = Mat::<f64>::from_fn(n, n, |i, j| {
+			if i == j {
+				2.0
+			} else if i.abs_diff(j) == 1 {
+				-1.0
+			} else {
+				0.0
+			}
+		});
+		let m = 3.0;
+		let M = Mat::<f64>::from_fn(n, n, |i, j| if i == j { m } else { 0.0 });
+
+		let evd = GEigen::new(K.as_ref(), M.as_ref(), Side::Lower).unwrap();
+		let (U, S) = (evd.eigenvectors(), evd.eigenvalues());
+
+		let expected = Col::<f64>::from_fn(n, |j| {
+			let s = ((j + 1) as f64 * core::f64::consts::PI / (2.0 * (n + 1) as f64)).sin();
+			4.0 * s * s / m
+		});
+		assert!(S.column_vector() ~ expected);
+		assert!(U.transpose() * &M * U ~ Mat::<f64>::identity(n, n));
+		assert!(&K * U ~ &M * U * S);
+
+		// random masses, and a random complex pencil read from the upper triangle
+		let rng = &mut StdRng::seed_from_u64(0);
+		let M = Mat::<f64>::from_fn(n, n, |i, j| if i == j { 1.0 + (i % 3) as f64 } else { 0.0 });
+		let evd = GEigen::new(K.as_ref(), M.as_ref(), Side::Upper).unwrap();
+		let (U, S) = (evd.eigenvectors(), evd.eigenvalues());
+		assert!(U.transpose() * &M * U ~ Mat::<f64>::identity(n, n));
+		assert!(&K * U ~ &M * U * S);
+
+		let X = CwiseMatDistribution {
+			nrows: n,
+			ncols: n,
+			dist: ComplexDistribution::new(StandardNormal, StandardNormal),
+		}
+		.rand::<Mat<c64>>(rng);
+		let Y = CwiseMatDistribution {
+			nrows: n,
+			ncols: n,
+			dist: ComplexDistribution::new(StandardNormal, StandardNormal),
+		}
+		.rand::<Mat<c64>>(rng);
+		let A = &X + X.adjoint();
+		let B = &Y * Y.adjoint() + Mat::<c64>::identity(n, n);
+
+		let evd = GEigen::new(A.as_ref(), B.as_ref(), Side::Upper).unwrap();
+		let U = evd.eigenvectors();
+		let S = Col::<c64>::from_fn(n, |i| c64::new(evd.eigenvalues()[i], 0.0)).into_diagonal();
+		assert!(U.adjoint() * &B * U ~ Mat::<c64>::identity(n, n));
+		assert!(&A * U ~ &B * U * S);
+
+		let B = Mat::<f64>::from_fn(n, n, |i, j| if i == j { if i == 3 { -1.0 } else { 1.0 } } else { 0.0 });
+		assert!(matches!(
+			GEigen::new(K.as_ref(), B.as_ref(), Side::Lower),
+			Err(GEigenError::Llt(LltError::NonPositivePivot { index: 3 }))
+		));
+	}
+
+	#[test]
+	fn test_self_adjoint_eigen_rank_one_update() {
+		let rng = &mut StdRng::seed_from_u64(0);
+		let n = 20;
+
+		// rank deficient, so that the zero eigenvalue is repeated
+		let A = randn_c64(n, n - 5, rng);
+		let H = &A * A.adjoint();
+
+		let approx_eq = CwiseMat(ApproxEq::eps() * 1024.0 * (n as f64));
+
+		for rho in [1.5, -0.75] {
+			let v = randn_c64(n, 1, rng);
+			let v = v.col(0);
+
+			let mut evd = SelfAdjointEigen::new(H.as_ref(), Side::Lower).unwrap();
+			evd.rank_one_update(v, rho);
+
+			let H_new = &H + Scale(c64::new(rho, 0.0)) * v * v.adjoint();
+			let fresh = SelfAdjointEigen::new(H_new.as_ref(), Side::Lower).unwrap();
+
+			assert!(evd.S().column_vector().as_mat() ~ fresh.S().column_vector().as_mat());
+			assert!(evd.U() * evd.S() * evd.U().adjoint() ~ H_new);
+			assert!(evd.U().adjoint() * evd.U() ~ Mat::<c64>::identity(n, n));
+		}
+
+		// v is an eigenvector, so that every other direction is deflated
+		let H = crate::mat![[2.0, 1.0, 0.0], [1.0, 2.0, 0.0], [0.0, 0.0, 3.0f64]];
+		let v = crate::col![1.0, 1.0, 0.0f64];
+		let mut evd = SelfAdjointEigen::new(H.as_ref(), Side::Lower).unwrap();
+		evd.rank_one_update(v.as_ref(), 0.5);
 
-		out
+		let S = evd.S().column_vector();
+		assert!(all((S[0] - 1.0).abs() < 1e-12, (S[1] - 3.0).abs() < 1e-12, (S[2] - 4.0).abs() < 1e-12));
+		let H_new = &H + Scale(0.5) * &v * v.transpose();
+		assert!(evd.U() * evd.S() * evd.U().transpose() ~ H_new);
 	}
 
-	#[track_caller]
-	fn inverse(&self) -> Mat<T> {
-		let par = get_global_parallelism();
+	#[test]
+	fn test_qr_hat_diagonal() {
+		let rng = &mut StdRng::seed_from_u64(0);
+		let (m, n) = (30, 6);
 
-		assert!(self.nrows() == self.ncols());
+		let A = CwiseMatDistribution {
+			nrows: m,
+			ncols: n,
+			dist: ComplexDistribution::new(StandardNormal, StandardNormal),
+		}
+		.rand::<Mat<c64>>(rng);
 
-		let n = self.ncols();
+		let h = A.qr().hat_diagonal();
+		let H = &A * (A.adjoint() * &A).partial_piv_lu().inverse() * A.adjoint();
 
-		let mut out = Mat::zeros(n, n);
+		assert!((h.sum() - n as f64).abs() < 1e-10);
+		for i in 0..m {
+			assert!((h[i] - H[(i, i)].re).abs() < 1e-10);
+			assert!(all(h[i] >= 0.0, h[i] <= 1.0 + 1e-10));
+		}
+	}
 
-		linalg::lu::full_pivoting::inverse::inverse(
-			out.as_mut(),
-			self.L(),
-			self.U(),
-			self.P(),
-			self.Q(),
-			par,
-			MemStack::new(&mut MemBuffer::new(linalg::lu::full_pivoting::inverse::inverse_scratch::<usize, T>(
-				n, par,
-			))),
-		);
+	#[test]
+	fn test_solve_blocked() {
+		let rng = &mut StdRng::seed_from_u64(0);
+		let n = 50;
+		let k = 300;
 
-		out
+		let A = CwiseMatDistribution {
+			nrows: n,
+			ncols: n,
+			dist: StandardNormal,
+		}
+		.rand::<Mat<f64>>(rng);
+		let B = CwiseMatDistribution {
+			nrows: n,
+			ncols: k,
+			dist: StandardNormal,
+		}
+		.rand::<Mat<f64>>(rng);
+
+		let approx_eq = CwiseMat(ApproxEq::eps() * 16.0 * (n as f64));
+
+		let lu = PartialPivLu::new(A.as_ref());
+		let X = lu.solve(&B);
+		for block in [1, 7, 64, k, 2 * k] {
+			let mut Y = B.clone();
+			lu.solve_blocked_in_place(&mut Y, block);
+			assert!(Y ~ X);
+		}
 	}
-}
 
-impl<T: ComplexField> SolveCore<T> for Qr<T> {
-	#[track_caller]
-	fn solve_in_place_with_conj(&self, conj: Conj, rhs: MatMut<'_, T>) {
-		let par = get_global_parallelism();
+	#[test]
+	fn test_solve_scaled() {
+		let rng = &mut StdRng::seed_from_u64(0);
+		let n = 30;
 
-		assert!(all(self.nrows() == self.ncols(), self.nrows() == rhs.nrows(),));
+		let A = randn_c64(n, n, rng);
+		let H = &A * A.adjoint() + Mat::<c64>::identity(n, n);
+		let B = randn_c64(n, 4, rng);
+		let d = Col::<f64>::from_fn(n, |i| 0.5 + (i % 5) as f64 * 0.75);
+		let D = Col::<c64>::from_fn(n, |i| c64::from(d[i]));
 
-		let n = self.nrows();
-		let blocksize = self.Q_coeff().nrows();
-		let k = rhs.ncols();
+		let approx_eq = CwiseMat(ApproxEq::eps() * 1e3);
 
-		linalg::qr::no_pivoting::solve::solve_in_place_with_conj(
-			self.Q_basis(),
-			self.Q_coeff(),
-			self.R(),
-			conj,
-			rhs,
-			par,
-			MemStack::new(&mut MemBuffer::new(linalg::qr::no_pivoting::solve::solve_in_place_scratch::<T>(
-				n, blocksize, k, par,
-			))),
-		);
+		let DAD = D.as_diagonal() * &A * D.as_diagonal();
+		let mut X = B.clone();
+		A.partial_piv_lu().solve_scaled_in_place(d.as_diagonal(), &mut X);
+		assert!(X ~ DAD.partial_piv_lu().solve(&B));
+
+		let DHD = D.as_diagonal() * &H * D.as_diagonal();
+		let mut X = B.clone();
+		H.llt(Side::Lower).unwrap().solve_scaled_in_place(d.as_diagonal(), &mut X);
+		assert!(X ~ DHD.llt(Side::Lower).unwrap().solve(&B));
 	}
 
-	#[track_caller]
-	fn solve_transpose_in_place_with_conj(&self, conj: Conj, rhs: MatMut<'_, T>) {
-		let par = get_global_parallelism();
+	#[test]
+	fn test_trace_solve() {
+		let rng = &mut StdRng::seed_from_u64(0);
+		let n = 20;
 
-		assert!(all(self.nrows() == self.ncols(), self.ncols() == rhs.nrows(),));
+		let A = randn_c64(n, n, rng);
+		let B = randn_c64(n, n, rng);
+		let H = &A * A.adjoint() + Mat::<c64>::identity(n, n);
 
-		let n = self.nrows();
-		let blocksize = self.Q_coeff().nrows();
-		let k = rhs.ncols();
+		let lu = PartialPivLu::new(A.as_ref());
+		let expected = (lu.inverse() * &B).diagonal().column_vector().sum();
+		assert!((lu.trace_solve(B.as_ref()) - expected).l1_norm() <= 1e-10 * expected.l1_norm());
 
-		linalg::qr::no_pivoting::solve::solve_transpose_in_place_with_conj(
-			self.Q_basis(),
-			self.Q_coeff(),
-			self.R(),
-			conj,
-			rhs,
-			par,
-			MemStack::new(&mut MemBuffer::new(
-				linalg::qr::no_pivoting::solve::solve_transpose_in_place_scratch::<T>(n, blocksize, k, par),
-			)),
-		);
+		let llt = Llt::new(H.as_ref(), Side::Lower).unwrap();
+		let expected = (llt.inverse() * &B).diagonal().column_vector().sum();
+		assert!((llt.trace_solve(B.as_ref()) - expected).l1_norm() <= 1e-10 * expected.l1_norm());
 	}
-}
 
-impl<T: ComplexField> SolveLstsqCore<T> for Qr<T> {
-	#[track_caller]
-	fn solve_lstsq_in_place_with_conj(&self, conj: Conj, rhs: MatMut<'_, T>) {
-		let par = get_global_parallelism();
+	#[test]
+	fn test_llt_solve_and_logdet() {
+		let rng = &mut StdRng::seed_from_u64(0);
+		let n = 40;
+
+		let B = CwiseMatDistribution {
+			nrows: n,
+			ncols: n,
+			dist: StandardNormal,
+		}
+		.rand::<Mat<f64>>(rng);
+		let A = &B * B.transpose() + Mat::<f64>::identity(n, n);
+		let rhs = CwiseMatDistribution {
+			nrows: n,
+			ncols: 3,
+			dist: StandardNormal,
+		}
+		.rand::<Mat<f64>>(rng);
 
-		assert!(all(self.nrows() == rhs.nrows(), self.nrows() >= self.ncols(),));
+		let llt = Llt::new(A.as_ref(), Side::Lower).unwrap();
 
-		let m = self.nrows();
-		let n = self.ncols();
-		let blocksize = self.Q_coeff().nrows();
-		let k = rhs.ncols();
+		let mut X = rhs.clone();
+		let logdet = llt.solve_and_logdet_in_place(X.as_mut());
 
-		linalg::qr::no_pivoting::solve::solve_lstsq_in_place_with_conj(
-			self.Q_basis(),
-			self.Q_coeff(),
-			self.R(),
-			conj,
-			rhs,
-			par,
-			MemStack::new(&mut MemBuffer::new(linalg::qr::no_pivoting::solve::solve_lstsq_in_place_scratch::<T>(
-				m, n, blocksize, k, par,
-			))),
-		);
+		assert!(X == llt.solve(&rhs));
+		assert!((logdet - A.determinant().ln()).abs() <= 1e-10 * logdet.abs());
 	}
-}
 
-impl<T: ComplexField> DenseSolveCore<T> for Qr<T> {
-	fn reconstruct(&self) -> Mat<T> {
-		let par = get_global_parallelism();
-		let m = self.nrows();
-		let n = self.ncols();
-		let blocksize = self.Q_coeff().nrows();
+	#[test]
+	#[should_panic(expected = "the singular vectors were not computed")]
+	fn test_svd_values_only_solve() {
+		let A = Mat::<f64>::identity(4, 4);
+		let svd = Svd::builder().vectors(ComputeSvdVectors::No).build(A.as_ref()).unwrap();
+		svd.solve(Mat::<f64>::ones(4, 2));
+	}
 
-		let mut out = Mat::zeros(m, n);
+	#[test]
+	#[should_panic(expected = "the singular vectors were not computed")]
+	fn test_svd_values_only_soft_threshold() {
+		let A = Mat::<f64>::identity(4, 4);
+		let svd = Svd::builder().vectors(ComputeSvdVectors::No).build(A.as_ref()).unwrap();
+		svd.soft_threshold(0.5);
+	}
 
-		linalg::qr::no_pivoting::reconstruct::reconstruct(
-			out.as_mut(),
-			self.Q_basis(),
-			self.Q_coeff(),
-			self.R(),
-			par,
-			MemStack::new(&mut MemBuffer::new(linalg::qr::no_pivoting::reconstruct::reconstruct_scratch::<T>(
-				m, n, blocksize, par,
-			))),
-		);
+	#[test]
+	#[should_panic(expected = "the decomposition is truncated")]
+	fn test_svd_truncated_solve() {
+		let A = Mat::<f64>::identity(4, 4);
+		let svd = Svd::new_truncated(A.as_ref(), 2, Par::Seq).unwrap();
+		svd.solve(Mat::<f64>::ones(4, 2));
+	}
 
-		out
+	#[test]
+	#[should_panic(expected = "the eigenvectors were not computed")]
+	fn test_self_adjoint_eigen_values_only_solve() {
+		let A = Mat::<f64>::identity(4, 4);
+		let evd = SelfAdjointEigen::new_values_only(A.as_ref(), Side::Lower).unwrap();
+		evd.solve(Mat::<f64>::ones(4, 2));
 	}
 
-	fn inverse(&self) -> Mat<T> {
-		let par = get_global_parallelism();
-		assert!(self.nrows() == self.ncols());
+	#[test]
+	fn test_self_adjoint_eigen_trace_log() {
+		let rng = &mut StdRng::seed_from_u64(0);
+		let n = 30;
 
-		let n = self.ncols();
-		let blocksize = self.Q_coeff().nrows();
+		let B = CwiseMatDistribution {
+			nrows: n,
+			ncols: n,
+			dist: ComplexDistribution::new(StandardNormal, StandardNormal),
+		}
+		.rand::<Mat<c64>>(rng);
+		let A = &B * B.adjoint() + Mat::<c64>::identity(n, n);
 
-		let mut out = Mat::zeros(n, n);
+		let llt = Llt::new(A.as_ref(), Side::Lower).unwrap();
+		let logdet = llt.log_abs_det().0;
 
-		linalg::qr::no_pivoting::inverse::inverse(
-			out.as_mut(),
-			self.Q_basis(),
-			self.Q_coeff(),
-			self.R(),
-			par,
-			MemStack::new(&mut MemBuffer::new(linalg::qr::no_pivoting::inverse::inverse_scratch::<T>(
-				n, blocksize, par,
-			))),
-		);
+		let evd = SelfAdjointEigen::new_values_only(A.as_ref(), Side::Lower).unwrap();
+		assert!((evd.trace_log().unwrap() - logdet).abs() <= 1e-10 * logdet.abs());
 
-		out
+		let A = &A - Mat::<c64>::identity(n, n) * Scale(c64::new(evd.S()[n / 2].re, 0.0) + 1e-3);
+		let evd = SelfAdjointEigen::new(A.as_ref(), Side::Lower).unwrap();
+		assert!(evd.trace_log() == Err(TraceLogError::NonPositiveEigenvalue { index: 0 }));
 	}
-}
 
-impl<T: ComplexField> SolveCore<T> for ColPivQr<T> {
-	#[track_caller]
-	fn solve_in_place_with_conj(&self, conj: Conj, rhs: MatMut<'_, T>) {
-		let par = get_global_parallelism();
+	#[test]
+	fn test_llt_rank_k_update() {
+		let rng = &mut StdRng::seed_from_u64(0);
+		let (n, k) = (50, 7);
 
-		assert!(all(self.nrows() == self.ncols(), self.nrows() == rhs.nrows(),));
+		let approx_eq = CwiseMat(ApproxEq::eps() * 128.0 * (n as f64));
 
-		let n = self.nrows();
-		let blocksize = self.Q_coeff().nrows();
-		let k = rhs.ncols();
+		let B = randn_c64(n, n, rng);
+		let A = &B * B.adjoint() + Mat::<c64>::identity(n, n);
+		let U = randn_c64(n, k, rng);
 
-		linalg::qr::col_pivoting::solve::solve_in_place_with_conj(
-			self.Q_basis(),
-			self.Q_coeff(),
-			self.R(),
-			self.P(),
-			conj,
-			rhs,
-			par,
-			MemStack::new(&mut MemBuffer::new(linalg::qr::col_pivoting::solve::solve_in_place_scratch::<usize, T>(
-				n, blocksize, k, par,
-			))),
-		);
-	}
+		let llt = Llt::new(A.as_ref(), Side::Lower).unwrap();
 
-	#[track_caller]
-	fn solve_transpose_in_place_with_conj(&self, conj: Conj, rhs: MatMut<'_, T>) {
-		let par = get_global_parallelism();
+		let mut blocked = llt.clone();
+		blocked.rank_k_update(U.as_ref()).unwrap();
 
-		assert!(all(self.nrows() == self.ncols(), self.ncols() == rhs.nrows(),));
+		let mut sequential = llt.clone();
+		for j in 0..k {
+			sequential.rank_k_update(U.get(.., j..j + 1)).unwrap();
+		}
 
-		let n = self.nrows();
-		let blocksize = self.Q_coeff().nrows();
-		let k = rhs.ncols();
+		assert!(blocked.L() ~ sequential.L());
+		assert!(blocked.reconstruct() ~ &A + &U * U.adjoint());
 
-		linalg::qr::col_pivoting::solve::solve_transpose_in_place_with_conj(
-			self.Q_basis(),
-			self.Q_coeff(),
-			self.R(),
-			self.P(),
-			conj,
-			rhs,
-			par,
-			MemStack::new(&mut MemBuffer::new(linalg::qr::col_pivoting::solve::solve_transpose_in_place_scratch::<
-				usize,
-				T,
-			>(n, blocksize, k, par))),
-		);
+		blocked.rank_k_downdate(U.as_ref()).unwrap();
+		assert!(blocked.L() ~ llt.L());
+
+		// removing too much makes the matrix indefinite
+		let mut indefinite = llt.clone();
+		let U = Scale(c64::new(1e3, 0.0)) * &U;
+		assert!(indefinite.rank_k_downdate(U.as_ref()).is_err());
+		assert!(indefinite.L() == llt.L());
 	}
-}
 
-impl<T: ComplexField> SolveLstsqCore<T> for ColPivQr<T> {
-	#[track_caller]
-	fn solve_lstsq_in_place_with_conj(&self, conj: Conj, rhs: MatMut<'_, T>) {
-		let par = get_global_parallelism();
+	#[test]
+	fn test_llt_rank1_update() {
+		let rng = &mut StdRng::seed_from_u64(0);
+		let n = 40;
 
-		assert!(all(self.nrows() == rhs.nrows(), self.nrows() >= self.ncols(),));
+		let approx_eq = CwiseMat(ApproxEq::eps() * 128.0 * (n as f64));
 
-		let m = self.nrows();
-		let n = self.ncols();
-		let blocksize = self.Q_coeff().nrows();
-		let k = rhs.ncols();
+		let B = randn_c64(n, n, rng);
+		let mut A = &B * B.adjoint() + Mat::<c64>::identity(n, n);
+		let V = randn_c64(n, 10, rng);
 
-		linalg::qr::col_pivoting::solve::solve_lstsq_in_place_with_conj(
-			self.Q_basis(),
-			self.Q_coeff(),
-			self.R(),
-			self.P(),
-			conj,
-			rhs,
-			par,
-			MemStack::new(&mut MemBuffer::new(linalg::qr::col_pivoting::solve::solve_lstsq_in_place_scratch::<
-				usize,
-				T,
-			>(m, n, blocksize, k, par))),
-		);
+		let llt = Llt::new(A.as_ref(), Side::Lower).unwrap();
+		let mut updated = llt.clone();
+		for v in V.col_iter() {
+			updated.rank1_update(v);
+			A = &A + v * v.adjoint();
+		}
+		assert!(updated.L() ~ Llt::new(A.as_ref(), Side::Lower).unwrap().L());
+
+		for v in V.col_iter().rev() {
+			updated.rank1_downdate(v).unwrap();
+		}
+		assert!(updated.L() ~ llt.L());
+
+		// removing too much makes the matrix indefinite
+		let v = Scale(c64::new(1e3, 0.0)) * V.col(0);
+		assert!(updated.rank1_downdate(v.as_ref()).is_err());
+		assert!(updated.L() ~ llt.L());
 	}
-}
 
-impl<T: ComplexField> DenseSolveCore<T> for ColPivQr<T> {
-	fn reconstruct(&self) -> Mat<T> {
-		let par = get_global_parallelism();
-		let m = self.nrows();
-		let n = self.ncols();
-		let blocksize = self.Q_coeff().nrows();
+	#[test]
+	fn test_llt_modified() {
+		let rng = &mut StdRng::seed_from_u64(0);
+		let n = 20;
+		let approx_eq = CwiseMat(ApproxEq::eps() * 1e4);
+
+		let B = CwiseMatDistribution {
+			nrows: n,
+			ncols: n,
+			dist: ComplexDistribution::new(StandardNormal, StandardNormal),
+		}
+		.rand::<Mat<c64>>(rng);
+		let rhs = CwiseMatDistribution {
+			nrows: n,
+			ncols: 2,
+			dist: ComplexDistribution::new(StandardNormal, StandardNormal),
+		}
+		.rand::<Mat<c64>>(rng);
 
-		let mut out = Mat::zeros(m, n);
+		// positive definite and well conditioned
+		let A = &B * B.adjoint() + Scale(c64::new(n as f64, 0.0)) * Mat::<c64>::identity(n, n);
+		for side in [Side::Lower, Side::Upper] {
+			let (llt, E) = Llt::new_modified(A.as_ref(), side);
+			assert!(E.column_vector() == Col::<f64>::zeros(n));
+			assert!(llt.L() ~ Llt::new(A.as_ref(), side).unwrap().L());
+			assert!(&A * llt.solve(&rhs) ~ rhs);
+		}
 
-		linalg::qr::col_pivoting::reconstruct::reconstruct(
-			out.as_mut(),
-			self.Q_basis(),
-			self.Q_coeff(),
-			self.R(),
-			self.P(),
-			par,
-			MemStack::new(&mut MemBuffer::new(
-				linalg::qr::col_pivoting::reconstruct::reconstruct_scratch::<usize, T>(m, n, blocksize, par),
-			)),
-		);
+		// one slightly negative eigenvalue
+		let Q = B.qr().compute_Q();
+		let S = Col::from_fn(n, |i| if i == 0 { -1e-3 } else { 1.0 + i as f64 });
+		let A = &Q * Mat::from_fn(n, n, |i, j| if i == j { c64::new(S[i], 0.0) } else { c64::new(0.0, 0.0) }) * Q.adjoint();
+		assert!(Llt::new(A.as_ref(), Side::Lower).is_err());
 
-		out
+		let (llt, E) = Llt::new_modified(A.as_ref(), Side::Lower);
+		let AE = Mat::from_fn(n, n, |i, j| if i == j { A[(i, j)] + E[i] } else { A[(i, j)] });
+		for i in 0..n {
+			assert!(E[i] >= 0.0);
+		}
+		assert!(llt.reconstruct() ~ AE);
+		assert!(&AE * llt.solve(&rhs) ~ rhs);
 	}
 
-	fn inverse(&self) -> Mat<T> {
-		let par = get_global_parallelism();
-		assert!(self.nrows() == self.ncols());
+	#[test]
+	fn test_llt_factor_in_place() {
+		let rng = &mut StdRng::seed_from_u64(0);
+		let n = 50;
+		let k = 3;
 
-		let n = self.ncols();
-		let blocksize = self.Q_coeff().nrows();
+		let approx_eq = CwiseMat(ApproxEq::eps() * 128.0 * (n as f64));
 
-		let mut out = Mat::zeros(n, n);
+		let B = randn_c64(n, n, rng);
+		let A = &B * B.adjoint() + Mat::<c64>::identity(n, n);
+		let rhs = randn_c64(n, k, rng);
 
-		linalg::qr::col_pivoting::inverse::inverse(
-			out.as_mut(),
-			self.Q_basis(),
-			self.Q_coeff(),
-			self.R(),
-			self.P(),
-			par,
-			MemStack::new(&mut MemBuffer::new(linalg::qr::col_pivoting::inverse::inverse_scratch::<usize, T>(
-				n, blocksize, par,
-			))),
-		);
+		for side in [Side::Lower, Side::Upper] {
+			let llt = Llt::new(A.as_ref(), side).unwrap();
 
-		out
+			let mut A_copy = A.clone();
+			let llt_ref = Llt::factor_in_place(A_copy.as_mut(), side).unwrap();
+
+			assert!(llt_ref.solve(&rhs) ~ llt.solve(&rhs));
+			assert!(llt_ref.solve_transpose(&rhs) ~ llt.solve_transpose(&rhs));
+			assert!(&A * llt_ref.solve(&rhs) ~ rhs);
+		}
+
+		let mut indefinite = -Mat::<c64>::identity(n, n);
+		assert!(Llt::factor_in_place(indefinite.as_mut(), Side::Lower).is_err());
 	}
-}
 
-impl<T: ComplexField> SolveCore<T> for Svd<T> {
-	#[track_caller]
-	fn solve_in_place_with_conj(&self, conj: Conj, rhs: MatMut<'_, T>) {
-		let par = get_global_parallelism();
+	#[test]
+	fn test_solve_refined() {
+		let n = 5;
+		let hilbert = Mat::<f64>::from_fn(n, n, |i, j| 1.0 / (i + j + 1) as f64);
+		let x = Mat::<f64>::from_fn(n, 2, |i, j| (i as f64 + 1.0) * if j == 0 { 1.0 } else { -0.5 });
+		let b = &hilbert * &x;
 
-		assert!(all(self.nrows() == self.ncols(), self.nrows() == rhs.nrows(),));
+		let hilbert_f32 = Mat::<f32>::from_fn(n, n, |i, j| hilbert[(i, j)] as f32);
+		let b_f32 = Mat::<f32>::from_fn(n, 2, |i, j| b[(i, j)] as f32);
+		let rel_err = |y: &Mat<f64>| (y - &x).norm_l2() / x.norm_l2();
 
-		let mut rhs = rhs;
-		let n = self.nrows();
-		let k = rhs.ncols();
-		let mut tmp = Mat::zeros(n, k);
+		// the condition number of the 5x5 hilbert matrix is about 4.8e5, so an f32 solve only has a
+		// few correct digits, while refinement in f64 should reach about 4.8e5 * 2.2e-16
+		let tol = 4.8e5 * f64::EPSILON;
 
-		linalg::matmul::matmul_with_conj(
-			tmp.as_mut(),
-			Accum::Replace,
-			self.U().transpose(),
-			conj.compose(Conj::Yes),
-			rhs.as_ref(),
-			Conj::No,
-			one(),
-			par,
-		);
+		let lu = hilbert_f32.partial_piv_lu();
+		let plain = lu.solve(&b_f32);
+		let plain = Mat::<f64>::from_fn(n, 2, |i, j| plain[(i, j)] as f64);
+		assert!(rel_err(&plain) > 1e3 * tol);
+		assert!(rel_err(&lu.solve_refined(hilbert.as_ref(), b.as_ref(), 0)) == rel_err(&plain));
+		assert!(rel_err(&lu.solve_refined(hilbert.as_ref(), b.as_ref(), 3)) < tol);
 
-		for j in 0..k {
-			for i in 0..n {
-				let s = recip(&real(&self.S()[i]));
-				tmp[(i, j)] = mul_real(&tmp[(i, j)], &s);
-			}
-		}
+		let llt = hilbert_f32.llt(Side::Lower).unwrap();
+		assert!(rel_err(&llt.solve_refined(hilbert.as_ref(), b.as_ref(), 3)) < tol);
 
-		linalg::matmul::matmul_with_conj(rhs.as_mut(), Accum::Replace, self.V(), conj, tmp.as_ref(), Conj::No, one(), par);
+		// same precision refinement doesn't hurt
+		let lu = hilbert.partial_piv_lu();
+		assert!(rel_err(&lu.solve_refined(hilbert.as_ref(), b.as_ref(), 2)) < tol);
 	}
 
-	#[track_caller]
-	fn solve_transpose_in_place_with_conj(&self, conj: Conj, rhs: MatMut<'_, T>) {
-		let par = get_global_parallelism();
+	#[test]
+	fn test_lu_solve_with_stats() {
+		let rng = &mut StdRng::seed_from_u64(0);
+		let n = 50;
+		let k = 3;
 
-		assert!(all(self.nrows() == self.ncols(), self.ncols() == rhs.nrows(),));
+		let approx_eq = CwiseMat(ApproxEq::eps() * 128.0 * (n as f64));
 
-		let mut rhs = rhs;
-		let n = self.nrows();
-		let k = rhs.ncols();
-		let mut tmp = Mat::zeros(n, k);
+		let A = randn_c64(n, n, rng) + Scale(c64::new(2.0 * n as f64, 0.0)) * Mat::<c64>::identity(n, n);
+		let B = randn_c64(n, k, rng);
+		let lu = PartialPivLu::new(A.as_ref());
 
-		linalg::matmul::matmul_with_conj(
-			tmp.as_mut(),
-			Accum::Replace,
-			self.V().transpose(),
-			conj,
-			rhs.as_ref(),
-			Conj::No,
-			one(),
-			par,
-		);
+		let mut X = B.clone();
+		let stats = lu.solve_with_stats(A.as_ref(), X.as_mut(), 5);
+		assert!(stats.final_residual <= stats.initial_residual);
+		assert!(stats.converged);
+		assert!(stats.iterations <= 5);
+		assert!(&A * &X ~ B);
 
-		for j in 0..k {
-			for i in 0..n {
-				let s = recip(&real(&self.S()[i]));
-				tmp[(i, j)] = mul_real(&tmp[(i, j)], &s);
+		let mut X = B.clone();
+		let stats = lu.solve_with_stats(A.as_ref(), X.as_mut(), 0);
+		assert!(stats.iterations == 0);
+		assert!(stats.final_residual == stats.initial_residual);
+		assert!(X == lu.solve(&B));
+	}
+
+	#[test]
+	fn test_det() {
+		let rng = &mut StdRng::seed_from_u64(0);
+		let approx_eq = ApproxEq::<f64>::eps() * 64.0;
+
+		for n in [0, 1, 2, 3, 4] {
+			let A = CwiseMatDistribution {
+				nrows: n,
+				ncols: n,
+				dist: ComplexDistribution::new(StandardNormal, StandardNormal),
 			}
+			.rand::<Mat<c64>>(rng);
+
+			let lu = PartialPivLu::new(A.as_ref());
+			let det = linalg::det_small(lu.reconstruct().as_ref());
+			assert!(lu.det() ~ det);
+			let (log_abs_det, phase) = lu.log_abs_det();
+			assert!(phase * log_abs_det.exp() ~ det);
+
+			let lu = FullPivLu::new(A.as_ref());
+			let det = linalg::det_small(lu.reconstruct().as_ref());
+			assert!(lu.det() ~ det);
+			let (log_abs_det, phase) = lu.log_abs_det();
+			assert!(phase * log_abs_det.exp() ~ det);
+
+			let H = &A * A.adjoint() + Mat::<c64>::identity(n, n);
+			let llt = Llt::new(H.as_ref(), Side::Lower).unwrap();
+			let det = linalg::det_small(llt.reconstruct().as_ref());
+			assert!(llt.det() ~ det);
+			let (log_abs_det, phase) = llt.log_abs_det();
+			assert!(phase == c64::new(1.0, 0.0));
+			assert!(phase * log_abs_det.exp() ~ det);
 		}
 
-		linalg::matmul::matmul_with_conj(
-			rhs.as_mut(),
-			Accum::Replace,
-			self.U(),
-			conj.compose(Conj::Yes),
-			tmp.as_ref(),
-			Conj::No,
-			one(),
-			par,
-		);
+		// the determinant overflows, but its logarithm doesn't
+		let n = 4;
+		let mut A = Mat::<f64>::zeros(n, n);
+		for i in 0..n {
+			A[(n - 1 - i, i)] = 1e100;
+		}
+		let (log_abs_det, sign) = A.partial_piv_lu().log_abs_det();
+		assert!((log_abs_det - 400.0 * core::f64::consts::LN_10).abs() < 1e-10 * log_abs_det);
+		assert!(sign == 1.0);
+		let (log_abs_det, sign) = A.full_piv_lu().log_abs_det();
+		assert!((log_abs_det - 400.0 * core::f64::consts::LN_10).abs() < 1e-10 * log_abs_det);
+		assert!(sign == 1.0);
+
+		let mut A = Mat::<f64>::identity(3, 3);
+		A[(2, 2)] = 0.0;
+		assert!(A.partial_piv_lu().det() == 0.0);
+		assert!(A.partial_piv_lu().log_abs_det() == (f64::NEG_INFINITY, 0.0));
 	}
-}
 
-impl<T: ComplexField> SolveLstsqCore<T> for Svd<T> {
-	#[track_caller]
-	fn solve_lstsq_in_place_with_conj(&self, conj: Conj, rhs: MatMut<'_, T>) {
-		let par = get_global_parallelism();
+	#[test]
+	fn test_pseudo_inverse() {
+		let rng = &mut StdRng::seed_from_u64(0);
+		let (m, n, r) = (30, 20, 7);
 
-		assert!(all(self.nrows() == rhs.nrows(), self.nrows() >= self.ncols(),));
+		let approx_eq = CwiseMat(ApproxEq::eps() * 128.0 * (m as f64));
 
-		let m = self.nrows();
-		let n = self.ncols();
+		for A in [randn_c64(m, r, rng) * randn_c64(r, n, rng), randn_c64(n, r, rng) * randn_c64(r, m, rng)] {
+			for svd in [Svd::new(A.as_ref()).unwrap(), Svd::new_thin(A.as_ref()).unwrap()] {
+				assert!(svd.numerical_rank(0.0) == r);
+				assert!(svd.numerical_rank(1e100) == 0);
 
-		let size = Ord::min(m, n);
+				let pinv = svd.pseudo_inverse(0.0);
+				assert!(pinv.shape() == (A.ncols(), A.nrows()));
+				assert!(&A * &pinv * &A ~ A);
+				assert!(&pinv * &A * &pinv ~ pinv);
+			}
 
-		let U = self.U().get(.., ..size);
-		let V = self.V().get(.., ..size);
+			let qr = A.col_piv_qr();
+			assert!(qr.numerical_rank(1e-10 * math_utils::abs(&qr.R()[(0, 0)])) == r);
+			assert!(qr.numerical_rank(1e100) == 0);
+		}
 
-		let k = rhs.ncols();
+		let A = randn_c64(n, n, rng);
+		assert!(A.thin_svd().unwrap().pseudo_inverse(0.0) ~ A.partial_piv_lu().inverse());
 
-		let mut rhs = rhs;
-		let mut tmp = Mat::zeros(size, k);
+		let A = Mat::<c64>::zeros(m, n);
+		let svd = A.thin_svd().unwrap();
+		assert!(svd.numerical_rank(0.0) == 0);
+		assert!(svd.pseudo_inverse(0.0) == Mat::<c64>::zeros(n, m));
+		assert!(A.col_piv_qr().numerical_rank(0.0) == 0);
+	}
 
-		linalg::matmul::matmul_with_conj(
-			tmp.as_mut(),
-			Accum::Replace,
-			U.transpose(),
-			conj.compose(Conj::Yes),
-			rhs.as_ref(),
-			Conj::No,
-			one(),
-			par,
-		);
+	#[test]
+	fn test_inverse_columns() {
+		let rng = &mut StdRng::seed_from_u64(0);
+		let n = 40;
 
-		for j in 0..k {
-			for i in 0..size {
-				let s = recip(&real(&self.S()[i]));
-				tmp[(i, j)] = mul_real(&tmp[(i, j)], &s);
-			}
+		let A = CwiseMatDistribution {
+			nrows: n,
+			ncols: n,
+			dist: ComplexDistribution::new(StandardNormal, StandardNormal),
+		}
+		.rand::<Mat<c64>>(rng);
+		let H = &A * A.adjoint() + Mat::<c64>::identity(n, n);
+
+		let approx_eq = CwiseMat(ApproxEq::eps() * 128.0 * (n as f64));
+		let cols = [3, 0, 39, 17, 3];
+
+		let lu = A.partial_piv_lu();
+		let inv = lu.inverse();
+		let X = lu.inverse_columns(&cols);
+		assert!(X.shape() == (n, cols.len()));
+		for (k, &j) in cols.iter().enumerate() {
+			assert!(X.col(k).as_mat() ~ inv.col(j).as_mat());
+		}
+
+		let llt = H.llt(Side::Lower).unwrap();
+		let inv = llt.inverse();
+		let X = llt.inverse_columns(&cols);
+		for (k, &j) in cols.iter().enumerate() {
+			assert!(X.col(k).as_mat() ~ inv.col(j).as_mat());
 		}
 
-		linalg::matmul::matmul_with_conj(rhs.as_mut(), Accum::Replace, V, conj, tmp.as_ref(), Conj::No, one(), par);
+		assert!(lu.inverse_columns(&[]).shape() == (n, 0));
 	}
-}
 
-impl<T: ComplexField> DenseSolveCore<T> for Svd<T> {
-	fn reconstruct(&self) -> Mat<T> {
-		let par = get_global_parallelism();
-		let m = self.nrows();
-		let n = self.ncols();
+	#[test]
+	fn test_condition_number_estimate() {
+		let rng = &mut StdRng::seed_from_u64(0);
 
-		let size = Ord::min(m, n);
+		for n in [1, 2, 10, 40] {
+			for log_cond in [0.0, 4.0, 8.0] {
+				// graded singular values
+				let U = randn_c64(n, n, rng).qr().compute_Q();
+				let V = randn_c64(n, n, rng).qr().compute_Q();
+				let sigma = Col::from_fn(n, |i| 10.0f64.powf(-log_cond * i as f64 / Ord::max(n - 1, 1) as f64));
+				let S = Col::from_fn(n, |i| c64::from(sigma[i]));
+				let A = &U * S.as_diagonal() * V.adjoint();
+				let H = &U * S.as_diagonal() * U.adjoint();
 
-		let U = self.U().get(.., ..size);
-		let V = self.V().get(.., ..size);
-		let S = self.S();
+				let exact_2 = sigma[0] / sigma[n - 1];
+				assert!((A.svd().unwrap().condition_number() - exact_2).abs() <= 1e-6 * exact_2);
 
-		let mut UxS = Mat::zeros(m, size);
-		for j in 0..size {
-			let s = real(&S[j]);
-			for i in 0..m {
-				UxS[(i, j)] = mul_real(&U[(i, j)], &s);
+				let exact_1 = |A: &Mat<c64>| linalg::condition::condition_number_1(A.as_ref(), A.partial_piv_lu().inverse().as_ref());
+				let within = |est: f64, exact: f64| est <= exact * (1.0 + 1e-6) && est >= exact / 100.0;
+
+				let exact = exact_1(&A);
+				assert!(within(A.partial_piv_lu().condition_number_estimate(), exact));
+				assert!(within(A.qr().condition_number_estimate(), exact));
+
+				let exact = exact_1(&H);
+				assert!(within(H.llt(Side::Lower).unwrap().condition_number_estimate(), exact));
 			}
 		}
 
-		let mut out = Mat::zeros(m, n);
-
-		linalg::matmul::matmul(out.as_mut(), Accum::Replace, UxS.as_ref(), V.adjoint(), one(), par);
+		let A = mat![[1.0, 0.0], [0.0, 0.0f64]];
+		assert!(A.svd().unwrap().condition_number() == f64::INFINITY);
 
-		out
+		let A = Mat::<f64>::zeros(0, 0);
+		assert!(A.svd().unwrap().condition_number() == 0.0);
+		assert!(A.partial_piv_lu().condition_number_estimate() == 0.0);
+		assert!(A.qr().condition_number_estimate() == 0.0);
+		assert!(A.llt(Side::Lower).unwrap().condition_number_estimate() == 0.0);
 	}
 
-	#[track_caller]
-	fn inverse(&self) -> Mat<T> {
-		let par = get_global_parallelism();
+	#[test]
+	fn test_column_importance_order() {
+		let rng = &mut StdRng::seed_from_u64(0);
+		let (m, n) = (30, 8);
 
-		assert!(self.nrows() == self.ncols());
-		let n = self.nrows();
+		let mut A = CwiseMatDistribution {
+			nrows: m,
+			ncols: n,
+			dist: StandardNormal,
+		}
+		.rand::<Mat<f64>>(rng);
 
-		let U = self.U();
-		let V = self.V();
-		let S = self.S();
+		// the fifth column dominates
+		for i in 0..m {
+			A[(i, 4)] *= 100.0;
+		}
 
-		let mut VxS = Mat::zeros(n, n);
-		for j in 0..n {
-			let s = recip(&real(&S[j]));
+		let order = A.column_importance_order();
+		assert!(order.len() == n);
+		assert!(order[0] == 4);
 
-			for i in 0..n {
-				VxS[(i, j)] = mul_real(&V[(i, j)], &s);
+		let mut sorted = order.clone();
+		sorted.sort();
+		assert!(sorted == (0..n).collect::<Vec<_>>());
+		assert!(order == A.col_piv_qr().P().arrays().0);
+	}
+
+	#[test]
+	fn test_svd_truncated() {
+		let rng = &mut StdRng::seed_from_u64(0);
+		let (m, n) = (60, 40);
+
+		let unitary = |dim: usize, rng: &mut StdRng| -> Mat<c64> {
+			UnitaryMat {
+				dim,
+				standard_normal: ComplexDistribution::new(StandardNormal, StandardNormal),
+			}
+			.rand(rng)
+		};
+		let U = unitary(m, rng);
+		let V = unitary(n, rng);
+		let sigma = Col::<f64>::from_fn(n, |i| 0.7f64.powi(i as i32));
+		let A = U.get(.., ..n) * Col::<c64>::from_fn(n, |i| c64::from(sigma[i])).as_diagonal() * V.adjoint();
+
+		for k in [1, 5, 12] {
+			let svd = Svd::new_truncated(A.as_ref(), k, Par::Seq).unwrap();
+			assert!(all(svd.U().shape() == (m, k), svd.V().shape() == (n, k), svd.S().dim() == k));
+
+			let approx = svd.U() * svd.S() * svd.V().adjoint();
+			let err = &A - &approx;
+
+			// eckart-young: the best rank k approximation has spectral error sigma_{k + 1} and
+			// frobenius error sqrt(sum_{i > k} sigma_i^2)
+			let spectral = err.singular_values().unwrap()[0];
+			let frobenius = err.norm_l2();
+			let optimal_frobenius = sigma.get(k..).norm_l2();
+			assert!(spectral >= sigma[k] * (1.0 - 1e-10));
+			assert!(spectral <= sigma[k] * 1.01);
+			assert!(frobenius >= optimal_frobenius * (1.0 - 1e-10));
+			assert!(frobenius <= optimal_frobenius * 1.01);
+
+			for i in 0..k {
+				assert!((svd.S()[i].re - sigma[i]).abs() <= 1e-10);
 			}
 		}
 
-		let mut out = Mat::zeros(n, n);
+		let approx_eq = CwiseMat(ApproxEq::eps() * 128.0 * (n as f64));
 
-		linalg::matmul::matmul(out.as_mut(), Accum::Replace, VxS.as_ref(), U.adjoint(), one(), par);
+		// k == min(m, n) is the thin svd
+		let svd = Svd::new_truncated(A.as_ref(), n, Par::Seq).unwrap();
+		let thin = Svd::new_thin(A.as_ref()).unwrap();
+		assert!(all(svd.U() == thin.U(), svd.V() == thin.V(), svd.S() == thin.S()));
 
-		out
+		// k == 0 returns empty factors
+		let svd = Svd::new_truncated(A.as_ref(), 0, Par::Seq).unwrap();
+		assert!(all(svd.U().shape() == (m, 0), svd.V().shape() == (n, 0), svd.S().dim() == 0));
+
+		// least squares with an exactly rank k matrix
+		let k = 8;
+		let A = U.get(.., ..k) * Col::<c64>::from_fn(k, |i| c64::from(sigma[i])).as_diagonal() * V.get(.., ..k).adjoint();
+		let svd = Svd::new_truncated(A.as_ref(), k, Par::rayon(4)).unwrap();
+		let x = CwiseMatDistribution {
+			nrows: n,
+			ncols: 2,
+			dist: ComplexDistribution::new(StandardNormal, StandardNormal),
+		}
+		.rand::<Mat<c64>>(rng);
+		let b = &A * &x;
+		let mut sol = b.clone();
+		svd.solve_lstsq_in_place_with_conj(Conj::No, sol.as_mut());
+		assert!(&A * sol.get(..n, ..) ~ b);
 	}
-}
 
-impl<T: ComplexField> SolveCore<T> for SelfAdjointEigen<T> {
-	#[track_caller]
-	fn solve_in_place_with_conj(&self, conj: Conj, rhs: MatMut<'_, T>) {
-		let par = get_global_parallelism();
+	#[test]
+	fn test_gaussian_test_matrix_cplx() {
+		let rng = &mut StdRng::seed_from_u64(0);
+		let omega = gaussian_test_matrix::<c64>(200, 50, rng);
+
+		// standard complex gaussian entries: both parts are used and E[|z|^2] = 1
+		let (mut re, mut im) = (0.0, 0.0);
+		for j in 0..50 {
+			for i in 0..200 {
+				re += omega[(i, j)].re * omega[(i, j)].re;
+				im += omega[(i, j)].im * omega[(i, j)].im;
+			}
+		}
+		let count = (200 * 50) as f64;
+		assert!((re / count - 0.5).abs() < 0.05);
+		assert!((im / count - 0.5).abs() < 0.05);
+	}
 
-		assert!(all(self.nrows() == self.ncols(), self.nrows() == rhs.nrows(),));
+	#[test]
+	fn test_svd_randomized() {
+		let rng = &mut StdRng::seed_from_u64(0);
+		let (m, n) = (60, 40);
 
-		let mut rhs = rhs;
-		let n = self.nrows();
-		let k = rhs.ncols();
-		let mut tmp = Mat::zeros(n, k);
+		let unitary = |dim: usize, rng: &mut StdRng| -> Mat<c64> {
+			UnitaryMat {
+				dim,
+				standard_normal: ComplexDistribution::new(StandardNormal, StandardNormal),
+			}
+			.rand(rng)
+		};
+		let U = unitary(m, rng);
+		let V = unitary(n, rng);
+		let sigma = Col::<f64>::from_fn(n, |i| 0.8f64.powi(i as i32));
+		let A = U.get(.., ..n) * Col::<c64>::from_fn(n, |i| c64::from(sigma[i])).as_diagonal() * V.adjoint();
+
+		// (rank, oversampling, power_iters, allowed ratio to the eckart-young bound)
+		for (k, p, q, ratio) in [(5, 10, 0, 1.5), (5, 10, 2, 1.01), (12, 0, 0, 3.0), (12, 0, 3, 1.05), (12, 5, 1, 1.05)] {
+			let svd = Svd::new_randomized(A.as_ref(), k, p, q, rng).unwrap();
+			assert!(all(svd.U().shape() == (m, k), svd.V().shape() == (n, k), svd.S().dim() == k));
+
+			let approx = svd.U() * svd.S() * svd.V().adjoint();
+			let frobenius = (&A - &approx).norm_l2();
+			let optimal_frobenius = sigma.get(k..).norm_l2();
+			assert!(frobenius >= optimal_frobenius * (1.0 - 1e-10));
+			assert!(frobenius <= optimal_frobenius * ratio);
+		}
 
-		linalg::matmul::matmul_with_conj(
-			tmp.as_mut(),
-			Accum::Replace,
-			self.U().transpose(),
-			conj.compose(Conj::Yes),
-			rhs.as_ref(),
-			Conj::No,
-			one(),
-			par,
-		);
+		// no oversampling is exact when the whole range is requested
+		let svd = Svd::new_randomized(A.as_ref(), n, 0, 0, rng).unwrap();
+		let approx_eq = CwiseMat(ApproxEq::eps() * 128.0 * (n as f64));
+		assert!(svd.U() * svd.S() * svd.V().adjoint() ~ A);
+		for i in 0..n {
+			assert!((svd.S()[i].re - sigma[i]).abs() <= 1e-10);
+		}
 
-		for j in 0..k {
-			for i in 0..n {
-				let s = recip(&real(&self.S()[i]));
-				tmp[(i, j)] = mul_real(&tmp[(i, j)], &s);
-			}
+		let svd = Svd::new_randomized(A.transpose(), n, 0, 0, rng).unwrap();
+		assert!(all(svd.U().shape() == (n, n), svd.V().shape() == (m, n)));
+		assert!(svd.U() * svd.S() * svd.V().adjoint() ~ A.transpose().to_owned());
+	}
+
+	#[test]
+	fn test_eigen_cplx() {
+		let rng = &mut StdRng::seed_from_u64(0);
+		let n = 50;
+
+		let A = CwiseMatDistribution {
+			nrows: n,
+			ncols: n,
+			dist: ComplexDistribution::new(StandardNormal, StandardNormal),
 		}
+		.rand::<Mat<c64>>(rng);
 
-		linalg::matmul::matmul_with_conj(rhs.as_mut(), Accum::Replace, self.U(), conj, tmp.as_ref(), Conj::No, one(), par);
+		let n = A.nrows();
+		let approx_eq = CwiseMat(ApproxEq::eps() * 128.0 * (n as f64));
+
+		let evd = A.eigen().unwrap();
+		let e = A.eigenvalues().unwrap();
+		assert!(&A * evd.U() ~ evd.U() * evd.S());
+		assert!(evd.S().column_vector() ~ ColRef::from_slice(&e));
+		assert!(evd.reconstruct().unwrap() ~ A);
 	}
 
-	#[track_caller]
-	fn solve_transpose_in_place_with_conj(&self, conj: Conj, rhs: MatMut<'_, T>) {
-		let par = get_global_parallelism();
+	#[test]
+	fn test_eigen_singular_reconstruct() {
+		// eigenvectors of a defective matrix are linearly dependent
+		let s = c64::new(0.5f64.sqrt(), 0.0);
+		let z = c64::new(0.0, 0.0);
+		let one = c64::new(1.0, 0.0);
 
-		assert!(all(self.nrows() == self.ncols(), self.ncols() == rhs.nrows(),));
+		let evd = Eigen::<f64> {
+			U: mat![[one, one, z], [z, z, s], [z, z, s]],
+			S: Col::from_fn(3, |_| c64::new(2.0, 0.0)).into_diagonal(),
+		};
+		assert!(evd.reconstruct().is_none());
 
-		let mut rhs = rhs;
-		let n = self.nrows();
-		let k = rhs.ncols();
-		let mut tmp = Mat::zeros(n, k);
+		// the eigenvectors are needed
+		let A = mat![[one, one], [z, c64::new(2.0, 0.0)]];
+		assert!(Eigen::new_values_only(A.as_ref()).unwrap().reconstruct().is_none());
+		assert!(Eigen::new(A.as_ref()).unwrap().reconstruct().is_some());
+	}
 
-		linalg::matmul::matmul_with_conj(
-			tmp.as_mut(),
-			Accum::Replace,
-			self.U().transpose(),
-			conj,
-			rhs.as_ref(),
-			Conj::No,
-			one(),
-			par,
-		);
+	#[test]
+	fn test_eigen_real() {
+		let rng = &mut StdRng::seed_from_u64(0);
+		let n = 50;
 
-		for j in 0..k {
-			for i in 0..n {
-				let s = recip(&real(&self.S()[i]));
-				tmp[(i, j)] = mul_real(&tmp[(i, j)], &s);
-			}
+		let A = CwiseMatDistribution {
+			nrows: n,
+			ncols: n,
+			dist: StandardNormal,
 		}
+		.rand::<Mat<f64>>(rng);
 
-		linalg::matmul::matmul_with_conj(
-			rhs.as_mut(),
-			Accum::Replace,
-			self.U(),
-			conj.compose(Conj::Yes),
-			tmp.as_ref(),
-			Conj::No,
-			one(),
-			par,
-		);
-	}
-}
+		let n = A.nrows();
+		let approx_eq = CwiseMat(ApproxEq::eps() * 128.0 * (n as f64));
 
-impl<T: ComplexField> DenseSolveCore<T> for SelfAdjointEigen<T> {
-	fn reconstruct(&self) -> Mat<T> {
-		let par = get_global_parallelism();
-		let m = self.nrows();
-		let n = self.ncols();
+		let evd = A.eigen_from_real().unwrap();
+		let e = A.eigenvalues_from_real().unwrap();
 
-		let size = Ord::min(m, n);
+		let A = Mat::from_fn(A.nrows(), A.ncols(), |i, j| c64::from(A[(i, j)]));
 
-		let U = self.U().get(.., ..size);
-		let V = self.U().get(.., ..size);
-		let S = self.S();
+		assert!(&A * evd.U() ~ evd.U() * evd.S());
+		assert!(evd.S().column_vector() ~ ColRef::from_slice(&e));
+		assert!(evd.reconstruct().unwrap() ~ A);
+	}
 
-		let mut UxS = Mat::zeros(m, size);
-		for j in 0..size {
-			let s = real(&S[j]);
-			for i in 0..m {
-				UxS[(i, j)] = mul_real(&U[(i, j)], &s);
-			}
+	#[test]
+	fn test_schur_apply_fn() {
+		let rng = &mut StdRng::seed_from_u64(0);
+		let n = 5;
+		let approx_eq = CwiseMat(ApproxEq::eps() * 128.0 * (n as f64));
+
+		let V = CwiseMatDistribution {
+			nrows: n,
+			ncols: n,
+			dist: StandardNormal,
 		}
+		.rand::<Mat<f64>>(rng);
+		let V = &V + Scale(4.0) * Mat::<f64>::identity(n, n);
 
-		let mut out = Mat::zeros(m, n);
+		// jordan blocks of size 3 and 2, so the matrix is not diagonalizable
+		let J = mat![
+			[4.0, 1.0, 0.0, 0.0, 0.0],
+			[0.0, 4.0, 1.0, 0.0, 0.0],
+			[0.0, 0.0, 4.0, 0.0, 0.0],
+			[0.0, 0.0, 0.0, 9.0, 1.0],
+			[0.0, 0.0, 0.0, 0.0, 9.0],
+		];
+		let A = &V * &J * V.partial_piv_lu().inverse();
 
-		linalg::matmul::matmul(out.as_mut(), Accum::Replace, UxS.as_ref(), V.adjoint(), one(), par);
+		let schur = Schur::new_from_real(A.as_ref()).unwrap();
+		let A = Mat::from_fn(n, n, |i, j| c64::from(A[(i, j)]));
+		assert!(schur.Q() * schur.T() * schur.Q().adjoint() ~ A);
 
-		out
+		let X = schur.apply_fn(|z| math_utils::sqrt(&z)).unwrap();
+		assert!(&X * &X ~ A);
+
+		// exp of a jordan block
+		let J = mat![[c64::new(1.0, 1.0), c64::new(1.0, 0.0)], [c64::new(0.0, 0.0), c64::new(1.0, 1.0)]];
+		let exp = |z: c64| c64::new(z.re.exp() * z.im.cos(), z.re.exp() * z.im.sin());
+		let e = exp(c64::new(1.0, 1.0));
+		let expected = mat![[e, e], [c64::new(0.0, 0.0), e]];
+		assert!(Schur::new(J.as_ref()).unwrap().apply_fn(exp).unwrap() ~ expected);
+
+		// a pole on the integration contour of the block
+		let pole = |z: c64| c64::new(1.0, 0.0) / (z - c64::new(1.1, 1.0));
+		assert!(Schur::new(J.as_ref()).unwrap().apply_fn(pole).is_err());
 	}
 
-	fn inverse(&self) -> Mat<T> {
-		let par = get_global_parallelism();
+	#[test]
+	fn test_schur() {
+		let rng = &mut StdRng::seed_from_u64(0);
+		let n = 30;
+		let approx_eq = CwiseMat(ApproxEq::eps() * 1e4);
 
-		assert!(self.nrows() == self.ncols());
-		let n = self.nrows();
+		let A = CwiseMatDistribution {
+			nrows: n,
+			ncols: n,
+			dist: StandardNormal,
+		}
+		.rand::<Mat<f64>>(rng);
+		let B = CwiseMatDistribution {
+			nrows: n,
+			ncols: 2,
+			dist: StandardNormal,
+		}
+		.rand::<Mat<f64>>(rng);
 
-		let U = self.U();
-		let V = self.U();
-		let S = self.S();
+		let schur = RealSchur::new(A.as_ref()).unwrap();
+		let (Q, T) = (schur.Q(), schur.T());
+		assert!(Q.transpose() * Q ~ Mat::<f64>::identity(n, n));
+		assert!(schur.reconstruct() ~ A);
 
-		let mut VxS = Mat::zeros(n, n);
+		// quasi-upper triangular, with no two consecutive nonzero subdiagonal entries
 		for j in 0..n {
-			let s = recip(&real(&S[j]));
-
-			for i in 0..n {
-				VxS[(i, j)] = mul_real(&V[(i, j)], &s);
+			for i in j + 2..n {
+				assert!(T[(i, j)] == 0.0);
 			}
 		}
+		for j in 0..n - 2 {
+			assert!(any(T[(j + 1, j)] == 0.0, T[(j + 2, j + 1)] == 0.0));
+		}
 
-		let mut out = Mat::zeros(n, n);
+		assert!(&A * schur.solve(&B) ~ B);
+		assert!(A.transpose() * schur.solve_transpose(&B) ~ B);
+		assert!(&A * schur.inverse() ~ Mat::<f64>::identity(n, n));
 
-		linalg::matmul::matmul(out.as_mut(), Accum::Replace, VxS.as_ref(), U.adjoint(), one(), par);
+		let mut expected = A.eigenvalues_from_real().unwrap();
+		let mut eigenvalues = schur.eigenvalues();
+		let key = |a: &c64, b: &c64| (a.re, a.im).partial_cmp(&(b.re, b.im)).unwrap();
+		expected.sort_by(key);
+		eigenvalues.sort_by(key);
+		let approx_eq = CwiseMat(ApproxEq::<f64>::eps() * 1e4);
+		assert!(ColRef::from_slice(&eigenvalues) ~ ColRef::from_slice(&expected));
 
-		out
-	}
-}
+		let A = CwiseMatDistribution {
+			nrows: n,
+			ncols: n,
+			dist: ComplexDistribution::new(StandardNormal, StandardNormal),
+		}
+		.rand::<Mat<c64>>(rng);
+		let B = CwiseMatDistribution {
+			nrows: n,
+			ncols: 2,
+			dist: ComplexDistribution::new(StandardNormal, StandardNormal),
+		}
+		.rand::<Mat<c64>>(rng);
 
-#[cfg(test)]
-mod tests {
-	use super::*;
-	use crate::assert;
-	use crate::stats::prelude::*;
-	use crate::utils::approx::*;
+		let schur = Schur::new(A.as_ref()).unwrap();
+		let (Q, T) = (schur.Q(), schur.T());
+		assert!(Q.adjoint() * Q ~ Mat::<c64>::identity(n, n));
+		assert!(schur.reconstruct() ~ A);
+		for j in 0..n {
+			for i in j + 1..n {
+				assert!(T[(i, j)] == c64::new(0.0, 0.0));
+			}
+		}
 
-	#[track_caller]
-	fn test_solver(A: MatRef<'_, c64>, A_dec: impl SolveCore<c64>) {
-		#[track_caller]
-		fn test_solver_imp(A: MatRef<'_, c64>, A_dec: &dyn SolveCore<c64>) {
-			let rng = &mut StdRng::seed_from_u64(0xC0FFEE);
+		assert!(&A * schur.solve(&B) ~ B);
+		assert!(A.conjugate() * schur.solve_conjugate(&B) ~ B);
+		assert!(A.transpose() * schur.solve_transpose(&B) ~ B);
+		assert!(A.adjoint() * schur.solve_adjoint(&B) ~ B);
+		assert!(&A * schur.inverse() ~ Mat::<c64>::identity(n, n));
+		assert!(ColRef::from_slice(&schur.eigenvalues()) ~ T.diagonal().column_vector());
+	}
 
-			let n = A.nrows();
-			let approx_eq = CwiseMat(ApproxEq::eps() * 128.0 * (n as f64));
+	#[test]
+	fn test_schur_large() {
+		let rng = &mut StdRng::seed_from_u64(0);
 
-			let k = 3;
+		// large enough for the multishift qr to use the lower left part of $T$ as workspace
+		for n in [100, 120, 200] {
+			let approx_eq = CwiseMat(ApproxEq::eps() * 8.0 * (n as f64));
 
-			let ref R = CwiseMatDistribution {
+			let A = CwiseMatDistribution {
 				nrows: n,
-				ncols: k,
-				dist: ComplexDistribution::new(StandardNormal, StandardNormal),
+				ncols: n,
+				dist: StandardNormal,
 			}
-			.rand::<Mat<c64>>(rng);
+			.rand::<Mat<f64>>(rng);
 
-			let ref L = CwiseMatDistribution {
-				nrows: k,
+			let schur = RealSchur::new(A.as_ref()).unwrap();
+			let T = schur.T();
+			for j in 0..n {
+				for i in j + 2..n {
+					assert!(T[(i, j)] == 0.0);
+				}
+			}
+			assert!(schur.reconstruct() ~ A);
+
+			let A = CwiseMatDistribution {
+				nrows: n,
 				ncols: n,
 				dist: ComplexDistribution::new(StandardNormal, StandardNormal),
 			}
 			.rand::<Mat<c64>>(rng);
 
-			assert!(A * A_dec.solve(R) ~ R);
-			assert!(A.conjugate() * A_dec.solve_conjugate(R) ~ R);
-			assert!(A.transpose() * A_dec.solve_transpose(R) ~ R);
-			assert!(A.adjoint() * A_dec.solve_adjoint(R) ~ R);
-
-			assert!(A_dec.rsolve(L) * A ~ L);
-			assert!(A_dec.rsolve_conjugate(L) * A.conjugate() ~ L);
-			assert!(A_dec.rsolve_transpose(L) * A.transpose() ~ L);
-			assert!(A_dec.rsolve_adjoint(L) * A.adjoint() ~ L);
+			let schur = Schur::new(A.as_ref()).unwrap();
+			let T = schur.T();
+			for j in 0..n {
+				for i in j + 1..n {
+					assert!(T[(i, j)] == c64::new(0.0, 0.0));
+				}
+			}
+			assert!(schur.reconstruct() ~ A);
 		}
-
-		test_solver_imp(A, &A_dec)
 	}
 
 	#[test]
-	fn test_all_solvers() {
+	fn test_eigen_values_only() {
 		let rng = &mut StdRng::seed_from_u64(0);
-		let n = 50;
+		let n = 20;
 
-		let ref A = CwiseMatDistribution {
+		let A = CwiseMatDistribution {
 			nrows: n,
 			ncols: n,
 			dist: ComplexDistribution::new(StandardNormal, StandardNormal),
 		}
 		.rand::<Mat<c64>>(rng);
-		let A = A.rb();
+		let approx_eq = CwiseMat(ApproxEq::eps() * 128.0 * (n as f64));
 
-		test_solver(A, A.partial_piv_lu());
-		test_solver(A, A.full_piv_lu());
-		test_solver(A, A.qr());
-		test_solver(A, A.col_piv_qr());
-		test_solver(A, A.svd().unwrap());
+		let H = &A + A.adjoint();
+		let full = SelfAdjointEigen::new(H.as_ref(), Side::Lower).unwrap();
+		let values = SelfAdjointEigen::new_values_only(H.as_ref(), Side::Lower).unwrap();
+		assert!(all(values.try_U().is_none(), full.try_U().is_some(), values.U().ncols() == 0));
+		assert!(values.nrows() == n);
+		assert!(values.S().column_vector() ~ full.S().column_vector());
 
-		{
-			let ref A = A * A.adjoint();
-			let A = A.rb();
-			test_solver(A, A.llt(Side::Lower).unwrap());
-			test_solver(A, A.ldlt(Side::Lower).unwrap());
-		}
+		let full = Eigen::new(A.as_ref()).unwrap();
+		let values = Eigen::new_values_only(A.as_ref()).unwrap();
+		assert!(all(values.try_U().is_none(), full.try_U().is_some()));
+		assert!(values.S().column_vector() ~ full.S().column_vector());
 
-		{
-			let ref A = A + A.adjoint();
-			let A = A.rb();
-			test_solver(A, A.lblt(Side::Lower));
-			test_solver(A, A.self_adjoint_eigen(Side::Lower).unwrap());
-		}
+		let A = Mat::from_fn(n, n, |i, j| A[(i, j)].re);
+		let full = Eigen::new_from_real(A.as_ref()).unwrap();
+		let values = Eigen::new_values_only_from_real(A.as_ref()).unwrap();
+		assert!(values.try_U().is_none());
+		assert!(values.S().column_vector() ~ full.S().column_vector());
 	}
 
+	#[cfg(feature = "serde")]
 	#[test]
-	fn test_eigen_cplx() {
+	fn test_serde_roundtrip() {
+		fn roundtrip<D: serde::Serialize + serde::de::DeserializeOwned>(dec: &D) -> D {
+			bincode::deserialize(&bincode::serialize(dec).unwrap()).unwrap()
+		}
+
 		let rng = &mut StdRng::seed_from_u64(0);
-		let n = 50;
+		let n = 20;
 
 		let A = CwiseMatDistribution {
 			nrows: n,
@@ -2676,37 +7103,162 @@ mod tests {
 			dist: ComplexDistribution::new(StandardNormal, StandardNormal),
 		}
 		.rand::<Mat<c64>>(rng);
-
-		let n = A.nrows();
+		let B = CwiseMatDistribution {
+			nrows: n,
+			ncols: 2,
+			dist: ComplexDistribution::new(StandardNormal, StandardNormal),
+		}
+		.rand::<Mat<c64>>(rng);
 		let approx_eq = CwiseMat(ApproxEq::eps() * 128.0 * (n as f64));
 
-		let evd = A.eigen().unwrap();
-		let e = A.eigenvalues().unwrap();
-		assert!(&A * evd.U() ~ evd.U() * evd.S());
-		assert!(evd.S().column_vector() ~ ColRef::from_slice(&e));
+		let A = A.as_ref();
+		let H = &A + A.adjoint();
+		let P = A * A.adjoint();
+
+		#[track_caller]
+		fn check(dec: impl DenseSolveCore<c64> + serde::Serialize + serde::de::DeserializeOwned, B: MatRef<'_, c64>) {
+			let approx_eq = CwiseMat(ApproxEq::eps() * 128.0 * (B.nrows() as f64));
+			let rt = roundtrip(&dec);
+			assert!(rt.reconstruct() ~ dec.reconstruct());
+			assert!(rt.solve(B) ~ dec.solve(B));
+		}
+
+		check(A.partial_piv_lu(), B.as_ref());
+		check(A.full_piv_lu(), B.as_ref());
+		check(A.qr(), B.as_ref());
+		check(A.col_piv_qr(), B.as_ref());
+		check(A.svd().unwrap(), B.as_ref());
+		check(P.llt(Side::Lower).unwrap(), B.as_ref());
+		check(P.ldlt(Side::Lower).unwrap(), B.as_ref());
+		check(H.lblt(Side::Lower), B.as_ref());
+		check(H.self_adjoint_eigen(Side::Lower).unwrap(), B.as_ref());
+
+		let cod = CompOrtho::new(A, 0.0);
+		let rt = roundtrip(&cod);
+		assert!(rt.rank() == cod.rank());
+		assert!(rt.column_space() ~ cod.column_space());
+		assert!(rt.null_space() ~ cod.null_space());
+
+		let lu = A.partial_piv_lu();
+		let (fwd, inv) = lu.P().arrays();
+		let perm = Perm::new_checked(fwd.into(), inv.into(), n);
+		assert!(roundtrip(&perm).as_ref().arrays() == perm.as_ref().arrays());
+
+		let A = Mat::from_fn(n, n, |i, j| A[(i, j)].re);
+		let evd = Eigen::new_from_real(A.as_ref()).unwrap();
+		let rt = roundtrip(&evd);
+		assert!(rt.U() ~ evd.U());
+		assert!(rt.S().column_vector() ~ evd.S().column_vector());
+	}
+
+	#[cfg(feature = "serde")]
+	#[test]
+	fn test_serde_invalid_dimensions() {
+		// bincode only encodes the fields, so tuples with the same field types can stand in for
+		// corrupted decompositions
+		let bytes = bincode::serialize(&(Mat::<f64>::zeros(3, 2),)).unwrap();
+		assert!(bincode::deserialize::<Llt<f64>>(&bytes).is_err());
+		let bytes = bincode::serialize(&(Mat::<f64>::zeros(3, 3),)).unwrap();
+		assert!(bincode::deserialize::<Llt<f64>>(&bytes).is_ok());
+
+		let bytes = bincode::serialize(&(Mat::<f64>::zeros(3, 3), Diag::<f64>::zeros(2))).unwrap();
+		assert!(bincode::deserialize::<Ldlt<f64>>(&bytes).is_err());
+
+		let P = Perm::<usize>::new_checked(Box::new([1, 0]), Box::new([1, 0]), 2);
+		let bytes = bincode::serialize(&(Mat::<f64>::zeros(3, 3), Mat::<f64>::zeros(3, 3), &P)).unwrap();
+		assert!(bincode::deserialize::<PartialPivLu<f64>>(&bytes).is_err());
+
+		let bytes = bincode::serialize(&(Mat::<f64>::zeros(4, 3), Mat::<f64>::zeros(1, 3), Mat::<f64>::zeros(4, 3))).unwrap();
+		assert!(bincode::deserialize::<Qr<f64>>(&bytes).is_err());
+		let bytes = bincode::serialize(&(Mat::<f64>::zeros(4, 3), Mat::<f64>::zeros(0, 3), Mat::<f64>::zeros(3, 3))).unwrap();
+		assert!(bincode::deserialize::<Qr<f64>>(&bytes).is_err());
+
+		let bytes = bincode::serialize(&(Mat::<f64>::zeros(4, 4), Mat::<f64>::zeros(3, 2), Diag::<f64>::zeros(3))).unwrap();
+		assert!(bincode::deserialize::<Svd<f64>>(&bytes).is_err());
+		let bytes = bincode::serialize(&(Mat::<f64>::zeros(4, 3), Mat::<f64>::zeros(3, 3), Diag::<f64>::zeros(3))).unwrap();
+		assert!(bincode::deserialize::<Svd<f64>>(&bytes).is_ok());
+
+		let bytes = bincode::serialize(&(Mat::<f64>::zeros(3, 2), Diag::<f64>::zeros(3))).unwrap();
+		assert!(bincode::deserialize::<SelfAdjointEigen<f64>>(&bytes).is_err());
 	}
 
 	#[test]
-	fn test_eigen_real() {
+	fn test_solve_transpose_into() {
 		let rng = &mut StdRng::seed_from_u64(0);
-		let n = 50;
+		let (m, n, k) = (12, 7, 3);
 
 		let A = CwiseMatDistribution {
-			nrows: n,
+			nrows: m,
 			ncols: n,
-			dist: StandardNormal,
+			dist: ComplexDistribution::new(StandardNormal, StandardNormal),
 		}
-		.rand::<Mat<f64>>(rng);
+		.rand::<Mat<c64>>(rng);
+		let B = CwiseMatDistribution {
+			nrows: n,
+			ncols: k,
+			dist: ComplexDistribution::new(StandardNormal, StandardNormal),
+		}
+		.rand::<Mat<c64>>(rng);
+		let approx_eq = CwiseMat(ApproxEq::eps() * 128.0 * (m as f64));
 
-		let n = A.nrows();
-		let approx_eq = CwiseMat(ApproxEq::eps() * 128.0 * (n as f64));
+		// minimum norm solution of the underdetermined system A^T x = b
+		let AH = A.conjugate();
+		let expected = AH * (A.transpose() * AH).partial_piv_lu().solve(&B);
 
-		let evd = A.eigen_from_real().unwrap();
-		let e = A.eigenvalues_from_real().unwrap();
+		let mut X = Mat::zeros(m, k);
+		A.qr().solve_transpose_into(B.as_ref(), X.as_mut());
+		assert!(X ~ expected);
 
-		let A = Mat::from_fn(A.nrows(), A.ncols(), |i, j| c64::from(A[(i, j)]));
+		let mut X = Mat::zeros(m, k);
+		A.col_piv_qr().solve_transpose_into(B.as_ref(), X.as_mut());
+		assert!(X ~ expected);
 
-		assert!(&A * evd.U() ~ evd.U() * evd.S());
-		assert!(evd.S().column_vector() ~ ColRef::from_slice(&e));
+		let mut X = Mat::zeros(m, k);
+		A.thin_svd().unwrap().solve_transpose_into(B.as_ref(), X.as_mut());
+		assert!(X ~ expected);
+
+		// least squares solution of the overdetermined system A^T x = b
+		let A = A.transpose().to_owned();
+		let B = CwiseMatDistribution {
+			nrows: m,
+			ncols: k,
+			dist: ComplexDistribution::new(StandardNormal, StandardNormal),
+		}
+		.rand::<Mat<c64>>(rng);
+		let AH = A.conjugate();
+		let expected = (AH * A.transpose()).partial_piv_lu().solve(AH * &B);
+
+		let mut X = Mat::zeros(n, k);
+		A.svd().unwrap().solve_transpose_into(B.as_ref(), X.as_mut());
+		assert!(X ~ expected);
+
+		// square matrices agree with the in-place solve
+		let A = A.get(.., ..n);
+		let B = B.get(..n, ..);
+		let expected = A.partial_piv_lu().solve_transpose(B);
+		let mut X = Mat::zeros(n, k);
+		A.qr().solve_transpose_into(B, X.as_mut());
+		assert!(X ~ expected);
+		A.partial_piv_lu().solve_transpose_into(B, X.as_mut());
+		assert!(X ~ expected);
+		A.full_piv_lu().solve_transpose_into(B, X.as_mut());
+		assert!(X ~ expected);
+	}
+
+	#[test]
+	#[should_panic(expected = "use `Svd::solve_transpose_into` for wide matrices")]
+	fn test_solve_transpose_into_wide_qr() {
+		let A = Mat::<f64>::identity(3, 5);
+		let B = Mat::<f64>::zeros(5, 2);
+		let mut X = Mat::<f64>::zeros(3, 2);
+		A.qr().solve_transpose_into(B.as_ref(), X.as_mut());
+	}
+
+	#[test]
+	#[should_panic(expected = "the in-place transpose solve requires a square matrix")]
+	fn test_solve_transpose_in_place_tall() {
+		let A = Mat::<f64>::identity(5, 3);
+		let mut B = Mat::<f64>::zeros(3, 2);
+		A.qr().solve_transpose_in_place(B.as_mut());
 	}
 }
@@ -395,6 +395,46 @@ pub fn solve_unit_upper_triangular_in_place<T: ComplexField, LhsT: Conjugate<Can
 	solve_unit_upper_triangular_in_place_with_conj(tri, Conj::get::<LhsT>(), rhs, par)
 }
 
+/// solves $L x = b$, and stores the result in `rhs`. if `unit_diag` is `true`, the diagonal of $L$
+/// is replaced with ones
+///
+/// only the lower triangular half of `triangular_lower` is accessed. it is the caller's
+/// responsibility to ensure that the matrix is invertible, otherwise the result is unspecified
+#[inline]
+#[track_caller]
+pub fn solve_lower_triangular<T: ComplexField, LhsT: Conjugate<Canonical = T>, N: Shape, K: Shape>(
+	triangular_lower: MatRef<'_, LhsT, N, N, impl Stride, impl Stride>,
+	rhs: MatMut<'_, T, N, K, impl Stride, impl Stride>,
+	unit_diag: bool,
+	par: Par,
+) {
+	if unit_diag {
+		solve_unit_lower_triangular_in_place(triangular_lower, rhs, par)
+	} else {
+		solve_lower_triangular_in_place(triangular_lower, rhs, par)
+	}
+}
+
+/// solves $U x = b$, and stores the result in `rhs`. if `unit_diag` is `true`, the diagonal of $U$
+/// is replaced with ones
+///
+/// only the upper triangular half of `triangular_upper` is accessed. it is the caller's
+/// responsibility to ensure that the matrix is invertible, otherwise the result is unspecified
+#[inline]
+#[track_caller]
+pub fn solve_upper_triangular<T: ComplexField, LhsT: Conjugate<Canonical = T>, N: Shape, K: Shape>(
+	triangular_upper: MatRef<'_, LhsT, N, N, impl Stride, impl Stride>,
+	rhs: MatMut<'_, T, N, K, impl Stride, impl Stride>,
+	unit_diag: bool,
+	par: Par,
+) {
+	if unit_diag {
+		solve_unit_upper_triangular_in_place(triangular_upper, rhs, par)
+	} else {
+		solve_upper_triangular_in_place(triangular_upper, rhs, par)
+	}
+}
+
 #[math]
 fn solve_unit_lower_triangular_in_place_imp<'N, 'K, T: ComplexField>(
 	tril: MatRef<'_, T, Dim<'N>, Dim<'N>>,
@@ -558,3 +598,69 @@ fn solve_upper_triangular_in_place_imp<'N, 'K, T: ComplexField>(
 ) {
 	solve_lower_triangular_in_place_imp(triu.reverse_rows_and_cols(), conj_lhs, rhs.reverse_rows_mut(), par);
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::assert;
+	use crate::linalg::solvers::{PartialPivLu, Solve};
+	use crate::stats::prelude::*;
+	use crate::utils::approx::*;
+
+	#[test]
+	fn test_solve_triangular() {
+		let rng = &mut StdRng::seed_from_u64(0);
+		let approx_eq = CwiseMat(ApproxEq::eps() * 128.0);
+
+		for n in [1, 4, 17, 80] {
+			let A = CwiseMatDistribution {
+				nrows: n,
+				ncols: n,
+				dist: StandardNormal,
+			}
+			.rand::<Mat<f64>>(rng);
+			let B = CwiseMatDistribution {
+				nrows: n,
+				ncols: 3,
+				dist: StandardNormal,
+			}
+			.rand::<Mat<f64>>(rng);
+
+			for unit_diag in [false, true] {
+				let diag = |i: usize, j: usize| if unit_diag { 1.0 } else { A[(i, j)] + 4.0 };
+
+				let L = Mat::from_fn(n, n, |i, j| {
+					if i == j {
+						diag(i, j)
+					} else if i > j {
+						A[(i, j)] / n as f64
+					} else {
+						0.0
+					}
+				});
+				let U = Mat::from_fn(n, n, |i, j| {
+					if i == j {
+						diag(i, j)
+					} else if i < j {
+						A[(i, j)] / n as f64
+					} else {
+						0.0
+					}
+				});
+
+				// the strictly upper (resp. lower) part must be ignored, and so must the diagonal if
+				// `unit_diag` is set
+				let L_full = Mat::from_fn(n, n, |i, j| if i < j || (unit_diag && i == j) { 1e3 } else { L[(i, j)] });
+				let U_full = Mat::from_fn(n, n, |i, j| if i > j || (unit_diag && i == j) { 1e3 } else { U[(i, j)] });
+
+				let mut X = B.clone();
+				solve_lower_triangular(L_full.as_ref(), X.as_mut(), unit_diag, Par::Seq);
+				assert!(X ~ PartialPivLu::new(L.as_ref()).solve(&B));
+
+				let mut X = B.clone();
+				solve_upper_triangular(U_full.as_ref(), X.as_mut(), unit_diag, Par::Seq);
+				assert!(X ~ PartialPivLu::new(U.as_ref()).solve(&B));
+			}
+		}
+	}
+}
@@ -0,0 +1,89 @@
+use super::*;
+use crate::col::ColRef;
+use equator::assert;
+
+/// sliding window over the `w` most recently pushed columns, backed by a matrix allocated once
+///
+/// every column is stored twice, `w` columns apart, so that the window is always a contiguous
+/// range of columns of the buffer, and can be viewed as a [`MatRef`] without copying
+#[derive(Clone, Debug)]
+pub struct ColWindow<T> {
+	data: Mat<T>,
+	head: usize,
+	len: usize,
+}
+
+impl<T: ComplexField> ColWindow<T> {
+	/// returns an empty window holding at most `width` columns with `nrows` rows
+	///
+	/// # panics
+	/// panics if `width` is zero
+	#[track_caller]
+	pub fn new(nrows: usize, width: usize) -> Self {
+		assert!(width > 0);
+		Self {
+			data: Mat::zeros(nrows, 2 * width),
+			head: 0,
+			len: 0,
+		}
+	}
+
+	/// returns the number of rows of the columns
+	#[inline]
+	pub fn nrows(&self) -> usize {
+		self.data.nrows()
+	}
+
+	/// returns the maximum number of columns in the window
+	#[inline]
+	pub fn width(&self) -> usize {
+		self.data.ncols() / 2
+	}
+
+	/// returns the number of columns currently in the window
+	#[inline]
+	pub fn len(&self) -> usize {
+		self.len
+	}
+
+	/// returns `true` if no column has been pushed
+	#[inline]
+	pub fn is_empty(&self) -> bool {
+		self.len == 0
+	}
+
+	/// appends `col` to the window, overwriting the oldest column if the window is full
+	///
+	/// # panics
+	/// panics if `col` doesn't have `self.nrows()` rows
+	#[track_caller]
+	pub fn push<C: Conjugate<Canonical = T>>(&mut self, col: ColRef<'_, C>) {
+		assert!(col.nrows() == self.nrows());
+
+		let w = self.width();
+		self.data.col_mut(self.head).copy_from(col);
+		self.data.col_mut(self.head + w).copy_from(col);
+
+		self.head = (self.head + 1) % w;
+		self.len = Ord::min(self.len + 1, w);
+	}
+
+	/// returns a view over the columns of the window, from the oldest to the most recent
+	#[inline]
+	pub fn as_mat_ref(&self) -> MatRef<'_, T> {
+		let w = self.width();
+		self.data.as_ref().subcols(self.head + w - self.len, self.len)
+	}
+}
+
+impl<T: ComplexField> AsMatRef for ColWindow<T> {
+	type Cols = usize;
+	type Owned = Mat<T>;
+	type Rows = usize;
+	type T = T;
+
+	#[inline]
+	fn as_mat_ref(&self) -> MatRef<'_, T> {
+		(*self).as_mat_ref()
+	}
+}
@@ -4,9 +4,11 @@ use crate::utils::bound::{Dim, Partition};
 use crate::{Conj, ContiguousFwd, Idx, IdxInc, unzip, zip};
 use core::ops::{Index, IndexMut};
 use equator::assert;
+// the matrix product `super::mul` would otherwise clash with the scalar one used by `#[math]`
+use faer_traits::math_utils::mul;
 use faer_traits::{ComplexField, Real};
 use generativity::{Guard, make_guard};
-use linalg::zip::Last;
+use linalg::zip::{Last, Zip};
 use matref::MatRef;
 
 /// mutable view over a matrix, similar to a mutable reference to a 2d strided [prim@slice]
@@ -111,6 +113,42 @@ impl<'a, T> MatMut<'a, T> {
 	pub fn from_column_major_array_mut<const ROWS: usize, const COLS: usize>(array: &'a mut [[T; ROWS]; COLS]) -> Self {
 		unsafe { Self::from_raw_parts_mut(array as *mut _ as *mut T, ROWS, COLS, 1, ROWS as isize) }
 	}
+
+	/// see [`MatRef::permute_rows`]
+	#[track_caller]
+	pub fn permute_rows<I: crate::Index>(&self, p: PermRef<'_, I>) -> Mat<T::Canonical>
+	where
+		T: Conjugate,
+	{
+		self.rb().permute_rows(p)
+	}
+
+	/// see [`MatRef::permute_cols`]
+	#[track_caller]
+	pub fn permute_cols<I: crate::Index>(&self, p: PermRef<'_, I>) -> Mat<T::Canonical>
+	where
+		T: Conjugate,
+	{
+		self.rb().permute_cols(p)
+	}
+
+	/// see [`MatRef::permute`]
+	#[track_caller]
+	pub fn permute<I: crate::Index>(&self, row_p: PermRef<'_, I>, col_p: PermRef<'_, I>) -> Mat<T::Canonical>
+	where
+		T: Conjugate,
+	{
+		self.rb().permute(row_p, col_p)
+	}
+
+	/// see [`MatRef::pack_lower_col_major`]
+	#[track_caller]
+	pub fn pack_lower_col_major(&self) -> alloc::vec::Vec<T::Canonical>
+	where
+		T: Conjugate,
+	{
+		self.rb().pack_lower_col_major()
+	}
 }
 
 impl<'a, T, Rows: Shape, Cols: Shape, RStride: Stride, CStride: Stride> MatMut<'a, T, Rows, Cols, RStride, CStride> {
@@ -205,6 +243,18 @@ impl<'a, T, Rows: Shape, Cols: Shape, RStride: Stride, CStride: Stride> MatMut<'
 		self.imp.col_stride
 	}
 
+	/// see [`MatRef::is_col_major_contiguous`]
+	#[inline]
+	pub fn is_col_major_contiguous(&self) -> bool {
+		self.rb().is_col_major_contiguous()
+	}
+
+	/// see [`MatRef::is_row_major_contiguous`]
+	#[inline]
+	pub fn is_row_major_contiguous(&self) -> bool {
+		self.rb().is_row_major_contiguous()
+	}
+
 	/// returns a raw pointer to the element at the given index
 	#[inline]
 	pub fn ptr_at(&self, row: IdxInc<Rows>, col: IdxInc<Cols>) -> *const T {
@@ -420,6 +470,34 @@ impl<'a, T, Rows: Shape, Cols: Shape, RStride: Stride, CStride: Stride> MatMut<'
 		self.into_const().row_iter()
 	}
 
+	#[inline]
+	#[track_caller]
+	/// see [`MatRef::col_chunks`]
+	pub fn col_chunks(
+		self,
+		chunk_size: usize,
+	) -> impl 'a + ExactSizeIterator + DoubleEndedIterator<Item = MatRef<'a, T, Rows, usize, RStride, CStride>>
+	where
+		Rows: 'a,
+		Cols: 'a,
+	{
+		self.into_const().col_chunks(chunk_size)
+	}
+
+	#[inline]
+	#[track_caller]
+	/// see [`MatRef::row_chunks`]
+	pub fn row_chunks(
+		self,
+		chunk_size: usize,
+	) -> impl 'a + ExactSizeIterator + DoubleEndedIterator<Item = MatRef<'a, T, usize, Cols, RStride, CStride>>
+	where
+		Rows: 'a,
+		Cols: 'a,
+	{
+		self.into_const().row_chunks(chunk_size)
+	}
+
 	#[inline]
 	#[cfg(feature = "rayon")]
 	/// see [`MatRef::par_col_iter`]
@@ -598,6 +676,57 @@ impl<'a, T, Rows: Shape, Cols: Shape, RStride: Stride, CStride: Stride> MatMut<'
 		self.rb().norm_l2()
 	}
 
+	#[inline]
+	/// see [`MatRef::norm_fro`]
+	pub fn norm_fro(&self) -> Real<T>
+	where
+		T: Conjugate,
+	{
+		self.rb().norm_fro()
+	}
+
+	#[inline]
+	/// see [`MatRef::operator_norm_l1`]
+	pub fn operator_norm_l1(&self) -> Real<T>
+	where
+		T: Conjugate,
+	{
+		self.rb().operator_norm_l1()
+	}
+
+	#[inline]
+	/// see [`MatRef::operator_norm_linf`]
+	pub fn operator_norm_linf(&self) -> Real<T>
+	where
+		T: Conjugate,
+	{
+		self.rb().operator_norm_linf()
+	}
+
+	#[inline]
+	/// see [`MatRef::normalized_frobenius`]
+	pub fn normalized_frobenius(&self) -> Mat<T::Canonical, Rows, Cols>
+	where
+		T: Conjugate,
+	{
+		self.rb().normalized_frobenius()
+	}
+
+	/// scales `self` in place to have unit frobenius norm, leaving it unchanged if it is zero
+	#[math]
+	pub fn normalize_frobenius(&mut self)
+	where
+		T: ComplexField,
+	{
+		let norm = self.norm_fro();
+		if norm == zero::<T::Real>() {
+			return;
+		}
+
+		let scale = recip(norm);
+		zip!(self.rb_mut().as_dyn_stride_mut().as_dyn_mut()).for_each(|unzip!(x)| *x = mul_real(*x, scale));
+	}
+
 	#[inline]
 	/// see [`MatRef::squared_norm_l2`]
 	pub fn squared_norm_l2(&self) -> Real<T>
@@ -616,6 +745,170 @@ impl<'a, T, Rows: Shape, Cols: Shape, RStride: Stride, CStride: Stride> MatMut<'
 		self.rb().norm_l1()
 	}
 
+	#[inline]
+	#[track_caller]
+	/// see [`MatRef::relative_frobenius_distance`]
+	pub fn relative_frobenius_distance(&self, other: impl AsMatRef<T = T, Rows = Rows, Cols = Cols>) -> Real<T>
+	where
+		T: Conjugate,
+	{
+		self.rb().relative_frobenius_distance(other)
+	}
+
+	#[track_caller]
+	/// see [`MatRef::quantile`]
+	pub fn quantile(&self, q: T) -> T
+	where
+		T: RealField,
+	{
+		self.rb().quantile(q)
+	}
+
+	#[track_caller]
+	/// see [`MatRef::quantiles`]
+	pub fn quantiles(&self, qs: &[T]) -> alloc::vec::Vec<T>
+	where
+		T: RealField,
+	{
+		self.rb().quantiles(qs)
+	}
+
+	/// see [`MatRef::logsumexp_cols`]
+	pub fn logsumexp_cols(&self) -> Row<T, Cols>
+	where
+		T: RealField,
+	{
+		self.rb().logsumexp_cols()
+	}
+
+	/// see [`MatRef::logsumexp_rows`]
+	pub fn logsumexp_rows(&self) -> Col<T, Rows>
+	where
+		T: RealField,
+	{
+		self.rb().logsumexp_rows()
+	}
+
+	/// see [`MatRef::softmax_cols`]
+	pub fn softmax_cols(&self) -> Mat<T, Rows, Cols>
+	where
+		T: RealField,
+	{
+		self.rb().softmax_cols()
+	}
+
+	/// see [`MatRef::adjoint_cast`]
+	pub fn adjoint_cast<U: crate::WidenFrom<T>>(&self) -> Mat<U, Cols, Rows>
+	where
+		T: ComplexField,
+	{
+		self.rb().adjoint_cast()
+	}
+
+	/// see [`MatRef::trace`]
+	#[track_caller]
+	pub fn trace(&self) -> T
+	where
+		T: ComplexField,
+	{
+		self.rb().trace()
+	}
+
+	/// see [`MatRef::kron`]
+	pub fn kron<Rows2: Shape, Cols2: Shape>(&self, rhs: MatRef<'_, T, Rows2, Cols2>) -> Mat<T>
+	where
+		T: ComplexField,
+	{
+		self.rb().kron(rhs)
+	}
+
+	/// see [`MatRef::as_permutation`]
+	pub fn as_permutation(&self, atol: T::Real) -> Option<crate::perm::Perm<usize>>
+	where
+		T: ComplexField,
+	{
+		self.rb().as_permutation(atol)
+	}
+
+	/// see [`MatRef::map_axis`]
+	pub fn map_axis<U>(&self, axis: crate::Axis, f: impl FnMut(ColRef<'_, T>) -> U) -> alloc::vec::Vec<U> {
+		self.rb().map_axis(axis, f)
+	}
+
+	/// see [`MatRef::reduce_cols`]
+	pub fn reduce_cols<B: Clone + Send + Sync>(
+		&self,
+		identity: B,
+		f: impl Fn(B, &T) -> B + Sync,
+		combine: impl Fn(B, B) -> B + Sync,
+		par: Par,
+	) -> Col<B>
+	where
+		T: Sync,
+	{
+		self.rb().reduce_cols(identity, f, combine, par)
+	}
+
+	/// see [`MatRef::reduce_rows`]
+	pub fn reduce_rows<B: Clone + Send + Sync>(
+		&self,
+		identity: B,
+		f: impl Fn(B, &T) -> B + Sync,
+		combine: impl Fn(B, B) -> B + Sync,
+		par: Par,
+	) -> Col<B>
+	where
+		T: Sync,
+	{
+		self.rb().reduce_rows(identity, f, combine, par)
+	}
+
+	#[inline]
+	/// see [`MatRef::gt`]
+	pub fn gt(&self, threshold: T) -> Mat<bool, Rows, Cols>
+	where
+		T: RealField,
+	{
+		self.rb().gt(threshold)
+	}
+
+	#[inline]
+	/// see [`MatRef::lt`]
+	pub fn lt(&self, threshold: T) -> Mat<bool, Rows, Cols>
+	where
+		T: RealField,
+	{
+		self.rb().lt(threshold)
+	}
+
+	#[inline]
+	/// see [`MatRef::ge`]
+	pub fn ge(&self, threshold: T) -> Mat<bool, Rows, Cols>
+	where
+		T: RealField,
+	{
+		self.rb().ge(threshold)
+	}
+
+	#[inline]
+	/// see [`MatRef::le`]
+	pub fn le(&self, threshold: T) -> Mat<bool, Rows, Cols>
+	where
+		T: RealField,
+	{
+		self.rb().le(threshold)
+	}
+
+	#[inline]
+	#[track_caller]
+	/// see [`MatRef::gt_elem`]
+	pub fn gt_elem(&self, other: impl AsMatRef<T = T, Rows = Rows, Cols = Cols>) -> Mat<bool, Rows, Cols>
+	where
+		T: RealField,
+	{
+		self.rb().gt_elem(other)
+	}
+
 	#[inline]
 	#[math]
 	/// see [`MatRef::sum`]
@@ -635,6 +928,69 @@ impl<'a, T, Rows: Shape, Cols: Shape, RStride: Stride, CStride: Stride> MatMut<'
 		self.rb().determinant()
 	}
 
+	#[inline]
+	/// see [`MatRef::is_normal`]
+	pub fn is_normal(&self, atol: Real<T>) -> bool
+	where
+		T: Conjugate,
+	{
+		self.rb().is_normal(atol)
+	}
+
+	#[inline]
+	/// see [`MatRef::lower_bandwidth`]
+	pub fn lower_bandwidth(&self, atol: Real<T>) -> usize
+	where
+		T: Conjugate,
+	{
+		self.rb().lower_bandwidth(atol)
+	}
+
+	#[inline]
+	/// see [`MatRef::upper_bandwidth`]
+	pub fn upper_bandwidth(&self, atol: Real<T>) -> usize
+	where
+		T: Conjugate,
+	{
+		self.rb().upper_bandwidth(atol)
+	}
+
+	#[inline]
+	/// see [`MatRef::is_banded`]
+	pub fn is_banded(&self, kl: usize, ku: usize, atol: Real<T>) -> bool
+	where
+		T: Conjugate,
+	{
+		self.rb().is_banded(kl, ku, atol)
+	}
+
+	#[inline]
+	/// see [`MatRef::is_tridiagonal`]
+	pub fn is_tridiagonal(&self, atol: Real<T>) -> bool
+	where
+		T: Conjugate,
+	{
+		self.rb().is_tridiagonal(atol)
+	}
+
+	#[inline]
+	/// see [`MatRef::is_upper_hessenberg`]
+	pub fn is_upper_hessenberg(&self, atol: Real<T>) -> bool
+	where
+		T: Conjugate,
+	{
+		self.rb().is_upper_hessenberg(atol)
+	}
+
+	#[track_caller]
+	/// see [`MatRef::try_into_diag`]
+	pub fn try_into_diag(&self, atol: Real<T>) -> Option<Diag<T::Canonical>>
+	where
+		T: Conjugate,
+	{
+		self.rb().try_into_diag(atol)
+	}
+
 	#[track_caller]
 	#[inline]
 	/// see [`MatRef::get`]
@@ -948,6 +1304,34 @@ impl<'a, T, Rows: Shape, Cols: Shape, RStride: Stride, CStride: Stride> MatMut<'
 		self.into_const().row_iter().map(|x| unsafe { x.const_cast() })
 	}
 
+	#[inline]
+	#[track_caller]
+	/// see [`MatRef::col_chunks`]
+	pub fn col_chunks_mut(
+		self,
+		chunk_size: usize,
+	) -> impl 'a + ExactSizeIterator + DoubleEndedIterator<Item = MatMut<'a, T, Rows, usize, RStride, CStride>>
+	where
+		Rows: 'a,
+		Cols: 'a,
+	{
+		self.into_const().col_chunks(chunk_size).map(|x| unsafe { x.const_cast() })
+	}
+
+	#[inline]
+	#[track_caller]
+	/// see [`MatRef::row_chunks`]
+	pub fn row_chunks_mut(
+		self,
+		chunk_size: usize,
+	) -> impl 'a + ExactSizeIterator + DoubleEndedIterator<Item = MatMut<'a, T, usize, Cols, RStride, CStride>>
+	where
+		Rows: 'a,
+		Cols: 'a,
+	{
+		self.into_const().row_chunks(chunk_size).map(|x| unsafe { x.const_cast() })
+	}
+
 	#[inline]
 	pub(crate) unsafe fn as_type<U>(self) -> MatMut<'a, U, Rows, Cols, RStride, CStride> {
 		MatMut::from_raw_parts_mut(
@@ -1274,6 +1658,153 @@ impl<'a, T, Rows: Shape, Cols: Shape, RStride: Stride, CStride: Stride> MatMut<'
 		}
 	}
 
+	/// computes `self = alpha * x + beta * self`
+	///
+	/// if `beta` is zero, the previous contents of `self` are not read, so they may contain
+	/// arbitrary values (including `NaN`)
+	#[track_caller]
+	pub fn axpby<XT: Conjugate<Canonical = T>>(&mut self, alpha: T, x: impl AsMatRef<T = XT, Rows = Rows, Cols = Cols>, beta: T, par: Par)
+	where
+		T: ComplexField,
+	{
+		let x = x.as_mat_ref();
+
+		assert!(all(self.nrows() == x.nrows(), self.ncols() == x.ncols(),));
+
+		let this = self.rb_mut().as_dyn_mut().as_dyn_stride_mut();
+		let x = x.as_dyn().as_dyn_stride();
+		imp(this, &alpha, x.canonical(), Conj::get::<XT>(), &beta, par);
+
+		#[math]
+		fn imp<T: ComplexField>(this: MatMut<'_, T>, alpha: &T, x: MatRef<'_, T>, conj_x: Conj, beta: &T, par: Par) {
+			let (m, n) = this.shape();
+
+			if n > 1 && par.degree() > 1 && m * n >= 4096 {
+				let (left, right) = this.split_at_col_mut(n / 2);
+				let (x_left, x_right) = x.split_at_col(n / 2);
+				crate::utils::thread::join_raw(
+					|par| imp(left, alpha, x_left, conj_x, beta, par),
+					|par| imp(right, alpha, x_right, conj_x, beta, par),
+					par,
+				);
+				return;
+			}
+
+			let beta_is_zero = *beta == zero();
+			match (conj_x, beta_is_zero) {
+				(Conj::No, true) => zip!(this, x).for_each(|unzip!(dst, src)| *dst = *alpha * *src),
+				(Conj::Yes, true) => zip!(this, x).for_each(|unzip!(dst, src)| *dst = *alpha * conj(*src)),
+				(Conj::No, false) => zip!(this, x).for_each(|unzip!(dst, src)| *dst = *alpha * *src + *beta * *dst),
+				(Conj::Yes, false) => zip!(this, x).for_each(|unzip!(dst, src)| *dst = *alpha * conj(*src) + *beta * *dst),
+			}
+		}
+	}
+
+	/// computes `self += alpha * x * y^H`
+	///
+	/// this is the rank-one update primitive `ger` from blas. for complex scalars, `y` is
+	/// conjugated
+	#[track_caller]
+	pub fn ger(&mut self, alpha: T, x: ColRef<'_, T, Rows>, y: ColRef<'_, T, Cols>, par: Par)
+	where
+		T: ComplexField,
+	{
+		assert!(all(self.nrows() == x.nrows(), self.ncols() == y.nrows()));
+
+		crate::linalg::matmul::matmul(
+			self.rb_mut().as_dyn_stride_mut(),
+			Accum::Add,
+			x.as_dyn_stride().as_mat(),
+			y.as_dyn_stride().adjoint().as_mat(),
+			alpha,
+			par,
+		);
+	}
+
+	/// replaces each element of `self` with its maximum with `value`
+	///
+	/// `max_scalar_in_place(0.0)` applies the rectified linear unit element-wise
+	pub fn max_scalar_in_place(&mut self, value: T)
+	where
+		T: RealField,
+	{
+		zip!(self.rb_mut().as_dyn_mut()).for_each(|unzip!(x)| {
+			if *x < value {
+				*x = value.clone();
+			}
+		});
+	}
+
+	/// replaces each element of `self` with its minimum with `value`
+	pub fn min_scalar_in_place(&mut self, value: T)
+	where
+		T: RealField,
+	{
+		zip!(self.rb_mut().as_dyn_mut()).for_each(|unzip!(x)| {
+			if *x > value {
+				*x = value.clone();
+			}
+		});
+	}
+
+	/// replaces each column of `self` with its softmax, $x_i \mapsto \exp(x_i) / \sum_k \exp(x_k)$
+	///
+	/// the maximum of each column is subtracted before exponentiating, so that large elements
+	/// don't overflow
+	///
+	/// if the maximum of a column is infinite, the weight is split evenly between the elements
+	/// equal to it, which is the limit of the softmax as they grow together. in particular, a
+	/// column whose elements are all $-\infty$ is replaced with uniform weights. a column
+	/// containing a nan is replaced with nans
+	#[math]
+	pub fn softmax_cols_in_place(&mut self)
+	where
+		T: RealField,
+	{
+		for mut col in self.rb_mut().col_iter_mut() {
+			let mut m = -infinity::<T>();
+			for x in col.rb().iter() {
+				m = max(m, *x);
+			}
+
+			// `x - m` would be nan for the infinite elements
+			if m == infinity::<T>() || m == -infinity::<T>() {
+				let mut count = 0usize;
+				let mut has_nan = false;
+				for x in col.rb_mut().iter_mut() {
+					has_nan = has_nan || is_nan(*x);
+					if *x == m {
+						count += 1;
+						*x = one();
+					} else {
+						*x = zero();
+					}
+				}
+
+				if has_nan {
+					col.fill(nan());
+				} else {
+					let inv = recip(from_f64::<T>(count as f64));
+					for x in col.iter_mut() {
+						*x = *x * inv;
+					}
+				}
+				continue;
+			}
+
+			let mut sum = zero::<T>();
+			for x in col.rb_mut().iter_mut() {
+				*x = exp(&(*x - m));
+				sum = sum + *x;
+			}
+
+			let inv = recip(sum);
+			for x in col.iter_mut() {
+				*x = *x * inv;
+			}
+		}
+	}
+
 	/// copies the lower triangular half of `other`, excluding the diagonal, into `self`
 	#[inline]
 	#[track_caller]
@@ -1333,6 +1864,44 @@ impl<'a, T, Rows: Shape, Cols: Shape, RStride: Stride, CStride: Stride> MatMut<'
 		z!(self.rb_mut().as_dyn_mut()).for_each(cloner::<T>(value));
 	}
 
+	/// swaps the elements of `self` with the elements of `other`
+	///
+	/// # panics
+	/// panics if `self` and `other` don't have the same shape
+	#[inline]
+	#[track_caller]
+	pub fn swap_with(&mut self, other: impl AsMatMut<T = T, Rows = Rows, Cols = Cols>) {
+		fn swap<T>() -> impl FnMut(Zip<&mut T, Last<&mut T>>) {
+			|unzip!(a, b)| core::mem::swap(a, b)
+		}
+
+		let mut other = other;
+		let other = other.as_mat_mut();
+		assert!(all(self.nrows() == other.nrows(), self.ncols() == other.ncols()));
+
+		z!(self.rb_mut().as_dyn_mut(), other.as_dyn_mut()).for_each(swap::<T>());
+	}
+
+	/// returns a guard that checks that every element of `self` is written before it is dropped
+	///
+	/// when `debug_assertions` are enabled, the elements are first filled with `NaN`, and dropping
+	/// the guard panics if any of them is still `NaN`. otherwise, this is a no-op. this is meant to
+	/// catch uninitialized scratch space that is read before being fully written
+	///
+	/// the guard dereferences to the wrapped view. note that writing `NaN` to an element
+	/// explicitly is indistinguishable from not writing it
+	#[track_caller]
+	pub fn assume_uninit_then_written(self) -> AssumeWritten<'a, T, Rows, Cols, RStride, CStride>
+	where
+		T: ComplexField,
+	{
+		#[allow(unused_mut)]
+		let mut inner = self;
+		#[cfg(debug_assertions)]
+		inner.fill(nan());
+		AssumeWritten { inner }
+	}
+
 	#[inline]
 	#[track_caller]
 	pub(crate) fn read(&self, row: Idx<Rows>, col: Idx<Cols>) -> T
@@ -1548,6 +2117,40 @@ impl<'COLS, 'a, T, Rows: Shape, RStride: Stride, CStride: Stride> MatMut<'a, T,
 	}
 }
 
+impl<'a, T: RealField, Rows: Shape, Cols: Shape, RStride: Stride, CStride: Stride> MatMut<'a, Complex<T>, Rows, Cols, RStride, CStride> {
+	/// see [`MatRef::try_into_real`]
+	pub fn try_into_real(&self, atol: T) -> Option<Mat<T, Rows, Cols>> {
+		self.rb().try_into_real(atol)
+	}
+}
+
+impl<'a, Rows: Shape, Cols: Shape, RStride: Stride, CStride: Stride> MatMut<'a, bool, Rows, Cols, RStride, CStride> {
+	/// see [`MatRef::any`]
+	pub fn any(&self) -> bool {
+		self.rb().any()
+	}
+
+	/// see [`MatRef::all`]
+	pub fn all(&self) -> bool {
+		self.rb().all()
+	}
+
+	/// see [`MatRef::count_true`]
+	pub fn count_true(&self) -> usize {
+		self.rb().count_true()
+	}
+
+	/// see [`MatRef::any_cols`]
+	pub fn any_cols(&self) -> alloc::vec::Vec<bool> {
+		self.rb().any_cols()
+	}
+
+	/// see [`MatRef::all_cols`]
+	pub fn all_cols(&self) -> alloc::vec::Vec<bool> {
+		self.rb().all_cols()
+	}
+}
+
 impl<'a, T, Dim: Shape, RStride: Stride, CStride: Stride> MatMut<'a, T, Dim, Dim, RStride, CStride> {
 	/// see [`MatRef::diagonal`]
 	#[inline]
@@ -1582,6 +2185,48 @@ impl<T, Rows: Shape, Cols: Shape, RStride: Stride, CStride: Stride> IndexMut<(Id
 	}
 }
 
+/// guard returned by [`MatMut::assume_uninit_then_written`]
+pub struct AssumeWritten<'a, T: ComplexField, Rows: Shape = usize, Cols: Shape = usize, RStride: Stride = isize, CStride: Stride = isize> {
+	inner: MatMut<'a, T, Rows, Cols, RStride, CStride>,
+}
+
+impl<'a, T: ComplexField, Rows: Shape, Cols: Shape, RStride: Stride, CStride: Stride> core::ops::Deref
+	for AssumeWritten<'a, T, Rows, Cols, RStride, CStride>
+{
+	type Target = MatMut<'a, T, Rows, Cols, RStride, CStride>;
+
+	#[inline]
+	fn deref(&self) -> &Self::Target {
+		&self.inner
+	}
+}
+
+impl<T: ComplexField, Rows: Shape, Cols: Shape, RStride: Stride, CStride: Stride> core::ops::DerefMut
+	for AssumeWritten<'_, T, Rows, Cols, RStride, CStride>
+{
+	#[inline]
+	fn deref_mut(&mut self) -> &mut Self::Target {
+		&mut self.inner
+	}
+}
+
+impl<T: ComplexField, Rows: Shape, Cols: Shape, RStride: Stride, CStride: Stride> Drop for AssumeWritten<'_, T, Rows, Cols, RStride, CStride> {
+	#[track_caller]
+	fn drop(&mut self) {
+		#[cfg(debug_assertions)]
+		{
+			#[cfg(feature = "std")]
+			if std::thread::panicking() {
+				return;
+			}
+
+			let mut written = true;
+			z!(self.inner.rb().as_dyn_stride().as_dyn()).for_each(|uz!(x)| written = written && !is_nan(x));
+			assert!(written, "some elements were not written");
+		}
+	}
+}
+
 impl<'a, T: core::fmt::Debug, Rows: Shape, Cols: Shape, RStride: Stride, CStride: Stride> core::fmt::Debug
 	for MatMut<'a, T, Rows, Cols, RStride, CStride>
 {
@@ -1590,6 +2235,30 @@ impl<'a, T: core::fmt::Debug, Rows: Shape, Cols: Shape, RStride: Stride, CStride
 	}
 }
 
+impl<'a, T: core::fmt::Display, Rows: Shape, Cols: Shape, RStride: Stride, CStride: Stride> core::fmt::Display
+	for MatMut<'a, T, Rows, Cols, RStride, CStride>
+{
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		self.rb().fmt(f)
+	}
+}
+
+impl<'a, T: core::fmt::LowerHex, Rows: Shape, Cols: Shape, RStride: Stride, CStride: Stride> core::fmt::LowerHex
+	for MatMut<'a, T, Rows, Cols, RStride, CStride>
+{
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		self.rb().fmt(f)
+	}
+}
+
+impl<'a, T: core::fmt::UpperHex, Rows: Shape, Cols: Shape, RStride: Stride, CStride: Stride> core::fmt::UpperHex
+	for MatMut<'a, T, Rows, Cols, RStride, CStride>
+{
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		self.rb().fmt(f)
+	}
+}
+
 #[track_caller]
 #[inline]
 fn from_strided_column_major_slice_mut_assert(nrows: usize, ncols: usize, col_stride: usize, len: usize) {
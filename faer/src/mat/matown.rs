@@ -1,6 +1,6 @@
 use super::*;
 use crate::internal_prelude::*;
-use crate::{Idx, IdxInc, TryReserveError};
+use crate::{Idx, IdxInc, Order, TryReserveError, assert};
 use core::alloc::Layout;
 use core::ops::{Index, IndexMut};
 use dyn_stack::StackReq;
@@ -354,6 +354,217 @@ impl<T> Mat<T> {
 		me.reserve(row_capacity, col_capacity);
 		me
 	}
+
+	/// returns a new `nrows × ncols` matrix holding the elements of `data`, which are stored in
+	/// the given `order`
+	///
+	/// if `order` is [`Order::ColMajor`], the allocation of `data` is reused directly without
+	/// copying. otherwise, the elements are moved into a new column-major buffer
+	///
+	/// # panics
+	/// the function panics if `data.len() != nrows * ncols`
+	#[track_caller]
+	pub fn from_parts(nrows: usize, ncols: usize, data: alloc::vec::Vec<T>, order: Order) -> Self {
+		assert!(Some(data.len()) == nrows.checked_mul(ncols));
+
+		if nrows == 0 || ncols == 0 {
+			return Self::from_fn(nrows, ncols, |_, _| unreachable!());
+		}
+
+		let mut data = core::mem::ManuallyDrop::new(data);
+		match order {
+			Order::ColMajor => {
+				let capacity = data.capacity();
+				Self {
+					raw: RawMat {
+						ptr: unsafe { NonNull::new_unchecked(data.as_mut_ptr()) },
+						row_capacity: nrows,
+						col_capacity: capacity / nrows,
+						layout: StackReq::new::<T>(capacity),
+						__marker: PhantomData,
+					},
+					nrows,
+					ncols,
+				}
+			},
+			Order::RowMajor => {
+				let ptr = data.as_ptr();
+				let mat = Self::from_fn(nrows, ncols, |i, j| unsafe { ptr.add(i * ncols + j).read() });
+				// the elements have been moved out, so only the allocation needs to be freed
+				unsafe {
+					data.set_len(0);
+					core::mem::ManuallyDrop::drop(&mut data);
+				}
+				mat
+			},
+		}
+	}
+
+	/// returns a new matrix assembled from the $2 \times 2$ block layout
+	/// $$\begin{bmatrix} A_{11} & A_{12} \\ A_{21} & A_{22} \end{bmatrix}$$
+	///
+	/// this is the layout of KKT and saddle point systems. see [`concat!`](crate::concat) for
+	/// general block layouts
+	///
+	/// # panics
+	/// the function panics if the blocks in the same block row don't have the same number of
+	/// rows, or if the blocks in the same block column don't have the same number of columns
+	#[track_caller]
+	pub fn from_blocks_2x2(a11: MatRef<'_, T>, a12: MatRef<'_, T>, a21: MatRef<'_, T>, a22: MatRef<'_, T>) -> Self
+	where
+		T: Clone,
+	{
+		assert!(all(
+			a11.nrows() == a12.nrows(),
+			a21.nrows() == a22.nrows(),
+			a11.ncols() == a21.ncols(),
+			a12.ncols() == a22.ncols(),
+		));
+
+		let (m, n) = (a11.nrows(), a11.ncols());
+		Self::from_fn(m + a21.nrows(), n + a12.ncols(), |i, j| match (i < m, j < n) {
+			(true, true) => a11[(i, j)].clone(),
+			(true, false) => a12[(i, j - n)].clone(),
+			(false, true) => a21[(i - m, j)].clone(),
+			(false, false) => a22[(i - m, j - n)].clone(),
+		})
+	}
+
+	/// see [`MatRef::permute_rows`]
+	#[track_caller]
+	pub fn permute_rows<I: crate::Index>(&self, p: PermRef<'_, I>) -> Mat<T::Canonical>
+	where
+		T: Conjugate,
+	{
+		self.as_ref().permute_rows(p)
+	}
+
+	/// see [`MatRef::permute_cols`]
+	#[track_caller]
+	pub fn permute_cols<I: crate::Index>(&self, p: PermRef<'_, I>) -> Mat<T::Canonical>
+	where
+		T: Conjugate,
+	{
+		self.as_ref().permute_cols(p)
+	}
+
+	/// see [`MatRef::permute`]
+	#[track_caller]
+	pub fn permute<I: crate::Index>(&self, row_p: PermRef<'_, I>, col_p: PermRef<'_, I>) -> Mat<T::Canonical>
+	where
+		T: Conjugate,
+	{
+		self.as_ref().permute(row_p, col_p)
+	}
+
+	/// see [`MatRef::pack_lower_col_major`]
+	#[track_caller]
+	pub fn pack_lower_col_major(&self) -> alloc::vec::Vec<T::Canonical>
+	where
+		T: Conjugate,
+	{
+		self.as_ref().pack_lower_col_major()
+	}
+
+	/// returns a new `n×n` matrix from a lower triangle stored in the LAPACK column-major packed
+	/// layout, as produced by [`MatRef::pack_lower_col_major`]
+	///
+	/// the element at index `i + j * (2 * n - j - 1) / 2` of `data` is the element at row $i$ and
+	/// column $j \le i$ of the lower triangle. if `side` is [`Side::Lower`], it is stored at that
+	/// position. if `side` is [`Side::Upper`], its conjugate is stored at row $j$ and column $i$
+	/// instead, which gives the upper triangle of the same self-adjoint matrix. the other triangle
+	/// is filled with zeros
+	///
+	/// # panics
+	/// the function panics if `data.len() != n * (n + 1) / 2`
+	#[track_caller]
+	pub fn from_packed_lower(data: &[T], n: usize, side: Side) -> Self
+	where
+		T: ComplexField,
+	{
+		assert!(data.len() == n * (n + 1) / 2);
+
+		let mut A = Mat::zeros(n, n);
+		let mut k = 0;
+		for j in 0..n {
+			for i in j..n {
+				match side {
+					Side::Lower => A[(i, j)] = data[k].clone(),
+					Side::Upper => A[(j, i)] = faer_traits::math_utils::conj(&data[k]),
+				}
+				k += 1;
+			}
+		}
+		A
+	}
+
+	/// returns the horizontal concatenation of the given matrices
+	///
+	/// # panics
+	/// the function panics if the matrices don't all have the same number of rows
+	#[track_caller]
+	pub fn hstack(mats: &[MatRef<'_, T>]) -> Self
+	where
+		T: ComplexField,
+	{
+		Self::block(&[mats])
+	}
+
+	/// returns the vertical concatenation of the given matrices
+	///
+	/// # panics
+	/// the function panics if the matrices don't all have the same number of columns
+	#[track_caller]
+	pub fn vstack(mats: &[MatRef<'_, T>]) -> Self
+	where
+		T: ComplexField,
+	{
+		let rows: alloc::vec::Vec<&[MatRef<'_, T>]> = mats.iter().map(core::slice::from_ref).collect();
+		Self::block(&rows)
+	}
+
+	/// returns the matrix assembled from a grid of blocks, where `blocks[i][j]` is the block at
+	/// block row $i$ and block column $j$
+	///
+	/// unlike [`concat!`](crate::concat), the blocks must form a regular grid
+	///
+	/// # panics
+	/// the function panics if the block rows don't all have the same number of blocks, if the
+	/// blocks in a block row don't all have the same number of rows, or if the blocks in a block
+	/// column don't all have the same number of columns
+	#[track_caller]
+	pub fn block(blocks: &[&[MatRef<'_, T>]]) -> Self
+	where
+		T: ComplexField,
+	{
+		let first: &[MatRef<'_, T>] = blocks.first().copied().unwrap_or(&[]);
+		assert!(blocks.iter().all(|row| row.len() == first.len()));
+
+		let mut m = 0;
+		for row in blocks {
+			let height = row.first().map_or(0, |A| A.nrows());
+			assert!(row.iter().all(|A| A.nrows() == height));
+			m += height;
+		}
+		let mut n = 0;
+		for (j, A) in first.iter().enumerate() {
+			let width = A.ncols();
+			assert!(blocks.iter().all(|row| row[j].ncols() == width));
+			n += width;
+		}
+
+		let mut out = Mat::zeros(m, n);
+		let mut i = 0;
+		for row in blocks {
+			let mut j = 0;
+			for A in *row {
+				out.as_mut().submatrix_mut(i, j, A.nrows(), A.ncols()).copy_from(A);
+				j += A.ncols();
+			}
+			i += row.first().map_or(0, |A| A.nrows());
+		}
+		out
+	}
 }
 
 impl<T, Rows: Shape, Cols: Shape> Mat<T, Rows, Cols> {
@@ -540,6 +751,15 @@ impl<T, Rows: Shape, Cols: Shape> Mat<T, Rows, Cols> {
 		}
 	}
 
+	/// swaps the contents of `self` and `other` by exchanging their buffers, without moving any
+	/// elements
+	///
+	/// the shapes of `self` and `other` don't need to match
+	#[inline]
+	pub fn swap(&mut self, other: &mut Self) {
+		core::mem::swap(self, other)
+	}
+
 	/// see [`MatRef::as_shape`]
 	pub fn into_shape<V: Shape, H: Shape>(self, nrows: V, ncols: H) -> Mat<T, V, H> {
 		let this = core::mem::ManuallyDrop::new(self);
@@ -649,6 +869,31 @@ impl<T: core::fmt::Debug, Rows: Shape, Cols: Shape> core::fmt::Debug for Mat<T,
 	}
 }
 
+impl<T: core::fmt::Display, Rows: Shape, Cols: Shape> core::fmt::Display for Mat<T, Rows, Cols> {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		self.as_ref().fmt(f)
+	}
+}
+
+impl<T: core::fmt::LowerHex, Rows: Shape, Cols: Shape> core::fmt::LowerHex for Mat<T, Rows, Cols> {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		self.as_ref().fmt(f)
+	}
+}
+
+impl<T: core::fmt::UpperHex, Rows: Shape, Cols: Shape> core::fmt::UpperHex for Mat<T, Rows, Cols> {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		self.as_ref().fmt(f)
+	}
+}
+
+impl<T: core::hash::Hash, Rows: Shape, Cols: Shape> core::hash::Hash for Mat<T, Rows, Cols> {
+	#[inline]
+	fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+		self.as_ref().hash(state)
+	}
+}
+
 impl<T, Rows: Shape, Cols: Shape> Mat<T, Rows, Cols> {
 	/// returns a pointer to the matrix data
 	#[inline(always)]
@@ -674,6 +919,18 @@ impl<T, Rows: Shape, Cols: Shape> Mat<T, Rows, Cols> {
 		self.raw.row_capacity as isize
 	}
 
+	/// see [`MatRef::is_col_major_contiguous`]
+	#[inline]
+	pub fn is_col_major_contiguous(&self) -> bool {
+		self.as_ref().is_col_major_contiguous()
+	}
+
+	/// see [`MatRef::is_row_major_contiguous`]
+	#[inline]
+	pub fn is_row_major_contiguous(&self) -> bool {
+		self.as_ref().is_row_major_contiguous()
+	}
+
 	/// returns a raw pointer to the element at the given index
 	#[inline(always)]
 	pub fn ptr_at(&self, row: IdxInc<Rows>, col: IdxInc<Cols>) -> *const T {
@@ -859,6 +1116,20 @@ impl<T, Rows: Shape, Cols: Shape> Mat<T, Rows, Cols> {
 		self.as_ref().row_iter()
 	}
 
+	#[inline]
+	#[track_caller]
+	/// see [`MatRef::col_chunks`]
+	pub fn col_chunks(&self, chunk_size: usize) -> impl '_ + ExactSizeIterator + DoubleEndedIterator<Item = MatRef<'_, T, Rows, usize>> {
+		self.as_ref().col_chunks(chunk_size)
+	}
+
+	#[inline]
+	#[track_caller]
+	/// see [`MatRef::row_chunks`]
+	pub fn row_chunks(&self, chunk_size: usize) -> impl '_ + ExactSizeIterator + DoubleEndedIterator<Item = MatRef<'_, T, usize, Cols>> {
+		self.as_ref().row_chunks(chunk_size)
+	}
+
 	#[inline]
 	#[track_caller]
 	#[cfg(feature = "rayon")]
@@ -955,6 +1226,215 @@ impl<T, Rows: Shape, Cols: Shape> Mat<T, Rows, Cols> {
 		self.as_ref().norm_l2()
 	}
 
+	#[inline]
+	/// see [`MatRef::norm_fro`]
+	pub fn norm_fro(&self) -> Real<T>
+	where
+		T: Conjugate,
+	{
+		self.as_ref().norm_fro()
+	}
+
+	#[inline]
+	/// see [`MatRef::operator_norm_l1`]
+	pub fn operator_norm_l1(&self) -> Real<T>
+	where
+		T: Conjugate,
+	{
+		self.as_ref().operator_norm_l1()
+	}
+
+	#[inline]
+	/// see [`MatRef::operator_norm_linf`]
+	pub fn operator_norm_linf(&self) -> Real<T>
+	where
+		T: Conjugate,
+	{
+		self.as_ref().operator_norm_linf()
+	}
+
+	#[inline]
+	/// see [`MatRef::normalized_frobenius`]
+	pub fn normalized_frobenius(&self) -> Mat<T::Canonical, Rows, Cols>
+	where
+		T: Conjugate,
+	{
+		self.as_ref().normalized_frobenius()
+	}
+
+	#[inline]
+	/// see [`MatMut::normalize_frobenius`]
+	pub fn normalize_frobenius(&mut self)
+	where
+		T: ComplexField,
+	{
+		self.as_mut().normalize_frobenius()
+	}
+
+	#[inline]
+	#[track_caller]
+	/// see [`MatRef::relative_frobenius_distance`]
+	pub fn relative_frobenius_distance(&self, other: impl AsMatRef<T = T, Rows = Rows, Cols = Cols>) -> Real<T>
+	where
+		T: Conjugate,
+	{
+		self.as_ref().relative_frobenius_distance(other)
+	}
+
+	#[track_caller]
+	/// see [`MatRef::quantile`]
+	pub fn quantile(&self, q: T) -> T
+	where
+		T: RealField,
+	{
+		self.as_ref().quantile(q)
+	}
+
+	#[track_caller]
+	/// see [`MatRef::quantiles`]
+	pub fn quantiles(&self, qs: &[T]) -> alloc::vec::Vec<T>
+	where
+		T: RealField,
+	{
+		self.as_ref().quantiles(qs)
+	}
+
+	/// see [`MatRef::logsumexp_cols`]
+	pub fn logsumexp_cols(&self) -> Row<T, Cols>
+	where
+		T: RealField,
+	{
+		self.as_ref().logsumexp_cols()
+	}
+
+	/// see [`MatRef::logsumexp_rows`]
+	pub fn logsumexp_rows(&self) -> Col<T, Rows>
+	where
+		T: RealField,
+	{
+		self.as_ref().logsumexp_rows()
+	}
+
+	/// see [`MatRef::softmax_cols`]
+	pub fn softmax_cols(&self) -> Mat<T, Rows, Cols>
+	where
+		T: RealField,
+	{
+		self.as_ref().softmax_cols()
+	}
+
+	/// see [`MatRef::adjoint_cast`]
+	pub fn adjoint_cast<U: crate::WidenFrom<T>>(&self) -> Mat<U, Cols, Rows>
+	where
+		T: ComplexField,
+	{
+		self.as_ref().adjoint_cast()
+	}
+
+	/// see [`MatRef::trace`]
+	#[track_caller]
+	pub fn trace(&self) -> T
+	where
+		T: ComplexField,
+	{
+		self.as_ref().trace()
+	}
+
+	/// see [`MatRef::kron`]
+	pub fn kron<Rows2: Shape, Cols2: Shape>(&self, rhs: MatRef<'_, T, Rows2, Cols2>) -> Mat<T>
+	where
+		T: ComplexField,
+	{
+		self.as_ref().kron(rhs)
+	}
+
+	/// see [`MatRef::as_permutation`]
+	pub fn as_permutation(&self, atol: T::Real) -> Option<crate::perm::Perm<usize>>
+	where
+		T: ComplexField,
+	{
+		self.as_ref().as_permutation(atol)
+	}
+
+	/// see [`MatRef::map_axis`]
+	pub fn map_axis<U>(&self, axis: crate::Axis, f: impl FnMut(ColRef<'_, T>) -> U) -> alloc::vec::Vec<U> {
+		self.as_ref().map_axis(axis, f)
+	}
+
+	/// see [`MatRef::reduce_cols`]
+	pub fn reduce_cols<B: Clone + Send + Sync>(
+		&self,
+		identity: B,
+		f: impl Fn(B, &T) -> B + Sync,
+		combine: impl Fn(B, B) -> B + Sync,
+		par: Par,
+	) -> Col<B>
+	where
+		T: Sync,
+	{
+		self.as_ref().reduce_cols(identity, f, combine, par)
+	}
+
+	/// see [`MatRef::reduce_rows`]
+	pub fn reduce_rows<B: Clone + Send + Sync>(
+		&self,
+		identity: B,
+		f: impl Fn(B, &T) -> B + Sync,
+		combine: impl Fn(B, B) -> B + Sync,
+		par: Par,
+	) -> Col<B>
+	where
+		T: Sync,
+	{
+		self.as_ref().reduce_rows(identity, f, combine, par)
+	}
+
+	#[inline]
+	/// see [`MatRef::gt`]
+	pub fn gt(&self, threshold: T) -> Mat<bool, Rows, Cols>
+	where
+		T: RealField,
+	{
+		self.as_ref().gt(threshold)
+	}
+
+	#[inline]
+	/// see [`MatRef::lt`]
+	pub fn lt(&self, threshold: T) -> Mat<bool, Rows, Cols>
+	where
+		T: RealField,
+	{
+		self.as_ref().lt(threshold)
+	}
+
+	#[inline]
+	/// see [`MatRef::ge`]
+	pub fn ge(&self, threshold: T) -> Mat<bool, Rows, Cols>
+	where
+		T: RealField,
+	{
+		self.as_ref().ge(threshold)
+	}
+
+	#[inline]
+	/// see [`MatRef::le`]
+	pub fn le(&self, threshold: T) -> Mat<bool, Rows, Cols>
+	where
+		T: RealField,
+	{
+		self.as_ref().le(threshold)
+	}
+
+	#[inline]
+	#[track_caller]
+	/// see [`MatRef::gt_elem`]
+	pub fn gt_elem(&self, other: impl AsMatRef<T = T, Rows = Rows, Cols = Cols>) -> Mat<bool, Rows, Cols>
+	where
+		T: RealField,
+	{
+		self.as_ref().gt_elem(other)
+	}
+
 	#[inline]
 	/// see [`MatRef::determinant`]
 	pub fn determinant(&self) -> T::Canonical
@@ -964,6 +1444,69 @@ impl<T, Rows: Shape, Cols: Shape> Mat<T, Rows, Cols> {
 		self.as_ref().determinant()
 	}
 
+	#[inline]
+	/// see [`MatRef::is_normal`]
+	pub fn is_normal(&self, atol: Real<T>) -> bool
+	where
+		T: Conjugate,
+	{
+		self.as_ref().is_normal(atol)
+	}
+
+	#[inline]
+	/// see [`MatRef::lower_bandwidth`]
+	pub fn lower_bandwidth(&self, atol: Real<T>) -> usize
+	where
+		T: Conjugate,
+	{
+		self.as_ref().lower_bandwidth(atol)
+	}
+
+	#[inline]
+	/// see [`MatRef::upper_bandwidth`]
+	pub fn upper_bandwidth(&self, atol: Real<T>) -> usize
+	where
+		T: Conjugate,
+	{
+		self.as_ref().upper_bandwidth(atol)
+	}
+
+	#[inline]
+	/// see [`MatRef::is_banded`]
+	pub fn is_banded(&self, kl: usize, ku: usize, atol: Real<T>) -> bool
+	where
+		T: Conjugate,
+	{
+		self.as_ref().is_banded(kl, ku, atol)
+	}
+
+	#[inline]
+	/// see [`MatRef::is_tridiagonal`]
+	pub fn is_tridiagonal(&self, atol: Real<T>) -> bool
+	where
+		T: Conjugate,
+	{
+		self.as_ref().is_tridiagonal(atol)
+	}
+
+	#[inline]
+	/// see [`MatRef::is_upper_hessenberg`]
+	pub fn is_upper_hessenberg(&self, atol: Real<T>) -> bool
+	where
+		T: Conjugate,
+	{
+		self.as_ref().is_upper_hessenberg(atol)
+	}
+
+	#[track_caller]
+	/// see [`MatRef::try_into_diag`]
+	pub fn try_into_diag(&self, atol: Real<T>) -> Option<Diag<T::Canonical>>
+	where
+		T: Conjugate,
+	{
+		self.as_ref().try_into_diag(atol)
+	}
+
 	#[track_caller]
 	#[inline]
 	/// see [`MatRef::get`]
@@ -1223,6 +1766,20 @@ impl<T, Rows: Shape, Cols: Shape> Mat<T, Rows, Cols> {
 		self.as_mut().row_iter_mut()
 	}
 
+	#[inline]
+	#[track_caller]
+	/// see [`MatMut::col_chunks_mut`]
+	pub fn col_chunks_mut(&mut self, chunk_size: usize) -> impl '_ + ExactSizeIterator + DoubleEndedIterator<Item = MatMut<'_, T, Rows, usize>> {
+		self.as_mut().col_chunks_mut(chunk_size)
+	}
+
+	#[inline]
+	#[track_caller]
+	/// see [`MatMut::row_chunks_mut`]
+	pub fn row_chunks_mut(&mut self, chunk_size: usize) -> impl '_ + ExactSizeIterator + DoubleEndedIterator<Item = MatMut<'_, T, usize, Cols>> {
+		self.as_mut().row_chunks_mut(chunk_size)
+	}
+
 	#[inline]
 	#[track_caller]
 	#[cfg(feature = "rayon")]
@@ -1330,6 +1887,55 @@ impl<T, Rows: Shape, Cols: Shape> Mat<T, Rows, Cols> {
 		self.as_mut().copy_from(other)
 	}
 
+	#[inline]
+	#[track_caller]
+	/// see [`MatMut::swap_with`]
+	pub fn swap_with(&mut self, other: impl AsMatMut<T = T, Rows = Rows, Cols = Cols>) {
+		self.as_mut().swap_with(other)
+	}
+
+	#[track_caller]
+	/// see [`MatMut::axpby`]
+	pub fn axpby<XT: Conjugate<Canonical = T>>(&mut self, alpha: T, x: impl AsMatRef<T = XT, Rows = Rows, Cols = Cols>, beta: T, par: Par)
+	where
+		T: ComplexField,
+	{
+		self.as_mut().axpby(alpha, x, beta, par)
+	}
+
+	#[track_caller]
+	/// see [`MatMut::ger`]
+	pub fn ger(&mut self, alpha: T, x: ColRef<'_, T, Rows>, y: ColRef<'_, T, Cols>, par: Par)
+	where
+		T: ComplexField,
+	{
+		self.as_mut().ger(alpha, x, y, par)
+	}
+
+	/// see [`MatMut::max_scalar_in_place`]
+	pub fn max_scalar_in_place(&mut self, value: T)
+	where
+		T: RealField,
+	{
+		self.as_mut().max_scalar_in_place(value)
+	}
+
+	/// see [`MatMut::min_scalar_in_place`]
+	pub fn min_scalar_in_place(&mut self, value: T)
+	where
+		T: RealField,
+	{
+		self.as_mut().min_scalar_in_place(value)
+	}
+
+	/// see [`MatMut::softmax_cols_in_place`]
+	pub fn softmax_cols_in_place(&mut self)
+	where
+		T: RealField,
+	{
+		self.as_mut().softmax_cols_in_place()
+	}
+
 	#[inline]
 	/// see [`MatMut::copy_from_triangular_lower`]
 	pub fn copy_from_triangular_lower<RhsT: Conjugate<Canonical = T>>(&mut self, other: impl AsMatRef<T = RhsT, Rows = Rows, Cols = Cols>)
@@ -1367,6 +1973,46 @@ impl<T, Rows: Shape, Cols: Shape> Mat<T, Rows, Cols> {
 	}
 }
 
+impl<T: RealField, Rows: Shape, Cols: Shape> Mat<Complex<T>, Rows, Cols> {
+	/// returns a new matrix with dimensions `(nrows, ncols)`, whose real and imaginary parts are
+	/// filled with the provided functions
+	pub fn from_fn_complex(nrows: Rows, ncols: Cols, re: impl Fn(Idx<Rows>, Idx<Cols>) -> T, im: impl Fn(Idx<Rows>, Idx<Cols>) -> T) -> Self {
+		Self::from_fn(nrows, ncols, |i, j| Complex::new(re(i, j), im(i, j)))
+	}
+
+	/// see [`MatRef::try_into_real`]
+	pub fn try_into_real(&self, atol: T) -> Option<Mat<T, Rows, Cols>> {
+		self.as_ref().try_into_real(atol)
+	}
+}
+
+impl<Rows: Shape, Cols: Shape> Mat<bool, Rows, Cols> {
+	/// see [`MatRef::any`]
+	pub fn any(&self) -> bool {
+		self.as_ref().any()
+	}
+
+	/// see [`MatRef::all`]
+	pub fn all(&self) -> bool {
+		self.as_ref().all()
+	}
+
+	/// see [`MatRef::count_true`]
+	pub fn count_true(&self) -> usize {
+		self.as_ref().count_true()
+	}
+
+	/// see [`MatRef::any_cols`]
+	pub fn any_cols(&self) -> alloc::vec::Vec<bool> {
+		self.as_ref().any_cols()
+	}
+
+	/// see [`MatRef::all_cols`]
+	pub fn all_cols(&self) -> alloc::vec::Vec<bool> {
+		self.as_ref().all_cols()
+	}
+}
+
 impl<T, Dim: Shape> Mat<T, Dim, Dim> {
 	#[inline]
 	/// see [`MatRef::diagonal`]
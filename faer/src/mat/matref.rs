@@ -7,6 +7,8 @@ use crate::{ContiguousFwd, Idx, IdxInc};
 use core::ops::Index;
 use equator::{assert, debug_assert};
 use faer_traits::Real;
+// the matrix product `super::mul` would otherwise clash with the scalar one used by `#[math]`
+use faer_traits::math_utils::mul;
 use generativity::Guard;
 use matmut::MatMut;
 use matown::Mat;
@@ -93,6 +95,87 @@ impl<'a, T> MatRef<'a, T> {
 	{
 		unsafe { MatRef::from_raw_parts(value as *const T, 1, 1, 0, 0) }
 	}
+
+	/// returns a new matrix whose `i`-th row is the row of `self` at index `p.arrays().0[i]`
+	///
+	/// see [`perm::permute_rows`](crate::perm::permute_rows) for a version that writes to an
+	/// existing matrix
+	///
+	/// # panics
+	/// panics if the size of the permutation doesn't match the number of rows of `self`
+	#[track_caller]
+	pub fn permute_rows<I: crate::Index>(&self, p: PermRef<'_, I>) -> Mat<T::Canonical>
+	where
+		T: Conjugate,
+	{
+		assert!(p.len() == self.nrows());
+		let fwd = p.arrays().0;
+		Mat::from_fn(self.nrows(), self.ncols(), |i, j| Conj::apply::<T>(self.at(fwd[i].zx(), j)))
+	}
+
+	/// returns a new matrix whose `j`-th column is the column of `self` at index `p.arrays().0[j]`
+	///
+	/// see [`perm::permute_cols`](crate::perm::permute_cols) for a version that writes to an
+	/// existing matrix
+	///
+	/// # panics
+	/// panics if the size of the permutation doesn't match the number of columns of `self`
+	#[track_caller]
+	pub fn permute_cols<I: crate::Index>(&self, p: PermRef<'_, I>) -> Mat<T::Canonical>
+	where
+		T: Conjugate,
+	{
+		assert!(p.len() == self.ncols());
+		let fwd = p.arrays().0;
+		Mat::from_fn(self.nrows(), self.ncols(), |i, j| Conj::apply::<T>(self.at(i, fwd[j].zx())))
+	}
+
+	/// returns a new matrix with the rows of `self` permuted by `row_p` and its columns permuted
+	/// by `col_p`
+	///
+	/// this is equivalent to `self.permute_rows(row_p).permute_cols(col_p)`
+	///
+	/// # panics
+	/// panics if the sizes of the permutations don't match the dimensions of `self`
+	#[track_caller]
+	pub fn permute<I: crate::Index>(&self, row_p: PermRef<'_, I>, col_p: PermRef<'_, I>) -> Mat<T::Canonical>
+	where
+		T: Conjugate,
+	{
+		assert!(all(row_p.len() == self.nrows(), col_p.len() == self.ncols()));
+		let row_fwd = row_p.arrays().0;
+		let col_fwd = col_p.arrays().0;
+		Mat::from_fn(self.nrows(), self.ncols(), |i, j| {
+			Conj::apply::<T>(self.at(row_fwd[i].zx(), col_fwd[j].zx()))
+		})
+	}
+
+	/// returns the lower triangle of `self` in the column-major packed layout expected by the `AP`
+	/// argument of LAPACK's packed routines, such as `?pptrf` and `?spmv` with `uplo = 'L'`
+	///
+	/// for a matrix of dimension $n$, the element at row $i$ and column $j \le i$ is stored at
+	/// index `i + j * (2 * n - j - 1) / 2`, and the output has length $n (n + 1) / 2$
+	///
+	/// see [`Mat::from_packed_lower`] for the inverse operation
+	///
+	/// # panics
+	/// panics if `self` is not a square matrix
+	#[track_caller]
+	pub fn pack_lower_col_major(&self) -> alloc::vec::Vec<T::Canonical>
+	where
+		T: Conjugate,
+	{
+		assert!(self.nrows() == self.ncols());
+		let n = self.nrows();
+
+		let mut packed = alloc::vec::Vec::with_capacity(n * (n + 1) / 2);
+		for j in 0..n {
+			for i in j..n {
+				packed.push(Conj::apply::<T>(self.at(i, j)));
+			}
+		}
+		packed
+	}
 }
 
 impl<'a, T, Rows: Shape, Cols: Shape> MatRef<'a, T, Rows, Cols> {
@@ -278,6 +361,26 @@ impl<'a, T, Rows: Shape, Cols: Shape, RStride: Stride, CStride: Stride> MatRef<'
 		self.imp.col_stride
 	}
 
+	/// returns `true` if the elements of the matrix occupy a single contiguous block of memory in
+	/// column-major order, i.e., the row stride is `1` and the column stride is `self.nrows()`
+	///
+	/// strides along dimensions of size `0` or `1` are ignored
+	#[inline]
+	pub fn is_col_major_contiguous(&self) -> bool {
+		let m = self.nrows().unbound();
+		let n = self.ncols().unbound();
+		(m <= 1 || self.row_stride().element_stride() == 1) && (n <= 1 || self.col_stride().element_stride() == m as isize)
+	}
+
+	/// returns `true` if the elements of the matrix occupy a single contiguous block of memory in
+	/// row-major order, i.e., the column stride is `1` and the row stride is `self.ncols()`
+	///
+	/// strides along dimensions of size `0` or `1` are ignored
+	#[inline]
+	pub fn is_row_major_contiguous(&self) -> bool {
+		self.transpose().is_col_major_contiguous()
+	}
+
 	/// returns a raw pointer to the element at the given index
 	#[inline]
 	pub fn ptr_at(&self, row: IdxInc<Rows>, col: IdxInc<Cols>) -> *const T {
@@ -818,6 +921,49 @@ impl<'a, T, Rows: Shape, Cols: Shape, RStride: Stride, CStride: Stride> MatRef<'
 		Rows::indices(Rows::start(), self.nrows().end()).map(move |i| self.row(i))
 	}
 
+	/// returns an iterator that provides successive chunks of the columns of this matrix, with
+	/// each having at most `chunk_size` columns
+	///
+	/// if the number of columns is a multiple of `chunk_size`, then all chunks have
+	/// `chunk_size` columns
+	#[inline]
+	#[track_caller]
+	pub fn col_chunks(
+		self,
+		chunk_size: usize,
+	) -> impl 'a + ExactSizeIterator + DoubleEndedIterator<Item = MatRef<'a, T, Rows, usize, RStride, CStride>>
+	where
+		Rows: 'a,
+		Cols: 'a,
+	{
+		let this = self.as_dyn_cols();
+
+		assert!(chunk_size > 0);
+		let chunk_count = this.ncols().msrv_div_ceil(chunk_size);
+		(0..chunk_count).map(move |chunk_idx| {
+			let pos = chunk_size * chunk_idx;
+			this.subcols(pos, Ord::min(chunk_size, this.ncols() - pos))
+		})
+	}
+
+	/// returns an iterator that provides successive chunks of the rows of this matrix, with
+	/// each having at most `chunk_size` rows
+	///
+	/// if the number of rows is a multiple of `chunk_size`, then all chunks have `chunk_size`
+	/// rows
+	#[inline]
+	#[track_caller]
+	pub fn row_chunks(
+		self,
+		chunk_size: usize,
+	) -> impl 'a + ExactSizeIterator + DoubleEndedIterator<Item = MatRef<'a, T, usize, Cols, RStride, CStride>>
+	where
+		Rows: 'a,
+		Cols: 'a,
+	{
+		self.transpose().col_chunks(chunk_size).map(MatRef::transpose)
+	}
+
 	/// returns a parallel iterator over the columns of the matrix
 	#[inline]
 	#[cfg(feature = "rayon")]
@@ -1135,6 +1281,415 @@ impl<'a, T, Rows: Shape, Cols: Shape, RStride: Stride, CStride: Stride> MatRef<'
 		linalg::reductions::norm_l1::norm_l1(self.canonical().as_dyn_stride().as_dyn())
 	}
 
+	/// returns the frobenius norm $\|A\|_F$ of `self`
+	///
+	/// this is the same as [`MatRef::norm_l2`], and is computed with scaling so that it doesn't
+	/// overflow or underflow when the squared norm would
+	#[inline]
+	pub fn norm_fro(&self) -> Real<T>
+	where
+		T: Conjugate,
+	{
+		self.norm_l2()
+	}
+
+	/// returns the operator norm of `self` induced by the vector l1 norm, which is the maximum
+	/// absolute column sum, or zero if `self` is empty
+	///
+	/// this differs from [`MatRef::norm_l1`], which returns the sum of $|\text{Re}(a_{ij})| +
+	/// |\text{Im}(a_{ij})|$ over all the elements
+	#[math]
+	pub fn operator_norm_l1(&self) -> Real<T>
+	where
+		T: Conjugate,
+	{
+		let A = self.canonical().as_dyn_stride().as_dyn();
+
+		let mut norm = zero::<Real<T>>();
+		for j in 0..A.ncols() {
+			let mut sum = zero::<Real<T>>();
+			zip!(A.col(j)).for_each(|unzip!(a)| sum = sum + abs(*a));
+			norm = max(norm, sum);
+		}
+		norm
+	}
+
+	/// returns the operator norm of `self` induced by the vector l-infinity norm, which is the
+	/// maximum absolute row sum, or zero if `self` is empty
+	#[math]
+	pub fn operator_norm_linf(&self) -> Real<T>
+	where
+		T: Conjugate,
+	{
+		let A = self.canonical().as_dyn_stride().as_dyn();
+
+		let mut row_sums = Col::<Real<T>>::zeros(A.nrows());
+		for j in 0..A.ncols() {
+			zip!(row_sums.as_mut(), A.col(j)).for_each(|unzip!(s, a)| *s = *s + abs(*a));
+		}
+
+		let mut norm = zero::<Real<T>>();
+		for s in row_sums.iter() {
+			norm = max(norm, *s);
+		}
+		norm
+	}
+
+	/// returns a copy of `self` scaled to have unit frobenius norm, or an unscaled copy if
+	/// `self` is zero
+	pub fn normalized_frobenius(&self) -> Mat<T::Canonical, Rows, Cols>
+	where
+		T: Conjugate,
+	{
+		let mut out = self.to_owned();
+		out.as_mut().normalize_frobenius();
+		out
+	}
+
+	/// returns the relative distance $\|A - B\|_F / \|B\|_F$ between `self` ($A$) and `other`
+	/// ($B$)
+	///
+	/// if $\|B\|_F$ is zero, the absolute distance $\|A - B\|_F$ is returned instead
+	#[inline]
+	#[track_caller]
+	#[math]
+	pub fn relative_frobenius_distance(&self, other: impl AsMatRef<T = T, Rows = Rows, Cols = Cols>) -> Real<T>
+	where
+		T: Conjugate,
+	{
+		let other = other.as_mat_ref();
+		assert!(all(self.nrows() == other.nrows(), self.ncols() == other.ncols()));
+
+		// both operands have the same conjugation, which doesn't change either norm
+		let A = self.canonical().as_dyn_stride().as_dyn();
+		let B = other.canonical().as_dyn_stride().as_dyn();
+
+		// the sums of squares are kept as scale^2 * ssq, so that they neither overflow nor underflow
+		let update = |scale: &mut Real<T>, ssq: &mut Real<T>, x: Real<T>| {
+			if x > *scale {
+				let r = *scale / x;
+				*ssq = one::<Real<T>>() + *ssq * r * r;
+				*scale = x;
+			} else if x > zero::<Real<T>>() {
+				let r = x / *scale;
+				*ssq = *ssq + r * r;
+			} else if is_nan(x) {
+				*ssq = x;
+			}
+		};
+
+		let (mut dist_scale, mut dist_ssq) = (zero::<Real<T>>(), one::<Real<T>>());
+		let (mut norm_scale, mut norm_ssq) = (zero::<Real<T>>(), one::<Real<T>>());
+		z!(A, B).for_each(|uz!(a, b)| {
+			update(&mut dist_scale, &mut dist_ssq, abs(*a - *b));
+			update(&mut norm_scale, &mut norm_ssq, abs(*b));
+		});
+
+		let dist = dist_scale * sqrt(dist_ssq);
+		let norm = norm_scale * sqrt(norm_ssq);
+
+		if norm == zero::<Real<T>>() { dist } else { dist / norm }
+	}
+
+	/// returns a boolean mask that is `true` at the positions where the element of `self` is
+	/// greater than `threshold`
+	#[inline]
+	pub fn gt(&self, threshold: T) -> Mat<bool, Rows, Cols>
+	where
+		T: RealField,
+	{
+		Mat::from_fn(self.nrows(), self.ncols(), |i, j| self[(i, j)] > threshold)
+	}
+
+	/// returns a boolean mask that is `true` at the positions where the element of `self` is
+	/// less than `threshold`
+	#[inline]
+	pub fn lt(&self, threshold: T) -> Mat<bool, Rows, Cols>
+	where
+		T: RealField,
+	{
+		Mat::from_fn(self.nrows(), self.ncols(), |i, j| self[(i, j)] < threshold)
+	}
+
+	/// returns a boolean mask that is `true` at the positions where the element of `self` is
+	/// greater than or equal to `threshold`
+	#[inline]
+	pub fn ge(&self, threshold: T) -> Mat<bool, Rows, Cols>
+	where
+		T: RealField,
+	{
+		Mat::from_fn(self.nrows(), self.ncols(), |i, j| self[(i, j)] >= threshold)
+	}
+
+	/// returns a boolean mask that is `true` at the positions where the element of `self` is
+	/// less than or equal to `threshold`
+	#[inline]
+	pub fn le(&self, threshold: T) -> Mat<bool, Rows, Cols>
+	where
+		T: RealField,
+	{
+		Mat::from_fn(self.nrows(), self.ncols(), |i, j| self[(i, j)] <= threshold)
+	}
+
+	/// returns a boolean mask that is `true` at the positions where the element of `self` is
+	/// greater than the corresponding element of `other`
+	///
+	/// # panics
+	/// panics if `self` and `other` don't have the same dimensions
+	#[inline]
+	#[track_caller]
+	pub fn gt_elem(&self, other: impl AsMatRef<T = T, Rows = Rows, Cols = Cols>) -> Mat<bool, Rows, Cols>
+	where
+		T: RealField,
+	{
+		let other = other.as_mat_ref();
+		assert!(all(self.nrows() == other.nrows(), self.ncols() == other.ncols()));
+		Mat::from_fn(self.nrows(), self.ncols(), |i, j| self[(i, j)] > other[(i, j)])
+	}
+
+	/// returns the `q`-th quantile of the elements of `self`, linearly interpolating between the
+	/// two closest order statistics
+	///
+	/// `q = 0` and `q = 1` return the minimum and maximum element respectively. if `self` is
+	/// empty or contains a `NaN`, `NaN` is returned
+	///
+	/// # panics
+	/// panics if `q` is not in $[0, 1]$
+	#[track_caller]
+	pub fn quantile(&self, q: T) -> T
+	where
+		T: RealField,
+	{
+		let mut values = self.values_to_vec();
+		quantile_imp(&mut values, q)
+	}
+
+	/// returns the quantiles of the elements of `self` for each of the values in `qs`
+	///
+	/// see [`MatRef::quantile`]
+	///
+	/// # panics
+	/// panics if any of the `qs` is not in $[0, 1]$
+	#[track_caller]
+	pub fn quantiles(&self, qs: &[T]) -> alloc::vec::Vec<T>
+	where
+		T: RealField,
+	{
+		let mut values = self.values_to_vec();
+		qs.iter().map(|q| quantile_imp(&mut values, q.clone())).collect()
+	}
+
+	/// returns $\log \sum_i \exp(a_{ij})$ for each column $j$ of `self`, computed as
+	/// $m + \log \sum_i \exp(a_{ij} - m)$, where $m$ is the maximum of the column, so that large
+	/// elements don't overflow
+	///
+	/// the result is $-\infty$ for empty columns
+	pub fn logsumexp_cols(&self) -> Row<T, Cols>
+	where
+		T: RealField,
+	{
+		Row::from_fn(self.ncols(), |j| logsumexp_imp(self.col(j)))
+	}
+
+	/// returns $\log \sum_j \exp(a_{ij})$ for each row $i$ of `self`
+	///
+	/// see [`MatRef::logsumexp_cols`]
+	pub fn logsumexp_rows(&self) -> Col<T, Rows>
+	where
+		T: RealField,
+	{
+		Col::from_fn(self.nrows(), |i| logsumexp_imp(self.row(i).transpose()))
+	}
+
+	/// returns a matrix whose columns are the softmax of the columns of `self`
+	///
+	/// see [`MatMut::softmax_cols_in_place`]
+	pub fn softmax_cols(&self) -> Mat<T, Rows, Cols>
+	where
+		T: RealField,
+	{
+		let mut out = Mat::from_fn(self.nrows(), self.ncols(), |i, j| self[(i, j)].clone());
+		out.softmax_cols_in_place();
+		out
+	}
+
+	/// returns the adjoint of `self`, with its elements converted to `U`
+	///
+	/// this is equivalent to conjugating, transposing, and converting each element with
+	/// [`WidenFrom::widen`](crate::WidenFrom::widen), e.g., from `c32` to `c64`,
+	/// without materializing the intermediate matrix. for real types, the result is the converted
+	/// transpose
+	pub fn adjoint_cast<U: crate::WidenFrom<T>>(&self) -> Mat<U, Cols, Rows>
+	where
+		T: ComplexField,
+	{
+		Mat::from_fn(self.ncols(), self.nrows(), |i, j| U::widen(&faer_traits::math_utils::conj(&self[(j, i)])))
+	}
+
+	/// returns the trace of `self`, i.e., the sum of its diagonal elements
+	///
+	/// # panics
+	/// panics if `self` is not a square matrix
+	#[track_caller]
+	pub fn trace(&self) -> T
+	where
+		T: ComplexField,
+	{
+		let A = self.as_dyn_stride().as_dyn();
+		assert!(A.nrows() == A.ncols());
+		A.diagonal().column_vector().sum()
+	}
+
+	/// returns the kronecker product $A \otimes B$ of `self` and `rhs`
+	///
+	/// see [`linalg::kron::kron`](crate::linalg::kron::kron)
+	pub fn kron<Rows2: Shape, Cols2: Shape>(&self, rhs: MatRef<'_, T, Rows2, Cols2>) -> Mat<T>
+	where
+		T: ComplexField,
+	{
+		let A = self.as_dyn_stride().as_dyn();
+		let B = rhs.as_dyn();
+		let mut out = Mat::zeros(A.nrows() * B.nrows(), A.ncols() * B.ncols());
+		crate::linalg::kron::kron(out.as_mut(), A, B);
+		out
+	}
+
+	/// returns the permutation $P$ whose matrix is `self`, if `self` is a permutation matrix up
+	/// to the absolute tolerance `atol`, i.e., it is square, each row and column has exactly one
+	/// element within `atol` of $1$, and the other elements are within `atol` of $0$. otherwise,
+	/// returns `None`
+	///
+	/// the element at $(i, j)$ is $1$ if and only if `P.as_ref().arrays().0[i] == j`, so that `P * A`
+	/// is equal to `self * A`
+	pub fn as_permutation(&self, atol: T::Real) -> Option<crate::perm::Perm<usize>>
+	where
+		T: ComplexField,
+	{
+		use faer_traits::math_utils::{abs, one, sub};
+
+		let A = self.as_dyn_stride().as_dyn();
+		let n = A.nrows();
+		if A.ncols() != n {
+			return None;
+		}
+
+		let mut fwd = alloc::vec![usize::MAX; n];
+		let mut inv = alloc::vec![usize::MAX; n];
+		for j in 0..n {
+			for i in 0..n {
+				let a = &A[(i, j)];
+				if abs(&sub(a, &one())) <= atol {
+					if fwd[i] != usize::MAX || inv[j] != usize::MAX {
+						return None;
+					}
+					fwd[i] = j;
+					inv[j] = i;
+				} else if abs(a) > atol || is_nan(a) {
+					return None;
+				}
+			}
+		}
+		if fwd.contains(&usize::MAX) {
+			return None;
+		}
+
+		Some(crate::perm::Perm::new_checked(fwd.into_boxed_slice(), inv.into_boxed_slice(), n))
+	}
+
+	/// applies `f` to each column of `self` if `axis` is [`Axis::Col`](crate::Axis::Col), or to
+	/// each row (viewed as a column) if it is [`Axis::Row`](crate::Axis::Row), and collects the
+	/// results
+	pub fn map_axis<U>(&self, axis: crate::Axis, f: impl FnMut(ColRef<'_, T>) -> U) -> alloc::vec::Vec<U> {
+		let A = self.as_dyn_stride().as_dyn();
+		match axis {
+			crate::Axis::Col => A.col_iter().map(f).collect(),
+			crate::Axis::Row => A.transpose().col_iter().map(f).collect(),
+		}
+	}
+
+	/// reduces each column of `self` with `f`, starting from `identity`, and returns the results
+	///
+	/// each column is split into fixed-size chunks of rows that are folded with `f` separately, and
+	/// the partial results are merged in order with `combine`, which must be associative and have
+	/// `identity` as its neutral element. the columns are distributed among the threads of `par`,
+	/// and since the chunking doesn't depend on the number of threads, the result of each column
+	/// is deterministic
+	pub fn reduce_cols<B: Clone + Send + Sync>(
+		&self,
+		identity: B,
+		f: impl Fn(B, &T) -> B + Sync,
+		combine: impl Fn(B, B) -> B + Sync,
+		par: Par,
+	) -> Col<B>
+	where
+		T: Sync,
+	{
+		const CHUNK_SIZE: usize = 128;
+
+		let A = self.as_dyn_stride().as_dyn();
+		let (m, n) = A.shape();
+
+		let reduce_col = |j: usize| {
+			let col = A.col(j);
+			let mut acc = identity.clone();
+			let mut start = 0;
+			while start < m {
+				let len = Ord::min(CHUNK_SIZE, m - start);
+				let partial = col.subrows(start, len).iter().fold(identity.clone(), &f);
+				acc = combine(acc, partial);
+				start += len;
+			}
+			acc
+		};
+
+		let n_threads = Ord::max(1, Ord::min(crate::utils::thread::parallelism_degree(par), n));
+		let chunk = |tid: usize| {
+			let (start, len) = crate::utils::thread::par_split_indices(n, tid, n_threads);
+			(start..start + len).map(reduce_col).collect::<alloc::vec::Vec<_>>()
+		};
+
+		let chunks: alloc::vec::Vec<alloc::vec::Vec<B>> = match par {
+			Par::Seq => alloc::vec![chunk(0)],
+			#[cfg(feature = "rayon")]
+			Par::Rayon(_) => {
+				use rayon::prelude::*;
+				(0..n_threads).into_par_iter().map(chunk).collect()
+			},
+		};
+
+		chunks.into_iter().flatten().collect()
+	}
+
+	/// reduces each row of `self` with `f`, starting from `identity`, and returns the results
+	///
+	/// see [`MatRef::reduce_cols`]
+	pub fn reduce_rows<B: Clone + Send + Sync>(
+		&self,
+		identity: B,
+		f: impl Fn(B, &T) -> B + Sync,
+		combine: impl Fn(B, B) -> B + Sync,
+		par: Par,
+	) -> Col<B>
+	where
+		T: Sync,
+	{
+		self.transpose().reduce_cols(identity, f, combine, par)
+	}
+
+	fn values_to_vec(&self) -> alloc::vec::Vec<T>
+	where
+		T: Clone,
+	{
+		let A = self.as_dyn_stride().as_dyn();
+		let mut values = alloc::vec::Vec::with_capacity(A.nrows() * A.ncols());
+		for j in 0..A.ncols() {
+			for i in 0..A.nrows() {
+				values.push(A[(i, j)].clone());
+			}
+		}
+		values
+	}
+
 	/// returns the sum of the elements of `self`
 	#[inline]
 	#[math]
@@ -1156,6 +1711,107 @@ impl<'a, T, Rows: Shape, Cols: Shape, RStride: Stride, CStride: Stride> MatRef<'
 		linalg::reductions::determinant::determinant(self.canonical().as_dyn_stride().as_dyn())
 	}
 
+	/// returns `true` if `self` is a normal matrix, i.e., $A^H A = A A^H$, up to an absolute
+	/// tolerance `atol` on each element of the difference
+	///
+	/// normal matrices are exactly the ones that are unitarily diagonalizable. non-square
+	/// matrices are never normal
+	#[math]
+	pub fn is_normal(&self, atol: Real<T>) -> bool
+	where
+		T: Conjugate,
+	{
+		let A = self.canonical().as_dyn_stride().as_dyn();
+		let n = A.nrows();
+		if n != A.ncols() {
+			return false;
+		}
+
+		let par = crate::get_global_parallelism();
+		let mut AhA = Mat::<T::Canonical>::zeros(n, n);
+		let mut AAh = Mat::<T::Canonical>::zeros(n, n);
+		linalg::matmul::smart_matmul(AhA.as_mut(), Accum::Replace, A.adjoint(), A, one::<T::Canonical>(), par);
+		linalg::matmul::smart_matmul(AAh.as_mut(), Accum::Replace, A, A.adjoint(), one::<T::Canonical>(), par);
+
+		let mut normal = true;
+		z!(AhA.as_ref(), AAh.as_ref()).for_each(|uz!(x, y)| normal = normal && abs(*x - *y) <= atol);
+		normal
+	}
+
+	/// returns the lower bandwidth of `self`, i.e., the number of subdiagonals that contain an
+	/// element whose absolute value is greater than `atol`
+	#[math]
+	pub fn lower_bandwidth(&self, atol: Real<T>) -> usize
+	where
+		T: Conjugate,
+	{
+		let A = self.canonical().as_dyn_stride().as_dyn();
+		let mut bandwidth = 0;
+		for j in 0..A.ncols() {
+			for i in j + 1 + bandwidth..A.nrows() {
+				if abs(A[(i, j)]) > atol {
+					bandwidth = i - j;
+				}
+			}
+		}
+		bandwidth
+	}
+
+	/// returns the upper bandwidth of `self`, i.e., the number of superdiagonals that contain an
+	/// element whose absolute value is greater than `atol`
+	pub fn upper_bandwidth(&self, atol: Real<T>) -> usize
+	where
+		T: Conjugate,
+	{
+		self.transpose().lower_bandwidth(atol)
+	}
+
+	/// returns `true` if all the elements of `self` outside of the band made of the main diagonal,
+	/// `kl` subdiagonals and `ku` superdiagonals have an absolute value less than or equal to
+	/// `atol`
+	pub fn is_banded(&self, kl: usize, ku: usize, atol: Real<T>) -> bool
+	where
+		T: Conjugate,
+	{
+		self.lower_bandwidth(copy(&atol)) <= kl && self.upper_bandwidth(atol) <= ku
+	}
+
+	/// returns `true` if `self` is tridiagonal up to an absolute tolerance `atol`
+	pub fn is_tridiagonal(&self, atol: Real<T>) -> bool
+	where
+		T: Conjugate,
+	{
+		self.is_banded(1, 1, atol)
+	}
+
+	/// returns `true` if `self` is upper hessenberg up to an absolute tolerance `atol`
+	pub fn is_upper_hessenberg(&self, atol: Real<T>) -> bool
+	where
+		T: Conjugate,
+	{
+		self.lower_bandwidth(atol) <= 1
+	}
+
+	/// returns the diagonal of `self` if all the off-diagonal elements have an absolute value less
+	/// than or equal to `atol`, or `None` otherwise
+	///
+	/// # panics
+	/// panics if `self` is not a square matrix
+	#[track_caller]
+	pub fn try_into_diag(&self, atol: Real<T>) -> Option<Diag<T::Canonical>>
+	where
+		T: Conjugate,
+	{
+		let A = self.as_dyn_stride().as_dyn();
+		assert!(A.nrows() == A.ncols());
+
+		if A.is_banded(0, 0, atol) {
+			Some(A.diagonal().column_vector().to_owned().into_diagonal())
+		} else {
+			None
+		}
+	}
+
 	/// returns references to the element at the given index, or submatrices if either `row`
 	/// or `col` is a range, with bound checks
 	///
@@ -1222,6 +1878,61 @@ impl<'a, T, Rows: Shape, Cols: Shape, RStride: Stride, CStride: Stride> MatRef<'
 	}
 }
 
+impl<'a, T: RealField, Rows: Shape, Cols: Shape, RStride: Stride, CStride: Stride> MatRef<'a, Complex<T>, Rows, Cols, RStride, CStride> {
+	/// returns the real part of `self` if the imaginary part of every element is within `atol`
+	/// of zero, and `None` otherwise
+	///
+	/// this is useful for the results of a complex computation that are known to be real, such
+	/// as the eigendecomposition of a matrix with real eigenvalues
+	pub fn try_into_real(&self, atol: T) -> Option<Mat<T, Rows, Cols>> {
+		let mut is_real = true;
+		z!(self.as_dyn_stride().as_dyn()).for_each(|uz!(x)| is_real = is_real && abs(&x.im) <= atol);
+
+		if is_real {
+			Some(Mat::from_fn(self.nrows(), self.ncols(), |i, j| self.at(i, j).re.clone()))
+		} else {
+			None
+		}
+	}
+}
+
+impl<'a, Rows: Shape, Cols: Shape, RStride: Stride, CStride: Stride> MatRef<'a, bool, Rows, Cols, RStride, CStride> {
+	/// returns `true` if any element of `self` is `true`, stopping at the first one that is
+	///
+	/// returns `false` if `self` is empty
+	pub fn any(&self) -> bool {
+		let A = self.as_dyn_stride().as_dyn();
+		(0..A.ncols()).any(|j| A.col(j).iter().any(|&x| x))
+	}
+
+	/// returns `true` if all the elements of `self` are `true`, stopping at the first one that
+	/// isn't
+	///
+	/// returns `true` if `self` is empty
+	pub fn all(&self) -> bool {
+		let A = self.as_dyn_stride().as_dyn();
+		(0..A.ncols()).all(|j| A.col(j).iter().all(|&x| x))
+	}
+
+	/// returns the number of elements of `self` that are `true`
+	pub fn count_true(&self) -> usize {
+		let A = self.as_dyn_stride().as_dyn();
+		(0..A.ncols()).map(|j| A.col(j).iter().filter(|&&x| x).count()).sum()
+	}
+
+	/// returns, for each column of `self`, whether any of its elements is `true`
+	pub fn any_cols(&self) -> alloc::vec::Vec<bool> {
+		let A = self.as_dyn_stride().as_dyn();
+		(0..A.ncols()).map(|j| A.col(j).iter().any(|&x| x)).collect()
+	}
+
+	/// returns, for each column of `self`, whether all of its elements are `true`
+	pub fn all_cols(&self) -> alloc::vec::Vec<bool> {
+		let A = self.as_dyn_stride().as_dyn();
+		(0..A.ncols()).map(|j| A.col(j).iter().all(|&x| x)).collect()
+	}
+}
+
 impl<'a, T, Dim: Shape, RStride: Stride, CStride: Stride> MatRef<'a, T, Dim, Dim, RStride, CStride> {
 	/// returns the diagonal of the matrix
 	#[inline]
@@ -1296,21 +2007,286 @@ impl<T, Rows: Shape, Cols: Shape, RStride: Stride, CStride: Stride> Index<(Idx<R
 	}
 }
 
+/// maximum number of rows or columns shown by the [`Debug`](core::fmt::Debug) implementation of
+/// matrices. larger matrices only show their first and last few rows or columns
+const DEBUG_MAX_DIM: usize = 8;
+/// number of significant digits shown for floating point elements, unless a precision is
+/// requested by the caller
+const DEBUG_SIGNIFICANT_DIGITS: usize = 3;
+
+/// returns the indices shown along a dimension of size `n`, with `None` standing for the elided
+/// ones
+fn debug_indices(n: usize) -> alloc::vec::Vec<Option<usize>> {
+	if n <= DEBUG_MAX_DIM {
+		(0..n).map(Some).collect()
+	} else {
+		let half = DEBUG_MAX_DIM / 2;
+		(0..half).map(Some).chain([None]).chain((n - half..n).map(Some)).collect()
+	}
+}
+
+/// writes the cells of an `m × n` matrix as a table with right-aligned columns, each as wide as
+/// its widest cell
+///
+/// the debug layout is prefixed with the dimensions and has one indented row per line, while the
+/// display layout only has one `[...]` row per line
+fn fmt_aligned(
+	m: usize,
+	n: usize,
+	debug: bool,
+	cell: impl Fn(usize, usize) -> alloc::string::String,
+	f: &mut core::fmt::Formatter<'_>,
+) -> core::fmt::Result {
+	use alloc::string::String;
+	use alloc::vec::Vec;
+
+	let rows = debug_indices(m);
+	let cols = debug_indices(n);
+
+	let cells: Vec<Vec<String>> = rows
+		.iter()
+		.flatten()
+		.map(|&i| {
+			cols.iter()
+				.map(|&j| match j {
+					Some(j) => cell(i, j),
+					None => "...".into(),
+				})
+				.collect()
+		})
+		.collect();
+	let mut widths = alloc::vec![0usize; cols.len()];
+	for row in &cells {
+		for (width, cell) in widths.iter_mut().zip(row) {
+			*width = Ord::max(*width, cell.chars().count());
+		}
+	}
+
+	let (indent, sep) = if debug { ("  ", ",\n") } else { ("", "\n") };
+	if debug {
+		writeln!(f, "{m}×{n} [")?;
+	}
+	let mut cells = cells.iter();
+	for (k, i) in rows.iter().enumerate() {
+		if k > 0 {
+			f.write_str(sep)?;
+		}
+		f.write_str(indent)?;
+		if i.is_none() {
+			f.write_str("...")?;
+			continue;
+		}
+		f.write_str("[")?;
+		for (k, (cell, &width)) in cells.next().unwrap().iter().zip(&widths).enumerate() {
+			if k > 0 {
+				f.write_str(", ")?;
+			}
+			write!(f, "{cell:>width$}")?;
+		}
+		f.write_str("]")?;
+	}
+	if debug {
+		if m > 0 {
+			f.write_str(sep)?;
+		}
+		f.write_str("]")?;
+	}
+	Ok(())
+}
+
 impl<'a, T: core::fmt::Debug, Rows: Shape, Cols: Shape, RStride: Stride, CStride: Stride> core::fmt::Debug
 	for MatRef<'a, T, Rows, Cols, RStride, CStride>
 {
 	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-		fn imp<'M, 'N, T: core::fmt::Debug>(this: MatRef<'_, T, Dim<'M>, Dim<'N>>, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-			writeln!(f, "[")?;
-			for i in this.nrows().indices() {
-				this.row(i).fmt(f)?;
-				f.write_str(",\n")?;
+		fn imp<T: core::fmt::Debug>(this: MatRef<'_, T>, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+			let precision = f.precision();
+			let cell = |i: usize, j: usize| match precision {
+				Some(p) => alloc::format!("{:.*?}", p, crate::hacks::hijack_debug(this.at(i, j))),
+				None => crate::hacks::debug_significant(this.at(i, j), DEBUG_SIGNIFICANT_DIGITS)
+					.unwrap_or_else(|| alloc::format!("{:?}", crate::hacks::hijack_debug(this.at(i, j)))),
+			};
+			fmt_aligned(this.nrows(), this.ncols(), true, cell, f)
+		}
+
+		imp(self.as_dyn_stride().as_dyn(), f)
+	}
+}
+
+/// formats the matrix as one `[...]` row per line, with right-aligned columns. a precision, as in
+/// `{:.4}`, is passed down to each element
+impl<'a, T: core::fmt::Display, Rows: Shape, Cols: Shape, RStride: Stride, CStride: Stride> core::fmt::Display
+	for MatRef<'a, T, Rows, Cols, RStride, CStride>
+{
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		fn imp<T: core::fmt::Display>(this: MatRef<'_, T>, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+			let precision = f.precision();
+			let cell = |i: usize, j: usize| match precision {
+				Some(p) => alloc::format!("{:.*}", p, this.at(i, j)),
+				None => alloc::format!("{}", this.at(i, j)),
+			};
+			fmt_aligned(this.nrows(), this.ncols(), false, cell, f)
+		}
+
+		imp(self.as_dyn_stride().as_dyn(), f)
+	}
+}
+
+/// formats the matrix with the same layout as [`Display`](core::fmt::Display), with each element
+/// in lowercase hexadecimal. the alternate flag `{:#x}` is passed down to each element
+impl<'a, T: core::fmt::LowerHex, Rows: Shape, Cols: Shape, RStride: Stride, CStride: Stride> core::fmt::LowerHex
+	for MatRef<'a, T, Rows, Cols, RStride, CStride>
+{
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		fn imp<T: core::fmt::LowerHex>(this: MatRef<'_, T>, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+			let alternate = f.alternate();
+			let cell = |i: usize, j: usize| {
+				if alternate {
+					alloc::format!("{:#x}", this.at(i, j))
+				} else {
+					alloc::format!("{:x}", this.at(i, j))
+				}
+			};
+			fmt_aligned(this.nrows(), this.ncols(), false, cell, f)
+		}
+
+		imp(self.as_dyn_stride().as_dyn(), f)
+	}
+}
+
+/// formats the matrix with the same layout as [`Display`](core::fmt::Display), with each element
+/// in uppercase hexadecimal. the alternate flag `{:#X}` is passed down to each element
+impl<'a, T: core::fmt::UpperHex, Rows: Shape, Cols: Shape, RStride: Stride, CStride: Stride> core::fmt::UpperHex
+	for MatRef<'a, T, Rows, Cols, RStride, CStride>
+{
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		fn imp<T: core::fmt::UpperHex>(this: MatRef<'_, T>, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+			let alternate = f.alternate();
+			let cell = |i: usize, j: usize| {
+				if alternate {
+					alloc::format!("{:#X}", this.at(i, j))
+				} else {
+					alloc::format!("{:X}", this.at(i, j))
+				}
+			};
+			fmt_aligned(this.nrows(), this.ncols(), false, cell, f)
+		}
+
+		imp(self.as_dyn_stride().as_dyn(), f)
+	}
+}
+
+impl<T: core::hash::Hash, Rows: Shape, Cols: Shape, RStride: Stride, CStride: Stride> core::hash::Hash
+	for MatRef<'_, T, Rows, Cols, RStride, CStride>
+{
+	fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+		fn imp<'M, 'N, T: core::hash::Hash, H: core::hash::Hasher>(this: MatRef<'_, T, Dim<'M>, Dim<'N>>, state: &mut H) {
+			for j in this.ncols().indices() {
+				for i in this.nrows().indices() {
+					this.at(i, j).hash(state);
+				}
 			}
-			write!(f, "]")
 		}
 
+		self.nrows().unbound().hash(state);
+		self.ncols().unbound().hash(state);
+
 		with_dim!(M, self.nrows().unbound());
 		with_dim!(N, self.ncols().unbound());
-		imp(self.as_shape(M, N).as_dyn_stride(), f)
+		imp(self.as_shape(M, N).as_dyn_stride(), state)
+	}
+}
+
+impl<T: Clone, RStride: Stride, CStride: Stride, const R: usize, const C: usize> TryFrom<MatRef<'_, T, usize, usize, RStride, CStride>>
+	for [[T; C]; R]
+{
+	type Error = super::ShapeMismatch;
+
+	/// copies the matrix into an array of rows, if it has `R` rows and `C` columns
+	fn try_from(value: MatRef<'_, T, usize, usize, RStride, CStride>) -> Result<Self, Self::Error> {
+		if value.shape() != (R, C) {
+			return Err(super::ShapeMismatch {
+				expected: (R, C),
+				found: value.shape(),
+			});
+		}
+
+		Ok(core::array::from_fn(|i| core::array::from_fn(|j| value[(i, j)].clone())))
+	}
+}
+
+impl<T: Clone, RStride: Stride, CStride: Stride, const N: usize> TryFrom<MatRef<'_, T, usize, usize, RStride, CStride>> for [T; N] {
+	type Error = super::ShapeMismatch;
+
+	/// copies the matrix into an array, if it is a column or a row with `N` elements
+	fn try_from(value: MatRef<'_, T, usize, usize, RStride, CStride>) -> Result<Self, Self::Error> {
+		match value.shape() {
+			(m, 1) if m == N => Ok(core::array::from_fn(|i| value[(i, 0)].clone())),
+			(1, n) if n == N => Ok(core::array::from_fn(|j| value[(0, j)].clone())),
+			found => Err(super::ShapeMismatch { expected: (N, 1), found }),
+		}
+	}
+}
+
+#[math]
+fn logsumexp_imp<T: RealField, N: Shape, S: Stride>(x: ColRef<'_, T, N, S>) -> T {
+	let mut m = -infinity::<T>();
+	for x in x.iter() {
+		m = max(m, *x);
+	}
+	// empty, or containing an infinity or a nan
+	if !is_finite(m) {
+		return m;
+	}
+
+	let mut sum = zero::<T>();
+	for x in x.iter() {
+		sum = sum + exp(&(*x - m));
+	}
+	m + ln(&sum)
+}
+
+#[track_caller]
+fn quantile_imp<T: RealField>(values: &mut [T], q: T) -> T {
+	assert!(all(q >= zero::<T>(), q <= one::<T>()));
+
+	let n = values.len();
+	// nan has no place in the ordering, so it propagates to the result
+	if n == 0 || values.iter().any(is_nan) {
+		return nan();
+	}
+
+	// find the largest `lo` such that `lo <= q * (n - 1)`
+	let pos = q * from_f64::<T>((n - 1) as f64);
+	let (mut lo, mut hi) = (0usize, n - 1);
+	while lo < hi {
+		let mid = lo + (hi - lo).msrv_div_ceil(2);
+		if from_f64::<T>(mid as f64) <= pos {
+			lo = mid;
+		} else {
+			hi = mid - 1;
+		}
+	}
+	let frac = pos - from_f64::<T>(lo as f64);
+
+	// no nan is left, so this is a total order
+	let cmp = |a: &T, b: &T| a.partial_cmp(b).unwrap();
+	let (_, a, right) = values.select_nth_unstable_by(lo, cmp);
+	let a = a.clone();
+	if frac == zero::<T>() || right.is_empty() {
+		return a;
+	}
+
+	// all the elements to the right of `lo` are greater than or equal to `a`, so the next order
+	// statistic is their minimum
+	let b = right.iter().min_by(|a, b| cmp(a, b)).unwrap().clone();
+	// `b - a` is nan if both are the same infinity, and `a + frac * (b - a)` is nan if `a` is
+	// infinite, so those cases are resolved directly. since `a <= b` and `frac < 1`, the weight
+	// on `a` is positive, so an infinite `a` dominates
+	if a == b || !is_finite(&a) {
+		return a;
+	}
+	if !is_finite(&b) {
+		return b;
 	}
+	a.clone() + frac * (b - a)
 }
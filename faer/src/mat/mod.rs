@@ -1,7 +1,7 @@
 use crate::{Shape, Stride, Unbind};
 use core::marker::PhantomData;
 use core::ptr::NonNull;
-use faer_traits::{ComplexField, Conjugate};
+use faer_traits::{ComplexField, Conjugate, Real, RealField};
 use reborrow::*;
 
 pub(crate) struct MatView<T: ?Sized, Rows, Cols, RStride, CStride> {
@@ -39,16 +39,19 @@ fn from_slice_assert(nrows: usize, ncols: usize, len: usize) {
 	assert!(size == Some(len));
 }
 
+mod col_window;
 mod mat_index;
 
 pub(crate) mod matmut;
 pub(crate) mod matown;
 pub(crate) mod matref;
 
-pub use matmut::MatMut;
+pub use matmut::{AssumeWritten, MatMut};
 pub use matown::Mat;
 pub use matref::MatRef;
 
+pub use col_window::ColWindow;
+
 /// trait for types that can be converted to a matrix view
 pub trait AsMatRef {
 	/// scalar type
@@ -78,6 +81,87 @@ pub trait AsMat<T>: AsMatMut {
 		T: ComplexField;
 }
 
+/// extension trait providing convenience methods on any type that can be converted to a matrix
+/// view, so that generic code does not need to commit to one of [`Mat`], [`MatRef`] or
+/// [`MatMut`]
+///
+/// this trait is implemented for every type implementing [`AsMatRef`]
+pub trait MatOps: AsMatRef {
+	/// returns the number of rows of the matrix
+	#[inline]
+	fn nrows(&self) -> Self::Rows {
+		self.as_mat_ref().nrows()
+	}
+
+	/// returns the number of columns of the matrix
+	#[inline]
+	fn ncols(&self) -> Self::Cols {
+		self.as_mat_ref().ncols()
+	}
+
+	/// returns the number of rows and columns of the matrix
+	#[inline]
+	fn shape(&self) -> (Self::Rows, Self::Cols) {
+		self.as_mat_ref().shape()
+	}
+
+	/// returns a view over the transpose of the matrix
+	#[inline]
+	fn transpose(&self) -> MatRef<'_, Self::T, Self::Cols, Self::Rows, isize, isize> {
+		self.as_mat_ref().transpose()
+	}
+
+	/// returns a view over the conjugate transpose of the matrix
+	#[inline]
+	fn adjoint(&self) -> MatRef<'_, <Self::T as Conjugate>::Conj, Self::Cols, Self::Rows, isize, isize>
+	where
+		Self::T: Conjugate,
+	{
+		self.as_mat_ref().adjoint()
+	}
+
+	/// returns the frobenius norm of the matrix
+	#[inline]
+	fn norm_frobenius(&self) -> Real<Self::T>
+	where
+		Self::T: Conjugate,
+	{
+		self.as_mat_ref().norm_l2()
+	}
+
+	/// returns the maximum norm of the matrix
+	#[inline]
+	fn norm_max(&self) -> Real<Self::T>
+	where
+		Self::T: Conjugate,
+	{
+		self.as_mat_ref().norm_max()
+	}
+
+	/// returns the sum of the elements of the matrix
+	#[inline]
+	fn sum(&self) -> <Self::T as Conjugate>::Canonical
+	where
+		Self::T: Conjugate,
+	{
+		self.as_mat_ref().sum()
+	}
+
+	/// returns a newly allocated matrix holding the (possibly conjugated) values of the matrix
+	///
+	/// this is named differently from [`MatRef::to_owned`] so that it doesn't collide with
+	/// [`ToOwned::to_owned`](alloc::borrow::ToOwned::to_owned) on references to matrices
+	#[inline]
+	fn to_mat(&self) -> Mat<<Self::T as Conjugate>::Canonical, Self::Rows, Self::Cols>
+	where
+		Self::T: Conjugate,
+	{
+		self.as_mat_ref().to_owned()
+	}
+}
+
+impl<M: ?Sized + AsMatRef> MatOps for M {}
+
 impl<M: AsMatRef> AsMatRef for &M {
 	type Cols = M::Cols;
 	type Owned = M::Owned;
@@ -167,9 +251,116 @@ impl<T, Rows: Shape, Cols: Shape> AsMatMut for Mat<T, Rows, Cols> {
 	}
 }
 
+/// error returned when converting a matrix to a type with a fixed shape that does not match it
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct ShapeMismatch {
+	/// expected shape, as `(nrows, ncols)`
+	pub expected: (usize, usize),
+	/// actual shape of the matrix, as `(nrows, ncols)`
+	pub found: (usize, usize),
+}
+
+impl core::fmt::Display for ShapeMismatch {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		write!(
+			f,
+			"expected a {}×{} matrix, found a {}×{} matrix",
+			self.expected.0, self.expected.1, self.found.0, self.found.1,
+		)
+	}
+}
+
+impl core::error::Error for ShapeMismatch {}
+
+/// returns the element-wise maximum of `a` and `b`
+///
+/// # panics
+/// panics if `a` and `b` don't have the same dimensions
+#[track_caller]
+pub fn cwise_max<T: RealField, Rows: Shape, Cols: Shape>(
+	a: impl AsMatRef<T = T, Rows = Rows, Cols = Cols>,
+	b: impl AsMatRef<T = T, Rows = Rows, Cols = Cols>,
+) -> Mat<T, Rows, Cols> {
+	let (a, b) = (a.as_mat_ref(), b.as_mat_ref());
+	crate::assert!(all(a.nrows() == b.nrows(), a.ncols() == b.ncols()));
+	Mat::from_fn(a.nrows(), a.ncols(), |i, j| {
+		if a[(i, j)] < b[(i, j)] { b[(i, j)].clone() } else { a[(i, j)].clone() }
+	})
+}
+
+/// returns the element-wise minimum of `a` and `b`
+///
+/// # panics
+/// panics if `a` and `b` don't have the same dimensions
+#[track_caller]
+pub fn cwise_min<T: RealField, Rows: Shape, Cols: Shape>(
+	a: impl AsMatRef<T = T, Rows = Rows, Cols = Cols>,
+	b: impl AsMatRef<T = T, Rows = Rows, Cols = Cols>,
+) -> Mat<T, Rows, Cols> {
+	let (a, b) = (a.as_mat_ref(), b.as_mat_ref());
+	crate::assert!(all(a.nrows() == b.nrows(), a.ncols() == b.ncols()));
+	Mat::from_fn(a.nrows(), a.ncols(), |i, j| {
+		if a[(i, j)] > b[(i, j)] { b[(i, j)].clone() } else { a[(i, j)].clone() }
+	})
+}
+
+/// returns the matrix product `a * b`, for any combination of owned matrices, views, or
+/// references to either
+///
+/// this is a convenience wrapper around [`matmul`](crate::linalg::matmul::matmul) that allocates
+/// the output and uses the global parallelism setting
+///
+/// # panics
+/// panics if `a.ncols() != b.nrows()`
+#[track_caller]
+pub fn mul<T: ComplexField, LhsT: Conjugate<Canonical = T>, RhsT: Conjugate<Canonical = T>, Rows: Shape, Cols: Shape, K: Shape>(
+	a: impl AsMatRef<T = LhsT, Rows = Rows, Cols = K>,
+	b: impl AsMatRef<T = RhsT, Rows = K, Cols = Cols>,
+) -> Mat<T, Rows, Cols> {
+	let (a, b) = (a.as_mat_ref(), b.as_mat_ref());
+	crate::assert!(a.ncols() == b.nrows());
+	let mut out = Mat::zeros(a.nrows(), b.ncols());
+	crate::linalg::matmul::matmul(
+		&mut out,
+		crate::Accum::Replace,
+		a,
+		b,
+		faer_traits::math_utils::one(),
+		crate::get_global_parallelism(),
+	);
+	out
+}
+
+/// returns the horizontal concatenation of the given matrices
+///
+/// see [`Mat::hstack`]
+#[track_caller]
+pub fn hstack<T: ComplexField>(mats: &[MatRef<'_, T>]) -> Mat<T> {
+	Mat::hstack(mats)
+}
+
+/// returns the vertical concatenation of the given matrices
+///
+/// see [`Mat::vstack`]
+#[track_caller]
+pub fn vstack<T: ComplexField>(mats: &[MatRef<'_, T>]) -> Mat<T> {
+	Mat::vstack(mats)
+}
+
+/// returns the matrix assembled from a grid of blocks, where `blocks[i][j]` is the block at
+/// block row $i$ and block column $j$
+///
+/// see [`Mat::block`]
+#[track_caller]
+pub fn block<T: ComplexField>(blocks: &[&[MatRef<'_, T>]]) -> Mat<T> {
+	Mat::block(blocks)
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
+	use crate::Side;
+	use crate::assert;
 	use crate::prelude::*;
 
 	#[test]
@@ -204,4 +395,1042 @@ mod tests {
 			}
 		}
 	}
+
+	#[test]
+	fn test_row_chunks() {
+		let mat = Mat::from_fn(10, 3, |i, j| (i * 3 + j) as f64);
+
+		let chunks = mat.row_chunks(4).collect::<alloc::vec::Vec<_>>();
+		assert!(chunks.len() == 3);
+		assert!(chunks.iter().map(|c| c.nrows()).collect::<alloc::vec::Vec<_>>() == [4, 4, 2]);
+
+		let mut out = Mat::zeros(10, 3);
+		let mut pos = 0;
+		for chunk in mat.row_chunks(4) {
+			out.subrows_mut(pos, chunk.nrows()).copy_from(chunk);
+			pos += chunk.nrows();
+		}
+		assert!(pos == 10);
+		assert!(out == mat);
+
+		let rev = mat.row_chunks(4).rev().map(|c| c.nrows()).collect::<alloc::vec::Vec<_>>();
+		assert!(rev == [2, 4, 4]);
+
+		let chunks = mat.col_chunks(2).collect::<alloc::vec::Vec<_>>();
+		assert!(all(chunks.len() == 2, chunks[0] == mat.get(.., ..2), chunks[1] == mat.get(.., 2..)));
+
+		#[cfg(feature = "rayon")]
+		{
+			use rayon::prelude::*;
+			let par = mat.par_row_chunks(4).collect::<alloc::vec::Vec<_>>();
+			let seq = mat.row_chunks(4).collect::<alloc::vec::Vec<_>>();
+			assert!(par == seq);
+		}
+	}
+
+	#[test]
+	fn test_mat_ops() {
+		fn frobenius_and_shape(A: impl MatOps<T = f64>) -> (f64, usize, usize) {
+			let (m, n) = A.shape();
+			(A.norm_frobenius(), m.unbound(), n.unbound())
+		}
+
+		let A = mat![[3.0, 0.0], [0.0, 4.0], [0.0, 0.0]];
+
+		assert!(frobenius_and_shape(&A) == (5.0, 3, 2));
+		assert!(frobenius_and_shape(A.as_ref()) == (5.0, 3, 2));
+		assert!(frobenius_and_shape(A.as_ref().transpose()) == (5.0, 2, 3));
+		assert!(frobenius_and_shape(A.clone()) == (5.0, 3, 2));
+
+		fn transposed(A: &impl MatOps<T = f64, Rows = usize, Cols = usize>) -> Mat<f64> {
+			MatOps::transpose(A).to_owned()
+		}
+		assert!(transposed(&A) == A.transpose());
+
+		fn owned(A: &impl MatOps<T = f64, Rows = usize, Cols = usize>) -> Mat<f64> {
+			A.to_mat()
+		}
+		assert!(owned(&A) == A);
+		assert!(owned(&A.as_ref()) == A);
+	}
+
+	#[test]
+	fn test_hash() {
+		use core::hash::{Hash, Hasher};
+
+		fn hash_of(x: impl Hash) -> u64 {
+			let mut h = std::collections::hash_map::DefaultHasher::new();
+			x.hash(&mut h);
+			h.finish()
+		}
+
+		let A = Mat::from_fn(3, 4, |i, j| (i as i64) - 2 * (j as i64));
+		let B = Mat::from_fn(3, 4, |i, j| (i as i64) - 2 * (j as i64));
+		let C = Mat::from_fn(4, 3, |i, j| (j as i64) - 2 * (i as i64));
+
+		assert!(hash_of(&A) == hash_of(&B));
+		assert!(hash_of(&A) == hash_of(C.transpose()));
+		assert!(hash_of(&A) != hash_of(&C));
+		assert!(hash_of(Mat::full(0, 2, 0i64)) != hash_of(Mat::full(2, 0, 0i64)));
+	}
+
+	#[test]
+	fn test_relative_frobenius_distance() {
+		let A = mat![[1.0, 2.0], [3.0, 4.0f64]];
+		let B = mat![[1.0, 2.0], [3.0, 4.0 + 1e-6f64]];
+
+		let expected = 1e-6 / A.norm_l2();
+		assert!(A.relative_frobenius_distance(&A) == 0.0);
+		assert!((B.relative_frobenius_distance(&A) - expected).abs() < 1e-8 * expected);
+		assert!((B.as_ref().relative_frobenius_distance(A.as_ref()) - expected).abs() < 1e-8 * expected);
+
+		// zero denominator falls back to the absolute distance
+		let Z = Mat::<f64>::zeros(2, 2);
+		assert!(A.relative_frobenius_distance(&Z) == A.norm_l2());
+		assert!(Z.relative_frobenius_distance(&Z) == 0.0);
+
+		// the sum of squares would overflow without scaling
+		let A = mat![[1e200, 0.0], [0.0, 1e200f64]];
+		let B = mat![[1e200, 0.0], [0.0, 2e200f64]];
+		assert!((B.relative_frobenius_distance(&A) - 0.5f64.sqrt()).abs() < 1e-12);
+
+		// and it would underflow to zero
+		let A = mat![[1e-200, 0.0], [0.0, 1e-200f64]];
+		let B = mat![[1e-200, 0.0], [0.0, 2e-200f64]];
+		assert!((B.relative_frobenius_distance(&A) - 0.5f64.sqrt()).abs() < 1e-12);
+		assert!(A.relative_frobenius_distance(&Mat::zeros(2, 2)) > 0.0);
+
+		let mut C = A.clone();
+		C[(0, 1)] = f64::NAN;
+		assert!(C.relative_frobenius_distance(&A).is_nan());
+	}
+
+	#[test]
+	fn test_comparison_masks() {
+		let A = mat![[1.0, -2.0, 0.5], [-0.5, 0.0, 3.0f64]];
+		let B = mat![[0.0, -2.0, 1.0], [-1.0, 1.0, 2.0f64]];
+
+		let gt = A.gt(0.5);
+		let lt = A.lt(0.5);
+		let ge = A.ge(0.5);
+		let le = A.as_ref().le(0.5);
+		let gt_elem = A.gt_elem(&B);
+
+		for j in 0..A.ncols() {
+			for i in 0..A.nrows() {
+				let a = A[(i, j)];
+				assert!(gt[(i, j)] == (a > 0.5));
+				assert!(lt[(i, j)] == (a < 0.5));
+				assert!(ge[(i, j)] == (a >= 0.5));
+				assert!(le[(i, j)] == (a <= 0.5));
+				assert!(gt_elem[(i, j)] == (a > B[(i, j)]));
+			}
+		}
+
+		assert!(ge[(0, 2)] && le[(0, 2)] && !gt[(0, 2)] && !lt[(0, 2)]);
+		assert!(gt_elem[(0, 0)] && !gt_elem[(0, 1)] && gt_elem[(1, 2)]);
+	}
+
+	#[test]
+	fn test_mask_reductions() {
+		let A = mat![[1.0, -2.0, 0.5, 4.0], [-0.5, 0.0, 3.0, 5.0f64]];
+		let mask = A.gt(0.0);
+
+		assert!(mask.count_true() == 5);
+		assert!(mask.any());
+		assert!(!mask.all());
+		assert!(mask.any_cols() == [true, false, true, true]);
+		assert!(mask.all_cols() == [false, false, true, true]);
+		assert!(mask.as_ref().transpose().any_cols() == [true, true]);
+		assert!(mask.as_ref().get(.., 2..).all());
+
+		let none = Mat::from_fn(3, 4, |_, _| false);
+		let every = Mat::from_fn(3, 4, |_, _| true);
+		assert!(all(!none.any(), !none.all(), none.count_true() == 0));
+		assert!(all(every.any(), every.all(), every.count_true() == 12));
+		assert!(none.any_cols() == [false; 4] && every.all_cols() == [true; 4]);
+
+		let empty = Mat::<bool>::from_fn(0, 3, |_, _| true);
+		assert!(all(!empty.any(), empty.all(), empty.count_true() == 0));
+		assert!(empty.any_cols() == [false; 3] && empty.all_cols() == [true; 3]);
+	}
+
+	#[test]
+	fn test_contiguous() {
+		// the column stride of an owned matrix is the row capacity, which is padded for alignment,
+		// so use a row count that doesn't require padding
+		let A = Mat::<f64>::zeros(16, 3);
+		assert!(A.is_col_major_contiguous());
+		assert!(!A.is_row_major_contiguous());
+
+		let At = A.transpose();
+		assert!(!At.is_col_major_contiguous());
+		assert!(At.is_row_major_contiguous());
+
+		// a column block is still contiguous, but a row block isn't
+		assert!(A.get(.., 1..).is_col_major_contiguous());
+		assert!(!A.get(1.., ..).is_col_major_contiguous());
+		assert!(A.get(1.., 1..2).is_col_major_contiguous());
+
+		let A = Mat::<f64>::zeros(5, 3);
+		assert!(A.row_stride() == 1);
+		assert!(A.is_col_major_contiguous() == (A.col_stride() == 5));
+	}
+
+	#[test]
+	fn test_from_parts() {
+		use crate::Order;
+		use alloc::vec;
+		use alloc::vec::Vec;
+
+		let data = vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0];
+		let ptr = data.as_ptr();
+		let A = Mat::from_parts(2, 3, data, Order::ColMajor);
+		assert!(A == mat![[1.0, 3.0, 5.0], [2.0, 4.0, 6.0]]);
+		// the buffer is reused
+		assert!(A.as_ptr() == ptr);
+		assert!(A.is_col_major_contiguous());
+
+		let A = Mat::from_parts(2, 3, vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0], Order::RowMajor);
+		assert!(A == mat![[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+
+		let A = Mat::<f64>::from_parts(0, 3, vec![], Order::ColMajor);
+		assert!(A.shape() == (0, 3));
+
+		// elements with a destructor are dropped exactly once
+		let rc = alloc::rc::Rc::new(());
+		let data = (0..6).map(|_| rc.clone()).collect::<Vec<_>>();
+		let A = Mat::from_parts(3, 2, data, Order::RowMajor);
+		assert!(alloc::rc::Rc::strong_count(&rc) == 7);
+		drop(A);
+		assert!(alloc::rc::Rc::strong_count(&rc) == 1);
+	}
+
+	#[test]
+	#[should_panic]
+	fn test_from_parts_wrong_len() {
+		Mat::from_parts(2, 3, alloc::vec![0.0; 5], crate::Order::ColMajor);
+	}
+
+	#[test]
+	fn test_from_blocks_2x2() {
+		let A = Mat::from_fn(3, 3, |i, j| (i + 10 * j) as f64);
+		let B = Mat::from_fn(2, 3, |i, j| (100 + i + 10 * j) as f64);
+		let Z = Mat::<f64>::zeros(2, 2);
+
+		let K = Mat::from_blocks_2x2(A.as_ref(), B.transpose(), B.as_ref(), Z.as_ref());
+		assert!(all(K.nrows() == 5, K.ncols() == 5));
+		assert!(K.get(..3, ..3) == A);
+		assert!(K.get(..3, 3..) == B.transpose());
+		assert!(K.get(3.., ..3) == B);
+		assert!(K.get(3.., 3..) == Z);
+
+		let empty = Mat::<f64>::zeros(0, 0);
+		let C = Mat::from_blocks_2x2(A.as_ref(), Mat::zeros(3, 0).as_ref(), Mat::zeros(0, 3).as_ref(), empty.as_ref());
+		assert!(C == A);
+	}
+
+	#[test]
+	#[should_panic]
+	fn test_from_blocks_2x2_row_mismatch() {
+		let A = Mat::<f64>::zeros(3, 3);
+		let B = Mat::<f64>::zeros(2, 3);
+		Mat::from_blocks_2x2(A.as_ref(), B.as_ref(), B.as_ref(), Mat::zeros(2, 3).as_ref());
+	}
+
+	#[test]
+	#[should_panic]
+	fn test_from_blocks_2x2_col_mismatch() {
+		let A = Mat::<f64>::zeros(3, 3);
+		let B = Mat::<f64>::zeros(2, 3);
+		Mat::from_blocks_2x2(A.as_ref(), B.transpose(), B.as_ref(), Mat::zeros(2, 3).as_ref());
+	}
+
+	#[test]
+	fn test_is_normal() {
+		use crate::stats::prelude::*;
+
+		let rng = &mut StdRng::seed_from_u64(0);
+		let n = 8;
+
+		let A = CwiseMatDistribution {
+			nrows: n,
+			ncols: n,
+			dist: ComplexDistribution::new(StandardNormal, StandardNormal),
+		}
+		.rand::<Mat<c64>>(rng);
+		let H = &A + A.adjoint();
+		let Q: Mat<c64> = UnitaryMat {
+			dim: n,
+			standard_normal: ComplexDistribution::new(StandardNormal, StandardNormal),
+		}
+		.rand(rng);
+
+		assert!(H.is_normal(1e-12));
+		assert!(Q.is_normal(1e-12));
+		assert!(Q.as_ref().adjoint().is_normal(1e-12));
+		assert!(!A.is_normal(1e-12));
+		assert!(!mat![[1.0, 1.0], [0.0, 1.0f64]].is_normal(1e-12));
+		assert!(!Mat::<f64>::zeros(2, 3).is_normal(1e-12));
+	}
+
+	#[test]
+	fn test_ger() {
+		use crate::stats::prelude::*;
+		use crate::utils::approx::*;
+
+		let rng = &mut StdRng::seed_from_u64(0);
+		let approx_eq = CwiseMat(ApproxEq::<f64>::eps() * 8.0);
+
+		for (m, n) in [(5, 3), (80, 90)] {
+			let A = randn_c64(m, n, rng);
+			let x = randn_c64(m, 1, rng);
+			let y = randn_c64(n, 1, rng);
+			let alpha = c64::new(0.5, -2.0);
+
+			let mut expected = A.clone();
+			crate::linalg::matmul::matmul(&mut expected, crate::Accum::Add, x.as_ref(), y.adjoint(), alpha, Par::Seq);
+
+			for par in [Par::Seq, Par::rayon(4)] {
+				let mut dst = A.clone();
+				dst.ger(alpha, x.col(0), y.col(0), par);
+				assert!(dst ~ expected);
+			}
+		}
+	}
+
+	#[test]
+	#[should_panic]
+	fn test_ger_dim_mismatch() {
+		let mut A = Mat::<f64>::zeros(3, 4);
+		A.ger(1.0, Col::zeros(3).as_ref(), Col::zeros(3).as_ref(), Par::Seq);
+	}
+
+	#[test]
+	fn test_try_into_array() {
+		let A = mat![[1.0, 2.0], [3.0, 4.0f64]];
+		let B = (&A * &A).as_ref().try_into();
+		assert!(B == Ok([[7.0, 10.0], [15.0, 22.0]]));
+
+		let B: Result<[[f64; 3]; 2], _> = A.as_ref().try_into();
+		assert!(
+			B == Err(ShapeMismatch {
+				expected: (2, 3),
+				found: (2, 2),
+			})
+		);
+
+		let x: Result<[f64; 2], _> = A.col(1).as_mat().try_into();
+		assert!(x == Ok([2.0, 4.0]));
+		let x: Result<[f64; 2], _> = A.row(1).as_mat().try_into();
+		assert!(x == Ok([3.0, 4.0]));
+		let x: Result<[f64; 4], _> = A.as_ref().try_into();
+		assert!(
+			x == Err(ShapeMismatch {
+				expected: (4, 1),
+				found: (2, 2)
+			})
+		);
+		assert!(alloc::format!("{}", x.unwrap_err()) == "expected a 4×1 matrix, found a 2×2 matrix");
+	}
+
+	#[test]
+	fn test_map_axis() {
+		use crate::Axis;
+
+		let A = mat![[3.0, 0.0, 1.0], [4.0, 2.0, -1.0f64]];
+
+		let col_norms = A.map_axis(Axis::Col, |c| c.norm_l2());
+		assert!(col_norms == (0..3).map(|j| A.col(j).norm_l2()).collect::<alloc::vec::Vec<_>>());
+		assert!(col_norms[0] == 5.0);
+
+		let row_sums = A.as_ref().map_axis(Axis::Row, |r| r.sum());
+		assert!(row_sums == [4.0, 5.0]);
+
+		let col_medians = A.as_ref().map_axis(Axis::Col, |c| c.as_mat().quantile(0.5));
+		assert!(col_medians == [3.5, 1.0, 0.0]);
+
+		assert!(Mat::<f64>::zeros(0, 4).map_axis(Axis::Col, |c| c.nrows()) == [0; 4]);
+	}
+
+	#[test]
+	fn test_reduce_cols() {
+		use crate::stats::prelude::*;
+
+		let rng = &mut StdRng::seed_from_u64(0);
+		let (m, n) = (300, 7);
+
+		let A = CwiseMatDistribution {
+			nrows: m,
+			ncols: n,
+			dist: StandardNormal,
+		}
+		.rand::<Mat<f64>>(rng);
+
+		let max = |A: MatRef<'_, f64>, par| A.reduce_cols(f64::NEG_INFINITY, |acc, &x| f64::max(acc, x), f64::max, par);
+		let sum = |A: MatRef<'_, f64>, par| A.reduce_cols(0.0, |acc, &x| acc + x, |a, b| a + b, par);
+
+		let max_ref = Col::<f64>::from_fn(n, |j| A.col(j).iter().fold(f64::NEG_INFINITY, |acc, &x| f64::max(acc, x)));
+		let sum_ref = Col::<f64>::from_fn(n, |j| A.col(j).iter().sum());
+
+		let max_seq = max(A.as_ref(), Par::Seq);
+		let sum_seq = sum(A.as_ref(), Par::Seq);
+		assert!(max_seq == max_ref);
+		assert!((&sum_seq - &sum_ref).norm_max() < 1e-12);
+
+		// the result of each column doesn't depend on the thread count
+		for par in [Par::rayon(2), Par::rayon(3), Par::rayon(16)] {
+			assert!(max(A.as_ref(), par) == max_seq);
+			assert!(sum(A.as_ref(), par) == sum_seq);
+		}
+
+		let row_sums = A.reduce_rows(0.0, |acc, &x| acc + x, |a, b| a + b, Par::rayon(4));
+		assert!(row_sums == sum(A.transpose(), Par::Seq));
+		assert!(row_sums.nrows() == m);
+
+		assert!(Mat::<f64>::zeros(0, 3).reduce_cols(1.0, |acc, &x| acc * x, |a, b| a * b, Par::rayon(4)) == Col::<f64>::ones(3));
+		assert!(
+			Mat::<f64>::zeros(3, 0)
+				.reduce_cols(0.0, |acc, &x| acc + x, |a, b| a + b, Par::rayon(4))
+				.nrows() == 0
+		);
+	}
+
+	#[test]
+	fn test_axpby() {
+		use crate::stats::prelude::*;
+		use crate::utils::approx::*;
+
+		let rng = &mut StdRng::seed_from_u64(0);
+		let approx_eq = CwiseMat(ApproxEq::<f64>::eps() * 8.0);
+
+		for (m, n) in [(5, 3), (80, 90)] {
+			let x = CwiseMatDistribution {
+				nrows: m,
+				ncols: n,
+				dist: ComplexDistribution::new(StandardNormal, StandardNormal),
+			}
+			.rand::<Mat<c64>>(rng);
+			let y = CwiseMatDistribution {
+				nrows: m,
+				ncols: n,
+				dist: ComplexDistribution::new(StandardNormal, StandardNormal),
+			}
+			.rand::<Mat<c64>>(rng);
+
+			for par in [Par::Seq, Par::rayon(4)] {
+				let mut dst = Mat::<c64>::full(m, n, c64::new(f64::NAN, f64::NAN));
+				dst.axpby(c64::new(1.0, 0.0), &x, c64::new(0.0, 0.0), par);
+				assert!(dst == x);
+
+				let mut dst = y.clone();
+				dst.axpby(c64::new(1.0, 0.0), &x, c64::new(1.0, 0.0), par);
+				assert!(dst ~ &x + &y);
+
+				let alpha = c64::new(0.5, -2.0);
+				let beta = c64::new(-1.5, 0.25);
+				let mut dst = y.clone();
+				dst.as_mut().axpby(alpha, x.adjoint().transpose(), beta, par);
+				assert!(dst ~ Scale(alpha) * x.conjugate() + Scale(beta) * &y);
+			}
+		}
+	}
+
+	#[test]
+	fn test_quantile() {
+		let A = mat![[3.0, -1.0, 7.5], [0.5, 2.0, 4.0], [10.0, -3.0, 6.0f64]];
+
+		let mut sorted = alloc::vec::Vec::new();
+		for j in 0..A.ncols() {
+			for i in 0..A.nrows() {
+				sorted.push(A[(i, j)]);
+			}
+		}
+		sorted.sort_by(f64::total_cmp);
+		let n = sorted.len();
+
+		for (k, &v) in sorted.iter().enumerate() {
+			let q = k as f64 / (n - 1) as f64;
+			assert!(A.quantile(q) == v);
+		}
+		assert!(A.quantile(0.0) == -3.0);
+		assert!(A.quantile(1.0) == 10.0);
+		assert!(A.quantile(0.5) == 3.0);
+
+		// halfway between the 2nd and 3rd order statistics
+		let q = 1.5 / (n - 1) as f64;
+		assert!((A.quantile(q) - 0.5 * (sorted[1] + sorted[2])).abs() < 1e-12);
+
+		let qs = [0.0, 0.25, 0.5, 0.75, 1.0];
+		let values = A.as_ref().quantiles(&qs);
+		for (q, v) in qs.iter().zip(&values) {
+			assert!(*v == A.quantile(*q));
+		}
+
+		assert!(Mat::<f64>::zeros(0, 3).quantile(0.5).is_nan());
+
+		// interpolating next to an infinity
+		let inf = f64::INFINITY;
+		let B = mat![[-inf, -inf, 1.0, inf, inf]];
+		assert!(B.quantile(0.1) == -inf);
+		assert!(B.quantile(0.3) == -inf);
+		assert!(B.quantile(0.6) == inf);
+		assert!(B.quantile(0.9) == inf);
+		assert!(B.quantile(0.5) == 1.0);
+		assert!(mat![[1.0, f64::NAN, 2.0]].quantile(0.0).is_nan());
+	}
+
+	#[test]
+	#[should_panic]
+	fn test_quantile_out_of_range() {
+		mat![[1.0, 2.0f64]].quantile(1.5);
+	}
+
+	#[test]
+	fn test_debug() {
+		let A = mat![[1.0, -2.5], [3.0, 40.0f64]];
+		assert!(alloc::format!("{A:?}") == "2×2 [\n  [1.00, -2.50],\n  [3.00,  40.0],\n]");
+		assert!(alloc::format!("{A:.1?}") == "2×2 [\n  [1.0, -2.5],\n  [3.0, 40.0],\n]");
+
+		let mask = A.gt(2.0);
+		assert!(alloc::format!("{mask:?}") == "2×2 [\n  [false, false],\n  [ true,  true],\n]");
+
+		let B = Mat::from_fn(20, 30, |i, j| (i + j) as f64);
+		let s = alloc::format!("{B:?}");
+		assert!(s.starts_with("20×30 ["));
+		assert!(s.contains("..."));
+		// header, 4 + 4 rows, elided rows, closing bracket
+		assert!(s.lines().count() == 11);
+		assert!(s.contains("[19.0, 20.0, 21.0, 22.0, ..., 45.0, 46.0, 47.0, 48.0]"));
+
+		// three significant digits, in scientific notation for small or large magnitudes
+		let C = mat![[1e-10, 0.5], [-123456.0, 0.00123f64]];
+		assert!(alloc::format!("{C:?}") == "2×2 [\n  [1.00e-10,   0.500],\n  [ -1.23e5, 0.00123],\n]");
+		let z = mat![[c64::new(1e-10, -2.0)]];
+		assert!(alloc::format!("{z:?}") == "1×1 [\n  [1.00e-10 - 2.00i],\n]");
+	}
+
+	#[test]
+	fn test_stack() {
+		use crate::linalg::solvers::DenseSolveCore;
+		use crate::utils::approx::*;
+
+		let A = mat![
+			[4.0, 2.0, -2.0, 1.0, 0.5],
+			[2.0, 5.0, 1.0, -1.0, 0.0],
+			[-2.0, 1.0, 6.0, 0.5, 1.0],
+			[1.0, -1.0, 0.5, 7.0, -2.0],
+			[0.5, 0.0, 1.0, -2.0, 8.0f64],
+		];
+		let ldlt = A.ldlt(Side::Lower).unwrap();
+		let (L, D) = (ldlt.L(), ldlt.D());
+
+		// assemble the factors from their blocks, splitting after the second row
+		let k = 2;
+		let Z = Mat::<f64>::zeros(k, 5 - k);
+		let L = Mat::block(&[&[L.get(..k, ..k), Z.as_ref()], &[L.get(k.., ..k), L.get(k.., k..)]]);
+		let D = D.column_vector();
+		let D0 = Mat::from_fn(k, k, |i, j| if i == j { D[i] } else { 0.0 });
+		let D1 = Mat::from_fn(5 - k, 5 - k, |i, j| if i == j { D[k + i] } else { 0.0 });
+		let D = Mat::vstack(&[
+			Mat::hstack(&[D0.as_ref(), Z.as_ref()]).as_ref(),
+			Mat::hstack(&[Z.transpose(), D1.as_ref()]).as_ref(),
+		]);
+
+		let approx_eq = CwiseMat(ApproxEq::<f64>::eps() * 16.0);
+		assert!(&L * &D * L.transpose() ~ ldlt.reconstruct());
+		assert!(Mat::block(&[&[A.get(..k, ..)], &[A.get(k.., ..)]]) == A);
+		assert!(Mat::hstack(&[A.get(.., ..1), A.get(.., 1..)]) == A);
+		assert!(Mat::<f64>::block(&[]).shape() == (0, 0));
+
+		// the free functions forward to the associated ones
+		assert!(hstack(&[A.get(.., ..1), A.get(.., 1..)]) == A);
+		assert!(vstack(&[A.get(..k, ..), A.get(k.., ..)]) == A);
+		assert!(block(&[&[A.get(..k, ..k), A.get(..k, k..)], &[A.get(k.., ..k), A.get(k.., k..)]]) == A);
+	}
+
+	#[test]
+	#[should_panic]
+	fn test_block_mismatch() {
+		let A = Mat::<f64>::zeros(2, 2);
+		let B = Mat::<f64>::zeros(3, 2);
+		Mat::block(&[&[A.as_ref(), B.as_ref()]]);
+	}
+
+	#[test]
+	fn test_normalize_frobenius() {
+		use crate::utils::approx::*;
+
+		let A = mat![[1.0, -2.0, 3.0], [0.5, 4.0, -6.0f64]];
+		let B = A.normalized_frobenius();
+		{
+			let approx_eq = ApproxEq::<f64>::eps() * 8.0;
+			assert!(B.norm_fro() ~ 1.0);
+		}
+		{
+			let approx_eq = CwiseMat(ApproxEq::<f64>::eps() * 8.0);
+			let norm = A.norm_fro();
+			assert!(B ~ Mat::from_fn(2, 3, |i, j| A[(i, j)] / norm));
+		}
+
+		let mut C = A.clone();
+		C.normalize_frobenius();
+		assert!(C == B);
+
+		let Z = Mat::<c64>::zeros(3, 2);
+		assert!(Z.normalized_frobenius() == Z);
+	}
+
+	#[test]
+	fn test_display() {
+		let A = mat![[1.0, -2.5, 1000.0, 0.125], [-30.25, 4.0, 0.5, 7.0], [0.0, 123.5, -6.0, -0.0625f64]];
+		assert!(alloc::format!("{A}") == "[     1,  -2.5, 1000,   0.125]\n[-30.25,     4,  0.5,       7]\n[     0, 123.5,   -6, -0.0625]");
+		assert!(
+			alloc::format!("{:.2}", A.as_ref())
+				== "[  1.00,  -2.50, 1000.00,  0.12]\n[-30.25,   4.00,    0.50,  7.00]\n[  0.00, 123.50,   -6.00, -0.06]"
+		);
+
+		let mut B = mat![[1u32, 255], [4096, 10]];
+		assert!(alloc::format!("{B:x}") == "[   1, ff]\n[1000,  a]");
+		assert!(alloc::format!("{:#X}", B.as_mut()) == "[   0x1, 0xFF]\n[0x1000,  0xA]");
+	}
+
+	#[test]
+	fn test_assume_uninit_then_written() {
+		let mut A = Mat::<f64>::zeros(4, 3);
+		{
+			let mut guard = A.as_mut().assume_uninit_then_written();
+			guard.copy_from(Mat::<f64>::identity(4, 3));
+		}
+		assert!(A == Mat::<f64>::identity(4, 3));
+	}
+
+	#[test]
+	#[cfg(debug_assertions)]
+	#[should_panic(expected = "some elements were not written")]
+	fn test_assume_uninit_then_written_missing() {
+		let mut A = Mat::<f64>::zeros(4, 3);
+		let mut guard = A.as_mut().assume_uninit_then_written();
+		guard.rb_mut().get_mut(..3, ..).fill(1.0);
+	}
+
+	#[test]
+	fn test_permute() {
+		let A = Mat::from_fn(4, 3, |i, j| c64::new((i + 10 * j) as f64, i as f64 - j as f64));
+		let p = crate::perm::Perm::<usize>::new_checked(alloc::vec![2, 0, 3, 1].into_boxed_slice(), alloc::vec![1, 3, 0, 2].into_boxed_slice(), 4);
+		let q = crate::perm::Perm::<usize>::new_checked(alloc::vec![1, 2, 0].into_boxed_slice(), alloc::vec![2, 0, 1].into_boxed_slice(), 3);
+
+		let B = A.permute_rows(p.as_ref());
+		for i in 0..4 {
+			assert!(B.row(i) == A.row(p.as_ref().arrays().0[i]));
+		}
+		assert!(B.permute_rows(p.as_ref().inverse()) == A);
+
+		let C = A.permute_cols(q.as_ref());
+		for j in 0..3 {
+			assert!(C.col(j) == A.col(q.as_ref().arrays().0[j]));
+		}
+		assert!(C.permute_cols(q.as_ref().inverse()) == A);
+
+		assert!(A.permute(p.as_ref(), q.as_ref()) == A.permute_rows(p.as_ref()).permute_cols(q.as_ref()));
+		assert!(A.as_ref().conjugate().permute_rows(p.as_ref()) == B.conjugate());
+		assert!(&p * &A == B);
+	}
+
+	#[test]
+	fn test_try_into_real() {
+		// upper triangular, so the eigenvalues are the diagonal elements
+		let A = mat![[2.0, 1.0, -3.0], [0.0, -1.0, 4.0], [0.0, 0.0, 5.0f64]];
+		let evd = A.eigen_from_real().unwrap();
+
+		let U = evd.U().try_into_real(1e-12).unwrap();
+		let S = evd.S().column_vector().as_mat().try_into_real(1e-12).unwrap();
+		assert!((&A * &U - &U * S.col(0).as_diagonal()).norm_max() < 1e-12);
+
+		let mut values = S.col(0).iter().copied().collect::<alloc::vec::Vec<_>>();
+		values.sort_by(f64::total_cmp);
+		assert!(values == [-1.0, 2.0, 5.0]);
+
+		// rotation matrix, with eigenvalues ±i
+		let R = mat![[0.0, -1.0], [1.0, 0.0f64]];
+		let evd = R.eigen_from_real().unwrap();
+		assert!(evd.S().column_vector().as_mat().try_into_real(1e-12).is_none());
+
+		let B = mat![[c64::new(1.0, 1e-14), c64::new(2.0, 0.0)], [c64::new(3.0, 0.1), c64::new(4.0, 0.0)]];
+		assert!(B.try_into_real(1e-12).is_none());
+		assert!(B.try_into_real(0.5) == Some(mat![[1.0, 2.0], [3.0, 4.0f64]]));
+	}
+
+	#[test]
+	fn test_bandwidth() {
+		let band = |m: usize, n: usize, kl: usize, ku: usize| {
+			Mat::from_fn(m, n, |i, j| if i <= j + kl && j <= i + ku { 1.0 + (i + j) as f64 } else { 1e-14 })
+		};
+
+		for (m, n) in [(6, 6), (8, 5), (5, 8)] {
+			for kl in 0..m {
+				for ku in 0..n {
+					let A = band(m, n, kl, ku);
+					assert!(all(A.lower_bandwidth(1e-12) == kl, A.upper_bandwidth(1e-12) == ku));
+					assert!(A.is_banded(kl, ku, 1e-12));
+					assert!(A.lower_bandwidth(0.0) == m - 1);
+					if kl > 0 {
+						assert!(!A.is_banded(kl - 1, ku, 1e-12));
+					}
+					assert!(A.is_tridiagonal(1e-12) == (kl <= 1 && ku <= 1));
+					assert!(A.is_upper_hessenberg(1e-12) == (kl <= 1));
+				}
+			}
+		}
+
+		let Z = Mat::<c64>::zeros(4, 4);
+		assert!(all(Z.lower_bandwidth(0.0) == 0, Z.upper_bandwidth(0.0) == 0));
+		let mut B = Z.clone();
+		B[(3, 1)] = c64::new(0.0, 1.0);
+		assert!(all(B.lower_bandwidth(0.0) == 2, B.upper_bandwidth(0.0) == 0));
+		assert!(B.as_ref().adjoint().upper_bandwidth(0.0) == 2);
+	}
+
+	#[test]
+	fn test_try_into_diag() {
+		let A = mat![[1.0, 1e-10, 0.0], [-2e-10, 2.0, 0.0], [0.0, 1e-11, 3.0f64]];
+		let D = A.try_into_diag(1e-9).unwrap();
+		assert!(D.column_vector() == col![1.0, 2.0, 3.0]);
+
+		assert!(A.try_into_diag(1e-10).is_none());
+		assert!(A.as_ref().try_into_diag(0.0).is_none());
+
+		let Z = mat![[c64::new(1.0, 2.0), c64::new(0.0, 0.5)], [c64::new(0.0, 0.0), c64::new(3.0, 0.0)]];
+		assert!(Z.try_into_diag(0.4).is_none());
+		assert!(Z.as_ref().adjoint().try_into_diag(0.5).unwrap().column_vector() == col![c64::new(1.0, -2.0), c64::new(3.0, 0.0)]);
+	}
+
+	#[test]
+	#[should_panic]
+	fn test_try_into_diag_non_square() {
+		Mat::<f64>::zeros(2, 3).try_into_diag(0.0);
+	}
+
+	#[test]
+	fn test_from_fn_complex() {
+		let re = |i: usize, j: usize| (i + 2 * j) as f64;
+		let im = |i: usize, j: usize| i as f64 - j as f64;
+
+		let A = Mat::<c64>::from_fn_complex(3, 4, re, im);
+		assert!(A == Mat::from_fn(3, 4, |i, j| c64::new(re(i, j), im(i, j))));
+		assert!(A[(2, 1)] == c64::new(4.0, 1.0));
+		assert!(Mat::<c64>::from_fn_complex(0, 2, re, im).shape() == (0, 2));
+	}
+
+	#[test]
+	fn test_packed_lower() {
+		let n = 5;
+		let A = Mat::from_fn(n, n, |i, j| c64::new((10 * i + j) as f64, (i as f64) - (j as f64)));
+
+		let packed = A.pack_lower_col_major();
+		assert!(packed.len() == n * (n + 1) / 2);
+		// LAPACK: AP(i + (j - 1) * (2 * n - j) / 2) = A(i, j) for j <= i, with one-based indices
+		for j in 0..n {
+			for i in j..n {
+				assert!(packed[i + j * (2 * n - j - 1) / 2] == A[(i, j)]);
+			}
+		}
+		let small = mat![[1.0, 0.0, 0.0], [2.0, 4.0, 0.0], [3.0, 5.0, 6.0f64]];
+		assert!(small.pack_lower_col_major() == [1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+
+		let L = Mat::<c64>::from_packed_lower(&packed, n, Side::Lower);
+		let U = Mat::<c64>::from_packed_lower(&packed, n, Side::Upper);
+		for j in 0..n {
+			for i in 0..n {
+				if i >= j {
+					assert!(L[(i, j)] == A[(i, j)]);
+				} else {
+					assert!(L[(i, j)] == c64::new(0.0, 0.0));
+				}
+				if i <= j {
+					assert!(U[(i, j)] == A[(j, i)].conj());
+				} else {
+					assert!(U[(i, j)] == c64::new(0.0, 0.0));
+				}
+			}
+		}
+		assert!(L.pack_lower_col_major() == packed);
+		assert!(U.adjoint().pack_lower_col_major() == packed);
+
+		assert!(Mat::<f64>::zeros(0, 0).pack_lower_col_major().is_empty());
+		assert!(Mat::<f64>::from_packed_lower(&[], 0, Side::Lower).shape() == (0, 0));
+	}
+
+	#[test]
+	fn test_matrix_norms() {
+		let A = mat![
+			[c64::new(3.0, 4.0), c64::new(-1.0, 0.0), c64::new(0.0, 2.0)],
+			[c64::new(0.0, 0.0), c64::new(6.0, -8.0), c64::new(1.0, 0.0)],
+		];
+
+		assert!(A.norm_fro() == A.norm_l2());
+		assert!((A.norm_fro() - 131.0f64.sqrt()).abs() < 1e-12);
+		// column sums: 5, 11, 3
+		assert!((A.operator_norm_l1() - 11.0).abs() < 1e-12);
+		// row sums: 8, 11
+		assert!((A.operator_norm_linf() - 11.0).abs() < 1e-12);
+		assert!((A.transpose().operator_norm_linf() - A.operator_norm_l1()).abs() < 1e-12);
+
+		let spectral = A.operator_norm_l2().unwrap();
+		assert!(spectral <= A.norm_fro());
+		assert!(spectral >= A.norm_fro() / 2.0f64.sqrt());
+		assert!((spectral * spectral - (&A * A.adjoint()).self_adjoint_eigenvalues(Side::Lower).unwrap()[1]).abs() < 1e-10);
+
+		for B in [Mat::<f64>::zeros(0, 3), Mat::<f64>::zeros(3, 0), Mat::<f64>::zeros(0, 0)] {
+			assert!(B.norm_fro() == 0.0);
+			assert!(B.operator_norm_l1() == 0.0);
+			assert!(B.operator_norm_linf() == 0.0);
+			assert!(B.operator_norm_l2().unwrap() == 0.0);
+		}
+
+		let mut B = mat![[-2.5f64]];
+		assert!(B.norm_fro() == 2.5);
+		assert!(B.operator_norm_l1() == 2.5);
+		assert!(B.operator_norm_linf() == 2.5);
+		assert!(B.as_mut().operator_norm_l2().unwrap() == 2.5);
+		assert!(B.norm_max() == 2.5);
+	}
+
+	#[test]
+	fn test_cwise_min_max() {
+		let mut A = mat![[-1.0, 2.0, 0.0], [3.5, -0.25, -7.0f64]];
+		let B = mat![[0.5, 1.0, -1.0], [3.5, 4.0, -8.0f64]];
+
+		assert!(cwise_max(&A, &B) == mat![[0.5, 2.0, 0.0], [3.5, 4.0, -7.0]]);
+		assert!(cwise_min(A.as_ref(), B.as_ref()) == mat![[-1.0, 1.0, -1.0], [3.5, -0.25, -8.0]]);
+
+		let mut C = A.clone();
+		C.min_scalar_in_place(1.0);
+		assert!(C == mat![[-1.0, 1.0, 0.0], [1.0, -0.25, -7.0]]);
+
+		A.max_scalar_in_place(0.0);
+		assert!(A == mat![[0.0, 2.0, 0.0], [3.5, 0.0, 0.0]]);
+	}
+
+	#[test]
+	#[should_panic]
+	fn test_cwise_max_dim_mismatch() {
+		cwise_max(Mat::<f64>::zeros(2, 3), Mat::<f64>::zeros(3, 2));
+	}
+
+	#[test]
+	fn test_mul() {
+		let A = mat![[1.0, 2.0, -1.0], [0.5, -3.0, 4.0f64]];
+		let mut B = mat![[2.0, 0.0], [-1.0, 1.0], [0.25, 3.0f64]];
+		let expected = &A * &B;
+
+		assert!(mul(&A, &B) == expected);
+		assert!(mul(A.as_ref(), B.as_ref()) == expected);
+		assert!(mul(&A, B.as_mut()) == expected);
+		assert!(mul(A.as_ref(), &B) == expected);
+		assert!(mul(A.clone(), B.clone()) == expected);
+		assert!(mul(&&A, &mut B) == expected);
+		assert!(mul(B.transpose(), A.transpose()) == expected.transpose());
+
+		let A = mat![[c64::new(1.0, 2.0), c64::new(0.0, -1.0)]];
+		let B = mat![[c64::new(3.0, 0.5)], [c64::new(-2.0, 1.0)]];
+		assert!(mul(A.conjugate(), &B) == A.conjugate() * &B);
+	}
+
+	#[test]
+	#[should_panic]
+	fn test_mul_dim_mismatch() {
+		mul(Mat::<f64>::zeros(2, 3), Mat::<f64>::zeros(2, 3));
+	}
+
+	#[test]
+	fn test_logsumexp() {
+		let A = mat![[-1.0, 2.0, 0.5], [3.0, -0.25, -7.0], [0.0, 1.5, 4.0f64]];
+		let naive = |x: &[f64]| x.iter().map(|x| x.exp()).sum::<f64>().ln();
+
+		let cols = A.logsumexp_cols();
+		let rows = A.as_ref().logsumexp_rows();
+		for j in 0..3 {
+			assert!((cols[j] - naive(&[A[(0, j)], A[(1, j)], A[(2, j)]])).abs() < 1e-14);
+		}
+		for i in 0..3 {
+			assert!((rows[i] - naive(&[A[(i, 0)], A[(i, 1)], A[(i, 2)]])).abs() < 1e-14);
+		}
+
+		// exp(1000) overflows, but log(exp(1000) + exp(1000)) = 1000 + log(2)
+		let B = mat![[1000.0, -1000.0], [1000.0, -1000.0f64]];
+		assert!(naive(&[1000.0, 1000.0]) == f64::INFINITY);
+		assert!(naive(&[-1000.0, -1000.0]) == f64::NEG_INFINITY);
+		let cols = B.logsumexp_cols();
+		assert!((cols[0] - (1000.0 + 2.0f64.ln())).abs() < 1e-12);
+		assert!((cols[1] - (-1000.0 + 2.0f64.ln())).abs() < 1e-12);
+
+		assert!(Mat::<f64>::zeros(0, 2).logsumexp_cols() == Row::from_fn(2, |_| f64::NEG_INFINITY));
+	}
+
+	#[test]
+	fn test_softmax() {
+		let A = mat![[-1.0, 2.0, 0.5], [3.0, -0.25, -7.0], [0.0, 1.5, 800.0f64]];
+		let S = A.softmax_cols();
+
+		for j in 0..3 {
+			assert!((S.col(j).sum() - 1.0).abs() < 1e-15);
+			for i in 0..3 {
+				assert!(S[(i, j)] >= 0.0);
+			}
+		}
+		assert!((S[(1, 0)] - 3.0f64.exp() / ((-1.0f64).exp() + 3.0f64.exp() + 1.0)).abs() < 1e-15);
+		assert!(S[(2, 2)] == 1.0);
+
+		// invariant to shifting each column by a constant
+		let mut B = Mat::from_fn(3, 3, |i, j| A[(i, j)] + 10.0 * j as f64 - 4.0);
+		B.softmax_cols_in_place();
+		for j in 0..3 {
+			for i in 0..3 {
+				assert!((B[(i, j)] - S[(i, j)]).abs() < 1e-14);
+			}
+		}
+
+		// non-finite maxima
+		let inf = f64::INFINITY;
+		let C = mat![
+			[-inf, inf, inf, inf],
+			[-inf, 1.0, inf, f64::NAN],
+			[-inf, -inf, 2.0, 0.0],
+			[-inf, 0.0, -inf, 0.0]
+		];
+		let S = C.softmax_cols();
+		assert!(S.col(0) == col![0.25, 0.25, 0.25, 0.25]);
+		assert!(S.col(1) == col![1.0, 0.0, 0.0, 0.0]);
+		assert!(S.col(2) == col![0.5, 0.5, 0.0, 0.0]);
+		assert!(S.col(3).iter().all(|x| x.is_nan()));
+	}
+
+	#[test]
+	fn test_adjoint_cast() {
+		let A = mat![[1.0f32, 2.5, -3.0], [0.25, -7.0, 4.0]];
+		let B: Mat<f64> = A.adjoint_cast();
+		assert!(B.shape() == (3, 2));
+		assert!(B == Mat::from_fn(3, 2, |i, j| A[(j, i)] as f64));
+		assert!(B == A.as_ref().adjoint_cast::<f64>());
+
+		let A = Mat::from_fn(2, 3, |i, j| c64::new(i as f64 + 0.5, j as f64 - 1.0));
+		let B: Mat<c64> = A.adjoint_cast();
+		assert!(B == A.adjoint().to_owned());
+		assert!(B[(2, 1)] == c64::new(1.5, -1.0));
+
+		let A = Mat::from_fn(2, 3, |i, j| c32::new(i as f32 + 0.5, j as f32 - 1.25));
+		let B: Mat<c64> = A.adjoint_cast();
+		assert!(B.shape() == (3, 2));
+		assert!(B == Mat::from_fn(3, 2, |i, j| c64::new(A[(j, i)].re as f64, -A[(j, i)].im as f64)));
+		assert!(B[(2, 1)] == c64::new(1.5, -0.75));
+	}
+
+	#[test]
+	fn test_swap() {
+		let A0 = Mat::from_fn(3, 4, |i, j| (i + 10 * j) as f64);
+		let B0 = Mat::from_fn(3, 4, |i, j| -((i * j) as f64));
+
+		let (mut A, mut B) = (A0.clone(), B0.clone());
+		A.as_mut().swap_with(B.as_mut());
+		assert!(all(A == B0, B == A0));
+
+		// strided and transposed views
+		let mut C = Mat::from_fn(4, 3, |i, j| (i + 10 * j) as f64);
+		let C0 = C.clone();
+		let mut D = Mat::<f64>::zeros(6, 8);
+		C.swap_with(D.as_mut().get_mut(1..4, 2..6).transpose_mut());
+		assert!(C == Mat::<f64>::zeros(4, 3));
+		assert!(D.get(1..4, 2..6) == C0.transpose());
+
+		let mut E = Mat::<f64>::zeros(2, 7);
+		let (a, e) = (A.as_ptr(), E.as_ptr());
+		A.swap(&mut E);
+		assert!(all(A.shape() == (2, 7), E == B0));
+		assert!(all(A.as_ptr() == e, E.as_ptr() == a));
+	}
+
+	#[test]
+	fn test_col_window() {
+		let (n, w) = (4, 5);
+		let col = |k: usize| Col::<f64>::from_fn(n, |i| (10 * k + i) as f64);
+
+		let mut window = ColWindow::<f64>::new(n, w);
+		assert!(window.as_mat_ref().shape() == (n, 0));
+
+		for k in 0..w + 3 {
+			window.push(col(k).as_ref());
+			let len = Ord::min(k + 1, w);
+			assert!(window.len() == len);
+
+			let W = window.as_mat_ref();
+			assert!(W.shape() == (n, len));
+			for j in 0..len {
+				assert!(W.col(j) == col(k + 1 - len + j));
+			}
+		}
+
+		let expected = Mat::from_fn(n, w, |i, j| (10 * (j + 3) + i) as f64);
+		assert!(window.as_mat_ref() == expected);
+	}
+
+	#[test]
+	fn test_trace_kron() {
+		let A = mat![[1.0, 2.0], [3.0, 4.0f64]];
+		let B = mat![[0.0, 5.0], [6.0, 7.0f64]];
+		assert!(all(A.trace() == 5.0, B.as_ref().trace() == 7.0));
+		assert!(Mat::<f64>::identity(6, 6).trace() == 6.0);
+		assert!(Mat::<f64>::zeros(0, 0).trace() == 0.0);
+
+		let C = A.kron(B.as_ref());
+		assert!(
+			C == mat![
+				[0.0, 5.0, 0.0, 10.0],
+				[6.0, 7.0, 12.0, 14.0],
+				[0.0, 15.0, 0.0, 20.0],
+				[18.0, 21.0, 24.0, 28.0]
+			]
+		);
+		assert!(C.trace() == A.trace() * B.trace());
+
+		let I2 = Mat::<f64>::identity(2, 2);
+		let I3 = Mat::<f64>::identity(3, 3);
+		assert!(I2.kron(I3.as_ref()) == Mat::<f64>::identity(6, 6));
+		let D = I2.kron(B.as_ref());
+		assert!(all(
+			D.get(..2, ..2) == B,
+			D.get(2.., 2..) == B,
+			D.get(..2, 2..) == Mat::<f64>::zeros(2, 2)
+		));
+
+		let R = mat![[1.0, 2.0, 3.0f64]];
+		assert!(R.kron(A.as_ref()).shape() == (2, 6));
+	}
+
+	#[test]
+	fn test_as_permutation() {
+		let fwd = [2usize, 0, 3, 1];
+		let P = Mat::<f64>::from_fn(4, 4, |i, j| if fwd[i] == j { 1.0 } else { 0.0 });
+		let perm = P.as_permutation(0.0).unwrap();
+		assert!(perm.as_ref().arrays().0 == &fwd[..]);
+		assert!(perm.as_ref() * Mat::<f64>::identity(4, 4) == P);
+
+		// within tolerance
+		let Q = Mat::<c64>::from_fn(4, 4, |i, j| c64::new(P[(i, j)] + 1e-10 * (i + j) as f64, -1e-11));
+		assert!(Q.as_permutation(1e-8).unwrap().as_ref().arrays().0 == &fwd[..]);
+		assert!(Q.as_permutation(1e-12).is_none());
+
+		// near misses
+		let mut R = P.clone();
+		R[(0, 1)] = 0.1;
+		assert!(R.as_permutation(1e-8).is_none());
+		let mut R = P.clone();
+		R[(1, 2)] = 1.0;
+		assert!(R.as_permutation(1e-8).is_none());
+		let mut R = P.clone();
+		R[(3, 1)] = 0.0;
+		assert!(R.as_permutation(1e-8).is_none());
+		assert!(P.get(.., ..3).as_permutation(1e-8).is_none());
+		assert!((&P * Scale(-1.0)).as_permutation(1e-8).is_none());
+	}
 }
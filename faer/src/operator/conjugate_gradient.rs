@@ -167,6 +167,7 @@ pub fn conjugate_gradient<T: ComplexField>(
 			z!(&mut r, &b).for_each(|uz!(res, rhs)| *res = *rhs - *res);
 			r.norm_l2()
 		} else {
+			r.copy_from(b);
 			copy(b_norm)
 		};
 
@@ -331,9 +332,61 @@ pub fn conjugate_gradient<T: ComplexField>(
 	implementation(out, &precond, &mat, rhs, params, &mut { callback }, par, stack)
 }
 
+/// solves $A x = b$ with the conjugate gradient method, where $A$ is a self-adjoint positive
+/// definite operator given by a closure computing $A v$
+///
+/// `precond`, if provided, computes $M v$ for a self-adjoint positive definite approximation $M$
+/// of $A^{-1}$. the iteration stops once the norm of the residual is below `tol` times the norm
+/// of $b$, or fails after `max_iters` iterations
+///
+/// see [`conjugate_gradient`] for the general version working with any [`LinOp`]
+#[track_caller]
+pub fn cg_solve<T: RealField>(
+	A: impl Sync + Fn(ColRef<'_, T>) -> Col<T>,
+	b: ColRef<'_, T>,
+	tol: T,
+	max_iters: usize,
+	precond: Option<impl Sync + Fn(ColRef<'_, T>) -> Col<T>>,
+) -> Result<(Col<T>, CgInfo<T>), CgError<T>> {
+	#[track_caller]
+	fn implementation<T: RealField>(
+		A: impl LinOp<T>,
+		M: impl Precond<T>,
+		b: ColRef<'_, T>,
+		params: CgParams<T>,
+	) -> Result<(Col<T>, CgInfo<T>), CgError<T>> {
+		let par = crate::get_global_parallelism();
+		let mut x = Mat::zeros(b.nrows(), 1);
+		let info = conjugate_gradient(
+			x.as_mut(),
+			&M,
+			&A,
+			b.as_mat(),
+			params,
+			|_| {},
+			par,
+			MemStack::new(&mut MemBuffer::new(conjugate_gradient_scratch(&M, &A, 1, par))),
+		)?;
+		Ok((x.col(0).to_owned(), info))
+	}
+
+	let dim = b.nrows();
+	let params = CgParams {
+		initial_guess: InitialGuessStatus::Zero,
+		rel_tolerance: tol,
+		max_iters,
+		..Default::default()
+	};
+	match precond {
+		Some(f) => implementation(FnOp { dim, f: A }, FnOp { dim, f }, b, params),
+		None => implementation(FnOp { dim, f: A }, IdentityPrecond { dim }, b, params),
+	}
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
+	use crate::linalg::solvers::Solve;
 	use crate::stats::prelude::*;
 	use crate::{mat, matrix_free};
 	use dyn_stack::MemBuffer;
@@ -367,6 +420,36 @@ mod tests {
 		assert!(result.iter_count <= 1);
 	}
 
+	#[test]
+	fn test_cg_zero_initial_guess() {
+		let ref A = mat![[2.5, -1.0], [-1.0, 3.1]];
+		let ref sol = mat![[2.1, 2.4], [4.1, 4.0]];
+		let ref rhs = A * sol;
+		let ref mut out = Mat::<f64>::zeros(2, sol.ncols());
+		let mut params = CgParams::default();
+		params.initial_guess = InitialGuessStatus::Zero;
+		params.max_iters = 10;
+		let precond = matrix_free::IdentityPrecond { dim: 2 };
+
+		// fill the workspace with nan, so that reading the residual before it's written is caught
+		let req = conjugate_gradient_scratch(precond, A.as_ref(), 2, Par::Seq);
+		let mut buf = vec![core::mem::MaybeUninit::new(f64::NAN); req.unaligned_bytes_required().div_ceil(8)];
+		let result = conjugate_gradient(
+			out.as_mut(),
+			precond,
+			A.as_ref(),
+			rhs.as_ref(),
+			params,
+			|_| {},
+			Par::Seq,
+			MemStack::new_any(&mut buf),
+		);
+		let ref out = *out;
+
+		assert!(result.is_ok());
+		assert!((A * out - rhs).norm_l2() <= params.rel_tolerance * rhs.norm_l2());
+	}
+
 	#[test]
 	fn test_cg_breakdown() {
 		let ref mut rng = StdRng::seed_from_u64(0);
@@ -428,4 +511,44 @@ mod tests {
 		assert!((A * out - rhs).norm_l2() <= params.rel_tolerance * rhs.norm_l2());
 		assert!(result.iter_count <= 1);
 	}
+
+	fn laplacian(x: ColRef<'_, f64>) -> Col<f64> {
+		let n = x.nrows();
+		Col::from_fn(n, |i| {
+			let left = if i > 0 { x[i - 1] } else { 0.0 };
+			let right = if i + 1 < n { x[i + 1] } else { 0.0 };
+			2.0 * x[i] - left - right
+		})
+	}
+
+	#[test]
+	fn test_cg_solve() {
+		let n = 50;
+		let A = Mat::<f64>::from_fn(n, n, |i, j| {
+			if i == j {
+				2.0
+			} else if i.abs_diff(j) == 1 {
+				-1.0
+			} else {
+				0.0
+			}
+		});
+		let b = Col::<f64>::from_fn(n, |i| ((i * i) % 7) as f64 - 3.0);
+
+		let direct = A.partial_piv_lu().solve(&b);
+		let tol = 1e-12;
+
+		let (x, info) = cg_solve(laplacian, b.as_ref(), tol, 1000, None::<fn(ColRef<'_, f64>) -> Col<f64>>).unwrap();
+		assert!((&x - &direct).norm_l2() <= 1e-8 * direct.norm_l2());
+		assert!(all(info.iter_count <= n, info.rel_residual <= tol));
+
+		// jacobi preconditioner
+		let (x, _) = cg_solve(laplacian, b.as_ref(), tol, 1000, Some(|x: ColRef<'_, f64>| x.to_owned() * Scale(0.5))).unwrap();
+		assert!((&x - &direct).norm_l2() <= 1e-8 * direct.norm_l2());
+
+		assert!(matches!(
+			cg_solve(laplacian, b.as_ref(), tol, 2, None::<fn(ColRef<'_, f64>) -> Col<f64>>),
+			Err(CgError::NoConvergence { .. })
+		));
+	}
 }
@@ -0,0 +1,404 @@
+use super::*;
+use crate::assert;
+
+/// algorithm parameters
+#[derive(Copy, Clone, Debug)]
+pub struct GmresParams<T: RealField> {
+	/// whether the initial guess is implicitly zero or not
+	pub initial_guess: InitialGuessStatus,
+	/// absolute tolerance for convergence testing
+	pub abs_tolerance: T,
+	/// relative tolerance for convergence testing
+	pub rel_tolerance: T,
+	/// number of arnoldi vectors built before restarting
+	pub restart: usize,
+	/// maximum number of restart cycles
+	pub max_restarts: usize,
+
+	#[doc(hidden)]
+	pub non_exhaustive: NonExhaustive,
+}
+
+/// algorithm result
+#[derive(Copy, Clone, Debug)]
+pub struct GmresInfo<T: RealField> {
+	/// absolute residual at the final step
+	pub abs_residual: T,
+	/// relative residual at the final step
+	pub rel_residual: T,
+	/// number of iterations executed by the algorithm, summed over all the restart cycles
+	pub iter_count: usize,
+
+	#[doc(hidden)]
+	pub non_exhaustive: NonExhaustive,
+}
+
+/// algorithm error
+#[derive(Copy, Clone, Debug)]
+pub enum GmresError<T: ComplexField> {
+	/// convergence failure
+	NoConvergence {
+		/// absolute residual at the final step
+		abs_residual: T::Real,
+		/// relative residual at the final step
+		rel_residual: T::Real,
+	},
+}
+
+impl<T: RealField> Default for GmresParams<T> {
+	#[inline]
+	#[math]
+	fn default() -> Self {
+		Self {
+			initial_guess: InitialGuessStatus::MaybeNonZero,
+			abs_tolerance: zero::<T>(),
+			rel_tolerance: eps::<T>() * from_f64::<T>(128.0),
+			restart: 30,
+			max_restarts: usize::MAX,
+			non_exhaustive: NonExhaustive(()),
+		}
+	}
+}
+
+/// computes the size and alignment of required workspace for executing the gmres algorithm with
+/// `restart` arnoldi vectors per cycle
+pub fn gmres_scratch<T: ComplexField>(right_precond: impl Precond<T>, mat: impl LinOp<T>, restart: usize, par: Par) -> StackReq {
+	fn implementation<T: ComplexField>(M: &dyn Precond<T>, A: &dyn LinOp<T>, restart: usize, par: Par) -> StackReq {
+		let n = A.nrows();
+		let m = restart;
+
+		StackReq::all_of(&[
+			temp_mat_scratch::<T>(n, m + 1), // V
+			temp_mat_scratch::<T>(m, m),     // H
+			temp_mat_scratch::<T>(m + 1, 1), // g
+			temp_mat_scratch::<T>(m, 2),     // givens rotations
+			temp_mat_scratch::<T>(n, 1),     // r
+			temp_mat_scratch::<T>(n, 1),     // z
+			StackReq::any_of(&[A.apply_scratch(1, par), M.apply_scratch(1, par)]),
+		])
+	}
+	implementation(&right_precond, &mat, restart, par)
+}
+
+/// executes the restarted gmres algorithm using the provided right preconditioner, i.e., solves
+/// $A M u = b$ and returns $x = M u$
+///
+/// each cycle builds an orthonormal basis of the krylov subspace with the arnoldi process, using
+/// modified gram-schmidt, and minimizes the residual over it. the hessenberg matrix is reduced to
+/// triangular form with givens rotations as it grows, which gives the residual norm at every step
+/// for free, and the triangular system is solved once at the end of the cycle. the residual is then
+/// recomputed from the updated iterate before the next cycle
+///
+/// # note
+/// the columns of the rhs are solved one after the other. the returned info holds the largest
+/// residuals and iteration count over all the columns
+#[inline]
+#[track_caller]
+pub fn gmres<T: ComplexField>(
+	out: MatMut<'_, T>,
+	right_precond: impl Precond<T>,
+	mat: impl LinOp<T>,
+	rhs: MatRef<'_, T>,
+	params: GmresParams<T::Real>,
+	callback: impl FnMut(MatRef<'_, T>),
+	par: Par,
+	stack: &mut MemStack,
+) -> Result<GmresInfo<T::Real>, GmresError<T::Real>> {
+	#[track_caller]
+	#[math]
+	fn implementation<T: ComplexField>(
+		mut x: MatMut<'_, T>,
+		M: &dyn Precond<T>,
+		A: &dyn LinOp<T>,
+		b: MatRef<'_, T>,
+
+		params: GmresParams<T::Real>,
+		callback: &mut dyn FnMut(MatRef<'_, T>),
+		par: Par,
+		stack: &mut MemStack,
+	) -> Result<GmresInfo<T::Real>, GmresError<T::Real>> {
+		assert!(all(
+			A.nrows() == A.ncols(),
+			M.nrows() == A.nrows(),
+			b.nrows() == A.nrows(),
+			x.nrows() == A.nrows(),
+			x.ncols() == b.ncols(),
+			params.restart > 0,
+		));
+
+		let n = A.nrows();
+		let m = params.restart;
+
+		let mut info = GmresInfo {
+			abs_residual: zero::<T::Real>(),
+			rel_residual: zero::<T::Real>(),
+			iter_count: 0,
+			non_exhaustive: NonExhaustive(()),
+		};
+
+		let (mut V, stack) = unsafe { temp_mat_uninit::<T, _, _>(n, m + 1, stack) };
+		let mut V = V.as_mat_mut();
+		let (mut H, stack) = unsafe { temp_mat_uninit::<T, _, _>(m, m, stack) };
+		let mut H = H.as_mat_mut();
+		let (mut g, stack) = unsafe { temp_mat_uninit::<T, _, _>(m + 1, 1, stack) };
+		let mut g = g.as_mat_mut();
+		let (mut G, stack) = unsafe { temp_mat_uninit::<T, _, _>(m, 2, stack) };
+		let mut G = G.as_mat_mut();
+		let (mut r, stack) = unsafe { temp_mat_uninit::<T, _, _>(n, 1, stack) };
+		let mut r = r.as_mat_mut();
+		let (mut z, stack) = unsafe { temp_mat_uninit::<T, _, _>(n, 1, stack) };
+		let mut z = z.as_mat_mut();
+
+		for j in 0..b.ncols() {
+			let b = b.col(j).as_mat();
+			let b_norm = b.norm_l2();
+			if b_norm == zero::<T::Real>() {
+				x.rb_mut().col_mut(j).fill(zero());
+				continue;
+			}
+
+			let rel_threshold = params.rel_tolerance * b_norm;
+			let abs_threshold = copy(params.abs_tolerance);
+			let threshold = if abs_threshold > rel_threshold { abs_threshold } else { rel_threshold };
+
+			if params.initial_guess == InitialGuessStatus::Zero {
+				x.rb_mut().col_mut(j).fill(zero());
+			}
+
+			let mut iter = 0;
+			let mut restarts = 0;
+			loop {
+				// r = b - A x
+				A.apply(r.rb_mut(), x.rb().col(j).as_mat(), par, stack);
+				z!(r.rb_mut(), b).for_each(|uz!(r, b)| *r = *b - *r);
+				let beta = r.norm_l2();
+
+				if beta < threshold {
+					info.iter_count = Ord::max(info.iter_count, iter);
+					info.rel_residual = max(info.rel_residual, beta / b_norm);
+					info.abs_residual = max(info.abs_residual, beta);
+					break;
+				}
+				if restarts == params.max_restarts {
+					return Err(GmresError::NoConvergence {
+						rel_residual: beta / b_norm,
+						abs_residual: beta,
+					});
+				}
+				restarts += 1;
+
+				let s = recip(beta);
+				z!(V.rb_mut().col_mut(0), r.rb().col(0)).for_each(|uz!(v, r)| *v = mul_real(*r, s));
+				g.fill(zero());
+				g[(0, 0)] = from_real(beta);
+
+				// arnoldi process
+				let mut k = 0;
+				while k < m {
+					M.apply(z.rb_mut(), V.rb().get(.., k..k + 1), par, stack);
+					let (V_done, mut V_next) = V.rb_mut().split_at_col_mut(k + 1);
+					let mut w = V_next.rb_mut().col_mut(0);
+					A.apply(w.rb_mut().as_mat_mut(), z.rb(), par, stack);
+
+					for i in 0..k + 1 {
+						let v = V_done.rb().col(i);
+						let h = v.dot(w.rb());
+						z!(w.rb_mut(), v).for_each(|uz!(w, v)| *w = *w - h * *v);
+						H[(i, k)] = h;
+					}
+					let h = w.norm_l2();
+
+					// apply the previous rotations to the new column of H
+					for i in 0..k {
+						let (c, s) = (copy(G[(i, 0)]), copy(G[(i, 1)]));
+						let (hi, hj) = (copy(H[(i, k)]), copy(H[(i + 1, k)]));
+						H[(i, k)] = c * hi + s * hj;
+						H[(i + 1, k)] = c * hj - conj(s) * hi;
+					}
+
+					// then annihilate the subdiagonal entry h with a new rotation
+					let a = copy(H[(k, k)]);
+					let a_abs = abs(a);
+					let r_abs = hypot(a_abs, h);
+					let (c, s, diag): (T, T, T) = if a_abs == zero::<T::Real>() {
+						(zero(), one(), from_real(h))
+					} else {
+						let sign = mul_real(a, recip(a_abs));
+						(from_real(a_abs / r_abs), mul_real(sign, h / r_abs), mul_real(sign, r_abs))
+					};
+					H[(k, k)] = diag;
+					let gk = copy(g[(k, 0)]);
+					g[(k, 0)] = c * gk;
+					g[(k + 1, 0)] = -conj(s) * gk;
+					G[(k, 0)] = c;
+					G[(k, 1)] = s;
+
+					k += 1;
+					iter += 1;
+
+					// |g_k| is the residual norm of the minimizer over the current subspace
+					if abs(g[(k, 0)]) < threshold || h == zero::<T::Real>() {
+						break;
+					}
+
+					let s = recip(h);
+					z!(w.rb_mut()).for_each(|uz!(w)| *w = mul_real(*w, s));
+				}
+
+				// y = R^{-1} g, then x += M V y
+				let mut y = g.rb_mut().get_mut(..k, ..);
+				linalg::triangular_solve::solve_upper_triangular_in_place(H.rb().get(..k, ..k), y.rb_mut(), par);
+				linalg::matmul::matmul(r.rb_mut(), Accum::Replace, V.rb().get(.., ..k), y.rb(), one::<T>(), par);
+				M.apply(z.rb_mut(), r.rb(), par, stack);
+				z!(x.rb_mut().col_mut(j).as_mat_mut(), z.rb()).for_each(|uz!(x, z)| *x = *x + *z);
+
+				callback(x.rb());
+			}
+		}
+
+		Ok(info)
+	}
+	implementation(out, &right_precond, &mat, rhs, params, &mut { callback }, par, stack)
+}
+
+/// solves $A x = b$ with the restarted gmres method, where $A$ is a general square operator given
+/// by a closure computing $A v$
+///
+/// `precond`, if provided, computes $M v$ for a right preconditioner $M$ approximating $A^{-1}$.
+/// the arnoldi basis is rebuilt every `restart` iterations, and the iteration stops once the norm
+/// of the residual is below `tol` times the norm of $b$, or fails after `max_restarts` cycles
+///
+/// see [`gmres`] for the general version working with any [`LinOp`]
+#[track_caller]
+pub fn gmres_solve<T: ComplexField>(
+	A: impl Sync + Fn(ColRef<'_, T>) -> Col<T>,
+	b: ColRef<'_, T>,
+	restart: usize,
+	tol: T::Real,
+	max_restarts: usize,
+	precond: Option<impl Sync + Fn(ColRef<'_, T>) -> Col<T>>,
+) -> Result<(Col<T>, GmresInfo<T::Real>), GmresError<T::Real>> {
+	#[track_caller]
+	fn implementation<T: ComplexField>(
+		A: impl LinOp<T>,
+		M: impl Precond<T>,
+		b: ColRef<'_, T>,
+		params: GmresParams<T::Real>,
+	) -> Result<(Col<T>, GmresInfo<T::Real>), GmresError<T::Real>> {
+		let par = crate::get_global_parallelism();
+		let restart = params.restart;
+		let mut x = Mat::zeros(b.nrows(), 1);
+		let info = gmres(
+			x.as_mut(),
+			&M,
+			&A,
+			b.as_mat(),
+			params,
+			|_| {},
+			par,
+			MemStack::new(&mut MemBuffer::new(gmres_scratch(&M, &A, restart, par))),
+		)?;
+		Ok((x.col(0).to_owned(), info))
+	}
+
+	let dim = b.nrows();
+	let params = GmresParams {
+		initial_guess: InitialGuessStatus::Zero,
+		rel_tolerance: tol,
+		restart,
+		max_restarts,
+		..Default::default()
+	};
+	match precond {
+		Some(f) => implementation(FnOp { dim, f: A }, FnOp { dim, f }, b, params),
+		None => implementation(FnOp { dim, f: A }, IdentityPrecond { dim }, b, params),
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::linalg::solvers::Solve;
+	use crate::stats::prelude::*;
+	use dyn_stack::MemBuffer;
+	use equator::assert;
+	use rand::prelude::*;
+
+	#[test]
+	fn test_gmres_solve() {
+		let rng = &mut StdRng::seed_from_u64(0);
+		let n = 200;
+
+		let X = CwiseMatDistribution {
+			nrows: n,
+			ncols: n,
+			dist: StandardNormal,
+		}
+		.rand::<Mat<f64>>(rng);
+		// non-symmetric, with eigenvalues clustered around 4
+		let A = Mat::<f64>::identity(n, n) * Scale(4.0) + &X * Scale(1.0 / (n as f64).sqrt());
+		let b = Col::<f64>::from_fn(n, |i| ((i * i) % 7) as f64 - 3.0);
+
+		let direct = A.partial_piv_lu().solve(&b);
+		let tol = 1e-10;
+
+		let op = |x: ColRef<'_, f64>| &A * x;
+		let none = None::<fn(ColRef<'_, f64>) -> Col<f64>>;
+		let (x, info) = gmres_solve(op, b.as_ref(), 20, tol, 100, none).unwrap();
+		assert!((&A * &x - &b).norm_l2() <= tol * b.norm_l2());
+		assert!((&x - &direct).norm_l2() <= 1e-8 * direct.norm_l2());
+		assert!(info.rel_residual <= tol);
+
+		// jacobi preconditioner
+		let d = A.diagonal().column_vector().to_owned();
+		let precond = |x: ColRef<'_, f64>| Col::<f64>::from_fn(n, |i| x[i] / d[i]);
+		let (x, _) = gmres_solve(op, b.as_ref(), 20, tol, 100, Some(precond)).unwrap();
+		assert!((&A * &x - &b).norm_l2() <= tol * b.norm_l2());
+
+		assert!(matches!(
+			gmres_solve(op, b.as_ref(), 2, tol, 1, none),
+			Err(GmresError::NoConvergence { .. })
+		));
+	}
+
+	#[test]
+	fn test_gmres_complex() {
+		let rng = &mut StdRng::seed_from_u64(0);
+		let n = 30;
+		let k = 3;
+
+		let X = CwiseMatDistribution {
+			nrows: n,
+			ncols: n,
+			dist: ComplexDistribution::new(StandardNormal, StandardNormal),
+		}
+		.rand::<Mat<c64>>(rng);
+		let ref A = Mat::<c64>::identity(n, n) * Scale(c64::new(3.0, 1.0)) + &X * Scale(c64::new(1.0 / (n as f64).sqrt(), 0.0));
+		let ref sol = CwiseMatDistribution {
+			nrows: n,
+			ncols: k,
+			dist: ComplexDistribution::new(StandardNormal, StandardNormal),
+		}
+		.rand::<Mat<c64>>(rng);
+		let ref rhs = A * sol;
+
+		let mut out = Mat::<c64>::zeros(n, k);
+		let precond = IdentityPrecond { dim: n };
+		let params = GmresParams {
+			restart: 10,
+			..Default::default()
+		};
+		let result = gmres(
+			out.as_mut(),
+			precond,
+			A,
+			rhs.as_ref(),
+			params,
+			|_| {},
+			Par::Seq,
+			MemStack::new(&mut MemBuffer::new(gmres_scratch(precond, A, params.restart, Par::Seq))),
+		);
+		assert!(result.is_ok());
+		assert!((&out - sol).norm_l2() <= 1e-10 * sol.norm_l2());
+	}
+}
@@ -0,0 +1,400 @@
+use super::*;
+use crate::assert;
+
+/// algorithm parameters
+#[derive(Copy, Clone, Debug)]
+pub struct MinresParams<T: RealField> {
+	/// whether the initial guess is implicitly zero or not
+	pub initial_guess: InitialGuessStatus,
+	/// absolute tolerance for convergence testing
+	pub abs_tolerance: T,
+	/// relative tolerance for convergence testing
+	pub rel_tolerance: T,
+	/// maximum number of iterations
+	pub max_iters: usize,
+
+	#[doc(hidden)]
+	pub non_exhaustive: NonExhaustive,
+}
+
+/// algorithm result
+#[derive(Copy, Clone, Debug)]
+pub struct MinresInfo<T: RealField> {
+	/// absolute residual at the final step
+	pub abs_residual: T,
+	/// relative residual at the final step
+	pub rel_residual: T,
+	/// number of iterations executed by the algorithm
+	pub iter_count: usize,
+
+	#[doc(hidden)]
+	pub non_exhaustive: NonExhaustive,
+}
+
+/// algorithm error
+#[derive(Copy, Clone, Debug)]
+pub enum MinresError<T: ComplexField> {
+	/// preconditioner was detected to not be positive definite
+	NonPositiveDefinitePreconditioner,
+	/// convergence failure
+	NoConvergence {
+		/// absolute residual at the final step
+		abs_residual: T::Real,
+		/// relative residual at the final step
+		rel_residual: T::Real,
+	},
+}
+
+impl<T: RealField> Default for MinresParams<T> {
+	#[inline]
+	#[math]
+	fn default() -> Self {
+		Self {
+			initial_guess: InitialGuessStatus::MaybeNonZero,
+			abs_tolerance: zero::<T>(),
+			rel_tolerance: eps::<T>() * from_f64::<T>(128.0),
+			max_iters: usize::MAX,
+			non_exhaustive: NonExhaustive(()),
+		}
+	}
+}
+
+/// computes the size and alignment of required workspace for executing the minres algorithm
+pub fn minres_scratch<T: ComplexField>(precond: impl Precond<T>, mat: impl LinOp<T>, par: Par) -> StackReq {
+	fn implementation<T: ComplexField>(M: &dyn Precond<T>, A: &dyn LinOp<T>, par: Par) -> StackReq {
+		let n = A.nrows();
+		let n1 = temp_mat_scratch::<T>(n, 1);
+		StackReq::all_of(&[
+			n1, // r1
+			n1, // r2
+			n1, // y
+			n1, // v
+			n1, // w
+			n1, // w1
+			n1, // w2
+			StackReq::any_of(&[A.apply_scratch(1, par), M.apply_scratch(1, par)]),
+		])
+	}
+	implementation(&precond, &mat, par)
+}
+
+#[math]
+fn inner_real<T: ComplexField>(a: MatRef<'_, T>, b: MatRef<'_, T>) -> T::Real {
+	let mut acc = zero::<T::Real>();
+	z!(a, b).for_each(|uz!(a, b)| acc = acc + real(conj(*a) * *b));
+	acc
+}
+
+/// executes the minres algorithm using the provided preconditioner
+///
+/// the operator must be self-adjoint, but may be indefinite, and the preconditioner must be
+/// self-adjoint positive definite. the residuals used for convergence testing are measured in the
+/// norm induced by the preconditioner
+///
+/// # note
+/// the columns of the rhs are solved one after the other. the returned info holds the largest
+/// residuals and iteration count over all the columns
+#[inline]
+#[track_caller]
+pub fn minres<T: ComplexField>(
+	out: MatMut<'_, T>,
+	precond: impl Precond<T>,
+	mat: impl LinOp<T>,
+	rhs: MatRef<'_, T>,
+	params: MinresParams<T::Real>,
+	callback: impl FnMut(MatRef<'_, T>),
+	par: Par,
+	stack: &mut MemStack,
+) -> Result<MinresInfo<T::Real>, MinresError<T::Real>> {
+	#[track_caller]
+	#[math]
+	fn implementation<T: ComplexField>(
+		mut x: MatMut<'_, T>,
+		M: &dyn Precond<T>,
+		A: &dyn LinOp<T>,
+		b: MatRef<'_, T>,
+
+		params: MinresParams<T::Real>,
+		callback: &mut dyn FnMut(MatRef<'_, T>),
+		par: Par,
+		mut stack: &mut MemStack,
+	) -> Result<MinresInfo<T::Real>, MinresError<T::Real>> {
+		assert!(all(
+			A.nrows() == A.ncols(),
+			M.nrows() == A.nrows(),
+			b.nrows() == A.nrows(),
+			x.nrows() == A.nrows(),
+			x.ncols() == b.ncols(),
+		));
+
+		let n = A.nrows();
+		let mut info = MinresInfo {
+			abs_residual: zero::<T::Real>(),
+			rel_residual: zero::<T::Real>(),
+			iter_count: 0,
+			non_exhaustive: NonExhaustive(()),
+		};
+
+		for j in 0..b.ncols() {
+			let (mut r1, mut stack) = unsafe { temp_mat_uninit::<T, _, _>(n, 1, stack.rb_mut()) };
+			let mut r1 = r1.as_mat_mut();
+			let (mut r2, mut stack) = unsafe { temp_mat_uninit::<T, _, _>(n, 1, stack.rb_mut()) };
+			let mut r2 = r2.as_mat_mut();
+			let (mut y, mut stack) = unsafe { temp_mat_uninit::<T, _, _>(n, 1, stack.rb_mut()) };
+			let mut y = y.as_mat_mut();
+			let (mut v, mut stack) = unsafe { temp_mat_uninit::<T, _, _>(n, 1, stack.rb_mut()) };
+			let mut v = v.as_mat_mut();
+			let (mut w, mut stack) = unsafe { temp_mat_uninit::<T, _, _>(n, 1, stack.rb_mut()) };
+			let mut w = w.as_mat_mut();
+			let (mut w1, mut stack) = unsafe { temp_mat_uninit::<T, _, _>(n, 1, stack.rb_mut()) };
+			let mut w1 = w1.as_mat_mut();
+			let (mut w2, mut stack) = unsafe { temp_mat_uninit::<T, _, _>(n, 1, stack.rb_mut()) };
+			let mut w2 = w2.as_mat_mut();
+
+			let b = b.col(j).as_mat();
+
+			// norm of b, in the norm induced by the preconditioner
+			M.apply(v.rb_mut(), b, par, stack.rb_mut());
+			let b_norm2 = inner_real(b, v.rb());
+			if b_norm2 < zero::<T::Real>() {
+				return Err(MinresError::NonPositiveDefinitePreconditioner);
+			}
+			let b_norm = sqrt(b_norm2);
+			if b_norm == zero::<T::Real>() {
+				x.rb_mut().col_mut(j).fill(zero());
+				continue;
+			}
+
+			if params.initial_guess == InitialGuessStatus::MaybeNonZero {
+				A.apply(r1.rb_mut(), x.rb().col(j).as_mat(), par, stack.rb_mut());
+				z!(&mut r1, b).for_each(|uz!(r, b)| *r = *b - *r);
+				M.apply(y.rb_mut(), r1.rb(), par, stack.rb_mut());
+			} else {
+				x.rb_mut().col_mut(j).fill(zero());
+				r1.copy_from(b);
+				y.copy_from(&v);
+			}
+
+			let beta1 = inner_real(r1.rb(), y.rb());
+			if beta1 < zero::<T::Real>() {
+				return Err(MinresError::NonPositiveDefinitePreconditioner);
+			}
+			let beta1 = sqrt(beta1);
+
+			let rel_threshold = params.rel_tolerance * b_norm;
+			let abs_threshold = copy(params.abs_tolerance);
+			let threshold = if abs_threshold > rel_threshold { abs_threshold } else { rel_threshold };
+
+			// lanczos iteration, with the tridiagonal system solved by givens rotations
+			r2.copy_from(&r1);
+			w.fill(zero());
+			w2.fill(zero());
+
+			let mut oldb = zero::<T::Real>();
+			let mut beta = copy(beta1);
+			let mut dbar = zero::<T::Real>();
+			let mut epsln = zero::<T::Real>();
+			let mut phibar = copy(beta1);
+			let mut cs = -one::<T::Real>();
+			let mut sn = zero::<T::Real>();
+
+			let mut iter = 0;
+			while phibar >= threshold {
+				if iter == params.max_iters {
+					return Err(MinresError::NoConvergence {
+						rel_residual: phibar / b_norm,
+						abs_residual: phibar,
+					});
+				}
+				iter += 1;
+
+				let s = recip(beta);
+				z!(&mut v, &y).for_each(|uz!(v, y)| *v = mul_real(*y, s));
+				A.apply(y.rb_mut(), v.rb(), par, stack.rb_mut());
+				if iter >= 2 {
+					let f = beta / oldb;
+					z!(&mut y, &r1).for_each(|uz!(y, r1)| *y = *y - mul_real(*r1, f));
+				}
+
+				let alfa = inner_real(v.rb(), y.rb());
+				let f = alfa / beta;
+				z!(&mut y, &r2).for_each(|uz!(y, r2)| *y = *y - mul_real(*r2, f));
+				r1.copy_from(&r2);
+				r2.copy_from(&y);
+				M.apply(y.rb_mut(), r2.rb(), par, stack.rb_mut());
+
+				oldb = beta;
+				let beta2 = inner_real(r2.rb(), y.rb());
+				if beta2 < zero::<T::Real>() {
+					return Err(MinresError::NonPositiveDefinitePreconditioner);
+				}
+				beta = sqrt(beta2);
+
+				let oldeps = epsln;
+				let delta = cs * dbar + sn * alfa;
+				let gbar = sn * dbar - cs * alfa;
+				epsln = sn * beta;
+				dbar = -cs * beta;
+
+				let gamma = max(hypot(gbar, beta), eps::<T::Real>());
+				cs = gbar / gamma;
+				sn = beta / gamma;
+				let phi = cs * phibar;
+				phibar = sn * phibar;
+
+				let denom = recip(gamma);
+				w1.copy_from(&w2);
+				w2.copy_from(&w);
+				z!(&mut w, &v, &w1, &w2).for_each(|uz!(w, v, w1, w2)| *w = mul_real(*v - mul_real(*w1, oldeps) - mul_real(*w2, delta), denom));
+				z!(x.rb_mut().col_mut(j).as_mat_mut(), &w).for_each(|uz!(x, w)| *x = *x + mul_real(*w, phi));
+
+				callback(x.rb());
+			}
+
+			info.iter_count = Ord::max(info.iter_count, iter);
+			info.rel_residual = max(info.rel_residual, phibar / b_norm);
+			info.abs_residual = max(info.abs_residual, phibar);
+		}
+
+		Ok(info)
+	}
+	implementation(out, &precond, &mat, rhs, params, &mut { callback }, par, stack)
+}
+
+/// solves $A x = b$ with the minres method, where $A$ is a self-adjoint, possibly indefinite
+/// operator given by a closure computing $A v$
+///
+/// `precond`, if provided, computes $M v$ for a self-adjoint positive definite approximation $M$
+/// of the inverse of $A$ (or of its absolute value). the iteration stops once the norm of the
+/// residual is below `tol` times the norm of $b$, or fails after `max_iters` iterations
+///
+/// see [`minres`] for the general version working with any [`LinOp`]
+#[track_caller]
+pub fn minres_solve<T: RealField>(
+	A: impl Sync + Fn(ColRef<'_, T>) -> Col<T>,
+	b: ColRef<'_, T>,
+	tol: T,
+	max_iters: usize,
+	precond: Option<impl Sync + Fn(ColRef<'_, T>) -> Col<T>>,
+) -> Result<(Col<T>, MinresInfo<T>), MinresError<T>> {
+	#[track_caller]
+	fn implementation<T: RealField>(
+		A: impl LinOp<T>,
+		M: impl Precond<T>,
+		b: ColRef<'_, T>,
+		params: MinresParams<T>,
+	) -> Result<(Col<T>, MinresInfo<T>), MinresError<T>> {
+		let par = crate::get_global_parallelism();
+		let mut x = Mat::zeros(b.nrows(), 1);
+		let info = minres(
+			x.as_mut(),
+			&M,
+			&A,
+			b.as_mat(),
+			params,
+			|_| {},
+			par,
+			MemStack::new(&mut MemBuffer::new(minres_scratch(&M, &A, par))),
+		)?;
+		Ok((x.col(0).to_owned(), info))
+	}
+
+	let dim = b.nrows();
+	let params = MinresParams {
+		initial_guess: InitialGuessStatus::Zero,
+		rel_tolerance: tol,
+		max_iters,
+		..Default::default()
+	};
+	match precond {
+		Some(f) => implementation(FnOp { dim, f: A }, FnOp { dim, f }, b, params),
+		None => implementation(FnOp { dim, f: A }, IdentityPrecond { dim }, b, params),
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::linalg::solvers::Solve;
+	use crate::stats::prelude::*;
+	use dyn_stack::MemBuffer;
+	use equator::assert;
+	use rand::prelude::*;
+
+	#[test]
+	fn test_minres_solve() {
+		let n = 50;
+		// shifted laplacian, with eigenvalues on both sides of zero
+		let shift = 1.3;
+		let A = Mat::<f64>::from_fn(n, n, |i, j| {
+			if i == j {
+				2.0 - shift
+			} else if i.abs_diff(j) == 1 {
+				-1.0
+			} else {
+				0.0
+			}
+		});
+		let b = Col::<f64>::from_fn(n, |i| ((i * i) % 7) as f64 - 3.0);
+
+		let direct = A.partial_piv_lu().solve(&b);
+		let tol = 1e-12;
+
+		let op = |x: ColRef<'_, f64>| &A * x;
+		let (x, info) = minres_solve(op, b.as_ref(), tol, 1000, None::<fn(ColRef<'_, f64>) -> Col<f64>>).unwrap();
+		assert!((&x - &direct).norm_l2() <= 1e-8 * direct.norm_l2());
+		assert!((&A * &x - &b).norm_l2() <= 1e-10 * b.norm_l2());
+		assert!(all(info.iter_count <= 2 * n, info.rel_residual <= tol));
+
+		let (x, _) = minres_solve(op, b.as_ref(), tol, 1000, Some(|x: ColRef<'_, f64>| x.to_owned() * Scale(0.5))).unwrap();
+		assert!((&x - &direct).norm_l2() <= 1e-8 * direct.norm_l2());
+
+		assert!(matches!(
+			minres_solve(op, b.as_ref(), tol, 2, None::<fn(ColRef<'_, f64>) -> Col<f64>>),
+			Err(MinresError::NoConvergence { .. })
+		));
+		assert!(matches!(
+			minres_solve(op, b.as_ref(), tol, 1000, Some(|x: ColRef<'_, f64>| -x.to_owned())),
+			Err(MinresError::NonPositiveDefinitePreconditioner)
+		));
+	}
+
+	#[test]
+	fn test_minres_complex() {
+		let rng = &mut StdRng::seed_from_u64(0);
+		let n = 20;
+		let k = 3;
+
+		let X = CwiseMatDistribution {
+			nrows: n,
+			ncols: n,
+			dist: ComplexDistribution::new(StandardNormal, StandardNormal),
+		}
+		.rand::<Mat<c64>>(rng);
+		let ref A = &X + X.adjoint();
+		let ref sol = CwiseMatDistribution {
+			nrows: n,
+			ncols: k,
+			dist: ComplexDistribution::new(StandardNormal, StandardNormal),
+		}
+		.rand::<Mat<c64>>(rng);
+		let ref rhs = A * sol;
+
+		let mut out = Mat::<c64>::zeros(n, k);
+		let params = MinresParams::default();
+		let precond = IdentityPrecond { dim: n };
+		let result = minres(
+			out.as_mut(),
+			precond,
+			A.as_ref(),
+			rhs.as_ref(),
+			params,
+			|_| {},
+			Par::Seq,
+			MemStack::new(&mut MemBuffer::new(minres_scratch::<c64>(precond, A.as_ref(), Par::Seq))),
+		);
+
+		assert!(result.is_ok());
+		assert!((A * &out - rhs).norm_l2() <= 1e-8 * rhs.norm_l2());
+	}
+}
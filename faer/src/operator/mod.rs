@@ -1,13 +1,18 @@
 //! matrix-free linear operator traits and algorithms
 
+use crate::assert;
 use crate::internal_prelude_sp::*;
 
 /// biconjugate gradient stabilized method
 pub mod bicgstab;
 /// conjugate gradient method
 pub mod conjugate_gradient;
+/// generalized minimal residual method
+pub mod gmres;
 /// least squares minimal residual
 pub mod lsmr;
+/// minimal residual method
+pub mod minres;
 
 mod operator_impl;
 
@@ -28,6 +33,24 @@ pub struct IdentityPrecond {
 	pub dim: usize,
 }
 
+/// square linear operator defined by a closure computing its product with a vector
+///
+/// this allows dense, sparse, or implicitly defined operators to be used with the iterative
+/// solvers in this module, either as the operator or as a preconditioner
+#[derive(Copy, Clone)]
+pub struct FnOp<F> {
+	/// dimension of the operator
+	pub dim: usize,
+	/// closure computing the product of the operator with a vector
+	pub f: F,
+}
+
+impl<F> core::fmt::Debug for FnOp<F> {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		f.debug_struct("FnOp").field("dim", &self.dim).finish_non_exhaustive()
+	}
+}
+
 /// linear operator from a finite-dimensional vector space
 pub trait LinOp<T: ComplexField>: Sync + core::fmt::Debug {
 	/// computes the workspace size and alignment required to apply `self` or the conjugate o
@@ -112,6 +135,46 @@ impl<T: ComplexField> BiPrecond<T> for IdentityPrecond {
 	fn adjoint_apply_in_place(&self, _rhs: MatMut<'_, T>, _par: Par, _stack: &mut MemStack) {}
 }
 
+impl<T: ComplexField, F: Sync + Fn(ColRef<'_, T>) -> Col<T>> LinOp<T> for FnOp<F> {
+	#[inline]
+	fn apply_scratch(&self, _rhs_ncols: usize, _par: Par) -> StackReq {
+		StackReq::EMPTY
+	}
+
+	#[inline]
+	fn nrows(&self) -> usize {
+		self.dim
+	}
+
+	#[inline]
+	fn ncols(&self) -> usize {
+		self.dim
+	}
+
+	#[track_caller]
+	fn apply(&self, out: MatMut<'_, T>, rhs: MatRef<'_, T>, _par: Par, _stack: &mut MemStack) {
+		let mut out = out;
+		for j in 0..rhs.ncols() {
+			let y = (self.f)(rhs.col(j));
+			assert!(y.nrows() == self.dim);
+			out.rb_mut().col_mut(j).copy_from(&y);
+		}
+	}
+
+	#[track_caller]
+	fn conj_apply(&self, out: MatMut<'_, T>, rhs: MatRef<'_, T>, _par: Par, _stack: &mut MemStack) {
+		// conj(A) x = conj(A conj(x))
+		let mut out = out;
+		for j in 0..rhs.ncols() {
+			let x = rhs.col(j).conjugate().to_owned();
+			let y = (self.f)(x.as_ref());
+			assert!(y.nrows() == self.dim);
+			out.rb_mut().col_mut(j).copy_from(y.conjugate());
+		}
+	}
+}
+impl<T: ComplexField, F: Sync + Fn(ColRef<'_, T>) -> Col<T>> Precond<T> for FnOp<F> {}
+
 /// linear operator that can be applied from either the right or the left side
 pub trait BiLinOp<T: ComplexField>: LinOp<T> {
 	/// computes the workspace size and alignment required to apply the transpose or adjoint o
@@ -312,6 +312,15 @@ impl<'a, T, Cols: Shape, CStride: Stride> RowMut<'a, T, Cols, CStride> {
 		self.rb().as_mat().sum()
 	}
 
+	#[inline]
+	/// see [`RowRef::dot`]
+	pub fn dot(&self, other: RowRef<'_, T, Cols, impl Stride>) -> T
+	where
+		T: ComplexField,
+	{
+		self.rb().dot(other)
+	}
+
 	/// see [`RowRef::cloned`]
 	#[inline]
 	pub fn cloned(&self) -> Row<T, Cols>
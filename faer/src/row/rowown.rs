@@ -108,6 +108,17 @@ impl<T, Cols: Shape> Row<T, Cols> {
 	}
 }
 
+impl<T> Row<T> {
+	/// returns a new row holding the cloned elements of `slice`
+	#[inline]
+	pub fn from_slice(slice: &[T]) -> Self
+	where
+		T: Clone,
+	{
+		Col::from_slice(slice).into_transpose()
+	}
+}
+
 impl<T, Cols: Shape> Row<T, Cols> {
 	/// returns the number of rows of the row (always 1)
 	#[inline]
@@ -178,6 +189,15 @@ impl<T, Cols: Shape> Row<T, Cols> {
 		self.rb().as_mat().sum()
 	}
 
+	#[inline]
+	/// see [`RowRef::dot`]
+	pub fn dot(&self, other: RowRef<'_, T, Cols, impl Stride>) -> T
+	where
+		T: ComplexField,
+	{
+		self.rb().dot(other)
+	}
+
 	/// see [`RowRef::cloned`]
 	#[inline]
 	pub fn cloned(&self) -> Row<T, Cols>
@@ -390,6 +390,15 @@ impl<'a, T, Cols: Shape, CStride: Stride> RowRef<'a, T, Cols, CStride> {
 		self.rb().as_mat().sum()
 	}
 
+	/// returns the inner product $\sum_i \bar a_i b_i$ of `self` and `other`
+	#[inline]
+	pub fn dot(&self, other: RowRef<'_, T, Cols, impl Stride>) -> T
+	where
+		T: ComplexField,
+	{
+		self.rb().transpose().dot(other.transpose())
+	}
+
 	/// returns a newly allocated row holding the cloned values of `self`
 	#[inline]
 	pub fn cloned(&self) -> Row<T, Cols>
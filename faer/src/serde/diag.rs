@@ -0,0 +1,42 @@
+use crate::internal_prelude::*;
+use alloc::vec::Vec;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+impl<T> Serialize for DiagRef<'_, T>
+where
+	T: Serialize,
+{
+	fn serialize<S>(&self, s: S) -> Result<<S as Serializer>::Ok, <S as Serializer>::Error>
+	where
+		S: Serializer,
+	{
+		s.collect_seq(self.column_vector().iter())
+	}
+}
+
+impl<T> Serialize for Diag<T>
+where
+	T: Serialize,
+{
+	fn serialize<S>(&self, s: S) -> Result<<S as Serializer>::Ok, <S as Serializer>::Error>
+	where
+		S: Serializer,
+	{
+		self.as_ref().serialize(s)
+	}
+}
+
+impl<'a, T> Deserialize<'a> for Diag<T>
+where
+	T: Deserialize<'a>,
+{
+	fn deserialize<D>(d: D) -> Result<Self, <D as Deserializer<'a>>::Error>
+	where
+		D: Deserializer<'a>,
+	{
+		let data = Vec::<T>::deserialize(d)?;
+		let n = data.len();
+		let mut data = data.into_iter();
+		Ok(Col::from_fn(n, |_| data.next().unwrap()).into_diagonal())
+	}
+}
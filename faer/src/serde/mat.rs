@@ -24,6 +24,8 @@ where
 				S: Serializer,
 			{
 				let mut seq = s.serialize_seq(Some(self.0.nrows() * self.0.ncols()))?;
+				// row-major, unlike the in-memory layout. kept for compatibility with data serialized
+				// by earlier versions
 				for i in 0..self.0.nrows() {
 					for j in 0..self.0.ncols() {
 						seq.serialize_element(&self.0[(i, j)])?;
@@ -65,12 +67,13 @@ where
 	}
 }
 
-impl<'a, T: 'a + Deserialize<'a>> Deserialize<'a> for Mat<T> {
+impl<'a, T: Deserialize<'a>> Deserialize<'a> for Mat<T> {
 	fn deserialize<D>(d: D) -> Result<Self, <D as serde::Deserializer<'a>>::Error>
 	where
 		D: serde::Deserializer<'a>,
 	{
 		#[derive(Deserialize)]
+		#[serde(field_identifier)]
 		#[allow(non_camel_case_types)]
 		enum Field {
 			nrows,
@@ -96,7 +99,7 @@ impl<'a, T: 'a + Deserialize<'a>> Deserialize<'a> for Mat<T> {
 			}
 		}
 		struct MatrixOrVecDeserializer<'a, T: Deserialize<'a>> {
-			marker: PhantomData<&'a T>,
+			marker: PhantomData<(&'a (), T)>,
 			nrows: Option<usize>,
 			ncols: Option<usize>,
 		}
@@ -177,7 +180,7 @@ impl<'a, T: 'a + Deserialize<'a>> Deserialize<'a> for Mat<T> {
 				}
 			}
 		}
-		impl<'a, T: 'a + Deserialize<'a>> Visitor<'a> for MatVisitor<T> {
+		impl<'a, T: Deserialize<'a>> Visitor<'a> for MatVisitor<T> {
 			type Value = Mat<T>;
 
 			fn expecting(&self, formatter: &mut alloc::fmt::Formatter) -> alloc::fmt::Result {
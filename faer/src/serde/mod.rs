@@ -1 +1,3 @@
+mod diag;
 mod mat;
+mod perm;
@@ -0,0 +1,119 @@
+use crate::internal_prelude::*;
+use alloc::vec::Vec;
+use serde::de::Error;
+use serde::ser::SerializeStruct;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+impl<I> Serialize for PermRef<'_, I>
+where
+	I: Index + Serialize,
+{
+	fn serialize<S>(&self, s: S) -> Result<<S as Serializer>::Ok, <S as Serializer>::Error>
+	where
+		S: Serializer,
+	{
+		let (forward, inverse) = self.arrays();
+		let mut structure = s.serialize_struct("Perm", 2)?;
+		structure.serialize_field("forward", forward)?;
+		structure.serialize_field("inverse", inverse)?;
+		structure.end()
+	}
+}
+
+impl<I> Serialize for Perm<I>
+where
+	I: Index + Serialize,
+{
+	fn serialize<S>(&self, s: S) -> Result<<S as Serializer>::Ok, <S as Serializer>::Error>
+	where
+		S: Serializer,
+	{
+		self.as_ref().serialize(s)
+	}
+}
+
+impl<'a, I> Deserialize<'a> for Perm<I>
+where
+	I: Index + Deserialize<'a>,
+{
+	fn deserialize<D>(d: D) -> Result<Self, <D as Deserializer<'a>>::Error>
+	where
+		D: Deserializer<'a>,
+	{
+		#[derive(Deserialize)]
+		#[serde(rename = "Perm")]
+		struct PermArrays<I> {
+			forward: Vec<I>,
+			inverse: Vec<I>,
+		}
+
+		let PermArrays { forward, inverse } = PermArrays::<I>::deserialize(d)?;
+		let n = forward.len();
+
+		if inverse.len() != n {
+			return Err(D::Error::invalid_length(inverse.len(), &"as many indices as `forward`"));
+		}
+		if n > I::Signed::MAX.zx() {
+			return Err(D::Error::custom("permutation dimension is too large for the index type"));
+		}
+		for (i, f) in forward.iter().enumerate() {
+			let f = f.zx();
+			if f >= n || inverse[f].zx() != i {
+				return Err(D::Error::custom("`forward` and `inverse` are not mutually inverse permutations"));
+			}
+		}
+
+		Ok(unsafe { Perm::new_unchecked(forward.into_boxed_slice(), inverse.into_boxed_slice()) })
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use serde_test::{Token, assert_de_tokens_error, assert_tokens};
+
+	#[test]
+	fn perm_serialization() {
+		let value = Perm::<usize>::new_checked(Box::new([1, 2, 0]), Box::new([2, 0, 1]), 3);
+		assert_tokens(
+			&value,
+			&[
+				Token::Struct { name: "Perm", len: 2 },
+				Token::Str("forward"),
+				Token::Seq { len: Some(3) },
+				Token::U64(1),
+				Token::U64(2),
+				Token::U64(0),
+				Token::SeqEnd,
+				Token::Str("inverse"),
+				Token::Seq { len: Some(3) },
+				Token::U64(2),
+				Token::U64(0),
+				Token::U64(1),
+				Token::SeqEnd,
+				Token::StructEnd,
+			],
+		)
+	}
+
+	#[test]
+	fn perm_deserialization_invalid() {
+		assert_de_tokens_error::<Perm<usize>>(
+			&[
+				Token::Struct { name: "Perm", len: 2 },
+				Token::Str("forward"),
+				Token::Seq { len: Some(2) },
+				Token::U64(1),
+				Token::U64(0),
+				Token::SeqEnd,
+				Token::Str("inverse"),
+				Token::Seq { len: Some(2) },
+				Token::U64(0),
+				Token::U64(1),
+				Token::SeqEnd,
+				Token::StructEnd,
+			],
+			"`forward` and `inverse` are not mutually inverse permutations",
+		)
+	}
+}
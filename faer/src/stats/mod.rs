@@ -11,8 +11,13 @@ pub mod prelude {
 	#[cfg(feature = "rand")]
 	pub use rand_distr::{Standard, StandardNormal};
 
+	#[cfg(all(feature = "rand", test))]
+	pub(crate) use super::randn_c64;
 	#[cfg(feature = "rand")]
-	pub use super::{CwiseColDistribution, CwiseMatDistribution, CwiseRowDistribution, DistributionExt, UnitaryMat};
+	pub use super::{
+		CwiseColDistribution, CwiseMatDistribution, CwiseRowDistribution, DistributionExt, FaerRng, UnitaryMat, rand_low_rank_plus_noise, sample_mat,
+		sample_mvn,
+	};
 }
 
 #[cfg(feature = "rand")]
@@ -20,8 +25,75 @@ pub use self::rand::*;
 
 #[cfg(feature = "rand")]
 mod rand {
+	use crate::assert;
 	use crate::internal_prelude::*;
 	use rand::distributions::Distribution;
+	use rand::rngs::StdRng;
+	use rand::{RngCore, SeedableRng};
+
+	/// seedable random number generator, used to draw reproducible random matrices
+	///
+	/// the recommended way to construct it is with [`SeedableRng::seed_from_u64`], which always
+	/// produces the same stream of numbers for the same seed on a given version of `faer`
+	#[derive(Clone, Debug)]
+	pub struct FaerRng(StdRng);
+
+	impl RngCore for FaerRng {
+		#[inline]
+		fn next_u32(&mut self) -> u32 {
+			self.0.next_u32()
+		}
+
+		#[inline]
+		fn next_u64(&mut self) -> u64 {
+			self.0.next_u64()
+		}
+
+		#[inline]
+		fn fill_bytes(&mut self, dest: &mut [u8]) {
+			self.0.fill_bytes(dest)
+		}
+
+		#[inline]
+		fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand::Error> {
+			self.0.try_fill_bytes(dest)
+		}
+	}
+
+	impl SeedableRng for FaerRng {
+		type Seed = <StdRng as SeedableRng>::Seed;
+
+		#[inline]
+		fn from_seed(seed: Self::Seed) -> Self {
+			Self(StdRng::from_seed(seed))
+		}
+	}
+
+	/// returns a matrix with dimensions `(nrows, ncols)` whose elements are independently sampled
+	/// from `dist`
+	///
+	/// this is a shorthand for sampling a [`CwiseMatDistribution`]
+	#[inline]
+	pub fn sample_mat<T, Rows: Shape, Cols: Shape>(
+		dist: impl Distribution<T>,
+		nrows: Rows,
+		ncols: Cols,
+		rng: &mut (impl ?Sized + rand::Rng),
+	) -> Mat<T, Rows, Cols> {
+		CwiseMatDistribution { nrows, ncols, dist }.sample(rng)
+	}
+
+	/// test helper: returns a matrix whose elements are sampled from the standard complex normal
+	/// distribution
+	#[cfg(test)]
+	pub(crate) fn randn_c64(nrows: usize, ncols: usize, rng: &mut (impl ?Sized + rand::Rng)) -> Mat<c64> {
+		sample_mat(
+			num_complex::ComplexDistribution::new(rand_distr::StandardNormal, rand_distr::StandardNormal),
+			nrows,
+			ncols,
+			rng,
+		)
+	}
 
 	pub trait DistributionExt {
 		fn rand<T>(&self, rng: &mut (impl ?Sized + rand::Rng)) -> T
@@ -80,29 +152,213 @@ mod rand {
 	}
 
 	impl<T: ComplexField, D: Distribution<T>> Distribution<Mat<T>> for UnitaryMat<usize, D> {
-		#[math]
 		fn sample<R: rand::prelude::Rng + ?Sized>(&self, rng: &mut R) -> Mat<T> {
-			let qr = CwiseMatDistribution {
-				nrows: self.dim,
-				ncols: self.dim,
-				dist: &self.standard_normal,
-			}
-			.sample(rng)
-			.qr();
+			haar_orthonormal(self.dim, self.dim, &self.standard_normal, rng)
+		}
+	}
+
+	/// returns a `nrows × ncols` matrix with orthonormal columns, sampled from the haar measure as
+	/// the $Q$ factor of a gaussian matrix, with the phases fixed so that $R$ has a positive
+	/// diagonal
+	#[math]
+	fn haar_orthonormal<T: ComplexField, D: Distribution<T>>(
+		nrows: usize,
+		ncols: usize,
+		standard_normal: &D,
+		rng: &mut (impl ?Sized + rand::Rng),
+	) -> Mat<T> {
+		let qr = CwiseMatDistribution {
+			nrows,
+			ncols,
+			dist: standard_normal,
+		}
+		.sample(rng)
+		.qr();
+
+		let r_diag = qr.thin_R().diagonal().column_vector();
+		let mut q = qr.compute_thin_Q();
+
+		for j in 0..ncols {
+			let r = r_diag.read(j);
+			let r = if r == zero() { one() } else { mul_real(r, recip(abs(r))) };
+
+			z!(q.as_mut().col_mut(j)).for_each(|uz!(q)| {
+				*q = *q * r;
+			});
+		}
 
-			let r_diag = qr.R().diagonal().column_vector();
-			let mut q = qr.compute_Q();
+		q
+	}
+
+	/// returns a random `nrows × ncols` matrix $U \Sigma V^H + \text{noise\_level} \cdot E$, where
+	/// $\Sigma$ is the diagonal matrix of the given `singular_values`
+	///
+	/// $U$ and $V$ have `singular_values.len()` orthonormal columns sampled from the haar measure,
+	/// and are computed from the $QR$ decompositions of gaussian `nrows × rank` and `ncols × rank`
+	/// sketches, so the cost is $O((m + n) r^2)$ on top of the product. the entries of $E$ are
+	/// sampled from `standard_normal`
+	///
+	/// # panics
+	///
+	/// panics if `singular_values.len() > min(nrows, ncols)`
+	#[track_caller]
+	#[math]
+	pub fn rand_low_rank_plus_noise<T: ComplexField, D: Distribution<T>>(
+		nrows: usize,
+		ncols: usize,
+		singular_values: &[T::Real],
+		noise_level: T::Real,
+		standard_normal: D,
+		rng: &mut (impl ?Sized + rand::Rng),
+	) -> Mat<T> {
+		let rank = singular_values.len();
+		assert!(rank <= Ord::min(nrows, ncols));
+
+		let U: Mat<T> = haar_orthonormal(nrows, rank, &standard_normal, rng);
+		let V: Mat<T> = haar_orthonormal(ncols, rank, &standard_normal, rng);
+
+		let US = Mat::from_fn(nrows, rank, |i, j| mul_real(U[(i, j)], singular_values[j]));
+
+		let mut A: Mat<T> = CwiseMatDistribution {
+			nrows,
+			ncols,
+			dist: &standard_normal,
+		}
+		.sample(rng);
+		z!(A.as_mut()).for_each(|uz!(a)| *a = mul_real(*a, noise_level));
+
+		linalg::matmul::matmul(A.as_mut(), Accum::Add, US.as_ref(), V.adjoint(), one::<T>(), Par::Seq);
+
+		A
+	}
+
+	/// returns `n_samples` draws from the multivariate normal distribution with mean `mean` and
+	/// covariance $L L^\top$, stored as the columns of a `dim × n_samples` matrix
+	///
+	/// each column is computed as $\mu + L z$, where $L$ is the factor stored in `cov_chol` and the
+	/// entries of $z$ are sampled from `standard_normal`
+	///
+	/// # panics
+	///
+	/// panics if `mean.nrows()` doesn't match the dimension of `cov_chol`
+	#[track_caller]
+	pub fn sample_mvn<T: RealField, D: Distribution<T>>(
+		mean: ColRef<'_, T>,
+		cov_chol: &linalg::solvers::Llt<T>,
+		n_samples: usize,
+		standard_normal: D,
+		rng: &mut (impl ?Sized + rand::Rng),
+	) -> Mat<T> {
+		let L = cov_chol.L();
+		let dim = L.nrows();
+		assert!(mean.nrows() == dim);
+
+		let Z: Mat<T> = CwiseMatDistribution {
+			nrows: dim,
+			ncols: n_samples,
+			dist: standard_normal,
+		}
+		.sample(rng);
+
+		let mut X = Mat::<T>::zeros(dim, n_samples);
+		for j in 0..n_samples {
+			X.col_mut(j).copy_from(mean);
+		}
+		linalg::matmul::triangular::matmul(
+			X.as_mut(),
+			linalg::matmul::triangular::BlockStructure::Rectangular,
+			Accum::Add,
+			L,
+			linalg::matmul::triangular::BlockStructure::TriangularLower,
+			Z.as_ref(),
+			linalg::matmul::triangular::BlockStructure::Rectangular,
+			one::<T>(),
+			Par::Seq,
+		);
+
+		X
+	}
 
-			for j in 0..self.dim {
-				let r = r_diag.read(j);
-				let r = if r == zero() { one() } else { mul_real(r, recip(abs(r))) };
+	#[cfg(test)]
+	mod tests {
+		use super::*;
+		use crate::assert;
+		use num_complex::ComplexDistribution;
+		use rand_distr::StandardNormal;
 
-				z!(q.as_mut().col_mut(j)).for_each(|uz!(q)| {
-					*q = *q * r;
-				});
+		#[test]
+		fn test_faer_rng() {
+			let A: Mat<f64> = sample_mat(StandardNormal, 7, 5, &mut FaerRng::seed_from_u64(42));
+			let B: Mat<f64> = sample_mat(StandardNormal, 7, 5, &mut FaerRng::seed_from_u64(42));
+			let C: Mat<f64> = sample_mat(StandardNormal, 7, 5, &mut FaerRng::seed_from_u64(43));
+			assert!(A == B);
+			assert!(A != C);
+
+			let rng = &mut FaerRng::seed_from_u64(0);
+			let dist = ComplexDistribution::new(StandardNormal, StandardNormal);
+			let A: Mat<c64> = sample_mat(dist, 4, 4, &mut rng.clone());
+			let B = CwiseMatDistribution { nrows: 4, ncols: 4, dist }.rand::<Mat<c64>>(rng);
+			assert!(A == B);
+		}
+
+		#[test]
+		fn test_rand_low_rank_plus_noise() {
+			let rng = &mut FaerRng::seed_from_u64(0);
+			let (m, n, r) = (40, 30, 5);
+
+			let s = (0..r).map(|i| 1.0 / (i + 1) as f64).collect::<alloc::vec::Vec<_>>();
+			let A = rand_low_rank_plus_noise::<f64, _>(m, n, &s, 1e-10, StandardNormal, rng);
+			assert!(all(A.nrows() == m, A.ncols() == n));
+
+			let computed = A.singular_values().unwrap();
+			for i in 0..r {
+				assert!((computed[i] - s[i]).abs() < 1e-8);
+			}
+			for &sigma in &computed[r..] {
+				assert!(sigma < 1e-8);
+			}
+
+			// the randomized svd recovers the planted singular values and the low rank part
+			let svd = linalg::solvers::Svd::new_randomized(A.as_ref(), r, 5, 1, rng).unwrap();
+			for i in 0..r {
+				assert!((svd.S()[i] - s[i]).abs() < 1e-8);
 			}
+			let low_rank = svd.U() * svd.S() * svd.V().adjoint();
+			assert!((&A - &low_rank).norm_max() < 1e-8);
 
-			q
+			// a flat spectrum
+			let s = alloc::vec![2.0; r];
+			let A = rand_low_rank_plus_noise::<c64, _>(m, n, &s, 1e-10, ComplexDistribution::new(StandardNormal, StandardNormal), rng);
+			let computed = A.singular_values().unwrap();
+			for i in 0..r {
+				assert!((computed[i] - s[i]).abs() < 1e-8);
+			}
+			assert!(computed[r] < 1e-8);
+		}
+
+		#[test]
+		fn test_sample_mvn() {
+			let rng = &mut FaerRng::seed_from_u64(0);
+			let n_samples = 20000;
+
+			let mean = crate::col![1.0, -2.0, 0.5];
+			let cov = crate::mat![[4.0, 1.0, -0.5], [1.0, 2.0, 0.3], [-0.5, 0.3, 1.0f64]];
+			let cov_chol = cov.llt(Side::Lower).unwrap();
+
+			let X = sample_mvn(mean.as_ref(), &cov_chol, n_samples, StandardNormal, rng);
+			assert!(all(X.nrows() == 3, X.ncols() == n_samples));
+
+			let mut mu = Col::<f64>::zeros(3);
+			crate::stats::col_mean(mu.as_mut(), X.as_ref(), crate::stats::NanHandling::Propagate);
+			assert!((&mu - &mean).norm_max() < 5e-2);
+
+			let mut C = Mat::<f64>::zeros(3, 3);
+			for j in 0..n_samples {
+				let x = X.col(j) - &mu;
+				C += &x * x.transpose();
+			}
+			C /= (n_samples - 1) as f64;
+			assert!((&C - &cov).norm_max() < 1e-1);
 		}
 	}
 }
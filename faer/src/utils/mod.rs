@@ -3,6 +3,13 @@
 /// compile-time bound-checked indexing types
 pub mod bound;
 
+#[cold]
+#[inline(never)]
+#[track_caller]
+pub(crate) fn panic_dim_mismatch(what: &str, expected: usize, got: usize) -> ! {
+	panic!("expected {what} == {expected} but got {got}")
+}
+
 #[doc(hidden)]
 pub mod thread {
 	use crate::*;